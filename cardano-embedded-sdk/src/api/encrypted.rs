@@ -0,0 +1,561 @@
+//! Password encrypted keys
+//!
+//! Container to move an extended private key, or an entropy, between
+//! devices without exposing it:
+//!
+//! | size | content                                          |
+//! |------|--------------------------------------------------|
+//! | 4    | magic `EMBK`                                     |
+//! | 1    | version, `1`                                     |
+//! | 1    | content: `1` extended private key, `2` entropy   |
+//! | 1    | KDF: `1` PBKDF2-HMAC-SHA512                      |
+//! | 4    | KDF rounds, big endian                           |
+//! | 32   | salt                                             |
+//! | 12   | nonce                                            |
+//! | n    | encrypted content                                |
+//! | 16   | Poly1305 tag                                     |
+//!
+//! The encryption key is derived from the password and the salt, the
+//! content is encrypted with ChaCha20-Poly1305 and the header is
+//! authenticated with it: a container whose rounds, or any other header
+//! field, were changed fails to decrypt. The rounds are only authenticated
+//! once the key is derived, so [`decrypt`] refuses more than [`MAX_ROUNDS`]
+//! before running PBKDF2. The password is used as given, it is not
+//! normalized.
+//!
+//! [`decrypt_emip3`] reads the encrypted root keys of Yoroi (EMIP-3:
+//! `salt | nonce | tag | encrypted content`, 19162 rounds of PBKDF2).
+//!
+//! The functions here write into caller provided buffers and do not need
+//! the `alloc` feature. With it, `XPrvKey` and `Entropy` get `encrypt` and
+//! `decrypt` methods.
+//!
+//! # Example
+//!
+//! ```
+//! use cardano_embedded_sdk::api::encrypted::{self, Content, DEFAULT_ROUNDS, OVERHEAD};
+//!
+//! let entropy = [0x0c; 16];
+//! let mut container = [0; 16 + OVERHEAD];
+//! let rng = rand::rngs::OsRng;
+//! encrypted::encrypt(Content::Entropy, &entropy, b"embedano", DEFAULT_ROUNDS, rng, &mut container).unwrap();
+//!
+//! let mut out = [0; 16];
+//! let (content, decrypted) = encrypted::decrypt(&container, b"embedano", &mut out).unwrap();
+//! assert_eq!((content, decrypted), (Content::Entropy, &entropy[..]));
+//! ```
+use core::{error, fmt};
+
+use cryptoxide::{chacha20poly1305::ChaCha20Poly1305, hmac::Hmac, pbkdf2::pbkdf2, sha2::Sha512};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::util::securemem;
+
+const MAGIC: [u8; 4] = *b"EMBK";
+const KDF_PBKDF2_SHA512: u8 = 1;
+const SALT_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+const KEY_SIZE: usize = 32;
+
+/// version of the container written by [`encrypt`].
+pub const VERSION: u8 = 1;
+
+/// size of the header, before the encrypted content.
+pub const HEADER_SIZE: usize = MAGIC.len() + 3 + 4 + SALT_SIZE + NONCE_SIZE;
+
+/// size of a container minus the size of its content.
+pub const OVERHEAD: usize = HEADER_SIZE + TAG_SIZE;
+
+/// PBKDF2 rounds, the same as EMIP-3. A few seconds on a Cortex-M4.
+pub const DEFAULT_ROUNDS: u32 = 19_162;
+
+/// PBKDF2 rounds accepted by [`encrypt`] and [`decrypt`], about ten times
+/// `DEFAULT_ROUNDS`.
+pub const MAX_ROUNDS: u32 = 200_000;
+
+/// PBKDF2 rounds of EMIP-3.
+pub const EMIP3_ROUNDS: u32 = 19_162;
+
+/// size of EMIP-3 encrypted data minus the size of its content.
+pub const EMIP3_OVERHEAD: usize = SALT_SIZE + NONCE_SIZE + TAG_SIZE;
+
+/// What a container holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Content {
+    /// extended private key: `kL | kR | cc` (96 bytes)
+    XPrv,
+    /// BIP39 entropy
+    Entropy,
+}
+
+impl Content {
+    fn to_byte(self) -> u8 {
+        match self {
+            Content::XPrv => 1,
+            Content::Entropy => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            1 => Some(Content::XPrv),
+            2 => Some(Content::Entropy),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// the output buffer needs at least `needed` bytes
+    BufferTooSmall {
+        needed: usize,
+    },
+    /// not a container, or truncated
+    InvalidFormat,
+    UnsupportedVersion(u8),
+    UnsupportedKdf(u8),
+    /// the KDF rounds are not between 1 and `MAX_ROUNDS`
+    InvalidRounds,
+    /// wrong password, or corrupted container
+    DecryptionFailed,
+    /// the container does not hold the expected content
+    UnexpectedContent(Content),
+    /// the decrypted content is not a valid key or entropy
+    InvalidContent,
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::BufferTooSmall { needed } => {
+                write!(f, "output buffer is too small, {needed} bytes needed")
+            }
+            Error::InvalidFormat => write!(f, "not an encrypted key container"),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported container version {version}")
+            }
+            Error::UnsupportedKdf(kdf) => write!(f, "unsupported key derivation function {kdf}"),
+            Error::InvalidRounds => {
+                write!(
+                    f,
+                    "key derivation rounds are not between 1 and {MAX_ROUNDS}"
+                )
+            }
+            Error::DecryptionFailed => write!(f, "wrong password or corrupted data"),
+            Error::UnexpectedContent(content) => {
+                write!(f, "unexpected content {content:?} in the container")
+            }
+            Error::InvalidContent => write!(f, "invalid decrypted content"),
+        }
+    }
+}
+impl error::Error for Error {}
+
+fn derive_key(password: &[u8], salt: &[u8], rounds: u32) -> [u8; KEY_SIZE] {
+    let mut key = [0; KEY_SIZE];
+    let mut mac = Hmac::new(Sha512::new(), password);
+    pbkdf2(&mut mac, salt, rounds, &mut key);
+    key
+}
+
+/// Encrypt `plaintext` in `out`, return the size of the container
+/// (`plaintext.len() + OVERHEAD`). `rng` draws the salt and the nonce.
+pub fn encrypt<R: RngCore + CryptoRng>(
+    content: Content,
+    plaintext: &[u8],
+    password: &[u8],
+    rounds: u32,
+    mut rng: R,
+    out: &mut [u8],
+) -> Result<usize, Error> {
+    let size = plaintext.len() + OVERHEAD;
+    let out = out
+        .get_mut(..size)
+        .ok_or(Error::BufferTooSmall { needed: size })?;
+    if !(1..=MAX_ROUNDS).contains(&rounds) {
+        return Err(Error::InvalidRounds);
+    }
+
+    let (header, rest) = out.split_at_mut(HEADER_SIZE);
+    header[..4].copy_from_slice(&MAGIC);
+    header[4] = VERSION;
+    header[5] = content.to_byte();
+    header[6] = KDF_PBKDF2_SHA512;
+    header[7..11].copy_from_slice(&rounds.to_be_bytes());
+    rng.fill_bytes(&mut header[11..]);
+    let (salt, nonce) = header[11..].split_at(SALT_SIZE);
+
+    let mut key = derive_key(password, salt, rounds);
+    let (ciphertext, tag) = rest.split_at_mut(plaintext.len());
+    ChaCha20Poly1305::new(&key, nonce, header).encrypt(plaintext, ciphertext, tag);
+    securemem::zero(&mut key);
+    Ok(size)
+}
+
+/// Decrypt a container in `out`, return its content type and the
+/// decrypted bytes (`container.len() - OVERHEAD`).
+pub fn decrypt<'a>(
+    container: &[u8],
+    password: &[u8],
+    out: &'a mut [u8],
+) -> Result<(Content, &'a [u8]), Error> {
+    if container.len() < OVERHEAD || container[..4] != MAGIC {
+        return Err(Error::InvalidFormat);
+    }
+    if container[4] != VERSION {
+        return Err(Error::UnsupportedVersion(container[4]));
+    }
+    let content = Content::from_byte(container[5]).ok_or(Error::InvalidFormat)?;
+    if container[6] != KDF_PBKDF2_SHA512 {
+        return Err(Error::UnsupportedKdf(container[6]));
+    }
+    let rounds = u32::from_be_bytes(container[7..11].try_into().unwrap());
+    if !(1..=MAX_ROUNDS).contains(&rounds) {
+        return Err(Error::InvalidRounds);
+    }
+
+    let (header, rest) = container.split_at(HEADER_SIZE);
+    let (salt, nonce) = header[11..].split_at(SALT_SIZE);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_SIZE);
+    open(ciphertext, tag, password, salt, nonce, rounds, header, out).map(|out| (content, &*out))
+}
+
+/// Encrypt `plaintext` as EMIP-3 (Yoroi) in `out`, return the size of the
+/// encrypted data (`plaintext.len() + EMIP3_OVERHEAD`).
+pub fn encrypt_emip3<R: RngCore + CryptoRng>(
+    plaintext: &[u8],
+    password: &[u8],
+    mut rng: R,
+    out: &mut [u8],
+) -> Result<usize, Error> {
+    let size = plaintext.len() + EMIP3_OVERHEAD;
+    let out = out
+        .get_mut(..size)
+        .ok_or(Error::BufferTooSmall { needed: size })?;
+
+    let (params, rest) = out.split_at_mut(SALT_SIZE + NONCE_SIZE);
+    rng.fill_bytes(params);
+    let (salt, nonce) = params.split_at(SALT_SIZE);
+    let (tag, ciphertext) = rest.split_at_mut(TAG_SIZE);
+
+    let mut key = derive_key(password, salt, EMIP3_ROUNDS);
+    ChaCha20Poly1305::new(&key, nonce, &[]).encrypt(plaintext, ciphertext, tag);
+    securemem::zero(&mut key);
+    Ok(size)
+}
+
+/// Decrypt EMIP-3 (Yoroi) encrypted data in `out`, return the decrypted
+/// bytes (`data.len() - EMIP3_OVERHEAD`).
+pub fn decrypt_emip3<'a>(
+    data: &[u8],
+    password: &[u8],
+    out: &'a mut [u8],
+) -> Result<&'a [u8], Error> {
+    if data.len() < EMIP3_OVERHEAD {
+        return Err(Error::InvalidFormat);
+    }
+    let (salt, rest) = data.split_at(SALT_SIZE);
+    let (nonce, rest) = rest.split_at(NONCE_SIZE);
+    let (tag, ciphertext) = rest.split_at(TAG_SIZE);
+    open(
+        ciphertext,
+        tag,
+        password,
+        salt,
+        nonce,
+        EMIP3_ROUNDS,
+        &[],
+        out,
+    )
+    .map(|out| &*out)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn open<'a>(
+    ciphertext: &[u8],
+    tag: &[u8],
+    password: &[u8],
+    salt: &[u8],
+    nonce: &[u8],
+    rounds: u32,
+    aad: &[u8],
+    out: &'a mut [u8],
+) -> Result<&'a mut [u8], Error> {
+    let needed = ciphertext.len();
+    let out = out
+        .get_mut(..needed)
+        .ok_or(Error::BufferTooSmall { needed })?;
+
+    let mut key = derive_key(password, salt, rounds);
+    let authentic = ChaCha20Poly1305::new(&key, nonce, aad).decrypt(ciphertext, out, tag);
+    securemem::zero(&mut key);
+    if !authentic {
+        securemem::zero(out);
+        return Err(Error::DecryptionFailed);
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "alloc")]
+mod keys {
+    use alloc::{vec, vec::Vec};
+
+    use rand_core::{CryptoRng, RngCore};
+
+    use super::{decrypt, decrypt_emip3, encrypt, Content, Error, OVERHEAD};
+    use crate::{
+        bip::bip39::Entropy,
+        types::{KeyFormat, XPrvKey},
+        util::securemem,
+    };
+
+    fn decrypt_to_vec(container: &[u8], password: &[u8]) -> Result<(Content, Vec<u8>), Error> {
+        let mut out = vec![0; container.len().saturating_sub(OVERHEAD)];
+        let content = decrypt(container, password, &mut out)?.0;
+        Ok((content, out))
+    }
+
+    fn encrypt_to_vec<R: RngCore + CryptoRng>(
+        content: Content,
+        plaintext: &[u8],
+        password: &[u8],
+        rounds: u32,
+        rng: R,
+    ) -> Result<Vec<u8>, Error> {
+        let mut container = vec![0; plaintext.len() + OVERHEAD];
+        encrypt(content, plaintext, password, rounds, rng, &mut container)?;
+        Ok(container)
+    }
+
+    impl XPrvKey {
+        /// Encrypt the key with `password`, see [`encrypted`](crate::api::encrypted).
+        pub fn encrypt<R: RngCore + CryptoRng>(
+            &self,
+            password: &[u8],
+            rounds: u32,
+            rng: R,
+        ) -> Result<Vec<u8>, Error> {
+            let mut bytes = self.to_format(KeyFormat::Extended);
+            let container = encrypt_to_vec(Content::XPrv, &bytes, password, rounds, rng);
+            securemem::zero(&mut bytes);
+            container
+        }
+
+        /// Decrypt a key encrypted by [`encrypt`](XPrvKey::encrypt).
+        pub fn decrypt(container: &[u8], password: &[u8]) -> Result<XPrvKey, Error> {
+            let (content, mut bytes) = decrypt_to_vec(container, password)?;
+            let key = match content {
+                Content::XPrv => XPrvKey::from_format(&bytes, KeyFormat::Extended)
+                    .map_err(|_| Error::InvalidContent),
+                content => Err(Error::UnexpectedContent(content)),
+            };
+            securemem::zero(&mut bytes);
+            key
+        }
+
+        /// Import a root key encrypted by Yoroi (EMIP-3).
+        pub fn from_emip3(data: &[u8], password: &[u8]) -> Result<XPrvKey, Error> {
+            let mut bytes = [0; 96];
+            let key = decrypt_emip3(data, password, &mut bytes).and_then(|bytes| {
+                XPrvKey::from_format(bytes, KeyFormat::Extended).map_err(|_| Error::InvalidContent)
+            });
+            securemem::zero(&mut bytes);
+            key
+        }
+    }
+
+    impl Entropy {
+        /// Encrypt the entropy with `password`, see [`encrypted`](crate::api::encrypted).
+        pub fn encrypt<R: RngCore + CryptoRng>(
+            &self,
+            password: &[u8],
+            rounds: u32,
+            rng: R,
+        ) -> Result<Vec<u8>, Error> {
+            encrypt_to_vec(Content::Entropy, self, password, rounds, rng)
+        }
+
+        /// Decrypt an entropy encrypted by [`encrypt`](Entropy::encrypt).
+        pub fn decrypt(container: &[u8], password: &[u8]) -> Result<Entropy, Error> {
+            let (content, mut bytes) = decrypt_to_vec(container, password)?;
+            let entropy = match content {
+                Content::Entropy => Entropy::from_slice(&bytes).map_err(|_| Error::InvalidContent),
+                content => Err(Error::UnexpectedContent(content)),
+            };
+            securemem::zero(&mut bytes);
+            entropy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    const ROUNDS: u32 = 8;
+
+    fn rng() -> ChaChaRng {
+        ChaChaRng::seed_from_u64(42)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let secret = [0x5a; 96];
+        let mut container = [0; 96 + OVERHEAD];
+        let size = encrypt(Content::XPrv, &secret, b"pw", ROUNDS, rng(), &mut container).unwrap();
+        assert_eq!(size, container.len());
+        assert_eq!(container[..5], *b"EMBK\x01");
+
+        let mut out = [0; 96];
+        let (content, decrypted) = decrypt(&container, b"pw", &mut out).unwrap();
+        assert_eq!((content, decrypted), (Content::XPrv, &secret[..]));
+    }
+
+    #[test]
+    fn test_wrong_password_or_tampering() {
+        let mut container = [0; 16 + OVERHEAD];
+        encrypt(
+            Content::Entropy,
+            &[1; 16],
+            b"pw",
+            ROUNDS,
+            rng(),
+            &mut container,
+        )
+        .unwrap();
+        let mut out = [0xff; 16];
+        assert_eq!(
+            decrypt(&container, b"other", &mut out),
+            Err(Error::DecryptionFailed)
+        );
+        assert_eq!(out, [0; 16]);
+
+        // the header is authenticated: content type or rounds cannot change
+        for i in [5, 10, 20, HEADER_SIZE, container.len() - 1] {
+            let mut tampered = container;
+            tampered[i] ^= 1;
+            assert!(decrypt(&tampered, b"pw", &mut out).is_err());
+        }
+    }
+
+    #[test]
+    fn test_invalid_containers() {
+        let mut container = [0; 16 + OVERHEAD];
+        let mut out = [0; 16];
+        assert_eq!(
+            encrypt(Content::Entropy, &[1; 16], b"pw", 0, rng(), &mut container),
+            Err(Error::InvalidRounds)
+        );
+        assert_eq!(
+            encrypt(
+                Content::Entropy,
+                &[1; 16],
+                b"pw",
+                MAX_ROUNDS + 1,
+                rng(),
+                &mut container
+            ),
+            Err(Error::InvalidRounds)
+        );
+        assert_eq!(
+            encrypt(Content::Entropy, &[1; 17], b"pw", 1, rng(), &mut container),
+            Err(Error::BufferTooSmall {
+                needed: 17 + OVERHEAD
+            })
+        );
+        encrypt(
+            Content::Entropy,
+            &[1; 16],
+            b"pw",
+            ROUNDS,
+            rng(),
+            &mut container,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decrypt(&container[..OVERHEAD - 1], b"pw", &mut out),
+            Err(Error::InvalidFormat)
+        );
+        assert_eq!(
+            decrypt(&container, b"pw", &mut out[..15]),
+            Err(Error::BufferTooSmall { needed: 16 })
+        );
+        let mut other = container;
+        other[4] = 2;
+        assert_eq!(
+            decrypt(&other, b"pw", &mut out),
+            Err(Error::UnsupportedVersion(2))
+        );
+        let mut other = container;
+        other[6] = 2;
+        assert_eq!(
+            decrypt(&other, b"pw", &mut out),
+            Err(Error::UnsupportedKdf(2))
+        );
+        // refused before deriving the key, which would take hours
+        for rounds in [0, MAX_ROUNDS + 1, u32::MAX] {
+            let mut other = container;
+            other[7..11].copy_from_slice(&rounds.to_be_bytes());
+            assert_eq!(decrypt(&other, b"pw", &mut out), Err(Error::InvalidRounds));
+        }
+    }
+
+    #[test]
+    fn test_emip3_vector() {
+        // salt | nonce | tag | encrypted "some data to encrypt", password "password"
+        let mut data = [0; 20 + EMIP3_OVERHEAD];
+        hex::decode_to_slice(
+            "50515253c0c1c2c3c4c5c6c750515253c0c1c2c3c4c5c6c750515253c0c1c2c3\
+             50515253c0c1c2c3c4c5c6c7\
+             c266630887d216bf88cc4990f73bad7f\
+             35bc7c0225b38fe24a7c28b5f9bda6283e3c5768",
+            &mut data,
+        )
+        .unwrap();
+        let mut out = [0; 20];
+        assert_eq!(
+            decrypt_emip3(&data, b"password", &mut out),
+            Ok(&b"some data to encrypt"[..])
+        );
+        assert_eq!(
+            decrypt_emip3(&data, b"Password", &mut out),
+            Err(Error::DecryptionFailed)
+        );
+
+        let mut encrypted = [0; 20 + EMIP3_OVERHEAD];
+        encrypt_emip3(b"some data to encrypt", b"password", rng(), &mut encrypted).unwrap();
+        assert_eq!(
+            decrypt_emip3(&encrypted, b"password", &mut out),
+            Ok(&b"some data to encrypt"[..])
+        );
+    }
+
+    #[cfg(feature = "bip39-english")]
+    #[test]
+    fn test_keys() {
+        use crate::{bip::bip39::Entropy, util::slip14};
+
+        let (prv_key, _) = slip14::make_address_keys();
+        let container = prv_key.encrypt(b"pw", ROUNDS, rng()).unwrap();
+        let decrypted = crate::types::XPrvKey::decrypt(&container, b"pw").unwrap();
+        assert_eq!(decrypted.to_hex(), prv_key.to_hex());
+
+        let entropy = Entropy::from_slice(&[0x0c; 20]).unwrap();
+        let container = entropy.encrypt(b"pw", ROUNDS, rng()).unwrap();
+        assert_eq!(Entropy::decrypt(&container, b"pw").unwrap(), entropy);
+        assert_eq!(
+            crate::types::XPrvKey::decrypt(&container, b"pw").err(),
+            Some(Error::UnexpectedContent(Content::Entropy))
+        );
+
+        let mut emip3 = [0; 96 + EMIP3_OVERHEAD];
+        let xprv = prv_key.to_format(crate::types::KeyFormat::Extended);
+        encrypt_emip3(&xprv, b"pw", rng(), &mut emip3).unwrap();
+        let imported = crate::types::XPrvKey::from_emip3(&emip3, b"pw").unwrap();
+        assert_eq!(imported.to_hex(), prv_key.to_hex());
+    }
+}
@@ -0,0 +1,255 @@
+//! Heap-free API
+//!
+//! The same operations as the [`api`](../index.html) functions, for firmware
+//! built without the `alloc` feature (and so without a global allocator):
+//! - entropy is given as raw bytes (`Entropy` derefs to `[u8]`)
+//! - derivation path is given as a slice of already parsed indexes, use
+//!   [`harden`](./fn.harden.html) for the hardened ones
+//! - keys and signatures are written in caller provided buffers
+//!
+//! Intermediate keys are zeroed when dropped, the normalized password lives
+//! in a stack buffer of `MAX_PASSWORD_BYTES` that is zeroed before returning.
+//!
+//! # Example
+//!
+//! ```
+//! use cardano_embedded_sdk::api::fixed::{self, harden, SIGNATURE_SIZE, XPUB_SIZE};
+//!
+//! let entropy = [0x0c; 16];
+//! let path = [harden(1852), harden(1815), harden(0), 0, 0];
+//!
+//! let mut pub_key = [0; XPUB_SIZE];
+//! fixed::export_public_key(&entropy, b"embedano", &path, &mut pub_key).unwrap();
+//!
+//! let mut signature = [0; SIGNATURE_SIZE];
+//! fixed::sign_data(b"some bytes", &entropy, b"embedano", &path, &mut signature).unwrap();
+//!
+//! let mut hex = [0; 2 * SIGNATURE_SIZE];
+//! let signature_hex: &str = fixed::to_hex(&signature, &mut hex).unwrap();
+//! ```
+use core::{error, fmt, str};
+
+use cryptoxide::{hmac::Hmac, pbkdf2::pbkdf2, sha2::Sha512};
+use ed25519_bip32::{DerivationScheme, XPrv, XPRV_SIZE};
+
+use crate::util::{nfkd, securemem};
+
+pub use ed25519_bip32::{SIGNATURE_SIZE, XPUB_SIZE};
+
+/// size of a public key without its chain code.
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// size of a transaction id (hash of the transaction body).
+pub const TX_ID_SIZE: usize = 32;
+
+/// maximum size of the NFKD normalized password.
+pub const MAX_PASSWORD_BYTES: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// the normalized password does not fit in `MAX_PASSWORD_BYTES`
+    PasswordTooLong,
+    /// the password has a character whose NFKD normalization is not
+    /// compiled in
+    UnsupportedCharacter(nfkd::UnsupportedError),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::PasswordTooLong => write!(
+                f,
+                "normalized password is longer than {} bytes",
+                MAX_PASSWORD_BYTES
+            ),
+            Error::UnsupportedCharacter(e) => write!(f, "unsupported password: {e}"),
+        }
+    }
+}
+impl error::Error for Error {}
+
+/// Harden derivation index.
+pub fn harden(i: u32) -> u32 {
+    i + 0x80000000
+}
+
+/// Derive extended private key from entropy (seed) for the given indexes.
+pub fn derive_key(entropy: &[u8], password: &[u8], path: &[u32]) -> Result<XPrv, Error> {
+    let mut key = root_key(entropy, password)?;
+    for index in path {
+        key = key.derive(DerivationScheme::V2, *index);
+    }
+    Ok(key)
+}
+
+/// Write the extended public key (public key and chain code) derived for
+/// the given indexes.
+pub fn export_public_key(
+    entropy: &[u8],
+    password: &[u8],
+    path: &[u32],
+    out: &mut [u8; XPUB_SIZE],
+) -> Result<(), Error> {
+    out.copy_from_slice(derive_key(entropy, password, path)?.public().as_ref());
+    Ok(())
+}
+
+/// Write the public key, without chain code, derived for the given indexes.
+pub fn export_raw_public_key(
+    entropy: &[u8],
+    password: &[u8],
+    path: &[u32],
+    out: &mut [u8; PUBLIC_KEY_SIZE],
+) -> Result<(), Error> {
+    *out = derive_key(entropy, password, path)?.public().public_key();
+    Ok(())
+}
+
+/// Sign binary data with the private key derived for the given indexes.
+pub fn sign_data(
+    data: &[u8],
+    entropy: &[u8],
+    password: &[u8],
+    path: &[u32],
+    signature: &mut [u8; SIGNATURE_SIZE],
+) -> Result<(), Error> {
+    let key = derive_key(entropy, password, path)?;
+    signature.copy_from_slice(key.sign::<()>(data).to_bytes());
+    Ok(())
+}
+
+/// Sign transaction id with the private key derived for the given indexes.
+pub fn sign_tx_id(
+    tx_id: &[u8; TX_ID_SIZE],
+    entropy: &[u8],
+    password: &[u8],
+    path: &[u32],
+    signature: &mut [u8; SIGNATURE_SIZE],
+) -> Result<(), Error> {
+    sign_data(tx_id, entropy, password, path, signature)
+}
+
+/// Write the hexadecimal representation of `bytes` at the start of `out`.
+/// Return `None` if `out` is shorter than twice `bytes`.
+pub fn to_hex<'a>(bytes: &[u8], out: &'a mut [u8]) -> Option<&'a str> {
+    let out = out.get_mut(..2 * bytes.len())?;
+    hex::encode_to_slice(bytes, out).ok()?;
+    str::from_utf8(out).ok()
+}
+
+fn root_key(entropy: &[u8], password: &[u8]) -> Result<XPrv, Error> {
+    const ITER: u32 = 4096;
+
+    nfkd::check_bytes(password).map_err(Error::UnsupportedCharacter)?;
+    let mut buf = [0; MAX_PASSWORD_BYTES];
+    let password = match str::from_utf8(password) {
+        Ok(password) => nfkd::normalize_into(password, &mut buf)
+            .map(str::as_bytes)
+            .ok_or(Error::PasswordTooLong),
+        Err(_) => Ok(password),
+    };
+    let result = password.map(|password| {
+        let mut pbkdf2_result = [0; XPRV_SIZE];
+        let mut mac = Hmac::new(Sha512::new(), password);
+        pbkdf2(&mut mac, entropy, ITER, &mut pbkdf2_result);
+        let xprv = XPrv::normalize_bytes_force3rd(pbkdf2_result);
+        securemem::zero(&mut pbkdf2_result);
+        xprv
+    });
+    securemem::zero(&mut buf);
+    result
+}
+
+#[cfg(all(test, feature = "bip39-english"))]
+mod tests {
+    use super::*;
+    use crate::{api, bip::bip39::Entropy, types::TxId};
+    use derivation_path::DerivationPath;
+
+    const PATH: &str = "m/1852'/1815'/0'/0/3";
+    const INDEXES: [u32; 5] = [0x8000073c, 0x80000717, 0x80000000, 0, 3];
+
+    fn entropy() -> Entropy {
+        Entropy::from_slice(&[0x5a; 16]).unwrap()
+    }
+
+    #[test]
+    fn test_same_keys_as_alloc_api() {
+        let path: DerivationPath = PATH.parse().unwrap();
+        for password in ["", "embedano", "caf\u{e9}"] {
+            if !nfkd::is_supported(password) {
+                continue;
+            }
+            let expected = api::derive_key(&entropy(), password.as_bytes(), &path)
+                .unwrap()
+                .to_public();
+
+            let mut xpub = [0; XPUB_SIZE];
+            export_public_key(&entropy(), password.as_bytes(), &INDEXES, &mut xpub).unwrap();
+            assert_eq!(&xpub[..], &expected.as_bytes()[..]);
+
+            let mut raw = [0; PUBLIC_KEY_SIZE];
+            export_raw_public_key(&entropy(), password.as_bytes(), &INDEXES, &mut raw).unwrap();
+            assert_eq!(&raw[..], &xpub[..PUBLIC_KEY_SIZE]);
+        }
+    }
+
+    #[test]
+    fn test_same_signatures_as_alloc_api() {
+        let path: DerivationPath = PATH.parse().unwrap();
+        let tx_id = [0xbb; TX_ID_SIZE];
+        let expected =
+            api::sign_tx_id(&TxId::from_bytes(&tx_id).unwrap(), &entropy(), b"pw", &path).unwrap();
+
+        let mut signature = [0; SIGNATURE_SIZE];
+        sign_tx_id(&tx_id, &entropy(), b"pw", &INDEXES, &mut signature).unwrap();
+        assert_eq!(signature, expected.to_bytes()[..]);
+
+        let mut hex = [0; 2 * SIGNATURE_SIZE];
+        assert_eq!(
+            to_hex(&signature, &mut hex),
+            Some(expected.to_hex().as_str())
+        );
+        assert_eq!(to_hex(&signature, &mut hex[1..]), None);
+    }
+
+    #[test]
+    fn test_hardened_indexes() {
+        assert_eq!([harden(1852), harden(1815), harden(0), 0, 3], INDEXES);
+    }
+
+    #[test]
+    fn test_password_too_long() {
+        let password = [b'a'; MAX_PASSWORD_BYTES + 1];
+        let mut signature = [0; SIGNATURE_SIZE];
+        assert_eq!(
+            sign_data(b"data", &entropy(), &password, &INDEXES, &mut signature),
+            Err(Error::PasswordTooLong)
+        );
+        assert!(sign_data(
+            b"data",
+            &entropy(),
+            &password[1..],
+            &INDEXES,
+            &mut signature
+        )
+        .is_ok());
+    }
+
+    #[cfg(not(feature = "nfkd-latin"))]
+    #[test]
+    fn test_unsupported_password() {
+        let mut signature = [0; SIGNATURE_SIZE];
+        assert_eq!(
+            sign_data(
+                b"data",
+                &entropy(),
+                "caf\u{e9}".as_bytes(),
+                &INDEXES,
+                &mut signature
+            ),
+            Err(Error::UnsupportedCharacter(nfkd::UnsupportedError(
+                '\u{e9}'
+            )))
+        );
+    }
+}
@@ -9,15 +9,33 @@
 //! that can be found in `types.rs`.
 //!
 //! Examples can be found in docs for each function.
+//!
+//! The functions deriving keys fail with an [`UnsupportedError`] if the
+//! password has characters whose NFKD normalization is not compiled in, see
+//! [`nfkd`](crate::util::nfkd).
+//!
+//! The functions of this module need the `alloc` feature, see [`fixed`](./fixed/index.html)
+//! for their heap-free counterparts. Use a [`session`](./session/index.html) to keep the
+//! keys derived between requests, and [`encrypted`](./encrypted/index.html) to move keys
+//! between devices.
 
+pub mod encrypted;
+pub mod fixed;
+#[cfg(feature = "alloc")]
+pub mod session;
+
+#[cfg(feature = "alloc")]
 use crate::{
     bip::bip39::Entropy,
     crypto::Ed25519Signature,
     types::{harden, TxId, XPrvKey, XPubKey},
+    util::nfkd::UnsupportedError,
 };
 
+#[cfg(feature = "alloc")]
 use derivation_path::{ChildIndex, DerivationPath};
 
+#[cfg(feature = "alloc")]
 const EXTERNAL_CHAIN_CODE: u32 = 0;
 
 /// Derive extended private key from entropy (seed) for specified derivation path.
@@ -33,14 +51,19 @@ const EXTERNAL_CHAIN_CODE: u32 = 0;
 /// let password = b"embedano";
 /// let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
 ///
-/// let private_key: XPrvKey  = embedano::derive_key(&entropy, password, &path);
+/// let private_key: XPrvKey  = embedano::derive_key(&entropy, password, &path).unwrap();
 /// ```
-pub fn derive_key(entropy: &Entropy, password: &[u8], path: &DerivationPath) -> XPrvKey {
-    let mut key = XPrvKey::from_entropy(entropy, password);
+#[cfg(feature = "alloc")]
+pub fn derive_key(
+    entropy: &Entropy,
+    password: &[u8],
+    path: &DerivationPath,
+) -> Result<XPrvKey, UnsupportedError> {
+    let mut key = XPrvKey::from_entropy(entropy, password)?;
     for index in path.into_iter().map(adjust_hardened) {
         key = key.derive(index);
     }
-    key
+    Ok(key)
 }
 
 /// Extension of `derive_key` that also returns public key.
@@ -55,16 +78,17 @@ pub fn derive_key(entropy: &Entropy, password: &[u8], path: &DerivationPath) ->
 /// let entropy = Entropy::from_mnemonics(&mnemonics).unwrap();
 /// let password = b"embedano";
 /// let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
-/// let (prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
+/// let (prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path).unwrap();
 /// ```
+#[cfg(feature = "alloc")]
 pub fn derive_key_pair(
     entropy: &Entropy,
     password: &[u8],
     path: &DerivationPath,
-) -> (XPrvKey, XPubKey) {
-    let private = derive_key(entropy, password, path);
+) -> Result<(XPrvKey, XPubKey), UnsupportedError> {
+    let private = derive_key(entropy, password, path)?;
     let public = private.to_public();
-    (private, public)
+    Ok((private, public))
 }
 
 /// Sign transaction id with private key derived for provided path.
@@ -82,14 +106,15 @@ pub fn derive_key_pair(
 /// let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
 ///
 /// let tx_id = TxId::from_hex("bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb").unwrap();
-/// let signature = embedano::sign_tx_id(&tx_id, &entropy, password, &path);
+/// let signature = embedano::sign_tx_id(&tx_id, &entropy, password, &path).unwrap();
 /// ```
+#[cfg(feature = "alloc")]
 pub fn sign_tx_id(
     tx_id: &TxId,
     entropy: &Entropy,
     password: &[u8],
     path: &DerivationPath,
-) -> Ed25519Signature {
+) -> Result<Ed25519Signature, UnsupportedError> {
     sign_data(tx_id.to_bytes(), entropy, password, path)
 }
 
@@ -106,21 +131,23 @@ pub fn sign_tx_id(
 /// let password = b"embedano";
 /// let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
 ///
-/// let signature = embedano::sign_data(b"some bytes", &entropy, password, &path);
+/// let signature = embedano::sign_data(b"some bytes", &entropy, password, &path).unwrap();
 /// ```
+#[cfg(feature = "alloc")]
 pub fn sign_data(
     data: &[u8],
     entropy: &Entropy,
     password: &[u8],
     path: &DerivationPath,
-) -> Ed25519Signature {
-    derive_key(entropy, password, path).sign(data)
+) -> Result<Ed25519Signature, UnsupportedError> {
+    Ok(derive_key(entropy, password, path)?.sign(data))
 }
 
 /// Prove public key ownership (account or address level) by signing nonce. See also `KeyType` docs.
 ///
 /// If function returns `Some(signature)`, caller should be able to verify signature
-/// with public key provided as function argument.
+/// with public key provided as function argument. `None` is also returned for a
+/// password with unsupported characters.
 /// To control how deep to search for the corresponding private key `KeyType`
 /// parameter should be specified.
 /// # Example
@@ -137,11 +164,12 @@ pub fn sign_data(
 /// let nonce = "some nonce".as_bytes();
 /// let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
 ///
-/// let (_, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
+/// let (_, pub_key) = embedano::derive_key_pair(&entropy, password, &path).unwrap();
 /// let key_type = embedano::KeyType::AddressKey { account_gap: 5, address_gap: 5};
 /// let proof = embedano::prove_ownership(&nonce, &pub_key, &entropy, password,key_type).unwrap();
 /// assert!(&pub_key.verify(nonce, &proof))
 /// ```
+#[cfg(feature = "alloc")]
 pub fn prove_ownership(
     nonce: &[u8],
     payment_key: &XPubKey,
@@ -149,11 +177,59 @@ pub fn prove_ownership(
     password: &[u8],
     key_type: KeyType,
 ) -> Option<Ed25519Signature> {
-    let root_key = XPrvKey::from_entropy(entropy, password);
-    find_matching_private_key(payment_key, &root_key, key_type).map(|key| key.sign(nonce))
+    prove_ownership_with_mode(
+        nonce,
+        payment_key,
+        entropy,
+        password,
+        key_type,
+        SearchMode::FirstMatch,
+    )
+}
+
+/// Extension of `prove_ownership` where the search strategy is given by `SearchMode`.
+///
+/// With `SearchMode::ScanAll` the time taken does not reveal the index of the
+/// private key corresponding to the public key.
+/// # Example
+/// ```
+/// use cardano_embedded_sdk::bip::bip39::{dictionary, Entropy, Mnemonics};
+/// use cardano_embedded_sdk::api::{self as embedano, KeyType, SearchMode};
+/// use derivation_path::{DerivationPath};
+///
+/// let mnemonics = "aim wool into nose tell ball arm expand design push elevator multiply glove lonely minimum";
+/// let mnemonics = Mnemonics::from_string(&dictionary::ENGLISH, mnemonics).unwrap();
+/// let entropy = Entropy::from_mnemonics(&mnemonics).unwrap();
+/// let password = b"embedano";
+/// let nonce = "some nonce".as_bytes();
+/// let path: DerivationPath = "m/1852'/1815'/1'/0/2".parse().unwrap();
+///
+/// let (_, pub_key) = embedano::derive_key_pair(&entropy, password, &path).unwrap();
+/// let key_type = KeyType::AddressKey { account_gap: 2, address_gap: 2 };
+/// let proof = embedano::prove_ownership_with_mode(
+///     &nonce, &pub_key, &entropy, password, key_type, SearchMode::ScanAll,
+/// ).unwrap();
+/// assert!(&pub_key.verify(nonce, &proof))
+/// ```
+#[cfg(feature = "alloc")]
+pub fn prove_ownership_with_mode(
+    nonce: &[u8],
+    payment_key: &XPubKey,
+    entropy: &Entropy,
+    password: &[u8],
+    key_type: KeyType,
+    mode: SearchMode,
+) -> Option<Ed25519Signature> {
+    let root_key = XPrvKey::from_entropy(entropy, password).ok()?;
+    let key = match mode {
+        SearchMode::FirstMatch => find_matching_private_key(payment_key, &root_key, key_type),
+        SearchMode::ScanAll => scan_matching_private_key(payment_key, &root_key, key_type),
+    };
+    key.map(|key| key.sign(nonce))
 }
 
 // Helper function for `prove_ownership`
+#[cfg(feature = "alloc")]
 fn find_matching_private_key(
     pub_key: &XPubKey,
     root_key: &XPrvKey,
@@ -192,6 +268,53 @@ fn find_matching_private_key(
     }
 }
 
+// Helper function for `prove_ownership_with_mode`, unlike `find_matching_private_key`
+// all the keys of the gap are derived and compared whichever one matches.
+#[cfg(feature = "alloc")]
+fn scan_matching_private_key(
+    pub_key: &XPubKey,
+    root_key: &XPrvKey,
+    key_type: KeyType,
+) -> Option<XPrvKey> {
+    let level_2_key = root_key.derive(harden(1852)).derive(harden(1815));
+    let account_key = |i: u32| level_2_key.derive(harden(i));
+    match key_type {
+        KeyType::AccountKey { account_gap } => {
+            let candidates = (0..=account_gap).map(|i| account_key(i).to_public());
+            let index = pub_key.position_in(candidates)?;
+            Some(account_key(index as u32))
+        }
+
+        KeyType::AddressKey {
+            account_gap,
+            address_gap,
+        } => {
+            let candidates = (0..=account_gap).flat_map(|i| {
+                let chain_key = account_key(i).derive(EXTERNAL_CHAIN_CODE);
+                (0..=address_gap)
+                    .map(move |address_index| chain_key.derive(address_index).to_public())
+            });
+            let index = pub_key.position_in(candidates)?;
+            let addresses = address_gap as usize + 1;
+            let addr_key = account_key((index / addresses) as u32)
+                .derive(EXTERNAL_CHAIN_CODE)
+                .derive((index % addresses) as u32);
+            Some(addr_key)
+        }
+    }
+}
+
+/// Search strategy of `prove_ownership_with_mode`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Stop at the first key matching the public key, as `prove_ownership` does.
+    FirstMatch,
+    /// Derive and compare every key allowed by the `KeyType` gaps, so the time
+    /// taken only depends on the gaps.
+    ScanAll,
+}
+
 /// Allows to specify gaps for account and address indexes for `prove_ownership` function.
 ///
 /// `prove_ownership` uses algorithm similar to account discovery (BIP-0044) to find
@@ -199,6 +322,7 @@ fn find_matching_private_key(
 /// `KeyType` allows to limit number of account and address indexes to iterate
 /// through while deriving private key.
 /// Usually account discovery uses indexes from 0 to 20 (inclusive).
+#[cfg(feature = "alloc")]
 #[derive(Clone)]
 pub enum KeyType {
     /// Use together with `prove_ownership` to limit the number of account indexes to search.
@@ -208,6 +332,7 @@ pub enum KeyType {
     AddressKey { account_gap: u32, address_gap: u32 },
 }
 
+#[cfg(feature = "alloc")]
 fn adjust_hardened(index: &ChildIndex) -> u32 {
     match *index {
         ChildIndex::Hardened(i) => harden(i),
@@ -216,7 +341,7 @@ fn adjust_hardened(index: &ChildIndex) -> u32 {
 }
 
 // todo: more tests
-#[cfg(test)]
+#[cfg(all(test, feature = "bip39-english"))]
 mod tests {
     use crate::{
         api::KeyType::*,
@@ -329,6 +454,65 @@ mod tests {
         assert_eq!((None, None), (check1, check2))
     }
 
+    #[test]
+    fn test_scan_all_matches_first_match() {
+        let entropy = slip14::make_entropy();
+        let nonce = "some test nonce".as_bytes();
+        let cases = [
+            ("m/1852'/1815'/4'", AccountKey { account_gap: 6 }, true),
+            ("m/1852'/1815'/7'", AccountKey { account_gap: 6 }, false),
+            (
+                "m/1852'/1815'/3'/0/2",
+                AddressKey {
+                    account_gap: 4,
+                    address_gap: 3,
+                },
+                true,
+            ),
+            (
+                "m/1852'/1815'/4'/0/0",
+                AddressKey {
+                    account_gap: 4,
+                    address_gap: 3,
+                },
+                true,
+            ),
+            (
+                "m/1852'/1815'/3'/0/4",
+                AddressKey {
+                    account_gap: 4,
+                    address_gap: 3,
+                },
+                false,
+            ),
+        ];
+        for (path, key_type, found) in cases {
+            let path: DerivationPath = path.parse().unwrap();
+            let (_, x_pub) = slip14::make_keys_for(&path);
+            let first = prove_ownership_with_mode(
+                nonce,
+                &x_pub,
+                &entropy,
+                b"",
+                key_type.clone(),
+                SearchMode::FirstMatch,
+            );
+            let scan = prove_ownership_with_mode(
+                nonce,
+                &x_pub,
+                &entropy,
+                b"",
+                key_type,
+                SearchMode::ScanAll,
+            );
+            assert_eq!(scan.is_some(), found, "{path}");
+            assert_eq!(first, scan, "{path}");
+            if let Some(signature) = scan {
+                assert!(x_pub.verify(nonce, &signature));
+            }
+        }
+    }
+
     #[test]
     fn test_sign_tx_id() {
         let mnemonics = "aim wool into nose tell ball arm expand design push elevator multiply glove lonely minimum";
@@ -340,9 +524,9 @@ mod tests {
                 .unwrap();
         let password = b"embedano";
 
-        let signature = sign_tx_id(&tx_id, &entropy, password, &path);
+        let signature = sign_tx_id(&tx_id, &entropy, password, &path).unwrap();
 
-        let (_, pub_key) = derive_key_pair(&entropy, password, &path);
+        let (_, pub_key) = derive_key_pair(&entropy, password, &path).unwrap();
         assert!(pub_key.verify(tx_id.to_bytes(), &signature))
     }
 
@@ -356,13 +540,13 @@ mod tests {
 
         // check account level exploration consistency
         let path: DerivationPath = "m/1852'/1815'/5'".parse().unwrap();
-        let (_, pub_key) = derive_key_pair(&entropy, password, &path);
+        let (_, pub_key) = derive_key_pair(&entropy, password, &path).unwrap();
         let key_type = AccountKey { account_gap: 5 };
         let check1 = check_ownership(&pub_key, &entropy, password, nonce, key_type);
 
         // check address level exploration consistency
         let path: DerivationPath = "m/1852'/1815'/5'/0/5".parse().unwrap();
-        let (_, pub_key) = derive_key_pair(&entropy, password, &path);
+        let (_, pub_key) = derive_key_pair(&entropy, password, &path).unwrap();
         let key_type = AddressKey {
             account_gap: 5,
             address_gap: 5,
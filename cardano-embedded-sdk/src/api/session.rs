@@ -0,0 +1,387 @@
+//! Unlock session
+//!
+//! Every call to the [`api`](../index.html) functions runs PBKDF2 (4096
+//! rounds of HMAC-SHA512) to get the root key and then derives the whole
+//! path, which takes seconds on a Cortex-M4. An `UnlockSession` derives the
+//! root key once when the password is entered and keeps it, together with
+//! the `N` most recently used account keys (`purpose'/coin_type'/account'`),
+//! until it is locked or times out.
+//!
+//! The keys are zeroed when the session is locked or dropped. Time is given
+//! by the caller as a monotonic tick count (`now`), in whatever unit the
+//! firmware has at hand, the timeout is in the same unit. [`Clock`] makes
+//! such a count out of a wrapping 32 bits counter, e.g. the cycle counter.
+//!
+//! # Example
+//! ```
+//! use cardano_embedded_sdk::api::session::{SessionError, UnlockSession};
+//! use cardano_embedded_sdk::bip::bip39::{dictionary, Entropy, Mnemonics};
+//! use derivation_path::DerivationPath;
+//!
+//! let mnemonics = Mnemonics::from_string(&dictionary::ENGLISH,"all all all all all all all all all all all all",).unwrap();
+//! let entropy = Entropy::from_mnemonics(&mnemonics).unwrap();
+//! let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
+//!
+//! // keep up to 2 account keys, lock after 60 ticks without use
+//! let mut session = UnlockSession::<2>::new(60);
+//! session.unlock(&entropy, b"embedano", 0).unwrap();
+//! let signature = session.sign_data(b"some bytes", &path, 10).unwrap();
+//! let pub_key = session.public_key(&path, 20).unwrap();
+//! assert!(pub_key.verify(b"some bytes", &signature));
+//!
+//! assert_eq!(session.sign_data(b"some bytes", &path, 100), Err(SessionError::Locked));
+//! ```
+use core::{error, fmt};
+
+use cryptoxide::blake2b::Blake2b;
+use derivation_path::{ChildIndex, DerivationPath};
+
+use super::adjust_hardened;
+use crate::{
+    bip::bip39::Entropy,
+    crypto::Ed25519Signature,
+    types::{TxId, XPrvKey, XPubKey},
+    util::{constant_time, nfkd, securemem},
+};
+
+/// number of indexes of an account path: `purpose'/coin_type'/account'`.
+const ACCOUNT_DEPTH: usize = 3;
+
+const PASSWORD_TAG_SIZE: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionError {
+    /// the session was locked, or timed out, and needs to be unlocked again
+    Locked,
+    /// the password has a character whose NFKD normalization is not
+    /// compiled in, the session stays locked
+    UnsupportedPassword(nfkd::UnsupportedError),
+}
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Locked => write!(f, "session is locked"),
+            SessionError::UnsupportedPassword(e) => write!(f, "unsupported password: {e}"),
+        }
+    }
+}
+impl error::Error for SessionError {}
+
+struct CachedAccount {
+    path: [u32; ACCOUNT_DEPTH],
+    key: XPrvKey,
+    last_use: u64,
+}
+
+/// Root key of an unlocked wallet and LRU cache of its `N` last used account
+/// keys. The cache lives inline, unlocking does not allocate.
+pub struct UnlockSession<const N: usize> {
+    root: Option<XPrvKey>,
+    // keyed hash of the password, to know if a request uses the same one
+    password_tag: [u8; PASSWORD_TAG_SIZE],
+    accounts: [Option<CachedAccount>; N],
+    timeout: u64,
+    last_use: u64,
+    // LRU counter of the account keys
+    uses: u64,
+}
+
+impl<const N: usize> UnlockSession<N> {
+    /// create a locked session, locking itself after `timeout` ticks without
+    /// use.
+    pub fn new(timeout: u64) -> Self {
+        UnlockSession {
+            root: None,
+            password_tag: [0; PASSWORD_TAG_SIZE],
+            accounts: core::array::from_fn(|_| None),
+            timeout,
+            last_use: 0,
+            uses: 0,
+        }
+    }
+
+    /// unlock the session with the given password. Nothing is derived again
+    /// if the session is already unlocked for the same entropy and password.
+    pub fn unlock(
+        &mut self,
+        entropy: &Entropy,
+        password: &[u8],
+        now: u64,
+    ) -> Result<(), SessionError> {
+        let mut tag = [0; PASSWORD_TAG_SIZE];
+        Blake2b::blake2b(&mut tag, password, entropy);
+        let mut result = Ok(());
+        if !(self.is_unlocked(now) && constant_time::eq(&tag, &self.password_tag)) {
+            self.lock();
+            match XPrvKey::from_entropy(entropy, password) {
+                Ok(root) => {
+                    self.root = Some(root);
+                    self.password_tag = tag;
+                }
+                Err(e) => result = Err(SessionError::UnsupportedPassword(e)),
+            }
+        }
+        self.last_use = now;
+        securemem::zero(&mut tag);
+        result
+    }
+
+    /// drop, and zero, the root and account keys.
+    pub fn lock(&mut self) {
+        self.root = None;
+        self.accounts.iter_mut().for_each(|account| *account = None);
+        securemem::zero(&mut self.password_tag);
+    }
+
+    /// true if the session is unlocked, lock it if it timed out.
+    pub fn is_unlocked(&mut self, now: u64) -> bool {
+        if self.root.is_some() && now.saturating_sub(self.last_use) > self.timeout {
+            self.lock();
+        }
+        self.root.is_some()
+    }
+
+    /// call `f` with the private key derived for `path`, starting from the
+    /// cached account key when the path goes through one.
+    pub fn with_key<T>(
+        &mut self,
+        path: &DerivationPath,
+        now: u64,
+        f: impl FnOnce(&XPrvKey) -> T,
+    ) -> Result<T, SessionError> {
+        if !self.is_unlocked(now) {
+            return Err(SessionError::Locked);
+        }
+        self.last_use = now;
+
+        let path = path.path();
+        let (start, rest) = match self.cached_account(path) {
+            Some(slot) => (&self.accounts[slot], &path[ACCOUNT_DEPTH..]),
+            None => (&None, path),
+        };
+        let start = match start {
+            Some(account) => &account.key,
+            None => self.root.as_ref().ok_or(SessionError::Locked)?,
+        };
+        let mut rest = rest.iter().map(adjust_hardened);
+        Ok(match rest.next() {
+            None => f(start),
+            Some(index) => f(&rest.fold(start.derive(index), |key, i| key.derive(i))),
+        })
+    }
+
+    /// Extended public key derived for `path`.
+    pub fn public_key(&mut self, path: &DerivationPath, now: u64) -> Result<XPubKey, SessionError> {
+        self.with_key(path, now, |key| key.to_public())
+    }
+
+    /// Sign binary data with private key derived for `path`.
+    pub fn sign_data(
+        &mut self,
+        data: &[u8],
+        path: &DerivationPath,
+        now: u64,
+    ) -> Result<Ed25519Signature, SessionError> {
+        self.with_key(path, now, |key| key.sign(data))
+    }
+
+    /// Sign transaction id with private key derived for `path`.
+    pub fn sign_tx_id(
+        &mut self,
+        tx_id: &TxId,
+        path: &DerivationPath,
+        now: u64,
+    ) -> Result<Ed25519Signature, SessionError> {
+        self.sign_data(tx_id.to_bytes(), path, now)
+    }
+
+    /// slot of the account key of `path`, derived and cached (in place of
+    /// the least recently used one) if needed.
+    fn cached_account(&mut self, path: &[ChildIndex]) -> Option<usize> {
+        if N == 0 || path.len() < ACCOUNT_DEPTH {
+            return None;
+        }
+        let account: [u32; ACCOUNT_DEPTH] = core::array::from_fn(|i| adjust_hardened(&path[i]));
+        self.uses += 1;
+
+        let hit = self
+            .accounts
+            .iter()
+            .position(|cached| matches!(cached, Some(cached) if cached.path == account));
+        let slot = match hit {
+            Some(slot) => slot,
+            None => {
+                // empty slots have the lowest `last_use`
+                let (slot, _) = self
+                    .accounts
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, cached)| cached.as_ref().map_or(0, |c| c.last_use))?;
+                let root = self.root.as_ref()?;
+                let key = account[1..]
+                    .iter()
+                    .fold(root.derive(account[0]), |key, i| key.derive(*i));
+                self.accounts[slot] = Some(CachedAccount {
+                    path: account,
+                    key,
+                    last_use: 0,
+                });
+                slot
+            }
+        };
+        self.accounts[slot].as_mut()?.last_use = self.uses;
+        Some(slot)
+    }
+}
+
+impl<const N: usize> Drop for UnlockSession<N> {
+    fn drop(&mut self) {
+        self.lock()
+    }
+}
+
+/// Monotonic tick count for the session, extended from a wrapping 32 bits
+/// hardware counter such as the Cortex-M DWT cycle counter. `now` has to be
+/// called more often than the counter wraps.
+pub struct Clock<F> {
+    counter: F,
+    last: u32,
+    now: u64,
+}
+
+impl<F: FnMut() -> u32> Clock<F> {
+    /// start counting from 0 at the current value of `counter`.
+    pub fn new(mut counter: F) -> Self {
+        let last = counter();
+        Clock {
+            counter,
+            last,
+            now: 0,
+        }
+    }
+
+    /// ticks elapsed since the clock was created.
+    pub fn now(&mut self) -> u64 {
+        let ticks = (self.counter)();
+        self.now += ticks.wrapping_sub(self.last) as u64;
+        self.last = ticks;
+        self.now
+    }
+}
+
+#[cfg(all(test, feature = "bip39-english"))]
+mod tests {
+    use super::*;
+    use crate::api;
+
+    fn entropy() -> Entropy {
+        Entropy::from_slice(&[0x5a; 16]).unwrap()
+    }
+
+    fn path(s: &str) -> DerivationPath {
+        s.parse().unwrap()
+    }
+
+    fn cached_paths<const N: usize>(session: &UnlockSession<N>) -> [Option<u32>; N] {
+        core::array::from_fn(|i| session.accounts[i].as_ref().map(|c| c.path[2] & 0xff))
+    }
+
+    #[test]
+    fn test_same_keys_as_api() {
+        let mut session = UnlockSession::<2>::new(100);
+        session.unlock(&entropy(), b"embedano", 0).unwrap();
+        for p in [
+            "m",
+            "m/1852'",
+            "m/1852'/1815'/0'",
+            "m/1852'/1815'/0'/0/3",
+            "m/1852'/1815'/1'/2/0",
+        ] {
+            let expected = api::derive_key(&entropy(), b"embedano", &path(p)).unwrap();
+            let signature = session.sign_data(b"data", &path(p), 1).unwrap();
+            assert_eq!(signature, expected.sign(b"data"));
+            let public = session.public_key(&path(p), 1).unwrap();
+            assert_eq!(public.as_bytes(), expected.to_public().as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_account_keys_lru() {
+        let mut session = UnlockSession::<2>::new(100);
+        session.unlock(&entropy(), b"", 0).unwrap();
+        session
+            .public_key(&path("m/1852'/1815'/0'/0/0"), 0)
+            .unwrap();
+        session
+            .public_key(&path("m/1852'/1815'/1'/0/0"), 0)
+            .unwrap();
+        assert_eq!(cached_paths(&session), [Some(0), Some(1)]);
+        // 0 is now the most recently used, 2 replaces 1
+        session
+            .public_key(&path("m/1852'/1815'/0'/0/1"), 0)
+            .unwrap();
+        session
+            .public_key(&path("m/1852'/1815'/2'/0/0"), 0)
+            .unwrap();
+        assert_eq!(cached_paths(&session), [Some(0), Some(2)]);
+        // paths shorter than an account do not go through the cache
+        session.public_key(&path("m/1852'/1815'"), 0).unwrap();
+        assert_eq!(cached_paths(&session), [Some(0), Some(2)]);
+    }
+
+    #[test]
+    fn test_lock_and_timeout() {
+        let p = path("m/1852'/1815'/0'/0/0");
+        let mut session = UnlockSession::<1>::new(10);
+        assert_eq!(session.sign_data(b"data", &p, 0), Err(SessionError::Locked));
+
+        session.unlock(&entropy(), b"", 0).unwrap();
+        assert!(session.sign_data(b"data", &p, 10).is_ok());
+        assert!(session.sign_data(b"data", &p, 20).is_ok());
+        assert_eq!(
+            session.sign_data(b"data", &p, 31),
+            Err(SessionError::Locked)
+        );
+        assert_eq!(cached_paths(&session), [None]);
+
+        session.unlock(&entropy(), b"", 40).unwrap();
+        assert!(session.is_unlocked(40));
+        session.lock();
+        assert!(!session.is_unlocked(40));
+        assert_eq!(session.password_tag, [0; PASSWORD_TAG_SIZE]);
+    }
+
+    #[test]
+    fn test_unlock_with_other_password() {
+        let p = path("m/1852'/1815'/0'/0/0");
+        let mut session = UnlockSession::<1>::new(10);
+        session.unlock(&entropy(), b"first", 0).unwrap();
+        session.unlock(&entropy(), b"second", 1).unwrap();
+        let expected = api::sign_data(b"data", &entropy(), b"second", &p).unwrap();
+        assert_eq!(session.sign_data(b"data", &p, 1), Ok(expected));
+    }
+
+    #[cfg(not(feature = "nfkd-latin"))]
+    #[test]
+    fn test_unsupported_password() {
+        let p = path("m/1852'/1815'/0'/0/0");
+        let mut session = UnlockSession::<1>::new(10);
+        session.unlock(&entropy(), b"", 0).unwrap();
+        assert_eq!(
+            session.unlock(&entropy(), "caf\u{e9}".as_bytes(), 1),
+            Err(SessionError::UnsupportedPassword(nfkd::UnsupportedError(
+                '\u{e9}'
+            )))
+        );
+        assert_eq!(session.sign_data(b"data", &p, 1), Err(SessionError::Locked));
+    }
+
+    #[test]
+    fn test_clock_wraps() {
+        let mut ticks = [u32::MAX - 5, u32::MAX, 4, 10].into_iter();
+        let mut clock = Clock::new(move || ticks.next().unwrap());
+        assert_eq!(clock.now(), 5);
+        assert_eq!(clock.now(), 10);
+        assert_eq!(clock.now(), 16);
+    }
+}
@@ -0,0 +1,464 @@
+//! Mnemonic backup verification
+//!
+//! After generating a new wallet the user must prove the mnemonic phrase has
+//! been written down before the device enables signing. The
+//! [`BackupQuiz`](./struct.BackupQuiz.html) asks for a few randomly chosen
+//! words of the phrase, limits the number of wrong answers and only then
+//! reports the seed as backed up.
+//!
+//! The quiz keeps only the expected words of the asked positions and never
+//! reveals the expected word on a wrong answer. It is rendered by the board
+//! through the [`QuizInterface`](./trait.QuizInterface.html) trait: devices
+//! with a keyboard can ask for the word directly, devices with a couple of
+//! buttons can enable multiple choice questions.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate rand;
+//! use cardano_embedded_sdk::bip::{backup_quiz::*, bip39::*};
+//!
+//! struct PerfectMemory(Mnemonics);
+//! impl QuizInterface for PerfectMemory {
+//!     fn ask(&mut self, question: &Question) -> Option<MnemonicIndex> {
+//!         Some(self.0.as_ref()[question.position])
+//!     }
+//!     fn wrong_answer(&mut self, _failures_left: u8) {}
+//! }
+//!
+//! let entropy = Entropy::generate(Type::Type24Words, rand::random);
+//! let quiz = BackupQuiz::new(&entropy, QuizConfig::default(), &mut rand::rngs::OsRng).unwrap();
+//!
+//! let mut user = PerfectMemory(entropy.to_mnemonics());
+//! assert_eq!(quiz.run(&mut user), QuizState::BackedUp);
+//! ```
+//!
+use alloc::vec::Vec;
+use core::{error, fmt, result};
+
+use rand_core::{CryptoRng, RngCore};
+
+use super::bip39::{Entropy, MnemonicIndex, MAX_MNEMONIC_VALUE};
+
+/// Error regarding the quiz configuration
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// the quiz must ask at least one question and at most one question
+    /// per word of the mnemonic phrase. The parameters are the number of
+    /// questions and the number of words.
+    InvalidQuestionCount(usize, usize),
+
+    /// multiple choice questions need between 2 and 2048 choices.
+    InvalidChoiceCount(usize),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidQuestionCount(questions, words) => write!(
+                f,
+                "Invalid number of questions {questions} for a {words} words mnemonic phrase"
+            ),
+            Error::InvalidChoiceCount(choices) => {
+                write!(f, "Invalid number of choices {choices}")
+            }
+        }
+    }
+}
+impl error::Error for Error {}
+
+pub type Result<T> = result::Result<T, Error>;
+
+/// Quiz parameters
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct QuizConfig {
+    /// number of distinct word positions to ask.
+    pub questions: usize,
+    /// number of wrong answers tolerated over the whole quiz.
+    pub max_failures: u8,
+    /// number of choices offered for each question, including the expected
+    /// word. `0` asks for the word without offering choices.
+    pub choices: usize,
+}
+impl Default for QuizConfig {
+    fn default() -> Self {
+        QuizConfig {
+            questions: 3,
+            max_failures: 3,
+            choices: 0,
+        }
+    }
+}
+
+/// A question of the quiz
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Question {
+    /// index of the question, starting at 1
+    pub number: usize,
+    /// total number of questions
+    pub total: usize,
+    /// position of the asked word in the mnemonic phrase, starting at 0
+    pub position: usize,
+    /// the words to choose from, empty if the word must be entered
+    pub choices: Vec<MnemonicIndex>,
+}
+
+/// State of the quiz
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum QuizState {
+    /// waiting for the answer of the current question
+    InProgress,
+    /// all questions were answered, the mnemonic phrase is backed up
+    BackedUp,
+    /// too many wrong answers
+    Failed,
+    /// the user interrupted the quiz
+    Aborted,
+}
+
+/// Result of answering the current question
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Answer {
+    /// correct answer, the next question is ready
+    Correct,
+    /// wrong answer, the same question is asked again
+    Wrong { failures_left: u8 },
+    /// correct answer to the last question
+    BackedUp,
+    /// wrong answer and no failure left
+    Failed,
+    /// the quiz is not in progress anymore
+    Finished,
+}
+
+/// Rendering of the quiz, implemented by the board.
+pub trait QuizInterface {
+    /// display the question and wait for the user's answer. Returns `None`
+    /// if the user aborts the quiz.
+    fn ask(&mut self, question: &Question) -> Option<MnemonicIndex>;
+
+    /// tell the user the answer was wrong without revealing the expected
+    /// word.
+    fn wrong_answer(&mut self, failures_left: u8);
+}
+
+/// Mnemonic backup verification state machine. See the module
+/// documentation.
+pub struct BackupQuiz {
+    /// (position, expected word) of each question
+    expected: Vec<(usize, MnemonicIndex)>,
+    /// choices of each question, empty if choices are disabled
+    choices: Vec<Vec<MnemonicIndex>>,
+    current: usize,
+    failures_left: u8,
+    state: QuizState,
+}
+impl BackupQuiz {
+    /// prepare the quiz for the mnemonic phrase of the given entropy,
+    /// picking the asked positions (and choices) with the given random
+    /// generator.
+    ///
+    /// # Error
+    ///
+    /// fails if the configuration asks for no question, more questions
+    /// than words in the phrase, or an invalid number of choices.
+    ///
+    pub fn new<R>(entropy: &Entropy, config: QuizConfig, rng: &mut R) -> Result<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let words = entropy.get_type().mnemonic_count();
+        if config.questions == 0 || config.questions > words {
+            return Err(Error::InvalidQuestionCount(config.questions, words));
+        }
+        if config.choices == 1 || config.choices > MAX_MNEMONIC_VALUE as usize + 1 {
+            return Err(Error::InvalidChoiceCount(config.choices));
+        }
+
+        let mnemonics = entropy.to_mnemonics();
+        let mut expected: Vec<(usize, MnemonicIndex)> = Vec::with_capacity(config.questions);
+        while expected.len() < config.questions {
+            let position = uniform(rng, words as u32) as usize;
+            if expected.iter().all(|(p, _)| *p != position) {
+                expected.push((position, mnemonics.as_ref()[position]));
+            }
+        }
+
+        let mut choices = Vec::new();
+        if config.choices > 0 {
+            for (_, word) in expected.iter() {
+                choices.push(multiple_choice(rng, *word, config.choices));
+            }
+        }
+
+        Ok(BackupQuiz {
+            expected,
+            choices,
+            current: 0,
+            failures_left: config.max_failures,
+            state: QuizState::InProgress,
+        })
+    }
+
+    pub fn state(&self) -> QuizState {
+        self.state
+    }
+
+    /// true once every question has been answered correctly.
+    pub fn is_backed_up(&self) -> bool {
+        self.state == QuizState::BackedUp
+    }
+
+    /// the current question, if the quiz is in progress.
+    pub fn question(&self) -> Option<Question> {
+        if self.state != QuizState::InProgress {
+            return None;
+        }
+        Some(Question {
+            number: self.current + 1,
+            total: self.expected.len(),
+            position: self.expected[self.current].0,
+            choices: self.choices.get(self.current).cloned().unwrap_or_default(),
+        })
+    }
+
+    /// answer the current question.
+    pub fn answer(&mut self, word: MnemonicIndex) -> Answer {
+        if self.state != QuizState::InProgress {
+            return Answer::Finished;
+        }
+        if word == self.expected[self.current].1 {
+            self.current += 1;
+            if self.current == self.expected.len() {
+                self.finish(QuizState::BackedUp);
+                Answer::BackedUp
+            } else {
+                Answer::Correct
+            }
+        } else if self.failures_left == 0 {
+            self.finish(QuizState::Failed);
+            Answer::Failed
+        } else {
+            self.failures_left -= 1;
+            Answer::Wrong {
+                failures_left: self.failures_left,
+            }
+        }
+    }
+
+    /// interrupt the quiz.
+    pub fn abort(&mut self) {
+        if self.state == QuizState::InProgress {
+            self.finish(QuizState::Aborted);
+        }
+    }
+
+    /// drive the whole quiz through the given interface and return its
+    /// final state.
+    pub fn run<U: QuizInterface>(mut self, ui: &mut U) -> QuizState {
+        while let Some(question) = self.question() {
+            match ui.ask(&question) {
+                None => self.abort(),
+                Some(word) => {
+                    if let Answer::Wrong { failures_left } = self.answer(word) {
+                        ui.wrong_answer(failures_left)
+                    }
+                }
+            }
+        }
+        self.state
+    }
+
+    fn finish(&mut self, state: QuizState) {
+        self.state = state;
+        for (position, word) in self.expected.iter_mut() {
+            *position = 0;
+            *word = MnemonicIndex(0);
+        }
+    }
+}
+impl Drop for BackupQuiz {
+    fn drop(&mut self) {
+        self.finish(self.state);
+    }
+}
+
+/// uniform random number in `0..n` (rejection sampling).
+fn uniform<R: RngCore>(rng: &mut R, n: u32) -> u32 {
+    let zone = u32::MAX - (u32::MAX % n);
+    loop {
+        let v = rng.next_u32();
+        if v < zone {
+            return v % n;
+        }
+    }
+}
+
+/// `count` distinct words including `expected`, in random order.
+///
+/// The choices are fixed per question: asking again with fresh choices
+/// would reveal the expected word as the intersection of the sets.
+fn multiple_choice<R: RngCore>(
+    rng: &mut R,
+    expected: MnemonicIndex,
+    count: usize,
+) -> Vec<MnemonicIndex> {
+    let mut choices = Vec::with_capacity(count);
+    choices.push(expected);
+    while choices.len() < count {
+        let word = MnemonicIndex(uniform(rng, MAX_MNEMONIC_VALUE as u32 + 1) as u16);
+        if !choices.contains(&word) {
+            choices.push(word);
+        }
+    }
+    // Fisher-Yates shuffle
+    for i in (1..count).rev() {
+        let j = uniform(rng, i as u32 + 1) as usize;
+        choices.swap(i, j);
+    }
+    choices
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bip::bip39::{Mnemonics, Type};
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    struct Scripted {
+        mnemonics: Mnemonics,
+        /// number of wrong answers to give before the right one
+        wrong_first: usize,
+        /// abort at the given question number
+        abort_at: Option<usize>,
+        asked: Vec<Question>,
+        wrong_answers: Vec<u8>,
+    }
+    impl Scripted {
+        fn new(entropy: &Entropy, wrong_first: usize) -> Self {
+            Scripted {
+                mnemonics: entropy.to_mnemonics(),
+                wrong_first,
+                abort_at: None,
+                asked: Vec::new(),
+                wrong_answers: Vec::new(),
+            }
+        }
+    }
+    impl QuizInterface for Scripted {
+        fn ask(&mut self, question: &Question) -> Option<MnemonicIndex> {
+            self.asked.push(question.clone());
+            if self.abort_at == Some(question.number) {
+                return None;
+            }
+            let right = self.mnemonics.as_ref()[question.position];
+            if self.wrong_first > 0 {
+                self.wrong_first -= 1;
+                Some(MnemonicIndex((right.0 + 1) % 2048))
+            } else {
+                Some(right)
+            }
+        }
+        fn wrong_answer(&mut self, failures_left: u8) {
+            self.wrong_answers.push(failures_left);
+        }
+    }
+
+    fn setup(config: QuizConfig) -> (Entropy, BackupQuiz) {
+        let mut rng = ChaChaRng::from_seed([3; 32]);
+        let entropy = Entropy::try_generate(Type::Type12Words, &mut rng).unwrap();
+        let quiz = BackupQuiz::new(&entropy, config, &mut rng).unwrap();
+        (entropy, quiz)
+    }
+
+    #[test]
+    fn invalid_config() {
+        let mut rng = ChaChaRng::from_seed([0; 32]);
+        let entropy = Entropy::Entropy12([0; 16]);
+        let config = |questions, choices| QuizConfig {
+            questions,
+            max_failures: 1,
+            choices,
+        };
+        assert_eq!(
+            BackupQuiz::new(&entropy, config(0, 0), &mut rng).err(),
+            Some(Error::InvalidQuestionCount(0, 12))
+        );
+        assert_eq!(
+            BackupQuiz::new(&entropy, config(13, 0), &mut rng).err(),
+            Some(Error::InvalidQuestionCount(13, 12))
+        );
+        assert_eq!(
+            BackupQuiz::new(&entropy, config(3, 1), &mut rng).err(),
+            Some(Error::InvalidChoiceCount(1))
+        );
+        assert!(BackupQuiz::new(&entropy, config(12, 2048), &mut rng).is_ok());
+    }
+
+    #[test]
+    fn correct_answers_back_up() {
+        let (entropy, quiz) = setup(QuizConfig::default());
+        let mut ui = Scripted::new(&entropy, 0);
+        assert_eq!(quiz.run(&mut ui), QuizState::BackedUp);
+
+        assert_eq!(ui.asked.len(), 3);
+        let mut positions: Vec<usize> = ui.asked.iter().map(|q| q.position).collect();
+        positions.sort();
+        positions.dedup();
+        assert_eq!(positions.len(), 3);
+        assert!(ui.asked.iter().all(|q| q.choices.is_empty()));
+    }
+
+    #[test]
+    fn retries_are_limited() {
+        let (entropy, quiz) = setup(QuizConfig::default());
+        let mut ui = Scripted::new(&entropy, 3);
+        assert_eq!(quiz.run(&mut ui), QuizState::BackedUp);
+        assert_eq!(ui.wrong_answers, [2, 1, 0]);
+
+        let (entropy, quiz) = setup(QuizConfig::default());
+        let mut ui = Scripted::new(&entropy, 4);
+        assert_eq!(quiz.run(&mut ui), QuizState::Failed);
+        // the same question was asked every time
+        assert!(ui.asked.iter().all(|q| q.position == ui.asked[0].position));
+    }
+
+    #[test]
+    fn abort_and_finished_quiz() {
+        let (entropy, quiz) = setup(QuizConfig::default());
+        let mut ui = Scripted::new(&entropy, 0);
+        ui.abort_at = Some(2);
+        assert_eq!(quiz.run(&mut ui), QuizState::Aborted);
+
+        let (_, mut quiz) = setup(QuizConfig::default());
+        quiz.abort();
+        assert_eq!(quiz.question(), None);
+        assert_eq!(quiz.answer(MnemonicIndex(0)), Answer::Finished);
+        assert!(!quiz.is_backed_up());
+    }
+
+    #[test]
+    fn multiple_choices() {
+        let config = QuizConfig {
+            questions: 4,
+            max_failures: 2,
+            choices: 6,
+        };
+        let (entropy, quiz) = setup(config);
+        let mut ui = Scripted::new(&entropy, 2);
+        assert_eq!(quiz.run(&mut ui), QuizState::BackedUp);
+
+        let mnemonics = entropy.to_mnemonics();
+        for q in ui.asked.iter() {
+            assert_eq!(q.choices.len(), 6);
+            assert!(q.choices.contains(&mnemonics.as_ref()[q.position]));
+            let mut distinct = q.choices.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(distinct.len(), 6);
+        }
+        // retries of the first question offer the same choices
+        assert_eq!(ui.asked[0], ui.asked[1]);
+        assert_eq!(ui.asked[0], ui.asked[2]);
+    }
+}
@@ -26,7 +26,8 @@
 //!
 //! // The seed of the HDWallet is generated from the mnemonic string
 //! // in the associated language.
-//! let seed = Seed::from_mnemonic_string(&mnemonic_phrase, b"some password");
+//! let seed = Seed::from_mnemonic_string(&mnemonic_phrase, b"some password")
+//!     .expect("the password is supported");
 //! ```
 //!
 //! ## To recover a HDWallet
@@ -44,20 +45,23 @@
 //!
 //! // The seed of the HDWallet is generated from the mnemonic string
 //! // in the associated language.
-//! let seed = Seed::from_mnemonic_string(&mnemonic_phrase, b"some password");
+//! let seed = Seed::from_mnemonic_string(&mnemonic_phrase, b"some password")
+//!     .expect("the password is supported");
 //! ```
 //!
 use alloc::{
+    borrow::Cow,
     string::String,
     {vec, vec::Vec},
 };
 use core::{error, fmt, ops::Deref, result, str};
 
-use crate::util::securemem;
+use crate::util::{nfkd, securemem};
 
 use cryptoxide::hmac::Hmac;
 use cryptoxide::pbkdf2::pbkdf2;
 use cryptoxide::sha2::Sha512;
+use rand_core::{CryptoRng, RngCore};
 
 /// Error regarding BIP39 operations
 #[derive(Debug, PartialEq, Eq)]
@@ -88,6 +92,11 @@ pub enum Error {
     /// mnemonics are invalid to retrieve the original entropy. The user might
     /// have given an invalid mnemonic phrase.
     InvalidChecksum(u8, u8),
+
+    /// the mnemonic phrase or the password has a character whose NFKD
+    /// normalization is not compiled in, see [`nfkd`](../../util/nfkd/index.html).
+    /// Deriving from it would give the seed of another wallet.
+    UnsupportedCharacter(nfkd::UnsupportedError),
 }
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -104,6 +113,7 @@ impl fmt::Display for Error {
                 write!(f, "The given mnemonic is out of bound, {val}")
             }
             Error::LanguageError(_) => write!(f, "Unknown mnemonic word"),
+            Error::UnsupportedCharacter(e) => write!(f, "Unsupported character: {e}"),
             Error::InvalidChecksum(cs1, cs2) => write!(
                 f,
                 "Invalid Entropy's Checksum, expected {cs1:08b} but found {cs2:08b}",
@@ -116,10 +126,16 @@ impl From<dictionary::Error> for Error {
         Error::LanguageError(e)
     }
 }
+impl From<nfkd::UnsupportedError> for Error {
+    fn from(e: nfkd::UnsupportedError) -> Self {
+        Error::UnsupportedCharacter(e)
+    }
+}
 impl error::Error for Error {
     fn cause(&self) -> Option<&dyn error::Error> {
         match self {
             Error::LanguageError(ref error) => Some(error),
+            Error::UnsupportedCharacter(ref error) => Some(error),
             _ => None,
         }
     }
@@ -181,6 +197,32 @@ impl Entropy {
         entropy
     }
 
+    /// generate entropy using the given cryptographically secure random
+    /// generator, propagating its failures (for example a
+    /// [`HealthTestedRng`](../../rng/struct.HealthTestedRng.html) whose
+    /// noise source failed a health test) instead of producing weak entropy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// # extern crate cardano_embedded_sdk;
+    /// # use cardano_embedded_sdk::bip::bip39::*;
+    ///
+    /// let entropy = Entropy::try_generate(Type::Type24Words, &mut rand::rngs::OsRng)
+    ///     .expect("the OS random generator is available");
+    /// ```
+    ///
+    pub fn try_generate<R>(t: Type, rng: &mut R) -> result::Result<Self, rand_core::Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let bytes = [0u8; 32];
+        let mut entropy = Self::new(t, &bytes[..]);
+        rng.try_fill_bytes(entropy.as_mut())?;
+        Ok(entropy)
+    }
+
     fn new(t: Type, bytes: &[u8]) -> Self {
         let mut e = match t {
             Type::Type9Words => Entropy::Entropy9([0u8; 12]),
@@ -462,6 +504,10 @@ impl Seed {
     /// Note that the `Seed` is not generated from the `Entropy` directly. It is a
     /// design choice of Bip39.
     ///
+    /// The password is normalized to NFKD (see [`nfkd`]) before being used.
+    ///
+    /// [`nfkd`]: ../../util/nfkd/index.html
+    ///
     /// # Safety
     ///
     /// The password is meant to allow plausible deniability. While it is possible
@@ -476,16 +522,34 @@ impl Seed {
     /// let mnemonics = MnemonicString::new(&dictionary::ENGLISH, MNEMONICS.to_owned())
     ///     .expect("valid Mnemonic phrase");
     ///
-    /// let seed = Seed::from_mnemonic_string(&mnemonics, b"Bourbaki team rocks!");
+    /// let seed = Seed::from_mnemonic_string(&mnemonics, b"Bourbaki team rocks!")
+    ///     .expect("supported password");
     /// ```
     ///
-    pub fn from_mnemonic_string(mnemonics: &MnemonicString, password: &[u8]) -> Self {
-        let mut salt = Vec::from("mnemonic".as_bytes());
-        salt.extend_from_slice(password);
-        let mut mac = Hmac::new(Sha512::new(), mnemonics.0.as_bytes());
+    /// # Error
+    ///
+    /// This function fails if the password has characters of a disabled
+    /// `nfkd-*` feature.
+    ///
+    pub fn from_mnemonic_string(mnemonics: &MnemonicString, password: &[u8]) -> Result<Self> {
+        nfkd::check_bytes(password)?;
+        let mut password = nfkd::normalize_bytes(password);
+        let mut salt = Vec::with_capacity(8 + password.len());
+        salt.extend_from_slice(b"mnemonic");
+        salt.extend_from_slice(&password);
+        if let Cow::Owned(password) = &mut password {
+            securemem::zero(password);
+        }
+        let mut mnemonics = nfkd::normalize(&mnemonics.0);
+        let mut mac = Hmac::new(Sha512::new(), mnemonics.as_bytes());
+        if let Cow::Owned(mnemonics) = &mut mnemonics {
+            // zero bytes are valid UTF-8
+            securemem::zero(unsafe { mnemonics.as_bytes_mut() });
+        }
         let mut result = [0; SEED_SIZE];
         pbkdf2(&mut mac, &salt, 2048, &mut result);
-        Self::from_bytes(result)
+        securemem::zero(&mut salt);
+        Ok(Self::from_bytes(result))
     }
 }
 impl PartialEq for Seed {
@@ -544,15 +608,26 @@ impl MnemonicString {
     ///     .expect("valid Mnemonic phrase");
     /// ```
     ///
+    /// The given string is normalized to NFKD (see [`nfkd`]) before being
+    /// validated, so it does not need to be pre-normalized.
+    ///
+    /// [`nfkd`]: ../../util/nfkd/index.html
+    ///
     /// # Error
     ///
     /// This function may fail if one or all words are not recognized
-    /// in the given [`Language`].
+    /// in the given [`Language`], or if the string has characters of a
+    /// disabled `nfkd-*` feature.
     ///
     pub fn new<D>(dic: &D, s: String) -> Result<Self>
     where
         D: dictionary::Language,
     {
+        nfkd::check(&s)?;
+        let s = match nfkd::normalize(&s) {
+            Cow::Borrowed(_) => s,
+            Cow::Owned(normalized) => normalized,
+        };
         let _ = Mnemonics::from_string(dic, &s)?;
 
         Ok(MnemonicString(s))
@@ -851,6 +926,13 @@ pub mod dictionary {
     //! UTF8 NFKD by default. Users must be sure to compose (or decompose)
     //! our output (or input) UTF8 strings.
     //!
+    //! Each default dictionary is behind its own cargo feature (`bip39-english`,
+    //! `bip39-french`, `bip39-japanese`, `bip39-korean`, `bip39-chinese-simplified`,
+    //! `bip39-chinese-traditional`, `bip39-italian` and `bip39-spanish`), all
+    //! enabled by default. Firmwares should only enable the languages they
+    //! support, see also the [`packed_dictionary`](../../packed_dictionary/index.html)
+    //! module for a more compact representation.
+    //!
 
     use alloc::string::{String, ToString};
     use core::{error, fmt, result};
@@ -927,6 +1009,7 @@ pub mod dictionary {
     /// default English dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#wordlists)
     ///
+    #[cfg(feature = "bip39-english")]
     pub const ENGLISH: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_english.txt"),
         name: "english",
@@ -935,6 +1018,7 @@ pub mod dictionary {
     /// default French dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#french)
     ///
+    #[cfg(feature = "bip39-french")]
     pub const FRENCH: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_french.txt"),
         name: "french",
@@ -943,6 +1027,7 @@ pub mod dictionary {
     /// default Japanese dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#japanese)
     ///
+    #[cfg(feature = "bip39-japanese")]
     pub const JAPANESE: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_japanese.txt"),
         name: "japanese",
@@ -951,6 +1036,7 @@ pub mod dictionary {
     /// default Korean dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#japanese)
     ///
+    #[cfg(feature = "bip39-korean")]
     pub const KOREAN: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_korean.txt"),
         name: "korean",
@@ -959,6 +1045,7 @@ pub mod dictionary {
     /// default chinese simplified dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#chinese)
     ///
+    #[cfg(feature = "bip39-chinese-simplified")]
     pub const CHINESE_SIMPLIFIED: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_chinese_simplified.txt"),
         name: "chinese-simplified",
@@ -966,6 +1053,7 @@ pub mod dictionary {
     /// default chinese traditional dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#chinese)
     ///
+    #[cfg(feature = "bip39-chinese-traditional")]
     pub const CHINESE_TRADITIONAL: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_chinese_traditional.txt"),
         name: "chinese-traditional",
@@ -974,6 +1062,7 @@ pub mod dictionary {
     /// default italian dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#italian)
     ///
+    #[cfg(feature = "bip39-italian")]
     pub const ITALIAN: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_italian.txt"),
         name: "italian",
@@ -982,6 +1071,7 @@ pub mod dictionary {
     /// default spanish dictionary as provided by the
     /// [BIP39 standard](https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md#spanish)
     ///
+    #[cfg(feature = "bip39-spanish")]
     pub const SPANISH: DefaultDictionary = DefaultDictionary {
         words: include!("bip39_spanish.txt"),
         name: "spanish",
@@ -991,17 +1081,18 @@ pub mod dictionary {
 // FIXME: commented to be able to run our tests
 #[cfg(test)]
 mod test {
-    use crate::bip::bip39::dictionary::Language;
-
     use super::*;
-    use alloc::string::ToString;
     use rand::random;
 
     extern crate unicode_normalization;
     use self::unicode_normalization::UnicodeNormalization;
 
+    #[cfg(feature = "bip39-english")]
     #[test]
     fn english_dic() {
+        use crate::bip::bip39::dictionary::Language;
+        use alloc::string::ToString;
+
         let dic = &dictionary::ENGLISH;
 
         assert_eq!(dic.lookup_mnemonic("abandon"), Ok(MnemonicIndex(0)));
@@ -1073,7 +1164,7 @@ mod test {
 
         assert_eq!(
             seed_ref,
-            Seed::from_mnemonic_string(&mnemonics_str, passphrase.as_bytes())
+            Seed::from_mnemonic_string(&mnemonics_str, passphrase.as_bytes()).unwrap()
         );
     }
 
@@ -1083,16 +1174,61 @@ mod test {
         }
     }
 
+    #[cfg(feature = "bip39-english")]
     #[test]
     fn test_vectors_english() {
         mk_tests(TEST_VECTORS_ENGLISH, &dictionary::ENGLISH)
     }
+    #[cfg(feature = "bip39-japanese")]
     #[test]
     fn test_vectors_japanese() {
         mk_tests(TEST_VECTORS_JAPANESE, &dictionary::JAPANESE)
     }
 
+    /// the raw vectors (ideographic spaces, composed kana) go through the
+    /// built-in normalization of `MnemonicString` and `Seed`.
+    #[cfg(feature = "bip39-japanese")]
+    #[test]
+    fn test_vectors_japanese_not_normalized() {
+        for test in TEST_VECTORS_JAPANESE {
+            let mnemonics =
+                MnemonicString::new(&dictionary::JAPANESE, String::from(test.mnemonics))
+                    .expect("valid mnemonics string");
+            let seed_ref =
+                Seed::from_slice(&hex::decode(test.seed).unwrap()).expect("decode seed from hex");
+            assert_eq!(
+                seed_ref,
+                Seed::from_mnemonic_string(&mnemonics, test.passphrase.as_bytes()).unwrap()
+            );
+        }
+    }
+
+    /// without the latin decompositions, `é` cannot be normalized: refuse it
+    /// rather than derive the seed of another wallet.
+    #[cfg(all(feature = "bip39-english", not(feature = "nfkd-latin")))]
+    #[test]
+    fn unsupported_characters_are_rejected() {
+        use crate::util::nfkd::UnsupportedError;
+
+        let mnemonics = MnemonicString::new(
+            &dictionary::ENGLISH,
+            String::from("mimic left ask vacant toast follow bitter join diamond gate attend obey"),
+        )
+        .unwrap();
+        assert_eq!(
+            Seed::from_mnemonic_string(&mnemonics, "caf\u{e9}".as_bytes()),
+            Err(Error::UnsupportedCharacter(UnsupportedError('\u{e9}')))
+        );
+        assert!(Seed::from_mnemonic_string(&mnemonics, b"cafe").is_ok());
+        assert_eq!(
+            MnemonicString::new(&dictionary::ENGLISH, String::from("\u{304c}")),
+            Err(Error::UnsupportedCharacter(UnsupportedError('\u{304c}')))
+        );
+    }
+
+    #[cfg(feature = "bip39-english")]
     const TEST_VECTORS_ENGLISH: &'static [TestVector] = &include!("test_vectors/bip39_english.txt");
+    #[cfg(feature = "bip39-japanese")]
     const TEST_VECTORS_JAPANESE: &'static [TestVector] =
         &include!("test_vectors/bip39_japanese.txt");
 }
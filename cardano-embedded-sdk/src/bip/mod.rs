@@ -4,5 +4,9 @@
 //! library for managing wallet, private keys and addresses.
 //!
 
+pub mod backup_quiz;
 pub mod bip39;
 pub mod bip44;
+pub mod packed_dictionary;
+pub mod recovery;
+pub mod user_entropy;
@@ -0,0 +1,304 @@
+//! Compact BIP39 dictionaries
+//!
+//! A [`DefaultDictionary`](../bip39/dictionary/struct.DefaultDictionary.html)
+//! stores 2048 `&'static str`, i.e. a pointer and a length per word on top of
+//! the words themselves. On a microcontroller with 256 KiB of flash this is
+//! a significant cost, specially when several languages are enabled.
+//!
+//! [`PackedDictionary`](./struct.PackedDictionary.html) stores the same words
+//! front coded: each word only stores the bytes that differ from the previous
+//! word, with a restart point (a word stored in full) every
+//! [`BLOCK_SIZE`](./constant.BLOCK_SIZE.html) words. The packing is done at
+//! compile time from the default dictionaries, and the lookups do not
+//! allocate:
+//!
+//! ```
+//! use cardano_embedded_sdk::bip::bip39::MnemonicIndex;
+//! use cardano_embedded_sdk::bip::packed_dictionary::{self, MAX_WORD_BYTES};
+//!
+//! let dic = &packed_dictionary::ENGLISH;
+//! let mut buf = [0u8; MAX_WORD_BYTES];
+//! assert_eq!(dic.word(MnemonicIndex(398), &mut buf), "crack");
+//! assert_eq!(dic.index_of("crack"), Some(MnemonicIndex(398)));
+//! ```
+//!
+//! Each dictionary is only compiled in if its cargo feature (`bip39-english`,
+//! `bip39-japanese`...) is enabled.
+//!
+//! # Format
+//!
+//! Each word is encoded as a header followed by its suffix bytes. The header
+//! is one byte `prefix << 4 | suffix` when both the length of the prefix
+//! shared with the previous word and the length of the suffix are below 15,
+//! otherwise it is `0xff` followed by one byte for each length. The offset of
+//! each restart point is kept in a table, so retrieving a word decodes at
+//! most `BLOCK_SIZE` words.
+//!
+use alloc::string::{String, ToString};
+use core::str;
+
+use super::bip39::dictionary::{DefaultDictionary, Error, Language, Result};
+use super::bip39::MnemonicIndex;
+
+/// number of words in a BIP39 dictionary.
+pub const WORD_COUNT: usize = 2048;
+
+/// number of words between two restart points.
+pub const BLOCK_SIZE: usize = 16;
+
+/// number of restart points.
+pub const BLOCKS: usize = WORD_COUNT / BLOCK_SIZE;
+
+/// size of the buffer needed to retrieve any word, in bytes.
+pub const MAX_WORD_BYTES: usize = 64;
+
+const ESCAPE: u8 = 0xff;
+
+/// Front coded dictionary of `N` bytes. See the module documentation.
+pub struct PackedDictionary<const N: usize> {
+    data: [u8; N],
+    offsets: [u16; BLOCKS],
+    sorted: bool,
+    name: &'static str,
+}
+
+/// number of bytes needed to pack the given dictionary.
+pub const fn packed_len(dic: &DefaultDictionary) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < WORD_COUNT {
+        let (prefix, suffix) = split(dic, i);
+        len += header_len(prefix, suffix) + suffix;
+        i += 1;
+    }
+    len
+}
+
+/// length of the prefix shared with the previous word (none at restart
+/// points) and length of the remaining suffix of word `i`.
+const fn split(dic: &DefaultDictionary, i: usize) -> (usize, usize) {
+    let word = dic.words[i].as_bytes();
+    assert!(word.len() <= MAX_WORD_BYTES, "word too long");
+    let mut prefix = 0;
+    if !i.is_multiple_of(BLOCK_SIZE) {
+        let prev = dic.words[i - 1].as_bytes();
+        while prefix < word.len() && prefix < prev.len() && word[prefix] == prev[prefix] {
+            prefix += 1;
+        }
+    }
+    (prefix, word.len() - prefix)
+}
+
+const fn header_len(prefix: usize, suffix: usize) -> usize {
+    if prefix < 15 && suffix < 15 {
+        1
+    } else {
+        3
+    }
+}
+
+/// byte-wise comparison of two strings, usable in const context.
+const fn less_or_equal(a: &[u8], b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() <= b.len()
+}
+
+impl<const N: usize> PackedDictionary<N> {
+    /// pack the given dictionary, `N` must be
+    /// [`packed_len(dic)`](./fn.packed_len.html).
+    pub const fn pack(dic: &DefaultDictionary) -> Self {
+        assert!(N == packed_len(dic), "invalid packed dictionary size");
+        assert!(N <= u16::MAX as usize, "packed dictionary too large");
+        let mut data = [0u8; N];
+        let mut offsets = [0u16; BLOCKS];
+        let mut sorted = true;
+        let mut pos = 0;
+        let mut i = 0;
+        while i < WORD_COUNT {
+            if i % BLOCK_SIZE == 0 {
+                offsets[i / BLOCK_SIZE] = pos as u16;
+            }
+            if i > 0 && !less_or_equal(dic.words[i - 1].as_bytes(), dic.words[i].as_bytes()) {
+                sorted = false;
+            }
+            let (prefix, suffix) = split(dic, i);
+            if header_len(prefix, suffix) == 1 {
+                data[pos] = (prefix << 4 | suffix) as u8;
+                pos += 1;
+            } else {
+                data[pos] = ESCAPE;
+                data[pos + 1] = prefix as u8;
+                data[pos + 2] = suffix as u8;
+                pos += 3;
+            }
+            let word = dic.words[i].as_bytes();
+            let mut j = prefix;
+            while j < word.len() {
+                data[pos] = word[j];
+                pos += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        PackedDictionary {
+            data,
+            offsets,
+            sorted,
+            name: dic.name,
+        }
+    }
+
+    /// decode the word at `pos` on top of the previous word in `buf`,
+    /// returns the length of the word and the position of the next one.
+    fn decode(&self, pos: usize, buf: &mut [u8; MAX_WORD_BYTES]) -> (usize, usize) {
+        let (prefix, suffix, start) = match self.data[pos] {
+            ESCAPE => (
+                self.data[pos + 1] as usize,
+                self.data[pos + 2] as usize,
+                pos + 3,
+            ),
+            h => ((h >> 4) as usize, (h & 0x0f) as usize, pos + 1),
+        };
+        let len = prefix + suffix;
+        buf[prefix..len].copy_from_slice(&self.data[start..start + suffix]);
+        (len, start + suffix)
+    }
+
+    /// retrieve the word of the given index, using `buf` as storage.
+    pub fn word<'a>(&self, index: MnemonicIndex, buf: &'a mut [u8; MAX_WORD_BYTES]) -> &'a str {
+        let index = index.0 as usize;
+        let mut pos = self.offsets[index / BLOCK_SIZE] as usize;
+        let mut len = 0;
+        for _ in 0..=(index % BLOCK_SIZE) {
+            (len, pos) = self.decode(pos, buf);
+        }
+        // the packed words come from valid `str`
+        str::from_utf8(&buf[..len]).unwrap()
+    }
+
+    /// retrieve the index of the given word, if it is in the dictionary.
+    pub fn index_of(&self, word: &str) -> Option<MnemonicIndex> {
+        let word = word.as_bytes();
+        let blocks = if self.sorted {
+            // last block whose first word is not after the given word,
+            // the first word of a block is stored in full.
+            let block = self.offsets.partition_point(|offset| {
+                let (len, start) = match self.data[*offset as usize] {
+                    ESCAPE => (self.data[*offset as usize + 2] as usize, 3),
+                    h => ((h & 0x0f) as usize, 1),
+                };
+                let start = *offset as usize + start;
+                less_or_equal(&self.data[start..start + len], word)
+            });
+            block.saturating_sub(1)..block.max(1)
+        } else {
+            0..BLOCKS
+        };
+
+        let mut buf = [0u8; MAX_WORD_BYTES];
+        for block in blocks {
+            let mut pos = self.offsets[block] as usize;
+            for i in 0..BLOCK_SIZE {
+                let len;
+                (len, pos) = self.decode(pos, &mut buf);
+                if &buf[..len] == word {
+                    return Some(MnemonicIndex((block * BLOCK_SIZE + i) as u16));
+                }
+            }
+        }
+        None
+    }
+}
+impl<const N: usize> Language for PackedDictionary<N> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+    fn separator(&self) -> &'static str {
+        " "
+    }
+    fn lookup_mnemonic(&self, word: &str) -> Result<MnemonicIndex> {
+        self.index_of(word)
+            .ok_or_else(|| Error::MnemonicWordNotFoundInDictionary(word.to_string()))
+    }
+    fn lookup_word(&self, mnemonic: MnemonicIndex) -> Result<String> {
+        let mut buf = [0u8; MAX_WORD_BYTES];
+        Ok(String::from(self.word(mnemonic, &mut buf)))
+    }
+}
+
+macro_rules! packed_dictionary {
+    ($feature:literal, $name:ident) => {
+        #[cfg(feature = $feature)]
+        pub static $name: PackedDictionary<{ packed_len(&$crate::bip::bip39::dictionary::$name) }> =
+            PackedDictionary::pack(&$crate::bip::bip39::dictionary::$name);
+    };
+}
+
+packed_dictionary!("bip39-english", ENGLISH);
+packed_dictionary!("bip39-french", FRENCH);
+packed_dictionary!("bip39-japanese", JAPANESE);
+packed_dictionary!("bip39-korean", KOREAN);
+packed_dictionary!("bip39-chinese-simplified", CHINESE_SIMPLIFIED);
+packed_dictionary!("bip39-chinese-traditional", CHINESE_TRADITIONAL);
+packed_dictionary!("bip39-italian", ITALIAN);
+packed_dictionary!("bip39-spanish", SPANISH);
+
+#[cfg(all(test, feature = "bip39-english"))]
+mod test {
+    use super::*;
+    use crate::bip::bip39::dictionary;
+
+    fn check_all_words<const N: usize>(packed: &PackedDictionary<N>, dic: &DefaultDictionary) {
+        let mut buf = [0u8; MAX_WORD_BYTES];
+        for (i, word) in dic.words.iter().enumerate() {
+            let index = MnemonicIndex(i as u16);
+            assert_eq!(packed.word(index, &mut buf), *word);
+            assert_eq!(packed.index_of(word), Some(index), "{word}");
+            assert_eq!(packed.lookup_mnemonic(word), dic.lookup_mnemonic(word));
+        }
+        assert_eq!(packed.name(), dic.name());
+        assert_eq!(packed.separator(), dic.separator());
+        assert_eq!(packed.index_of(""), None);
+        assert_eq!(packed.index_of("notaword"), None);
+        assert_eq!(packed.index_of("zzzzzzzzz"), None);
+    }
+
+    macro_rules! round_trip {
+        ($feature:literal, $test:ident, $name:ident) => {
+            #[cfg(feature = $feature)]
+            #[test]
+            fn $test() {
+                check_all_words(&$name, &dictionary::$name);
+            }
+        };
+    }
+
+    round_trip!("bip39-english", english, ENGLISH);
+    round_trip!("bip39-french", french, FRENCH);
+    round_trip!("bip39-japanese", japanese, JAPANESE);
+    round_trip!("bip39-korean", korean, KOREAN);
+    round_trip!(
+        "bip39-chinese-simplified",
+        chinese_simplified,
+        CHINESE_SIMPLIFIED
+    );
+    round_trip!(
+        "bip39-chinese-traditional",
+        chinese_traditional,
+        CHINESE_TRADITIONAL
+    );
+    round_trip!("bip39-italian", italian, ITALIAN);
+    round_trip!("bip39-spanish", spanish, SPANISH);
+
+    #[cfg(feature = "bip39-english")]
+    #[test]
+    fn sorted_flag() {
+        assert!(ENGLISH.sorted);
+    }
+}
@@ -0,0 +1,406 @@
+//! BIP39 mnemonic recovery assistant
+//!
+//! Helps a user who mistyped their mnemonic phrase: for words that are not
+//! in the dictionary it lists the nearest dictionary words, and for phrases
+//! made of valid words but with an invalid checksum it enumerates the single
+//! word substitutions that make the checksum valid again.
+//!
+//! Distances are a weighted Damerau-Levenshtein (optimal string alignment)
+//! where substituting a letter for one of its neighbours on a QWERTY keyboard
+//! is cheaper than any other substitution. All results are ranked by cost and
+//! bounded by the caller so the functions can run on-device with a small heap.
+//!
+//! # Example
+//!
+//! ```
+//! use cardano_embedded_sdk::bip::bip39::dictionary;
+//! use cardano_embedded_sdk::bip::recovery::*;
+//!
+//! let suggestions = suggest_words(&dictionary::ENGLISH, "abandn", DEFAULT_MAX_COST, 3);
+//! assert_eq!(suggestions[0].index.0, 0); // "abandon"
+//! ```
+//!
+use alloc::vec::Vec;
+use core::cmp::min;
+
+use super::bip39::{
+    dictionary::{DefaultDictionary, Language},
+    Entropy, MnemonicIndex, Mnemonics, Type,
+};
+
+/// cost of inserting or deleting a letter.
+pub const INDEL_COST: u16 = 2;
+/// cost of substituting a letter with one of its keyboard neighbours.
+pub const ADJACENT_SUBSTITUTION_COST: u16 = 1;
+/// cost of substituting a letter with any other letter.
+pub const SUBSTITUTION_COST: u16 = 2;
+/// cost of swapping two consecutive letters.
+pub const TRANSPOSITION_COST: u16 = 1;
+
+/// reasonable default for the maximum cost of a suggestion: roughly two
+/// typos away from the typed word.
+pub const DEFAULT_MAX_COST: u16 = 4;
+
+/// longest dictionary word (in characters) considered for suggestions.
+/// The longest word of the BIP39 default dictionaries is 11 characters.
+const MAX_WORD_CHARS: usize = 16;
+
+/// A dictionary word suggested for a mistyped word.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Suggestion {
+    /// index of the suggested word in the dictionary
+    pub index: MnemonicIndex,
+    /// weighted edit distance between the typed word and the suggestion
+    pub cost: u16,
+}
+
+/// A single word replacement that makes the mnemonic checksum valid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Substitution {
+    /// position of the word to replace in the mnemonic phrase
+    pub position: usize,
+    /// the word to put at `position` instead
+    pub replacement: MnemonicIndex,
+    /// weighted edit distance between the original word and its replacement
+    pub cost: u16,
+}
+
+/// A word of the mnemonic phrase that is not in the dictionary.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnknownWord {
+    /// position of the word in the mnemonic phrase
+    pub position: usize,
+    /// nearest dictionary words, best first
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Outcome of [`diagnose`](./fn.diagnose.html).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Diagnosis {
+    /// all words are known and the checksum is valid
+    Valid,
+    /// unsupported number of words, nothing can be suggested
+    WrongNumberOfWords(usize),
+    /// some words are not in the dictionary
+    UnknownWords(Vec<UnknownWord>),
+    /// all words are known but the checksum is invalid
+    InvalidChecksum(Vec<Substitution>),
+}
+
+/// list up to `limit` dictionary words within `max_cost` of `word`,
+/// ranked by increasing cost (ties are broken by dictionary order).
+///
+/// The typed word is expected to be normalized the same way as the
+/// dictionary (UTF-8 NFKD).
+///
+pub fn suggest_words(
+    dic: &DefaultDictionary,
+    word: &str,
+    max_cost: u16,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let mut ranked = Vec::with_capacity(limit);
+    for (i, candidate) in dic.words.iter().enumerate() {
+        if let Some(cost) = distance(word, candidate, max_cost) {
+            insert_ranked(
+                &mut ranked,
+                limit,
+                Suggestion {
+                    index: MnemonicIndex(i as u16),
+                    cost,
+                },
+                |s| (s.cost, s.index.0 as usize),
+            );
+        }
+    }
+    ranked
+}
+
+/// enumerate up to `limit` single word substitutions that turn the given
+/// mnemonics into a phrase with a valid checksum.
+///
+/// Substitutions are ranked by the distance between the original word and
+/// its replacement, so the most likely typo comes first. For a phrase of `n`
+/// words this performs `n * 2048` checksum computations.
+///
+pub fn suggest_checksum_fixes(
+    dic: &DefaultDictionary,
+    mnemonics: &Mnemonics,
+    limit: usize,
+) -> Vec<Substitution> {
+    let original = mnemonics.as_ref();
+    let mut candidate = Vec::from(original);
+    let mut ranked = Vec::with_capacity(limit);
+
+    for position in 0..original.len() {
+        let typed = dic.words[original[position].0 as usize];
+        for (i, word) in dic.words.iter().enumerate() {
+            let replacement = MnemonicIndex(i as u16);
+            if replacement == original[position] {
+                continue;
+            }
+            candidate[position] = replacement;
+            if !has_valid_checksum(&candidate) {
+                continue;
+            }
+            let cost = distance(typed, word, u16::MAX).unwrap_or(u16::MAX);
+            insert_ranked(
+                &mut ranked,
+                limit,
+                Substitution {
+                    position,
+                    replacement,
+                    cost,
+                },
+                |s| (s.cost, s.position * 2048 + s.replacement.0 as usize),
+            );
+        }
+        candidate[position] = original[position];
+    }
+    ranked
+}
+
+/// check the given mnemonic phrase and, if it is invalid, suggest how to
+/// fix it. At most `limit` suggestions are returned for each unknown word,
+/// or in total for checksum fixes.
+///
+pub fn diagnose(dic: &DefaultDictionary, mnemonics: &str, limit: usize) -> Diagnosis {
+    let words: Vec<&str> = mnemonics.split(dic.separator()).collect();
+    if Type::from_word_count(words.len()).is_err() {
+        return Diagnosis::WrongNumberOfWords(words.len());
+    }
+
+    let mut indices = Vec::with_capacity(words.len());
+    let mut unknown = Vec::new();
+    for (position, word) in words.iter().enumerate() {
+        match dic.lookup_mnemonic(word) {
+            Ok(index) => indices.push(index),
+            Err(_) => unknown.push(UnknownWord {
+                position,
+                suggestions: suggest_words(dic, word, DEFAULT_MAX_COST, limit),
+            }),
+        }
+    }
+    if !unknown.is_empty() {
+        return Diagnosis::UnknownWords(unknown);
+    }
+
+    // the word count has been validated above
+    let mnemonics = Mnemonics::from_mnemonics(indices).unwrap();
+    if Entropy::from_mnemonics(&mnemonics).is_ok() {
+        Diagnosis::Valid
+    } else {
+        Diagnosis::InvalidChecksum(suggest_checksum_fixes(dic, &mnemonics, limit))
+    }
+}
+
+fn has_valid_checksum(indices: &[MnemonicIndex]) -> bool {
+    Mnemonics::from_mnemonics(Vec::from(indices))
+        .and_then(|m| Entropy::from_mnemonics(&m))
+        .is_ok()
+}
+
+/// insert `item` in the ascending `ranked` list, keeping at most `limit` items.
+fn insert_ranked<T, F>(ranked: &mut Vec<T>, limit: usize, item: T, key: F)
+where
+    F: Fn(&T) -> (u16, usize),
+{
+    let k = key(&item);
+    let pos = ranked.partition_point(|r| key(r) < k);
+    if pos < limit {
+        if ranked.len() == limit {
+            ranked.pop();
+        }
+        ranked.insert(pos, item);
+    }
+}
+
+/// position of a lowercase ASCII letter on a QWERTY keyboard, the column
+/// is doubled and shifted by the row so staggered keys can be compared.
+fn key_position(c: char) -> Option<(i8, i8)> {
+    const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+    for (row, keys) in ROWS.iter().enumerate() {
+        if let Some(col) = keys.find(c) {
+            return Some((row as i8, 2 * col as i8 + row as i8));
+        }
+    }
+    None
+}
+
+fn substitution_cost(a: char, b: char) -> u16 {
+    if a == b {
+        return 0;
+    }
+    match (key_position(a), key_position(b)) {
+        (Some((ra, xa)), Some((rb, xb))) => {
+            let adjacent = match (ra - rb).abs() {
+                0 => (xa - xb).abs() == 2,
+                1 => (xa - xb).abs() == 1,
+                _ => false,
+            };
+            if adjacent {
+                ADJACENT_SUBSTITUTION_COST
+            } else {
+                SUBSTITUTION_COST
+            }
+        }
+        _ => SUBSTITUTION_COST,
+    }
+}
+
+/// weighted optimal string alignment distance between `typed` and
+/// `candidate`, or `None` if it exceeds `bound`.
+///
+/// Only three rows of the distance matrix are kept, on the stack.
+fn distance(typed: &str, candidate: &str, bound: u16) -> Option<u16> {
+    let mut cand = ['\0'; MAX_WORD_CHARS];
+    let mut n = 0;
+    for c in candidate.chars() {
+        if n == MAX_WORD_CHARS {
+            return None;
+        }
+        cand[n] = c;
+        n += 1;
+    }
+
+    let mut prev2 = [0u16; MAX_WORD_CHARS + 1];
+    let mut prev = [0u16; MAX_WORD_CHARS + 1];
+    let mut cur = [0u16; MAX_WORD_CHARS + 1];
+    for (j, p) in prev.iter_mut().enumerate().take(n + 1) {
+        *p = j as u16 * INDEL_COST;
+    }
+
+    let mut last: Option<char> = None;
+    // the next row can also extend a transposition from the previous one
+    let mut prev_min = 0u16;
+    for (i, a) in typed.chars().enumerate() {
+        cur[0] = (i as u16 + 1).saturating_mul(INDEL_COST);
+        let mut row_min = cur[0];
+        for j in 1..=n {
+            let b = cand[j - 1];
+            let mut d = min(
+                min(prev[j], cur[j - 1]).saturating_add(INDEL_COST),
+                prev[j - 1].saturating_add(substitution_cost(a, b)),
+            );
+            if j > 1 && last == Some(b) && cand[j - 2] == a {
+                d = min(d, prev2[j - 2].saturating_add(TRANSPOSITION_COST));
+            }
+            cur[j] = d;
+            row_min = min(row_min, d);
+        }
+        if row_min > bound && prev_min.saturating_add(TRANSPOSITION_COST) > bound {
+            return None;
+        }
+        prev_min = row_min;
+        prev2 = prev;
+        prev = cur;
+        last = Some(a);
+    }
+
+    if prev[n] <= bound {
+        Some(prev[n])
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "bip39-english"))]
+mod test {
+    use super::*;
+    use crate::bip::bip39::dictionary;
+
+    const MNEMONICS: &str =
+        "mimic left ask vacant toast follow bitter join diamond gate attend obey";
+
+    fn word(i: MnemonicIndex) -> &'static str {
+        dictionary::ENGLISH.words[i.0 as usize]
+    }
+
+    #[test]
+    fn distance_weights() {
+        assert_eq!(distance("crack", "crack", 10), Some(0));
+        // `j` is next to `k` on the keyboard, `p` is not
+        assert_eq!(distance("cracj", "crack", 10), Some(1));
+        assert_eq!(distance("cracp", "crack", 10), Some(2));
+        assert_eq!(distance("carck", "crack", 10), Some(1));
+        assert_eq!(distance("crak", "crack", 10), Some(2));
+        assert_eq!(distance("crackk", "crack", 10), Some(2));
+        assert_eq!(distance("zoo", "abandon", 4), None);
+    }
+
+    #[test]
+    fn distance_transposition_at_bound() {
+        // the first row is above the bound, the transposition is not
+        assert_eq!(distance("ba", "ab", 1), Some(1));
+        assert_eq!(distance("rcack", "crack", 1), Some(1));
+        assert_eq!(distance("ba", "ab", 0), None);
+    }
+
+    #[test]
+    fn suggest_missing_letter() {
+        let suggestions = suggest_words(&dictionary::ENGLISH, "abandn", DEFAULT_MAX_COST, 5);
+        assert_eq!(word(suggestions[0].index), "abandon");
+        assert_eq!(suggestions[0].cost, 2);
+    }
+
+    #[test]
+    fn suggest_adjacent_key_first() {
+        let suggestions = suggest_words(&dictionary::ENGLISH, "shwll", DEFAULT_MAX_COST, 5);
+        assert_eq!(word(suggestions[0].index), "shell");
+    }
+
+    #[test]
+    fn suggestions_are_ranked_and_bounded() {
+        let suggestions = suggest_words(&dictionary::ENGLISH, "cat", DEFAULT_MAX_COST, 4);
+        assert_eq!(suggestions.len(), 4);
+        assert_eq!(word(suggestions[0].index), "cat");
+        assert!(suggestions.windows(2).all(|w| w[0].cost <= w[1].cost));
+        assert!(suggestions.iter().all(|s| s.cost <= DEFAULT_MAX_COST));
+    }
+
+    #[test]
+    fn checksum_fix_recovers_original_word() {
+        let dic = &dictionary::ENGLISH;
+        let typo = MNEMONICS.replace("diamond", "dinosaur");
+        let mnemonics = Mnemonics::from_string(dic, &typo).unwrap();
+        assert!(Entropy::from_mnemonics(&mnemonics).is_err());
+
+        let fixes = suggest_checksum_fixes(dic, &mnemonics, 2048);
+        assert!(fixes
+            .iter()
+            .any(|s| s.position == 8 && word(s.replacement) == "diamond"));
+        assert!(fixes.windows(2).all(|w| w[0].cost <= w[1].cost));
+        for fix in fixes.iter() {
+            let mut indices = Vec::from(mnemonics.as_ref());
+            indices[fix.position] = fix.replacement;
+            assert!(has_valid_checksum(&indices));
+        }
+
+        let bounded = suggest_checksum_fixes(dic, &mnemonics, 3);
+        assert_eq!(bounded[..], fixes[..3]);
+    }
+
+    #[test]
+    fn diagnose_phrases() {
+        let dic = &dictionary::ENGLISH;
+        assert_eq!(diagnose(dic, MNEMONICS, 3), Diagnosis::Valid);
+        assert_eq!(
+            diagnose(dic, "mimic left ask", 3),
+            Diagnosis::WrongNumberOfWords(3)
+        );
+
+        match diagnose(dic, &MNEMONICS.replace("toast", "toasr"), 3) {
+            Diagnosis::UnknownWords(unknown) => {
+                assert_eq!(unknown.len(), 1);
+                assert_eq!(unknown[0].position, 4);
+                assert_eq!(word(unknown[0].suggestions[0].index), "toast");
+            }
+            d => panic!("unexpected diagnosis {d:?}"),
+        }
+
+        match diagnose(dic, &MNEMONICS.replace("obey", "abandon"), 3) {
+            Diagnosis::InvalidChecksum(fixes) => assert!(fixes.len() <= 3),
+            d => panic!("unexpected diagnosis {d:?}"),
+        }
+    }
+}
@@ -0,0 +1,471 @@
+//! User supplied entropy for seed generation
+//!
+//! For users who do not want to trust the device random generator alone,
+//! the [`EntropyCollector`](./struct.EntropyCollector.html) gathers dice rolls
+//! or coin flips, estimates how many bits of entropy were collected and mixes
+//! them with the device random generator output through SHA-512. Neither the
+//! physical source nor the device alone determines the resulting
+//! [`Entropy`](../bip39/enum.Entropy.html).
+//!
+//! Every generation produces a [`Transcript`](./struct.Transcript.html) with
+//! all the inputs of the mix. It can be written down and checked offline by
+//! recomputing:
+//!
+//! ```text
+//! SHA-512( "embedano entropy transcript v1"
+//!        | word count (1 byte)
+//!        | number of sides, 2 for a coin (1 byte)
+//!        | number of outcomes (4 bytes, big endian)
+//!        | outcomes, zero based, one byte each
+//!        | device random bytes
+//!        )
+//! ```
+//!
+//! and keeping the first `entropy size` bytes of the digest.
+//!
+//! **The transcript contains everything needed to recreate the wallet, it
+//! must be handled with the same care as the mnemonic phrase.**
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate rand;
+//! use cardano_embedded_sdk::bip::{bip39::Type, user_entropy::*};
+//!
+//! let mut collector = EntropyCollector::new(Source::Dice(6)).unwrap();
+//! for _ in 0..50 {
+//!     collector.push(4).unwrap();
+//! }
+//! assert_eq!(collector.bits_collected(), 129);
+//!
+//! let transcript = collector
+//!     .finish(Type::Type12Words, &mut rand::rngs::OsRng)
+//!     .unwrap();
+//! let entropy = transcript.to_entropy();
+//!
+//! let text = transcript.to_string();
+//! let parsed: Transcript = text.parse().unwrap();
+//! assert_eq!(parsed.to_entropy(), entropy);
+//! ```
+//!
+use alloc::{vec, vec::Vec};
+use core::{error, fmt, result, str};
+
+use cryptoxide::digest::Digest;
+use cryptoxide::sha2::Sha512;
+use rand_core::{CryptoRng, RngCore};
+
+use super::bip39::{Entropy, Type};
+use crate::util::securemem;
+
+const TRANSCRIPT_HEADER: &str = "embedano entropy transcript v1";
+
+/// Error regarding user entropy collection
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// dice must have between 2 and 255 sides.
+    UnsupportedSides(u8),
+
+    /// the given outcome is not a face of the die (or a side of the coin).
+    OutcomeOutOfRange(u8),
+
+    /// not enough outcomes have been collected for the requested
+    /// entropy type. The parameters are the collected and required bits.
+    NotEnoughEntropy { collected: usize, required: usize },
+
+    /// the transcript could not be parsed, the parameter describes
+    /// the faulty part.
+    InvalidTranscript(&'static str),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnsupportedSides(sides) => write!(f, "Unsupported die with {sides} sides"),
+            Error::OutcomeOutOfRange(outcome) => write!(f, "Invalid outcome {outcome}"),
+            Error::NotEnoughEntropy {
+                collected,
+                required,
+            } => write!(
+                f,
+                "Not enough entropy, collected {collected} bits but {required} bits are required"
+            ),
+            Error::InvalidTranscript(part) => write!(f, "Invalid entropy transcript: {part}"),
+        }
+    }
+}
+impl error::Error for Error {}
+
+pub type Result<T> = result::Result<T, Error>;
+
+/// physical source of the user entropy
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Source {
+    /// a fair coin, outcomes are `0` (tails) or `1` (heads)
+    Coin,
+    /// a fair die with the given number of sides, outcomes are `1..=sides`
+    Dice(u8),
+}
+impl Source {
+    fn sides(self) -> u8 {
+        match self {
+            Source::Coin => 2,
+            Source::Dice(sides) => sides,
+        }
+    }
+
+    /// zero based value of the given outcome
+    fn value_of(self, outcome: u8) -> Result<u8> {
+        match self {
+            Source::Coin if outcome <= 1 => Ok(outcome),
+            Source::Dice(sides) if outcome >= 1 && outcome <= sides => Ok(outcome - 1),
+            _ => Err(Error::OutcomeOutOfRange(outcome)),
+        }
+    }
+}
+
+/// Collect dice rolls or coin flips until enough entropy is gathered.
+pub struct EntropyCollector {
+    source: Source,
+    values: Vec<u8>,
+}
+impl EntropyCollector {
+    /// start collecting outcomes of the given source.
+    ///
+    /// # Error
+    ///
+    /// fails if the source is a die with less than 2 sides.
+    pub fn new(source: Source) -> Result<Self> {
+        if source.sides() < 2 {
+            return Err(Error::UnsupportedSides(source.sides()));
+        }
+        Ok(EntropyCollector {
+            source,
+            values: Vec::new(),
+        })
+    }
+
+    /// record one outcome: `1..=sides` for dice, `0` or `1` for a coin.
+    pub fn push(&mut self, outcome: u8) -> Result<()> {
+        let value = self.source.value_of(outcome)?;
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// number of outcomes recorded so far
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// conservative estimate (rounded down) of the bits of entropy
+    /// collected so far, assuming a fair source.
+    pub fn bits_collected(&self) -> usize {
+        bits_of(self.source.sides(), self.values.len())
+    }
+
+    /// number of outcomes still needed to cover the entropy size of
+    /// the given [`Type`](../bip39/enum.Type.html).
+    pub fn remaining_for(&self, t: Type) -> usize {
+        let mut n = self.values.len();
+        while bits_of(self.source.sides(), n) < t.to_key_size() {
+            n += 1;
+        }
+        n - self.values.len()
+    }
+
+    /// mix the collected outcomes with the output of the device random
+    /// generator and return the transcript of the operation.
+    ///
+    /// # Error
+    ///
+    /// fails if the outcomes collected so far do not carry at least as many
+    /// bits as the entropy size of the requested type.
+    pub fn finish<R>(mut self, t: Type, rng: &mut R) -> Result<Transcript>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let collected = self.bits_collected();
+        let required = t.to_key_size();
+        if collected < required {
+            return Err(Error::NotEnoughEntropy {
+                collected,
+                required,
+            });
+        }
+        let mut device = vec![0u8; required / 8];
+        rng.fill_bytes(&mut device);
+        Ok(Transcript {
+            entropy_type: t,
+            source: self.source,
+            values: core::mem::take(&mut self.values),
+            device,
+        })
+    }
+}
+impl Drop for EntropyCollector {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.values);
+    }
+}
+
+/// All the inputs of an entropy mix, enough to recompute the
+/// resulting [`Entropy`](../bip39/enum.Entropy.html) offline.
+///
+/// The text representation (`Display` and `FromStr`) is line based:
+///
+/// ```text
+/// embedano entropy transcript v1
+/// words: 12
+/// source: d6
+/// outcomes: 4 1 6 ...
+/// device: 3f0a...
+/// ```
+///
+/// Coin flips are written `source: coin` with outcomes `0` and `1`.
+pub struct Transcript {
+    entropy_type: Type,
+    source: Source,
+    values: Vec<u8>,
+    device: Vec<u8>,
+}
+impl Transcript {
+    pub fn entropy_type(&self) -> Type {
+        self.entropy_type
+    }
+
+    pub fn source(&self) -> Source {
+        self.source
+    }
+
+    /// recompute the `Entropy` from the transcript inputs.
+    pub fn to_entropy(&self) -> Entropy {
+        let mut hasher = Sha512::new();
+        hasher.input(TRANSCRIPT_HEADER.as_bytes());
+        hasher.input(&[
+            self.entropy_type.mnemonic_count() as u8,
+            self.source.sides(),
+        ]);
+        hasher.input(&(self.values.len() as u32).to_be_bytes());
+        hasher.input(&self.values);
+        hasher.input(&self.device);
+        let mut digest = [0u8; 64];
+        hasher.result(&mut digest);
+
+        // the size is valid by construction of the transcript
+        let entropy = Entropy::from_slice(&digest[..self.entropy_type.to_key_size() / 8]).unwrap();
+        securemem::zero(&mut digest);
+        entropy
+    }
+}
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{TRANSCRIPT_HEADER}")?;
+        writeln!(f, "words: {}", self.entropy_type)?;
+        match self.source {
+            Source::Coin => writeln!(f, "source: coin")?,
+            Source::Dice(sides) => writeln!(f, "source: d{sides}")?,
+        }
+        write!(f, "outcomes:")?;
+        let offset = match self.source {
+            Source::Coin => 0,
+            Source::Dice(_) => 1,
+        };
+        for value in self.values.iter() {
+            write!(f, " {}", value + offset)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "device: {}", hex::encode(&self.device))
+    }
+}
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transcript")
+            .field("entropy_type", &self.entropy_type)
+            .field("source", &self.source)
+            .field("outcomes", &self.values.len())
+            .finish()
+    }
+}
+impl str::FromStr for Transcript {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+        if lines.next() != Some(TRANSCRIPT_HEADER) {
+            return Err(Error::InvalidTranscript("header"));
+        }
+        let mut field = |name: &'static str| {
+            lines
+                .next()
+                .and_then(|l| l.strip_prefix(name))
+                .and_then(|l| l.strip_prefix(':'))
+                .map(str::trim)
+                .ok_or(Error::InvalidTranscript(name))
+        };
+
+        let entropy_type = field("words")?
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| Type::from_word_count(n).ok())
+            .ok_or(Error::InvalidTranscript("words"))?;
+
+        let source = match field("source")? {
+            "coin" => Source::Coin,
+            d => d
+                .strip_prefix('d')
+                .and_then(|sides| sides.parse::<u8>().ok())
+                .filter(|sides| *sides >= 2)
+                .map(Source::Dice)
+                .ok_or(Error::InvalidTranscript("source"))?,
+        };
+
+        let mut values = Vec::new();
+        for outcome in field("outcomes")?.split_whitespace() {
+            let outcome = outcome
+                .parse::<u8>()
+                .map_err(|_| Error::InvalidTranscript("outcomes"))?;
+            values.push(source.value_of(outcome)?);
+        }
+        if bits_of(source.sides(), values.len()) < entropy_type.to_key_size() {
+            return Err(Error::InvalidTranscript("outcomes"));
+        }
+
+        let device =
+            hex::decode(field("device")?).map_err(|_| Error::InvalidTranscript("device"))?;
+        if device.len() != entropy_type.to_key_size() / 8 {
+            return Err(Error::InvalidTranscript("device"));
+        }
+
+        Ok(Transcript {
+            entropy_type,
+            source,
+            values,
+            device,
+        })
+    }
+}
+impl Drop for Transcript {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.values);
+        securemem::zero(&mut self.device);
+    }
+}
+
+/// `floor(count * log2(sides))`
+fn bits_of(sides: u8, count: usize) -> usize {
+    ((count as u64 * log2_q16(sides as u32) as u64) >> 16) as usize
+}
+
+/// `log2(n)` in 16.16 fixed point, rounded down. `n` must not be zero.
+fn log2_q16(n: u32) -> u32 {
+    let int = 31 - n.leading_zeros();
+    // n / 2^int in 32.32 fixed point, in the range [1, 2)
+    let mut y = (n as u128) << (32 - int);
+    let mut frac = 0;
+    for bit in (0..16).rev() {
+        y = (y * y) >> 32;
+        if y >= 2 << 32 {
+            y >>= 1;
+            frac |= 1 << bit;
+        }
+    }
+    (int << 16) | frac
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    fn rng() -> ChaChaRng {
+        ChaChaRng::from_seed([7; 32])
+    }
+
+    #[test]
+    fn log2_fixed_point() {
+        assert_eq!(log2_q16(2), 1 << 16);
+        assert_eq!(log2_q16(8), 3 << 16);
+        // log2(6) = 2.58496...
+        assert_eq!(log2_q16(6), 169_408);
+        assert_eq!(bits_of(6, 99), 255);
+        assert_eq!(bits_of(6, 100), 258);
+        assert_eq!(bits_of(2, 128), 128);
+    }
+
+    #[test]
+    fn outcomes_are_validated() {
+        assert_eq!(
+            EntropyCollector::new(Source::Dice(1)).err(),
+            Some(Error::UnsupportedSides(1))
+        );
+        let mut dice = EntropyCollector::new(Source::Dice(6)).unwrap();
+        assert_eq!(dice.push(0), Err(Error::OutcomeOutOfRange(0)));
+        assert_eq!(dice.push(7), Err(Error::OutcomeOutOfRange(7)));
+        assert_eq!(dice.push(6), Ok(()));
+        let mut coin = EntropyCollector::new(Source::Coin).unwrap();
+        assert_eq!(coin.push(2), Err(Error::OutcomeOutOfRange(2)));
+        assert_eq!(coin.push(0), Ok(()));
+    }
+
+    #[test]
+    fn not_enough_entropy() {
+        let mut coin = EntropyCollector::new(Source::Coin).unwrap();
+        for i in 0..127 {
+            coin.push(i % 2).unwrap();
+        }
+        assert_eq!(coin.remaining_for(Type::Type12Words), 1);
+        assert_eq!(
+            coin.finish(Type::Type12Words, &mut rng()).err(),
+            Some(Error::NotEnoughEntropy {
+                collected: 127,
+                required: 128
+            })
+        );
+    }
+
+    #[test]
+    fn both_sources_influence_the_result() {
+        let collect = |last: u8| {
+            let mut dice = EntropyCollector::new(Source::Dice(6)).unwrap();
+            for _ in 0..99 {
+                dice.push(3).unwrap();
+            }
+            dice.push(last).unwrap();
+            dice
+        };
+        let a = collect(1).finish(Type::Type24Words, &mut rng()).unwrap();
+        let b = collect(2).finish(Type::Type24Words, &mut rng()).unwrap();
+        let mut other_rng = ChaChaRng::from_seed([8; 32]);
+        let c = collect(1)
+            .finish(Type::Type24Words, &mut other_rng)
+            .unwrap();
+
+        assert_eq!(a.to_entropy().get_type(), Type::Type24Words);
+        assert_ne!(a.to_entropy(), b.to_entropy());
+        assert_ne!(a.to_entropy(), c.to_entropy());
+    }
+
+    #[test]
+    fn transcript_round_trip() {
+        let mut coin = EntropyCollector::new(Source::Coin).unwrap();
+        for i in 0..96u32 {
+            coin.push((i * 7 % 3 % 2) as u8).unwrap();
+        }
+        let transcript = coin.finish(Type::Type9Words, &mut rng()).unwrap();
+        let text = transcript.to_string();
+        assert!(text.contains("source: coin"));
+
+        let parsed: Transcript = text.parse().unwrap();
+        assert_eq!(parsed.to_entropy(), transcript.to_entropy());
+
+        let tampered = text.replace("words: 9", "words: 12");
+        assert_eq!(
+            tampered.parse::<Transcript>().err(),
+            Some(Error::InvalidTranscript("outcomes"))
+        );
+    }
+}
@@ -1,10 +1,15 @@
+use crate::chain_crypto::cip5::Prefix;
 use crate::chain_crypto::key::{
     AsymmetricKey, AsymmetricPublicKey, PublicKeyError, SecretKeyError, SecretKeySizeStatic,
 };
 use crate::chain_crypto::sign::{
-    SignatureError, SigningAlgorithm, Verification, VerificationAlgorithm,
+    SignatureError, SigningAlgorithm, Verification, VerificationAlgorithm, VerifyMode,
 };
+use crate::util::securemem;
+use alloc::vec::Vec;
+use cryptoxide::curve25519::{Ge, GeCached, GeP2, Scalar};
 use cryptoxide::ed25519;
+use cryptoxide::hashing::sha2::Sha512;
 use rand_core::{CryptoRng, RngCore};
 
 use ed25519_bip32::XPub;
@@ -13,7 +18,7 @@ use ed25519_bip32::XPub;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Ed25519;
 
-#[derive(Clone)]
+#[cfg_attr(test, derive(Clone))]
 pub struct Priv([u8; ed25519::PRIVATE_KEY_LENGTH]);
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -35,6 +40,11 @@ impl AsRef<[u8]> for Priv {
         &self.0[..]
     }
 }
+impl Drop for Priv {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.0);
+    }
+}
 
 impl AsRef<[u8]> for Pub {
     fn as_ref(&self) -> &[u8] {
@@ -51,7 +61,7 @@ impl AsRef<[u8]> for Sig {
 impl AsymmetricPublicKey for Ed25519 {
     type Public = Pub;
 
-    const PUBLIC_BECH32_HRP: &'static str = "ed25519_pk";
+    const PUBLIC_BECH32_PREFIX: Prefix = Prefix::Ed25519Pk;
     const PUBLIC_KEY_SIZE: usize = ed25519::PUBLIC_KEY_LENGTH;
 
     fn public_from_binary(data: &[u8]) -> Result<Self::Public, PublicKeyError> {
@@ -68,16 +78,19 @@ impl AsymmetricKey for Ed25519 {
     type Secret = Priv;
     type PubAlg = Ed25519;
 
-    const SECRET_BECH32_HRP: &'static str = "ed25519_sk";
+    const SECRET_BECH32_PREFIX: Prefix = Prefix::Ed25519Sk;
 
     fn generate<T: RngCore + CryptoRng>(mut rng: T) -> Self::Secret {
         let mut priv_bytes = [0u8; ed25519::PRIVATE_KEY_LENGTH];
         rng.fill_bytes(&mut priv_bytes);
-        Priv(priv_bytes)
+        let key = Priv(priv_bytes);
+        securemem::zero(&mut priv_bytes);
+        key
     }
 
     fn compute_public(key: &Self::Secret) -> <Self::PubAlg as AsymmetricPublicKey>::Public {
-        let (_, pk) = ed25519::keypair(&key.0);
+        let (mut sk, pk) = ed25519::keypair(&key.0);
+        securemem::zero(&mut sk);
         Pub(pk)
     }
 
@@ -87,7 +100,9 @@ impl AsymmetricKey for Ed25519 {
         }
         let mut buf = [0; ed25519::PRIVATE_KEY_LENGTH];
         buf[0..ed25519::PRIVATE_KEY_LENGTH].clone_from_slice(data);
-        Ok(Priv(buf))
+        let key = Priv(buf);
+        securemem::zero(&mut buf);
+        Ok(key)
     }
 }
 
@@ -99,7 +114,7 @@ impl VerificationAlgorithm for Ed25519 {
     type Signature = Sig;
 
     const SIGNATURE_SIZE: usize = ed25519::SIGNATURE_LENGTH;
-    const SIGNATURE_BECH32_HRP: &'static str = "ed25519_sig";
+    const SIGNATURE_BECH32_PREFIX: Prefix = Prefix::Ed25519Sig;
 
     fn signature_from_bytes(data: &[u8]) -> Result<Self::Signature, SignatureError> {
         if data.len() != ed25519::SIGNATURE_LENGTH {
@@ -120,12 +135,185 @@ impl VerificationAlgorithm for Ed25519 {
     ) -> Verification {
         ed25519::verify(msg, &pubkey.0, &signature.0).into()
     }
+
+    fn verify_bytes_with_mode(
+        pubkey: &Self::Public,
+        signature: &Self::Signature,
+        msg: &[u8],
+        mode: VerifyMode,
+    ) -> Verification {
+        verify_with_mode(msg, &pubkey.0, &signature.0, mode).into()
+    }
+}
+
+const IDENTITY: [u8; 32] = {
+    let mut identity = [0; 32];
+    identity[0] = 1;
+    identity
+};
+
+/// true if the `y` coordinate is reduced (`y < p`) and the sign bit is not
+/// set for `x = 0` (the points of `y = 1` and `y = p - 1`).
+fn is_canonical(point: &[u8; 32]) -> bool {
+    let high = point[1..31].iter().all(|b| *b == 0xff) && point[31] & 0x7f == 0x7f;
+    let reduced = !(high && point[0] >= 0xed);
+    let y_is_one = point[0] == 1 && point[1..31].iter().all(|b| *b == 0) && point[31] & 0x7f == 0;
+    let y_is_minus_one = high && point[0] == 0xec;
+    let x_is_zero = y_is_one || y_is_minus_one;
+    reduced && !(x_is_zero && point[31] & 0x80 != 0)
+}
+
+/// `[8] point`, as an encoded point.
+fn mul_by_cofactor(point: &Ge) -> [u8; 32] {
+    point
+        .to_p2()
+        .dbl()
+        .to_p2()
+        .dbl()
+        .to_p2()
+        .dbl()
+        .to_p2()
+        .to_bytes()
+}
+
+fn has_small_order(point: &[u8; 32]) -> bool {
+    match Ge::from_bytes(point) {
+        Some(point) => mul_by_cofactor(&point) == IDENTITY,
+        None => false,
+    }
+}
+
+/// `k = H(R || A || M)`
+fn challenge(r: &[u8; 32], public: &[u8; 32], msg: &[u8]) -> Scalar {
+    let k = Sha512::new()
+        .update(r)
+        .update(public)
+        .update(msg)
+        .finalize();
+    Scalar::reduce_from_wide_bytes(&k)
+}
+
+/// `sum [scalar] point` by windows of 4 bits, the doublings are shared by
+/// all the points (Straus).
+fn multiscalar_mul(terms: &[([u8; 32], Ge)]) -> Ge {
+    // [1] to [15] point of each term
+    let tables: Vec<Vec<GeCached>> = terms
+        .iter()
+        .map(|(_, point)| {
+            let mut table = Vec::with_capacity(15);
+            table.push(point.to_cached());
+            let mut multiple = (point + &table[0]).to_full();
+            for _ in 1..15 {
+                table.push(multiple.to_cached());
+                multiple = (&multiple + &table[0]).to_full();
+            }
+            table
+        })
+        .collect();
+    // decoding negates the point, the identity stays the identity
+    let mut acc = Ge::from_bytes(&IDENTITY).unwrap();
+    for window in (0..64).rev() {
+        for _ in 0..4 {
+            acc = acc.to_p2().dbl().to_full();
+        }
+        for ((scalar, _), table) in terms.iter().zip(&tables) {
+            let digit = (scalar[window / 2] >> (4 * (window % 2))) & 0x0f;
+            if digit != 0 {
+                acc = (&acc + &table[digit as usize - 1]).to_full();
+            }
+        }
+    }
+    acc
+}
+
+/// verify all the signatures at once with the cofactored equation of
+/// `VerifyMode::Zip215`, combined with random 128 bits factors `z`:
+/// `[8]([sum z S]B - sum [z]R - sum [z k]A) = 0`.
+///
+/// `false` if any of the signatures is invalid, which one is not known.
+pub(crate) fn verify_batch<R: RngCore + CryptoRng>(
+    entries: &[(&[u8; 32], &[u8], &[u8; 64])],
+    mut rng: R,
+) -> bool {
+    let zero = Scalar::from_bytes(&[0; 32]);
+    let mut s_sum = zero;
+    let mut terms = Vec::with_capacity(2 * entries.len());
+    for (public, msg, signature) in entries {
+        let r = <&[u8; 32]>::try_from(&signature[0..32]).unwrap();
+        let s = match Scalar::from_bytes_canonical(signature[32..64].try_into().unwrap()) {
+            Some(s) => s,
+            None => return false,
+        };
+        // both decoded negated: -A and -R
+        let (minus_a, minus_r) = match (Ge::from_bytes(public), Ge::from_bytes(r)) {
+            (Some(minus_a), Some(minus_r)) => (minus_a, minus_r),
+            _ => return false,
+        };
+        let k = challenge(r, public, msg);
+
+        let mut z = [0; 32];
+        rng.fill_bytes(&mut z[..16]);
+        let z = Scalar::from_bytes(&z);
+        s_sum = Scalar::muladd(&z, &s, &s_sum);
+        terms.push((z.to_bytes(), minus_r));
+        terms.push((Scalar::muladd(&z, &k, &zero).to_bytes(), minus_a));
+    }
+    let sum = (&multiscalar_mul(&terms) + &Ge::scalarmult_base(&s_sum).to_cached()).to_full();
+    mul_by_cofactor(&sum) == IDENTITY
+}
+
+/// verify an Ed25519 signature with the acceptance rules of `mode`.
+pub(crate) fn verify_with_mode(
+    msg: &[u8],
+    public: &[u8; 32],
+    signature: &[u8; 64],
+    mode: VerifyMode,
+) -> bool {
+    let r = <&[u8; 32]>::try_from(&signature[0..32]).unwrap();
+    let s = match Scalar::from_bytes_canonical(signature[32..64].try_into().unwrap()) {
+        Some(s) => s,
+        None => return false,
+    };
+    let encodings_ok = match mode {
+        VerifyMode::Libsodium => {
+            is_canonical(public) && !has_small_order(public) && !has_small_order(r)
+        }
+        VerifyMode::Rfc8032Strict => is_canonical(public) && is_canonical(r),
+        VerifyMode::Zip215 => true,
+    };
+    if !encodings_ok {
+        return false;
+    }
+    // the decoded point is negated: -A
+    let minus_a = match Ge::from_bytes(public) {
+        Some(point) => point,
+        None => return false,
+    };
+    let k = challenge(r, public, msg);
+    // R' = [S]B - [k]A
+    let r_computed = GeP2::double_scalarmult_vartime(&k, minus_a, &s).to_bytes();
+
+    match mode {
+        VerifyMode::Libsodium | VerifyMode::Rfc8032Strict => r_computed == *r,
+        VerifyMode::Zip215 => {
+            // [8](R - R') == 0, both points decoded negated
+            match (Ge::from_bytes(r), Ge::from_bytes(&r_computed)) {
+                (Some(minus_r), Some(minus_r_computed)) => {
+                    let diff = (&minus_r_computed - &minus_r.to_cached()).to_full();
+                    mul_by_cofactor(&diff) == IDENTITY
+                }
+                _ => false,
+            }
+        }
+    }
 }
 
 impl SigningAlgorithm for Ed25519 {
     fn sign(key: &Self::Secret, msg: &[u8]) -> Sig {
-        let (sk, _) = ed25519::keypair(&key.0);
-        Sig(ed25519::signature(msg, &sk))
+        let (mut sk, _) = ed25519::keypair(&key.0);
+        let signature = ed25519::signature(msg, &sk);
+        securemem::zero(&mut sk);
+        Sig(signature)
     }
 }
 
@@ -146,4 +334,175 @@ mod test {
     fn sign_ko(input: (KeyPair<Ed25519>, KeyPair<Ed25519>, Vec<u8>)) -> bool {
         keypair_signing_ko(input)
     }
+
+    // Besides the 12 cases of "Taming the many EdDSAs" (ed25519-speccheck),
+    // the vectors below are hand-built edge cases: non-canonical S, R and
+    // public keys, and small order points.
+
+    const MODES: [VerifyMode; 3] = [
+        VerifyMode::Libsodium,
+        VerifyMode::Rfc8032Strict,
+        VerifyMode::Zip215,
+    ];
+
+    /// y = p - 1, x = 0: the point of order 2
+    const ORDER_2: [u8; 32] = {
+        let mut point = [0xff; 32];
+        point[0] = 0xec;
+        point[31] = 0x7f;
+        point
+    };
+
+    /// (libsodium, strict, zip215) results
+    fn accepted(public: &[u8; 32], signature: &[u8; 64], msg: &[u8]) -> [bool; 3] {
+        MODES.map(|mode| verify_with_mode(msg, public, signature, mode))
+    }
+
+    fn signature(r: &[u8; 32], s: &[u8; 32]) -> [u8; 64] {
+        let mut signature = [0; 64];
+        signature[..32].copy_from_slice(r);
+        signature[32..].copy_from_slice(s);
+        signature
+    }
+
+    #[test]
+    fn rfc8032_test_1() {
+        let public =
+            hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+                .unwrap();
+        let signature = hex::decode("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b").unwrap();
+        let public = public.as_slice().try_into().unwrap();
+        let signature = signature.as_slice().try_into().unwrap();
+        assert_eq!(accepted(public, signature, b""), [true; 3]);
+        assert_eq!(accepted(public, signature, b"x"), [false; 3]);
+    }
+
+    #[test]
+    fn non_canonical_s() {
+        let (keypair, public) = ed25519::keypair(&[0x42; 32]);
+        let mut signature = ed25519::signature(b"msg", &keypair);
+        assert_eq!(accepted(&public, &signature, b"msg"), [true; 3]);
+
+        // S + L
+        const L: [u8; 32] = [
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+        ];
+        let mut carry = 0;
+        for (s, l) in signature[32..].iter_mut().zip(L) {
+            let sum = *s as u16 + l as u16 + carry;
+            *s = sum as u8;
+            carry = sum >> 8;
+        }
+        assert_eq!(accepted(&public, &signature, b"msg"), [false; 3]);
+    }
+
+    #[test]
+    fn small_order_public_key_and_r() {
+        let signature = signature(&IDENTITY, &[0; 32]);
+        assert_eq!(accepted(&IDENTITY, &signature, b"msg"), [false, true, true]);
+    }
+
+    #[test]
+    fn non_canonical_r() {
+        // y = p + 1, another encoding of the identity
+        let mut r = ORDER_2;
+        r[0] = 0xee;
+        let signature = signature(&r, &[0; 32]);
+        assert_eq!(
+            accepted(&IDENTITY, &signature, b"msg"),
+            [false, false, true]
+        );
+    }
+
+    #[test]
+    fn non_canonical_public_key() {
+        let signature = signature(&IDENTITY, &[0; 32]);
+        let mut y_overflow = ORDER_2;
+        y_overflow[0] = 0xee;
+        let mut negative_zero = IDENTITY;
+        negative_zero[31] |= 0x80;
+        for public in [y_overflow, negative_zero] {
+            assert_eq!(accepted(&public, &signature, b"msg"), [false, false, true]);
+        }
+    }
+
+    #[test]
+    fn mixed_order_public_key() {
+        // A = [a]B + T with T of order 2, S = r + k a: [S]B - [k]A = R - [k]T
+        let a = Scalar::reduce_from_wide_bytes(&[7; 64]);
+        let t = Ge::from_bytes(&ORDER_2).unwrap();
+        let public = (&Ge::scalarmult_base(&a) + &t.to_cached())
+            .to_full()
+            .to_bytes();
+        let sign = |msg: &[u8]| {
+            let r = Scalar::reduce_from_wide_bytes(&Sha512::new().update(msg).finalize());
+            let r_point = Ge::scalarmult_base(&r).to_bytes();
+            let k = Sha512::new()
+                .update(&r_point)
+                .update(&public)
+                .update(msg)
+                .finalize();
+            let k = Scalar::reduce_from_wide_bytes(&k);
+            let s = Scalar::muladd(&k, &a, &r);
+            (k.to_bytes()[0] & 1 == 1, signature(&r_point, &s.to_bytes()))
+        };
+
+        let (mut odd, mut even) = (false, false);
+        for i in 0u8..=255 {
+            let (k_is_odd, signature) = sign(&[i]);
+            if k_is_odd {
+                assert_eq!(accepted(&public, &signature, &[i]), [false, false, true]);
+                odd = true;
+            } else {
+                assert_eq!(accepted(&public, &signature, &[i]), [true; 3]);
+                even = true;
+            }
+        }
+        assert!(odd && even);
+    }
+
+    fn hex_field<const N: usize>(case: &serde_json::Value, field: &str) -> [u8; N] {
+        hex::decode(case[field].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn speccheck_cases() {
+        // expected (libsodium, strict, zip215) result of each case
+        const EXPECTED: [[bool; 3]; 12] = [
+            // small order A or R
+            [false, true, true],
+            [false, true, true],
+            [false, true, true],
+            // mixed order A and R
+            [true, true, true],
+            // cofactored equation only
+            [false, false, true],
+            [false, false, true],
+            // S >= L
+            [false, false, false],
+            [false, false, false],
+            // small order R, then non canonical R
+            [false, true, true],
+            [false, false, true],
+            // non canonical A
+            [false, false, true],
+            [false, false, true],
+        ];
+        let cases: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../tests/ed25519-test-data/speccheck-cases.json"
+        ))
+        .unwrap();
+        let cases = cases.as_array().unwrap();
+        assert_eq!(cases.len(), EXPECTED.len());
+        for (i, (case, expected)) in cases.iter().zip(EXPECTED).enumerate() {
+            let msg: [u8; 32] = hex_field(case, "message");
+            let public = hex_field(case, "pub_key");
+            let signature = hex_field(case, "signature");
+            assert_eq!(accepted(&public, &signature, &msg), expected, "case {i}");
+        }
+    }
 }
@@ -1,10 +1,13 @@
+use super::ed25519;
+use crate::chain_crypto::cip5::Prefix;
 use crate::chain_crypto::key::{
     AsymmetricKey, AsymmetricPublicKey, PublicKeyError, SecretKeyError, SecretKeySizeStatic,
 };
 use crate::chain_crypto::sign::{
-    SignatureError, SigningAlgorithm, Verification, VerificationAlgorithm,
+    SignatureError, SigningAlgorithm, Verification, VerificationAlgorithm, VerifyMode,
 };
 
+use crate::util::securemem;
 use ed25519_bip32 as i;
 use ed25519_bip32::{XPrv, XPub, XPRV_SIZE, XPUB_SIZE};
 use rand_core::{CryptoRng, RngCore};
@@ -32,7 +35,7 @@ impl From<i::PublicKeyError> for PublicKeyError {
 
 impl AsymmetricPublicKey for Ed25519Bip32 {
     type Public = XPub;
-    const PUBLIC_BECH32_HRP: &'static str = "xpub";
+    const PUBLIC_BECH32_PREFIX: Prefix = Prefix::Xpub;
     const PUBLIC_KEY_SIZE: usize = XPUB_SIZE;
     fn public_from_binary(data: &[u8]) -> Result<Self::Public, PublicKeyError> {
         let xpub = XPub::from_slice(data)?;
@@ -44,12 +47,14 @@ impl AsymmetricKey for Ed25519Bip32 {
     type Secret = XPrv;
     type PubAlg = Ed25519Bip32;
 
-    const SECRET_BECH32_HRP: &'static str = "xprv";
+    const SECRET_BECH32_PREFIX: Prefix = Prefix::Xprv;
 
     fn generate<T: RngCore + CryptoRng>(mut rng: T) -> Self::Secret {
         let mut priv_bytes = [0u8; XPRV_SIZE];
         rng.fill_bytes(&mut priv_bytes);
-        XPrv::normalize_bytes_force3rd(priv_bytes)
+        let xprv = XPrv::normalize_bytes_force3rd(priv_bytes);
+        securemem::zero(&mut priv_bytes);
+        xprv
     }
 
     fn compute_public(key: &Self::Secret) -> <Self as AsymmetricPublicKey>::Public {
@@ -83,7 +88,7 @@ impl VerificationAlgorithm for Ed25519Bip32 {
     type Signature = XSig;
 
     const SIGNATURE_SIZE: usize = ed25519_bip32::SIGNATURE_SIZE;
-    const SIGNATURE_BECH32_HRP: &'static str = "xsig";
+    const SIGNATURE_BECH32_PREFIX: Prefix = Prefix::Xsig;
 
     fn signature_from_bytes(data: &[u8]) -> Result<Self::Signature, SignatureError> {
         let xsig = XSig::from_slice(data)?;
@@ -97,6 +102,15 @@ impl VerificationAlgorithm for Ed25519Bip32 {
     ) -> Verification {
         pubkey.verify(msg, signature).into()
     }
+
+    fn verify_bytes_with_mode(
+        pubkey: &Self::Public,
+        signature: &Self::Signature,
+        msg: &[u8],
+        mode: VerifyMode,
+    ) -> Verification {
+        ed25519::verify_with_mode(msg, pubkey.public_key_bytes(), signature.to_bytes(), mode).into()
+    }
 }
 
 impl SigningAlgorithm for Ed25519Bip32 {
@@ -110,15 +124,16 @@ mod test {
     use super::*;
     use alloc::vec::Vec;
 
-    use crate::chain_crypto::key::KeyPair;
     use crate::chain_crypto::sign::test::{keypair_signing_ko, keypair_signing_ok};
+    use crate::chain_crypto::testing::TestCryptoGen;
 
+    // `XPrv` is not `Clone`, the keys are generated from a `TestCryptoGen`
     #[quickcheck]
-    fn sign_ok(input: (KeyPair<Ed25519Bip32>, Vec<u8>)) -> bool {
-        keypair_signing_ok(input)
+    fn sign_ok(gen: TestCryptoGen, data: Vec<u8>) -> bool {
+        keypair_signing_ok::<Ed25519Bip32>((gen.keypair(0), data))
     }
     #[quickcheck]
-    fn sign_ko(input: (KeyPair<Ed25519Bip32>, KeyPair<Ed25519Bip32>, Vec<u8>)) -> bool {
-        keypair_signing_ko(input)
+    fn sign_ko(gen: TestCryptoGen, data: Vec<u8>) -> bool {
+        keypair_signing_ko::<Ed25519Bip32>((gen.keypair(0), gen.keypair(1), data))
     }
 }
@@ -1,3 +1,4 @@
+use crate::chain_crypto::cip5::Prefix;
 use crate::chain_crypto::key::{
     AsymmetricKey, AsymmetricPublicKey, SecretKeyError, SecretKeySizeStatic,
 };
@@ -5,6 +6,7 @@ use crate::chain_crypto::sign::SigningAlgorithm;
 
 use super::ed25519 as ei;
 
+use crate::util::securemem;
 use cryptoxide::ed25519;
 use rand_core::{CryptoRng, RngCore};
 
@@ -13,7 +15,7 @@ use ed25519_bip32::{XPrv, XPRV_SIZE};
 /// ED25519 Signing Algorithm with extended secret key
 pub struct Ed25519Extended;
 
-#[derive(Clone)]
+#[cfg_attr(test, derive(Clone))]
 pub struct ExtendedPriv([u8; ed25519::EXTENDED_KEY_LENGTH]);
 
 impl AsRef<[u8]> for ExtendedPriv {
@@ -26,7 +28,14 @@ impl ExtendedPriv {
     pub fn from_xprv(xprv: &XPrv) -> Self {
         let mut buf = [0; ed25519::EXTENDED_KEY_LENGTH];
         xprv.get_extended_mut(&mut buf);
-        ExtendedPriv(buf)
+        let key = ExtendedPriv(buf);
+        securemem::zero(&mut buf);
+        key
+    }
+}
+impl Drop for ExtendedPriv {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.0);
     }
 }
 
@@ -34,16 +43,14 @@ impl AsymmetricKey for Ed25519Extended {
     type Secret = ExtendedPriv;
     type PubAlg = ei::Ed25519;
 
-    const SECRET_BECH32_HRP: &'static str = "ed25519e_sk";
+    const SECRET_BECH32_PREFIX: Prefix = Prefix::Ed25519eSk;
 
     fn generate<T: RngCore + CryptoRng>(mut rng: T) -> Self::Secret {
         let mut priv_bytes = [0u8; XPRV_SIZE];
         rng.fill_bytes(&mut priv_bytes);
         let xprv = XPrv::normalize_bytes_force3rd(priv_bytes);
-
-        let mut out = [0u8; ed25519::EXTENDED_KEY_LENGTH];
-        xprv.get_extended_mut(&mut out);
-        ExtendedPriv(out)
+        securemem::zero(&mut priv_bytes);
+        ExtendedPriv::from_xprv(&xprv)
     }
 
     fn compute_public(key: &Self::Secret) -> <Self::PubAlg as AsymmetricPublicKey>::Public {
@@ -58,7 +65,9 @@ impl AsymmetricKey for Ed25519Extended {
         let mut buf = [0; ed25519::EXTENDED_KEY_LENGTH];
         buf.clone_from_slice(data);
         // TODO structure check
-        Ok(ExtendedPriv(buf))
+        let key = ExtendedPriv(buf);
+        securemem::zero(&mut buf);
+        Ok(key)
     }
 }
 
@@ -0,0 +1,126 @@
+//! Batch verification of Ed25519 signatures
+//!
+//! All the signatures are first checked together with one random linear
+//! combination of their equations, one multiscalar multiplication instead
+//! of a scalar multiplication per signature. When the batch fails, each
+//! signature is checked individually to find the invalid ones.
+//!
+//! The result of every entry is the one of
+//! `verify_slice_with_mode(.., mode)`. Only `VerifyMode::Zip215` is checked
+//! as a batch: its cofactored equation is the one that can be combined, a
+//! batch of the other modes could accept what they reject (a small order or
+//! mixed order public key), so their signatures are checked one by one.
+//! See [`VerifyMode`] for the edge cases; use `VerifyMode::Libsodium` when
+//! the result has to match the ledger.
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+use super::algorithms::ed25519;
+use super::{Ed25519, PublicKey, Signature, Verification, VerifyMode};
+
+/// `(public key, message, signature)`
+pub type BatchEntry<'a, T> = (&'a PublicKey<Ed25519>, &'a [u8], &'a Signature<T, Ed25519>);
+
+/// Verify `(public key, message, signature)` entries with the acceptance
+/// rules of `mode`, the results are in the same order as the entries.
+///
+/// `rng` draws the factors of the linear combination, it has to be
+/// unpredictable to whoever made the signatures.
+pub fn verify_batch<T: ?Sized, R: RngCore + CryptoRng>(
+    entries: &[BatchEntry<T>],
+    mode: VerifyMode,
+    rng: R,
+) -> Vec<Verification> {
+    if mode == VerifyMode::Zip215 {
+        let raw: Vec<_> = entries
+            .iter()
+            .filter_map(|(public, msg, signature)| {
+                Some((
+                    public.as_ref().try_into().ok()?,
+                    *msg,
+                    signature.as_ref().try_into().ok()?,
+                ))
+            })
+            .collect();
+        if raw.len() == entries.len() && ed25519::verify_batch(&raw, rng) {
+            return alloc::vec![Verification::Success; entries.len()];
+        }
+    }
+    entries
+        .iter()
+        .map(|(public, msg, signature)| signature.verify_slice_with_mode(public, msg, mode))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chain_crypto::testing::TestCryptoGen;
+    use crate::chain_crypto::{KeyPair, SecretKey};
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    /// sign `messages` with keys from `gen`, corrupt the entries of
+    /// `invalid` (by index, modulo the number of messages) and check that
+    /// the batch gives the same results as single verification, in every
+    /// mode.
+    fn batch_agrees(gen: TestCryptoGen, messages: Vec<Vec<u8>>, invalid: Vec<usize>) -> bool {
+        let keys: Vec<KeyPair<Ed25519>> =
+            (0..messages.len() as u32).map(|i| gen.keypair(i)).collect();
+        let mut signatures: Vec<Signature<[u8], Ed25519>> = keys
+            .iter()
+            .zip(&messages)
+            .map(|(key, msg)| key.private_key().sign_slice(msg))
+            .collect();
+        let mut publics: Vec<&PublicKey<Ed25519>> = keys.iter().map(|k| k.public_key()).collect();
+
+        let other = SecretKey::<Ed25519>::generate(gen.get_rng(u32::MAX));
+        for (n, i) in invalid.iter().enumerate() {
+            let Some(i) = i.checked_rem(messages.len()) else {
+                break;
+            };
+            match n % 2 {
+                0 => signatures[i] = other.sign_slice(&messages[i]),
+                _ => publics[i] = keys[(i + 1) % keys.len()].public_key(),
+            }
+        }
+
+        let entries: Vec<_> = (0..messages.len())
+            .map(|i| (publics[i], messages[i].as_slice(), &signatures[i]))
+            .collect();
+        [
+            VerifyMode::Libsodium,
+            VerifyMode::Rfc8032Strict,
+            VerifyMode::Zip215,
+        ]
+        .into_iter()
+        .all(|mode| {
+            let expected: Vec<Verification> = entries
+                .iter()
+                .map(|(public, msg, signature)| signature.verify_slice_with_mode(public, msg, mode))
+                .collect();
+            verify_batch(&entries, mode, ChaChaRng::seed_from_u64(gen.0)) == expected
+        })
+    }
+
+    #[quickcheck]
+    fn batch_of_valid_signatures(gen: TestCryptoGen, messages: Vec<Vec<u8>>) -> bool {
+        batch_agrees(gen, messages, Vec::new())
+    }
+
+    #[quickcheck]
+    fn batch_with_invalid_signatures(
+        gen: TestCryptoGen,
+        messages: Vec<Vec<u8>>,
+        invalid: Vec<usize>,
+    ) -> bool {
+        batch_agrees(gen, messages, invalid)
+    }
+
+    #[test]
+    fn empty_batch() {
+        let entries: [BatchEntry<[u8]>; 0] = [];
+        assert!(verify_batch(&entries, VerifyMode::Zip215, ChaChaRng::seed_from_u64(0)).is_empty());
+    }
+}
@@ -0,0 +1,362 @@
+//! CIP-5 bech32 prefixes
+//!
+//! Registry of the human readable prefixes of CIP-5 and the [`Cip5`] trait,
+//! the bech32 encoding of every key, signature and hash of the crate. The
+//! prefix of a key depends on its role (`root_xsk`, `acct_xvk`,
+//! `addr_vk`...): every type lists the prefixes it can be encoded with and
+//! decoding checks the prefix.
+//!
+//! The registry also has the prefixes of the `chain_crypto` algorithms and
+//! digests (`xprv`, `ed25519_pk`, `blake2b256`...), which are not in CIP-5
+//! but are the only ones of their types.
+//!
+//! Cardano strings are longer than the 90 characters of BIP-173 (a
+//! `root_xsk` is 165 characters), no length limit is applied.
+use alloc::{string::String, vec::Vec};
+use core::{error, fmt};
+
+use bech32::{FromBase32, ToBase32, Variant};
+
+use crate::util::securemem;
+
+macro_rules! registry {
+    ($($(#[$doc:meta])* $variant:ident => $hrp:expr,)*) => {
+        /// Prefix of the CIP-5 registry
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Prefix {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Prefix {
+            pub const ALL: &'static [Prefix] = &[$(Prefix::$variant,)*];
+
+            /// human readable part of the bech32 string
+            pub const fn hrp(self) -> &'static str {
+                match self {
+                    $(Prefix::$variant => $hrp,)*
+                }
+            }
+
+            pub fn from_hrp(hrp: &str) -> Option<Prefix> {
+                match hrp {
+                    $($hrp => Some(Prefix::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+registry! {
+    // keys
+    AcctSk => "acct_sk",
+    AcctVk => "acct_vk",
+    AcctXsk => "acct_xsk",
+    AcctXvk => "acct_xvk",
+    AcctSharedSk => "acct_shared_sk",
+    AcctSharedVk => "acct_shared_vk",
+    AcctSharedXsk => "acct_shared_xsk",
+    AcctSharedXvk => "acct_shared_xvk",
+    AddrSk => "addr_sk",
+    AddrVk => "addr_vk",
+    AddrXsk => "addr_xsk",
+    AddrXvk => "addr_xvk",
+    AddrSharedSk => "addr_shared_sk",
+    AddrSharedVk => "addr_shared_vk",
+    AddrSharedXsk => "addr_shared_xsk",
+    AddrSharedXvk => "addr_shared_xvk",
+    CcColdSk => "cc_cold_sk",
+    CcColdVk => "cc_cold_vk",
+    CcColdXsk => "cc_cold_xsk",
+    CcColdXvk => "cc_cold_xvk",
+    CcHotSk => "cc_hot_sk",
+    CcHotVk => "cc_hot_vk",
+    CcHotXsk => "cc_hot_xsk",
+    CcHotXvk => "cc_hot_xvk",
+    DrepSk => "drep_sk",
+    DrepVk => "drep_vk",
+    DrepXsk => "drep_xsk",
+    DrepXvk => "drep_xvk",
+    KesSk => "kes_sk",
+    KesVk => "kes_vk",
+    PolicySk => "policy_sk",
+    PolicyVk => "policy_vk",
+    PoolSk => "pool_sk",
+    PoolVk => "pool_vk",
+    PoolXsk => "pool_xsk",
+    PoolXvk => "pool_xvk",
+    RootSk => "root_sk",
+    RootVk => "root_vk",
+    RootXsk => "root_xsk",
+    RootXvk => "root_xvk",
+    RootSharedSk => "root_shared_sk",
+    RootSharedVk => "root_shared_vk",
+    RootSharedXsk => "root_shared_xsk",
+    RootSharedXvk => "root_shared_xvk",
+    StakeSk => "stake_sk",
+    StakeVk => "stake_vk",
+    StakeXsk => "stake_xsk",
+    StakeXvk => "stake_xvk",
+    StakeSharedSk => "stake_shared_sk",
+    StakeSharedVk => "stake_shared_vk",
+    StakeSharedXsk => "stake_shared_xsk",
+    StakeSharedXvk => "stake_shared_xvk",
+    VrfSk => "vrf_sk",
+    VrfVk => "vrf_vk",
+    // hashes
+    /// fingerprint of a native asset (CIP-14)
+    Asset => "asset",
+    /// hash of the cold key of a stake pool, the pool id
+    Pool => "pool",
+    Script => "script",
+    AddrVkh => "addr_vkh",
+    AddrSharedVkh => "addr_shared_vkh",
+    PolicyVkh => "policy_vkh",
+    StakeVkh => "stake_vkh",
+    StakeSharedVkh => "stake_shared_vkh",
+    ReqSignerVkh => "req_signer_vkh",
+    VrfVkh => "vrf_vkh",
+    Datum => "datum",
+    ScriptData => "script_data",
+    /// hash of a DRep key, the DRep id
+    Drep => "drep",
+    DrepScript => "drep_script",
+    CcCold => "cc_cold",
+    CcColdScript => "cc_cold_script",
+    CcHot => "cc_hot",
+    CcHotScript => "cc_hot_script",
+    // addresses
+    Addr => "addr",
+    AddrTest => "addr_test",
+    Stake => "stake",
+    StakeTest => "stake_test",
+    // chain_crypto algorithms and digests, not in CIP-5
+    Ed25519Pk => "ed25519_pk",
+    Ed25519Sk => "ed25519_sk",
+    Ed25519eSk => "ed25519e_sk",
+    Ed25519Sig => "ed25519_sig",
+    Xpub => "xpub",
+    Xprv => "xprv",
+    Xsig => "xsig",
+    Blake2b => "blake2b",
+    Blake2b224 => "blake2b224",
+    Blake2b256 => "blake2b256",
+    Sha3 => "sha3",
+    Sha3_256 => "sha3256",
+}
+
+impl Prefix {
+    /// Ed25519 private keys
+    pub const SIGNING_KEYS: &'static [Prefix] = &[
+        Prefix::AcctSk,
+        Prefix::AcctSharedSk,
+        Prefix::AddrSk,
+        Prefix::AddrSharedSk,
+        Prefix::CcColdSk,
+        Prefix::CcHotSk,
+        Prefix::DrepSk,
+        Prefix::PolicySk,
+        Prefix::PoolSk,
+        Prefix::RootSk,
+        Prefix::RootSharedSk,
+        Prefix::StakeSk,
+        Prefix::StakeSharedSk,
+    ];
+
+    /// Ed25519 public keys
+    pub const VERIFICATION_KEYS: &'static [Prefix] = &[
+        Prefix::AcctVk,
+        Prefix::AcctSharedVk,
+        Prefix::AddrVk,
+        Prefix::AddrSharedVk,
+        Prefix::CcColdVk,
+        Prefix::CcHotVk,
+        Prefix::DrepVk,
+        Prefix::PolicyVk,
+        Prefix::PoolVk,
+        Prefix::RootVk,
+        Prefix::RootSharedVk,
+        Prefix::StakeVk,
+        Prefix::StakeSharedVk,
+    ];
+
+    /// BIP32-Ed25519 private keys with their chain code
+    pub const EXTENDED_SIGNING_KEYS: &'static [Prefix] = &[
+        Prefix::AcctXsk,
+        Prefix::AcctSharedXsk,
+        Prefix::AddrXsk,
+        Prefix::AddrSharedXsk,
+        Prefix::CcColdXsk,
+        Prefix::CcHotXsk,
+        Prefix::DrepXsk,
+        Prefix::PoolXsk,
+        Prefix::RootXsk,
+        Prefix::RootSharedXsk,
+        Prefix::StakeXsk,
+        Prefix::StakeSharedXsk,
+    ];
+
+    /// BIP32-Ed25519 public keys with their chain code
+    pub const EXTENDED_VERIFICATION_KEYS: &'static [Prefix] = &[
+        Prefix::AcctXvk,
+        Prefix::AcctSharedXvk,
+        Prefix::AddrXvk,
+        Prefix::AddrSharedXvk,
+        Prefix::CcColdXvk,
+        Prefix::CcHotXvk,
+        Prefix::DrepXvk,
+        Prefix::PoolXvk,
+        Prefix::RootXvk,
+        Prefix::RootSharedXvk,
+        Prefix::StakeXvk,
+        Prefix::StakeSharedXvk,
+    ];
+
+    /// Blake2b-224 hashes of Ed25519 public keys
+    pub const KEY_HASHES: &'static [Prefix] = &[
+        Prefix::AddrVkh,
+        Prefix::AddrSharedVkh,
+        Prefix::PolicyVkh,
+        Prefix::StakeVkh,
+        Prefix::StakeSharedVkh,
+        Prefix::ReqSignerVkh,
+        Prefix::Pool,
+        Prefix::Drep,
+        Prefix::CcCold,
+        Prefix::CcHot,
+    ];
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.hrp())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// not a bech32 string (bech32m strings are rejected too)
+    Bech32Malformed,
+    /// the prefix is not in the registry
+    UnknownPrefix(String),
+    /// the string has another prefix than the expected one
+    PrefixMismatch { expected: Prefix, actual: Prefix },
+    /// the type is not encoded with this prefix
+    PrefixNotAllowed(Prefix),
+    /// the data does not decode to a value of the type
+    DataInvalid,
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Bech32Malformed => write!(f, "invalid bech32 string"),
+            Error::UnknownPrefix(hrp) => write!(f, "prefix '{hrp}' is not in the registry"),
+            Error::PrefixMismatch { expected, actual } => {
+                write!(f, "invalid prefix '{actual}', expected '{expected}'")
+            }
+            Error::PrefixNotAllowed(prefix) => {
+                write!(f, "prefix '{prefix}' is not used for this type")
+            }
+            Error::DataInvalid => write!(f, "invalid data for this prefix"),
+        }
+    }
+}
+impl error::Error for Error {}
+
+/// bech32 encoding of `data` with `prefix`.
+pub fn encode(prefix: Prefix, data: &[u8]) -> String {
+    bech32::encode(prefix.hrp(), data.to_base32(), Variant::Bech32)
+        .unwrap_or_else(|e| panic!("Failed to build bech32: {e}"))
+}
+
+/// prefix and data of a bech32 string.
+pub fn decode(bech32_str: &str) -> Result<(Prefix, Vec<u8>), Error> {
+    let (hrp, data, variant) = bech32::decode(bech32_str).map_err(|_| Error::Bech32Malformed)?;
+    if variant != Variant::Bech32 {
+        return Err(Error::Bech32Malformed);
+    }
+    let prefix = Prefix::from_hrp(&hrp).ok_or(Error::UnknownPrefix(hrp))?;
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|_| Error::Bech32Malformed)?;
+    Ok((prefix, bytes))
+}
+
+/// Bech32 encoding with the prefixes of the CIP-5 registry.
+///
+/// The byte buffers of secret keys are zeroed once encoded or decoded.
+pub trait Cip5: Sized {
+    /// prefixes this type is encoded with
+    const PREFIXES: &'static [Prefix];
+
+    fn cip5_bytes(&self) -> Vec<u8>;
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self>;
+
+    /// encode with `prefix`, which has to be one of `PREFIXES`.
+    fn to_bech32(&self, prefix: Prefix) -> Result<String, Error> {
+        if !Self::PREFIXES.contains(&prefix) {
+            return Err(Error::PrefixNotAllowed(prefix));
+        }
+        let mut bytes = self.cip5_bytes();
+        let bech32_str = encode(prefix, &bytes);
+        securemem::zero(&mut bytes);
+        Ok(bech32_str)
+    }
+
+    /// decode a string that has to be prefixed with `prefix`.
+    fn from_bech32(bech32_str: &str, prefix: Prefix) -> Result<Self, Error> {
+        let (actual, mut bytes) = decode(bech32_str)?;
+        let value = if actual != prefix {
+            Err(Error::PrefixMismatch {
+                expected: prefix,
+                actual,
+            })
+        } else if !Self::PREFIXES.contains(&prefix) {
+            Err(Error::PrefixNotAllowed(prefix))
+        } else {
+            Self::from_cip5_bytes(&bytes).ok_or(Error::DataInvalid)
+        };
+        securemem::zero(&mut bytes);
+        value
+    }
+
+    /// decode a string with any of `PREFIXES`, returned with the value.
+    fn from_bech32_any(bech32_str: &str) -> Result<(Self, Prefix), Error> {
+        let (prefix, mut bytes) = decode(bech32_str)?;
+        let value = if Self::PREFIXES.contains(&prefix) {
+            Self::from_cip5_bytes(&bytes).ok_or(Error::DataInvalid)
+        } else {
+            Err(Error::PrefixNotAllowed(prefix))
+        };
+        securemem::zero(&mut bytes);
+        value.map(|value| (value, prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hrp_round_trip() {
+        for prefix in Prefix::ALL {
+            assert_eq!(Prefix::from_hrp(prefix.hrp()), Some(*prefix));
+        }
+        assert_eq!(Prefix::from_hrp("bc"), None);
+    }
+
+    #[test]
+    fn long_strings() {
+        let data = [0xab; 96];
+        let bech32_str = encode(Prefix::RootXsk, &data);
+        assert!(bech32_str.len() > 90);
+        assert_eq!(decode(&bech32_str), Ok((Prefix::RootXsk, data.to_vec())));
+    }
+
+    #[test]
+    fn rejects_bech32m_and_unknown_prefixes() {
+        let bech32m = bech32::encode("addr_vk", [1u8; 32].to_base32(), Variant::Bech32m).unwrap();
+        assert_eq!(decode(&bech32m), Err(Error::Bech32Malformed));
+        let unknown = bech32::encode("bc", [1u8; 32].to_base32(), Variant::Bech32).unwrap();
+        assert_eq!(decode(&unknown), Err(Error::UnknownPrefix("bc".into())));
+    }
+}
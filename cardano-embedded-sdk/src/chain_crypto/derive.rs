@@ -3,6 +3,7 @@ use crate::chain_crypto::algorithms::{
 };
 use crate::chain_crypto::key::{PublicKey, SecretKey};
 use crate::chain_crypto::Ed25519Extended;
+use crate::util::securemem;
 
 use cryptoxide::hmac::Hmac;
 use cryptoxide::pbkdf2::pbkdf2;
@@ -37,5 +38,7 @@ pub fn from_bip39_entropy(entropy: &[u8], password: &[u8]) -> SecretKey<Ed25519B
     let mut mac = Hmac::new(Sha512::new(), password);
     pbkdf2(&mut mac, entropy, ITER, &mut pbkdf2_result);
 
-    SecretKey(XPrv::normalize_bytes_force3rd(pbkdf2_result))
+    let xprv = XPrv::normalize_bytes_force3rd(pbkdf2_result);
+    securemem::zero(&mut pbkdf2_result);
+    SecretKey(xprv)
 }
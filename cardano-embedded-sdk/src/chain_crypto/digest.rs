@@ -1,14 +1,14 @@
 //! module to provide some handy interfaces atop the hashes so we have
 //! the common interfaces for the project to work with.
 
-use alloc::string::String;
+use alloc::vec::Vec;
 use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 use core::{error, fmt, result};
 
-use crate::chain_crypto::bech32::{self, Bech32};
-use crate::chain_crypto::hash::{Blake2b256, Sha3_256};
+use crate::chain_crypto::cip5::{Cip5, Prefix};
+use crate::chain_crypto::hash::{Blake2b224, Blake2b256, Sha3_256};
 use crate::error::FromHexErrorWrapper;
 use crate::typed_bytes::ByteSlice;
 
@@ -73,6 +73,39 @@ pub trait DigestAlg {
     fn finalize(ctx: Self::DigestContext) -> Self::DigestData;
 }
 
+impl DigestAlg for Blake2b224 {
+    const HASH_SIZE: usize = 28;
+    type DigestData = [u8; Self::HASH_SIZE];
+    type DigestContext = Blake2b;
+
+    fn try_from_slice(slice: &[u8]) -> Result<Self::DigestData, Error> {
+        if slice.len() == Self::HASH_SIZE {
+            let mut out = [0u8; Self::HASH_SIZE];
+            out.copy_from_slice(slice);
+            Ok(out)
+        } else {
+            Err(Error::InvalidDigestSize {
+                expected: Self::HASH_SIZE,
+                got: slice.len(),
+            })
+        }
+    }
+
+    fn new() -> Self::DigestContext {
+        Blake2b::new(Self::HASH_SIZE)
+    }
+
+    fn append_data(ctx: &mut Self::DigestContext, data: &[u8]) {
+        ctx.input(data)
+    }
+
+    fn finalize(mut ctx: Self::DigestContext) -> Self::DigestData {
+        let mut out: Self::DigestData = [0; Self::HASH_SIZE];
+        ctx.result(&mut out);
+        out
+    }
+}
+
 impl DigestAlg for Blake2b256 {
     const HASH_SIZE: usize = 32;
     type DigestData = [u8; Self::HASH_SIZE];
@@ -174,7 +207,7 @@ impl<H: DigestAlg> Clone for Digest<H> {
 }
 
 macro_rules! define_from_instances {
-    ($hash_ty:ty, $hash_size:expr, $bech32_hrp:expr) => {
+    ($hash_ty:ty, $hash_size:expr, $bech32_prefix:expr) => {
         impl From<Digest<$hash_ty>> for [u8; $hash_size] {
             fn from(digest: Digest<$hash_ty>) -> Self {
                 digest.0
@@ -198,23 +231,23 @@ macro_rules! define_from_instances {
                 out.into()
             }
         }
-        impl Bech32 for Digest<$hash_ty> {
-            const BECH32_HRP: &'static str = $bech32_hrp;
+        impl Cip5 for Digest<$hash_ty> {
+            const PREFIXES: &'static [Prefix] = &[$bech32_prefix];
 
-            fn try_from_bech32_str(bech32_str: &str) -> bech32::Result<Self> {
-                let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-                Digest::try_from(&bytes[..]).map_err(bech32::Error::data_invalid)
+            fn cip5_bytes(&self) -> Vec<u8> {
+                self.as_ref().to_vec()
             }
 
-            fn to_bech32_str(&self) -> String {
-                bech32::to_bech32_from_bytes::<Self>(self.as_ref())
+            fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+                Digest::try_from(bytes).ok()
             }
         }
     };
 }
 
-define_from_instances!(Sha3_256, 32, "sha3");
-define_from_instances!(Blake2b256, 32, "blake2b");
+define_from_instances!(Sha3_256, 32, Prefix::Sha3);
+define_from_instances!(Blake2b256, 32, Prefix::Blake2b);
+define_from_instances!(Blake2b224, 28, Prefix::Blake2b224);
 
 unsafe impl<H: DigestAlg> Send for Digest<H> {}
 
@@ -424,7 +457,7 @@ impl<H: DigestAlg, T> DigestOf<H, T> {
 }
 
 macro_rules! typed_define_from_instances {
-    ($hash_ty:ty, $hash_size:expr, $bech32_hrp:expr) => {
+    ($hash_ty:ty, $hash_size:expr, $bech32_prefix:expr) => {
         impl<T> From<DigestOf<$hash_ty, T>> for [u8; $hash_size] {
             fn from(digest: DigestOf<$hash_ty, T>) -> Self {
                 digest.inner.into()
@@ -448,22 +481,20 @@ macro_rules! typed_define_from_instances {
                 out.into()
             }
         }
-        impl<T> Bech32 for DigestOf<$hash_ty, T> {
-            const BECH32_HRP: &'static str = $bech32_hrp;
-
-            fn try_from_bech32_str(bech32_str: &str) -> bech32::Result<Self> {
-                let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-                Digest::try_from(&bytes[..])
-                    .map_err(bech32::Error::data_invalid)
-                    .map(|d| d.into())
+        impl<T> Cip5 for DigestOf<$hash_ty, T> {
+            const PREFIXES: &'static [Prefix] = &[$bech32_prefix];
+
+            fn cip5_bytes(&self) -> Vec<u8> {
+                self.inner.as_ref().to_vec()
             }
 
-            fn to_bech32_str(&self) -> String {
-                bech32::to_bech32_from_bytes::<Self>(self.inner.as_ref())
+            fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+                Digest::try_from(bytes).ok().map(|d| d.into())
             }
         }
     };
 }
 
-typed_define_from_instances!(Sha3_256, 32, "sha3");
-typed_define_from_instances!(Blake2b256, 32, "blake2b");
+typed_define_from_instances!(Sha3_256, 32, Prefix::Sha3);
+typed_define_from_instances!(Blake2b256, 32, Prefix::Blake2b);
+typed_define_from_instances!(Blake2b224, 28, Prefix::Blake2b224);
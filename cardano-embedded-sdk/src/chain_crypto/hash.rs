@@ -1,12 +1,12 @@
 //! module to provide some handy interfaces atop the hashes so we have
 //! the common interfaces for the project to work with.
 
-use alloc::string::String;
+use alloc::vec::Vec;
 use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 use core::{error, fmt, result};
 
-use crate::chain_crypto::bech32::{self, Bech32};
+use crate::chain_crypto::cip5::{Cip5, Prefix};
 use crate::error::FromHexErrorWrapper;
 
 use cryptoxide::blake2b::Blake2b;
@@ -62,7 +62,7 @@ macro_rules! define_blake2b_new {
     };
 }
 macro_rules! define_hash_object {
-    ($hash_ty:ty, $constructor:expr, $hash_size:ident, $bech32_hrp:expr) => {
+    ($hash_ty:ty, $constructor:expr, $hash_size:ident, $bech32_prefix:expr) => {
         impl $hash_ty {
             pub const HASH_SIZE: usize = $hash_size;
 
@@ -124,32 +124,38 @@ macro_rules! define_hash_object {
                 f.write_str(")")
             }
         }
-        impl Bech32 for $hash_ty {
-            const BECH32_HRP: &'static str = $bech32_hrp;
+        impl Cip5 for $hash_ty {
+            const PREFIXES: &'static [Prefix] = &[$bech32_prefix];
 
-            fn try_from_bech32_str(bech32_str: &str) -> bech32::Result<Self> {
-                let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-                Self::try_from_slice(&bytes).map_err(bech32::Error::data_invalid)
+            fn cip5_bytes(&self) -> Vec<u8> {
+                self.as_ref().to_vec()
             }
 
-            fn to_bech32_str(&self) -> String {
-                bech32::to_bech32_from_bytes::<Self>(self.as_ref())
+            fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+                Self::try_from_slice(bytes).ok()
             }
         }
     };
 }
 
+pub const HASH_SIZE_224: usize = 28;
 pub const HASH_SIZE_256: usize = 32;
 
+/// Blake2b 224 bits, the hash of keys and scripts in Cardano
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub struct Blake2b224([u8; HASH_SIZE_224]);
+define_hash_object!(Blake2b224, Blake2b224, HASH_SIZE_224, Prefix::Blake2b224);
+define_blake2b_new!(Blake2b224);
+
 /// Blake2b 256 bits
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 pub struct Blake2b256([u8; HASH_SIZE_256]);
-define_hash_object!(Blake2b256, Blake2b256, HASH_SIZE_256, "blake2b256");
+define_hash_object!(Blake2b256, Blake2b256, HASH_SIZE_256, Prefix::Blake2b256);
 define_blake2b_new!(Blake2b256);
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 pub struct Sha3_256([u8; HASH_SIZE_256]);
-define_hash_object!(Sha3_256, Sha3_256, HASH_SIZE_256, "sha3256");
+define_hash_object!(Sha3_256, Sha3_256, HASH_SIZE_256, Prefix::Sha3_256);
 impl Sha3_256 {
     pub fn new(buf: &[u8]) -> Self {
         let mut sh3 = sha3::Sha3_256::new();
@@ -1,8 +1,9 @@
-use alloc::string::String;
-use core::{fmt, hash::Hash, str::FromStr};
+use alloc::vec::Vec;
+use core::{borrow::Borrow, fmt, hash::Hash, str::FromStr};
 
-use crate::chain_crypto::bech32::{self, Bech32};
+use crate::chain_crypto::cip5::{Cip5, Prefix};
 use crate::error::FromHexErrorWrapper;
+use crate::util::constant_time;
 
 use rand_core::{CryptoRng, RngCore};
 
@@ -26,7 +27,7 @@ pub enum PublicKeyFromStrError {
 
 pub trait AsymmetricPublicKey {
     type Public: AsRef<[u8]> + Clone + PartialEq + Eq + Hash;
-    const PUBLIC_BECH32_HRP: &'static str;
+    const PUBLIC_BECH32_PREFIX: Prefix;
     const PUBLIC_KEY_SIZE: usize;
 
     fn public_from_binary(data: &[u8]) -> Result<Self::Public, PublicKeyError>;
@@ -37,10 +38,10 @@ pub trait AsymmetricKey {
     // where PubAlg::Public is the public key type.
     type PubAlg: AsymmetricPublicKey;
 
-    // the secret key type
-    type Secret: AsRef<[u8]> + Clone;
+    // the secret key type, expected to zero itself when dropped
+    type Secret: AsRef<[u8]>;
 
-    const SECRET_BECH32_HRP: &'static str;
+    const SECRET_BECH32_PREFIX: Prefix;
 
     fn generate<T: RngCore + CryptoRng>(rng: T) -> Self::Secret;
     fn compute_public(secret: &Self::Secret) -> <Self::PubAlg as AsymmetricPublicKey>::Public;
@@ -183,9 +184,37 @@ impl<A: AsymmetricPublicKey> PublicKey<A> {
             data,
         )?))
     }
+
+    /// constant time equality, this is also what `==` does.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        constant_time::eq(self.0.as_ref(), other.0.as_ref())
+    }
+
+    /// position of the first candidate equal to this key.
+    ///
+    /// Every candidate is compared, in constant time, so the time taken
+    /// only depends on the number of candidates and not on which one (if
+    /// any) matched.
+    pub fn position_in<I>(&self, candidates: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut found = false;
+        let mut position = 0;
+        for (i, candidate) in candidates.into_iter().enumerate() {
+            let matched = self.ct_eq(candidate.borrow());
+            position = constant_time::select(matched & !found, i, position);
+            found |= matched;
+        }
+        found.then_some(position)
+    }
 }
 
-impl<A: AsymmetricKey> Clone for SecretKey<A> {
+impl<A: AsymmetricKey> Clone for SecretKey<A>
+where
+    A::Secret: Clone,
+{
     fn clone(&self) -> Self {
         SecretKey(self.0.clone())
     }
@@ -195,7 +224,10 @@ impl<A: AsymmetricPublicKey> Clone for PublicKey<A> {
         PublicKey(self.0.clone())
     }
 }
-impl<A: AsymmetricKey> Clone for KeyPair<A> {
+impl<A: AsymmetricKey> Clone for KeyPair<A>
+where
+    A::Secret: Clone,
+{
     fn clone(&self) -> Self {
         KeyPair(self.0.clone(), self.1.clone())
     }
@@ -203,7 +235,7 @@ impl<A: AsymmetricKey> Clone for KeyPair<A> {
 
 impl<A: AsymmetricPublicKey> core::cmp::PartialEq<Self> for PublicKey<A> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.as_ref().eq(other.0.as_ref())
+        self.ct_eq(other)
     }
 }
 
@@ -230,35 +262,48 @@ impl<A: AsymmetricPublicKey> Hash for PublicKey<A> {
     }
 }
 
-impl<A: AsymmetricPublicKey> Bech32 for PublicKey<A> {
-    const BECH32_HRP: &'static str = A::PUBLIC_BECH32_HRP;
+impl<A: AsymmetricPublicKey> Cip5 for PublicKey<A> {
+    const PREFIXES: &'static [Prefix] = &[A::PUBLIC_BECH32_PREFIX];
 
-    fn try_from_bech32_str(bech32_str: &str) -> Result<Self, bech32::Error> {
-        let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-        Self::from_binary(&bytes).map_err(bech32::Error::data_invalid)
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_ref().to_vec()
     }
 
-    fn to_bech32_str(&self) -> String {
-        bech32::to_bech32_from_bytes::<Self>(self.as_ref())
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_binary(bytes).ok()
     }
 }
 
-impl<A: AsymmetricKey> Bech32 for SecretKey<A> {
-    const BECH32_HRP: &'static str = A::SECRET_BECH32_HRP;
+impl<A: AsymmetricKey> Cip5 for SecretKey<A> {
+    const PREFIXES: &'static [Prefix] = &[A::SECRET_BECH32_PREFIX];
 
-    fn try_from_bech32_str(bech32_str: &str) -> Result<Self, bech32::Error> {
-        let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-        Self::from_binary(&bytes).map_err(bech32::Error::data_invalid)
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_vec()
     }
 
-    fn to_bech32_str(&self) -> String {
-        bech32::to_bech32_from_bytes::<Self>(self.0.as_ref())
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_binary(bytes).ok()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::chain_crypto::Ed25519;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn position_in_candidates() {
+        let key = |b: u8| PublicKey::<Ed25519>::from_binary(&[b; 32]).unwrap();
+        let candidates: Vec<_> = (0..8).map(key).collect();
+        assert_eq!(key(0).position_in(&candidates), Some(0));
+        assert_eq!(key(5).position_in(&candidates), Some(5));
+        assert_eq!(key(9).position_in(&candidates), None);
+        assert_eq!(key(1).position_in([key(1), key(1)]), Some(0));
+        assert_eq!(key(1).position_in(Vec::<PublicKey<Ed25519>>::new()), None);
+        assert!(key(3).ct_eq(&key(3)));
+        assert!(key(3) != key(4));
+    }
 
     // ONLY ALLOWED WHEN TESTING
     impl<A> core::fmt::Debug for SecretKey<A>
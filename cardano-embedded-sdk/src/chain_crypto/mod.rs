@@ -7,20 +7,27 @@ cfg_if! {
 }
 
 pub mod algorithms;
-pub mod bech32;
+pub mod batch;
+pub mod cip5;
 pub mod derive;
 pub mod digest;
 pub mod hash;
 mod key;
 mod sign;
+pub mod typed_hash;
 
 pub use algorithms::*;
-pub use hash::{Blake2b256, Sha3_256};
+pub use batch::verify_batch;
+pub use hash::{Blake2b224, Blake2b256, Sha3_256};
 pub use key::{
     AsymmetricKey, AsymmetricPublicKey, KeyPair, PublicKey, PublicKeyError, PublicKeyFromStrError,
     SecretKey, SecretKeyError, SecretKeySizeStatic,
 };
 pub use sign::{
     Signature, SignatureError, SignatureFromStrError, SigningAlgorithm, Verification,
-    VerificationAlgorithm,
+    VerificationAlgorithm, VerifyMode,
+};
+pub use typed_hash::{
+    AuxiliaryDataHash, DatumHash, GenesisDelegateHash, PolicyId, PoolKeyHash, ScriptDataHash,
+    ScriptHash, VrfKeyHash,
 };
@@ -1,12 +1,13 @@
-use alloc::string::String;
+use alloc::vec::Vec;
 use core::{fmt, marker::PhantomData, str::FromStr};
 
 use crate::chain_crypto::{
-    bech32::{self, Bech32},
+    cip5::{Cip5, Prefix},
     key,
 };
 use crate::error::FromHexErrorWrapper;
 use crate::typed_bytes::{ByteArray, ByteSlice};
+use crate::util::constant_time;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Verification {
@@ -24,6 +25,26 @@ impl From<bool> for Verification {
     }
 }
 
+/// Acceptance rules of Ed25519 signatures. Implementations disagree on the
+/// edge cases (small order points, non canonical encodings, cofactor), a
+/// verifier should use the same rules as the party it has to agree with.
+///
+/// All the modes require the `S` half of the signature to be reduced
+/// (`S < L`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyMode {
+    /// rules of libsodium (>= 1.0.16), used by cardano-node to validate the
+    /// witnesses: canonical public key, public key and `R` not of small
+    /// order, cofactorless equation.
+    Libsodium,
+    /// RFC 8032 with strict decoding: canonical encodings of the public key
+    /// and `R`, cofactorless equation.
+    Rfc8032Strict,
+    /// ZIP 215: any encoding of the public key and `R` that decodes to a
+    /// point, cofactored equation.
+    Zip215,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SignatureError {
     SizeInvalid { expected: usize, got: usize }, // expected, got in bytes
@@ -40,11 +61,20 @@ pub trait VerificationAlgorithm: key::AsymmetricPublicKey {
     type Signature: AsRef<[u8]> + Clone;
 
     const SIGNATURE_SIZE: usize;
-    const SIGNATURE_BECH32_HRP: &'static str;
+    const SIGNATURE_BECH32_PREFIX: Prefix;
 
     fn verify_bytes(pubkey: &Self::Public, signature: &Self::Signature, msg: &[u8])
         -> Verification;
 
+    /// verify with explicit acceptance rules, `verify_bytes` uses the rules
+    /// of cryptoxide.
+    fn verify_bytes_with_mode(
+        pubkey: &Self::Public,
+        signature: &Self::Signature,
+        msg: &[u8],
+        mode: VerifyMode,
+    ) -> Verification;
+
     fn signature_from_bytes(data: &[u8]) -> Result<Self::Signature, SignatureError>;
 }
 
@@ -141,6 +171,21 @@ impl<A: VerificationAlgorithm, T: AsRef<[u8]>> Signature<T, A> {
     pub fn verify(&self, publickey: &key::PublicKey<A>, object: &T) -> Verification {
         <A as VerificationAlgorithm>::verify_bytes(&publickey.0, &self.signdata, object.as_ref())
     }
+
+    #[must_use]
+    pub fn verify_with_mode(
+        &self,
+        publickey: &key::PublicKey<A>,
+        object: &T,
+        mode: VerifyMode,
+    ) -> Verification {
+        <A as VerificationAlgorithm>::verify_bytes_with_mode(
+            &publickey.0,
+            &self.signdata,
+            object.as_ref(),
+            mode,
+        )
+    }
 }
 
 impl<A: VerificationAlgorithm, T: ?Sized> Signature<T, A> {
@@ -148,6 +193,21 @@ impl<A: VerificationAlgorithm, T: ?Sized> Signature<T, A> {
     pub fn verify_slice(&self, publickey: &key::PublicKey<A>, slice: &[u8]) -> Verification {
         <A as VerificationAlgorithm>::verify_bytes(&publickey.0, &self.signdata, slice)
     }
+
+    #[must_use]
+    pub fn verify_slice_with_mode(
+        &self,
+        publickey: &key::PublicKey<A>,
+        slice: &[u8],
+        mode: VerifyMode,
+    ) -> Verification {
+        <A as VerificationAlgorithm>::verify_bytes_with_mode(
+            &publickey.0,
+            &self.signdata,
+            slice,
+            mode,
+        )
+    }
 }
 
 /*
@@ -192,7 +252,8 @@ impl<T, A: VerificationAlgorithm> Clone for Signature<T, A> {
 
 impl<T, A: VerificationAlgorithm> PartialEq<Self> for Signature<T, A> {
     fn eq(&self, other: &Self) -> bool {
-        self.signdata.as_ref() == other.signdata.as_ref() && self.phantom == other.phantom
+        constant_time::eq(self.signdata.as_ref(), other.signdata.as_ref())
+            && self.phantom == other.phantom
     }
 }
 
@@ -204,16 +265,15 @@ impl<T: ?Sized, A: VerificationAlgorithm> AsRef<[u8]> for Signature<T, A> {
     }
 }
 
-impl<T, A: VerificationAlgorithm> Bech32 for Signature<T, A> {
-    const BECH32_HRP: &'static str = A::SIGNATURE_BECH32_HRP;
+impl<T, A: VerificationAlgorithm> Cip5 for Signature<T, A> {
+    const PREFIXES: &'static [Prefix] = &[A::SIGNATURE_BECH32_PREFIX];
 
-    fn try_from_bech32_str(bech32_str: &str) -> Result<Self, bech32::Error> {
-        let bytes = bech32::try_from_bech32_to_bytes::<Self>(bech32_str)?;
-        Self::from_binary(&bytes).map_err(bech32::Error::data_invalid)
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_ref().to_vec()
     }
 
-    fn to_bech32_str(&self) -> String {
-        bech32::to_bech32_from_bytes::<Self>(self.as_ref())
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_binary(bytes).ok()
     }
 }
 
@@ -58,7 +58,7 @@ where
 impl<A> Arbitrary for SecretKey<A>
 where
     A: AsymmetricKey + 'static,
-    A::Secret: Send,
+    A::Secret: Clone + Send,
 {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
         arbitrary_secret_key(g)
@@ -67,7 +67,7 @@ where
 impl<A> Arbitrary for KeyPair<A>
 where
     A: AsymmetricKey + 'static,
-    A::Secret: Send,
+    A::Secret: Clone + Send,
     <A::PubAlg as AsymmetricPublicKey>::Public: Send,
 {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
@@ -99,6 +99,15 @@ impl Arbitrary for Blake2b256 {
     }
 }
 
+impl Arbitrary for Blake2b224 {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let bytes: Vec<_> = core::iter::repeat_with(|| u8::arbitrary(g))
+            .take(Self::HASH_SIZE)
+            .collect();
+        Self::try_from_slice(&bytes).unwrap()
+    }
+}
+
 impl Arbitrary for Sha3_256 {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
         let bytes: Vec<_> = core::iter::repeat_with(|| u8::arbitrary(g))
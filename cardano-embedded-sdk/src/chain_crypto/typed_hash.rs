@@ -0,0 +1,231 @@
+//! Typed hashes of the Cardano ledger
+//!
+//! Every hash is a [`DigestOf`] tagged with its own type, so a script hash
+//! cannot be given where a datum hash is expected. They all have the same
+//! hex interface (`Display`/`FromStr`, `to_hex`/`from_hex`) and bech32 with
+//! the CIP-5 prefix of the type.
+//!
+//! CIP-5 has no prefix for the policy id, which is the hash of the minting
+//! script, it uses the one of scripts. The auxiliary data hash and the
+//! genesis delegate hash have no prefix at all: they have no bech32
+//! encoding, a made up prefix would not be read by other tools.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use super::cip5::{Cip5, Prefix};
+use super::digest::{Digest, DigestOf, Error};
+use super::hash::{Blake2b224, Blake2b256};
+
+macro_rules! define_typed_hash {
+    ($(#[$meta:meta])* $name:ident, $hash_alg:ty, $hash_size:expr, $prefix:expr) => {
+        define_typed_hash!($(#[$meta])* $name, $hash_alg, $hash_size);
+
+        impl Cip5 for $name {
+            const PREFIXES: &'static [Prefix] = &[$prefix];
+
+            fn cip5_bytes(&self) -> Vec<u8> {
+                self.as_ref().to_vec()
+            }
+
+            fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+                Self::try_from_slice(bytes).ok()
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident, $hash_alg:ty, $hash_size:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(DigestOf<$hash_alg, $name>);
+
+        impl $name {
+            pub const HASH_SIZE: usize = $hash_size;
+
+            /// hash `data` with the algorithm of this hash type
+            pub fn digest(data: &[u8]) -> Self {
+                $name(Digest::<$hash_alg>::digest(data).into())
+            }
+
+            pub fn as_hash_bytes(&self) -> &[u8; $hash_size] {
+                (&self.0).into()
+            }
+
+            pub fn try_from_slice(slice: &[u8]) -> Result<Self, Error> {
+                DigestOf::try_from(slice).map($name)
+            }
+
+            pub fn to_hex(&self) -> String {
+                self.0.to_string()
+            }
+
+            pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
+                hex_str.parse()
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+        impl From<[u8; $hash_size]> for $name {
+            fn from(bytes: [u8; $hash_size]) -> Self {
+                $name(bytes.into())
+            }
+        }
+        impl From<$name> for [u8; $hash_size] {
+            fn from(hash: $name) -> Self {
+                hash.0.into()
+            }
+        }
+        impl From<$hash_alg> for $name {
+            fn from(hash: $hash_alg) -> Self {
+                $name(hash.into())
+            }
+        }
+        impl FromStr for $name {
+            type Err = Error;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                DigestOf::from_str(s).map($name)
+            }
+        }
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, concat!(stringify!($name), "({})"), self.0)
+            }
+        }
+    };
+}
+
+define_typed_hash!(
+    /// Blake2b-224 of a script, hashed with its language tag byte in front
+    ScriptHash, Blake2b224, 28, Prefix::Script
+);
+define_typed_hash!(
+    /// hash of the minting policy script of a native asset
+    PolicyId, Blake2b224, 28, Prefix::Script
+);
+define_typed_hash!(
+    /// Blake2b-224 of the cold verification key of a stake pool, the pool id
+    PoolKeyHash, Blake2b224, 28, Prefix::Pool
+);
+define_typed_hash!(
+    /// Blake2b-256 of a VRF verification key
+    VrfKeyHash, Blake2b256, 32, Prefix::VrfVkh
+);
+define_typed_hash!(
+    /// Blake2b-256 of the auxiliary data (metadata) of a transaction
+    AuxiliaryDataHash, Blake2b256, 32
+);
+define_typed_hash!(
+    /// Blake2b-256 of the redeemers, datums and cost models of a transaction
+    ScriptDataHash, Blake2b256, 32, Prefix::ScriptData
+);
+define_typed_hash!(
+    /// Blake2b-256 of a datum
+    DatumHash, Blake2b256, 32, Prefix::Datum
+);
+define_typed_hash!(
+    /// Blake2b-224 of a genesis delegate verification key
+    GenesisDelegateHash, Blake2b224, 28
+);
+
+impl From<ScriptHash> for PolicyId {
+    fn from(hash: ScriptHash) -> Self {
+        PolicyId(hash.0.coerce())
+    }
+}
+
+impl From<PolicyId> for ScriptHash {
+    fn from(policy_id: PolicyId) -> Self {
+        ScriptHash(policy_id.0.coerce())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_crypto::cip5;
+
+    macro_rules! round_trips {
+        ($name:ident, $test:ident) => {
+            #[quickcheck]
+            fn $test(data: Vec<u8>) -> bool {
+                let hash = $name::digest(&data);
+                let prefix = $name::PREFIXES[0];
+                let bech32 = hash.to_bech32(prefix).unwrap();
+                bech32
+                    .strip_prefix(prefix.hrp())
+                    .map_or(false, |data| data.starts_with('1'))
+                    && $name::from_hex(&hash.to_hex()) == Ok(hash.clone())
+                    && $name::from_bech32(&bech32, prefix) == Ok(hash.clone())
+                    && $name::from(*hash.as_hash_bytes()) == hash
+            }
+        };
+    }
+
+    round_trips!(ScriptHash, script_hash_round_trips);
+    round_trips!(PolicyId, policy_id_round_trips);
+    round_trips!(PoolKeyHash, pool_key_hash_round_trips);
+    round_trips!(VrfKeyHash, vrf_key_hash_round_trips);
+    round_trips!(ScriptDataHash, script_data_hash_round_trips);
+    round_trips!(DatumHash, datum_hash_round_trips);
+
+    macro_rules! hex_round_trips {
+        ($name:ident, $test:ident) => {
+            #[quickcheck]
+            fn $test(data: Vec<u8>) -> bool {
+                let hash = $name::digest(&data);
+                $name::from_hex(&hash.to_hex()) == Ok(hash.clone())
+                    && $name::from(*hash.as_hash_bytes()) == hash
+            }
+        };
+    }
+
+    hex_round_trips!(AuxiliaryDataHash, auxiliary_data_hash_round_trips);
+    hex_round_trips!(GenesisDelegateHash, genesis_delegate_hash_round_trips);
+
+    #[test]
+    fn blake2b224() {
+        assert_eq!(
+            ScriptHash::digest(b"").to_hex(),
+            "836cc68931c2e4e3e838602eca1902591d216837bafddfe6f0c8cb07"
+        );
+        assert_eq!(
+            Blake2b224::new(b"abc").as_hash_bytes(),
+            ScriptHash::digest(b"abc").as_hash_bytes()
+        );
+        assert_eq!(ScriptHash::digest(b"").as_hash_bytes().len(), 28);
+    }
+
+    #[test]
+    fn wrong_size_or_prefix() {
+        let datum = DatumHash::digest(b"datum");
+        assert!(ScriptHash::try_from_slice(datum.as_ref()).is_err());
+        assert!(ScriptHash::from_hex(&datum.to_hex()).is_err());
+        let bech32 = datum.to_bech32(Prefix::Datum).unwrap();
+        assert_eq!(
+            ScriptDataHash::from_bech32_any(&bech32),
+            Err(cip5::Error::PrefixNotAllowed(Prefix::Datum))
+        );
+        assert_eq!(
+            ScriptHash::from_bech32(&bech32, Prefix::Script),
+            Err(cip5::Error::PrefixMismatch {
+                expected: Prefix::Script,
+                actual: Prefix::Datum
+            })
+        );
+        assert_eq!(DatumHash::from_bech32(&bech32, Prefix::Datum), Ok(datum));
+
+        let script = ScriptHash::digest(b"script");
+        let policy_id = PolicyId::from(script.clone());
+        assert_eq!(ScriptHash::from(policy_id), script);
+    }
+}
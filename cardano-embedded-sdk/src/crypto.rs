@@ -1,28 +1,56 @@
 use alloc::{
     format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use core::str::FromStr;
 
 use crate::chain_crypto as crypto;
 use crate::impl_mockchain as chain;
+use crate::util::{constant_time, securemem};
 use chain::key;
-use crypto::bech32::Bech32 as _;
+use crypto::cip5::{self, Cip5, Prefix};
+
+pub use crypto::typed_hash::{
+    AuxiliaryDataHash, DatumHash, GenesisDelegateHash, PolicyId, PoolKeyHash, ScriptDataHash,
+    ScriptHash, VrfKeyHash,
+};
 
-use bech32::{ToBase32, Variant};
 use cryptoxide::blake2b::Blake2b;
 use rand_core::{CryptoRng, RngCore};
 
 use super::*;
 
 pub(crate) fn blake2b224(data: &[u8]) -> [u8; 28] {
-    let mut out = [0; 28];
-    Blake2b::blake2b(&mut out, data, &[]);
-    out
+    crypto::Blake2b224::new(data).into()
+}
+
+/// bech32 of a `chain_crypto` key or signature, with the prefix of its
+/// algorithm.
+fn bech32_encode<C: Cip5>(value: &C) -> String {
+    value
+        .to_bech32(C::PREFIXES[0])
+        .expect("the prefix of the algorithm")
+}
+
+fn bech32_decode<C: Cip5>(bech32_str: &str) -> Result<C, cip5::Error> {
+    C::from_bech32(bech32_str, C::PREFIXES[0])
+}
+
+/// decode hex encoded key material, unlike `hex::decode` the output is not
+/// reallocated while decoding so no partial copy of the key is left behind.
+fn decode_secret_hex(hex_str: &str) -> Result<Vec<u8>, hex::FromHexError> {
+    let mut data = vec![0; hex_str.len() / 2];
+    match hex::decode_to_slice(hex_str, &mut data) {
+        Ok(()) => Ok(data),
+        Err(e) => {
+            securemem::zero(&mut data);
+            Err(e)
+        }
+    }
 }
 
-#[allow(dead_code)]
 pub(crate) fn blake2b256(data: &[u8]) -> [u8; 32] {
     let mut out = [0; 32];
     Blake2b::blake2b(&mut out, data, &[]);
@@ -66,16 +94,31 @@ impl Bip32PrivateKey {
     /// prv | pub | chaincode
     /// so be careful if you see the term "xprv" as it could refer to either one
     /// our library does not require the pub (instead we compute the pub key when needed)
+    /// but checks that it matches the private key, to catch a corrupted backup
     pub fn from_128_xprv(bytes: &[u8]) -> Result<Bip32PrivateKey, JsError> {
+        if bytes.len() != 128 {
+            return Err(JsError::from_str(&format!(
+                "128-byte xprv expected, but data length is {}",
+                bytes.len()
+            )));
+        }
         let mut buf = [0; 96];
         buf[0..64].clone_from_slice(&bytes[0..64]);
         buf[64..96].clone_from_slice(&bytes[96..128]);
 
-        Bip32PrivateKey::from_bytes(&buf)
+        let key = Bip32PrivateKey::from_bytes(&buf);
+        securemem::zero(&mut buf);
+        let key = key?;
+        if !constant_time::eq(&key.to_public().to_raw_key().as_bytes(), &bytes[64..96]) {
+            return Err(JsError::from_str(
+                "public key of the 128-byte xprv does not match the private key",
+            ));
+        }
+        Ok(key)
     }
     /// see from_128_xprv
     pub fn to_128_xprv(&self) -> Vec<u8> {
-        let prv_key = self.to_raw_key().as_bytes();
+        let mut prv_key = self.to_raw_key().as_bytes();
         let pub_key = self.to_public().to_raw_key().as_bytes();
         let cc = self.chaincode();
 
@@ -83,7 +126,10 @@ impl Bip32PrivateKey {
         buf[0..64].clone_from_slice(&prv_key);
         buf[64..96].clone_from_slice(&pub_key);
         buf[96..128].clone_from_slice(&cc);
-        buf.to_vec()
+        let xprv = buf.to_vec();
+        securemem::zero(&mut prv_key);
+        securemem::zero(&mut buf);
+        xprv
     }
 
     pub fn generate_ed25519_bip32<T: RngCore + CryptoRng>(rng: T) -> Bip32PrivateKey {
@@ -111,13 +157,13 @@ impl Bip32PrivateKey {
     }
 
     pub fn from_bech32(bech32_str: &str) -> Result<Bip32PrivateKey, JsError> {
-        crypto::SecretKey::try_from_bech32_str(bech32_str)
+        bech32_decode(bech32_str)
             .map(Bip32PrivateKey)
             .map_err(|_| JsError::from_str("Invalid secret key"))
     }
 
     pub fn to_bech32(&self) -> String {
-        self.0.to_bech32_str()
+        bech32_encode(&self.0)
     }
 
     pub fn from_bip39_entropy(entropy: &[u8], password: &[u8]) -> Bip32PrivateKey {
@@ -131,18 +177,25 @@ impl Bip32PrivateKey {
     }
 
     pub fn to_hex(&self) -> String {
-        hex::encode(self.as_bytes())
+        let mut bytes = self.as_bytes();
+        let hex = hex::encode(&bytes);
+        securemem::zero(&mut bytes);
+        hex
     }
 
     pub fn from_hex(hex_str: &str) -> Result<Bip32PrivateKey, JsError> {
-        match hex::decode(hex_str) {
-            Ok(data) => Ok(Self::from_bytes(data.as_ref())?),
+        match decode_secret_hex(hex_str) {
+            Ok(mut data) => {
+                let key = Self::from_bytes(data.as_ref());
+                securemem::zero(&mut data);
+                key
+            }
             Err(e) => Err(JsError::from_str(&e.to_string())),
         }
     }
 }
 
-pub struct Bip32PublicKey(crypto::PublicKey<crypto::Ed25519Bip32>);
+pub struct Bip32PublicKey(pub(crate) crypto::PublicKey<crypto::Ed25519Bip32>);
 
 impl Bip32PublicKey {
     /// derive this public key with the given index.
@@ -190,13 +243,13 @@ impl Bip32PublicKey {
     }
 
     pub fn from_bech32(bech32_str: &str) -> Result<Bip32PublicKey, JsError> {
-        crypto::PublicKey::try_from_bech32_str(bech32_str)
+        bech32_decode(bech32_str)
             .map(Bip32PublicKey)
             .map_err(|e| JsError::from_str(&format!("{e}")))
     }
 
     pub fn to_bech32(&self) -> String {
-        self.0.to_bech32_str()
+        bech32_encode(&self.0)
     }
 
     pub fn chaincode(&self) -> Vec<u8> {
@@ -253,20 +306,17 @@ impl PrivateKey {
     /// PrivateKey.from_bech32(&#39;ed25519e_sk1gqwl4szuwwh6d0yk3nsqcc6xxc3fpvjlevgwvt60df59v8zd8f8prazt8ln3lmz096ux3xvhhvm3ca9wj2yctdh3pnw0szrma07rt5gl748fp&#39;);
     /// ```
     pub fn from_bech32(bech32_str: &str) -> Result<PrivateKey, JsError> {
-        crypto::SecretKey::try_from_bech32_str(bech32_str)
+        bech32_decode(bech32_str)
             .map(key::EitherEd25519SecretKey::Extended)
-            .or_else(|_| {
-                crypto::SecretKey::try_from_bech32_str(bech32_str)
-                    .map(key::EitherEd25519SecretKey::Normal)
-            })
+            .or_else(|_| bech32_decode(bech32_str).map(key::EitherEd25519SecretKey::Normal))
             .map(PrivateKey)
             .map_err(|_| JsError::from_str("Invalid secret key"))
     }
 
     pub fn to_bech32(&self) -> String {
         match self.0 {
-            key::EitherEd25519SecretKey::Normal(ref secret) => secret.to_bech32_str(),
-            key::EitherEd25519SecretKey::Extended(ref secret) => secret.to_bech32_str(),
+            key::EitherEd25519SecretKey::Normal(ref secret) => bech32_encode(secret),
+            key::EitherEd25519SecretKey::Extended(ref secret) => bech32_encode(secret),
         }
     }
 
@@ -296,23 +346,28 @@ impl PrivateKey {
     }
 
     pub fn to_hex(&self) -> String {
-        hex::encode(self.as_bytes())
+        let mut bytes = self.as_bytes();
+        let hex = hex::encode(&bytes);
+        securemem::zero(&mut bytes);
+        hex
     }
 
     pub fn from_hex(hex_str: &str) -> Result<PrivateKey, JsError> {
-        let data: Vec<u8> = match hex::decode(hex_str) {
+        let mut data: Vec<u8> = match decode_secret_hex(hex_str) {
             Ok(d) => d,
             Err(e) => return Err(JsError::from_str(&e.to_string())),
         };
         let data_slice: &[u8] = data.as_slice();
-        crypto::SecretKey::from_binary(data_slice)
+        let key = crypto::SecretKey::from_binary(data_slice)
             .map(key::EitherEd25519SecretKey::Normal)
             .or_else(|_| {
                 crypto::SecretKey::from_binary(data_slice)
                     .map(key::EitherEd25519SecretKey::Extended)
             })
             .map(PrivateKey)
-            .map_err(|_| JsError::from_str("Invalid secret key"))
+            .map_err(|_| JsError::from_str("Invalid secret key"));
+        securemem::zero(&mut data);
+        key
     }
 }
 
@@ -333,13 +388,13 @@ impl PublicKey {
     /// const pkey = PublicKey.from_bech32(&#39;ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2&#39;);
     /// ```
     pub fn from_bech32(bech32_str: &str) -> Result<PublicKey, JsError> {
-        crypto::PublicKey::try_from_bech32_str(bech32_str)
+        bech32_decode(bech32_str)
             .map(PublicKey)
             .map_err(|_| JsError::from_str("Malformed public key"))
     }
 
     pub fn to_bech32(&self) -> String {
-        self.0.to_bech32_str()
+        bech32_encode(&self.0)
     }
 
     pub fn as_bytes(&self) -> Vec<u8> {
@@ -356,6 +411,35 @@ impl PublicKey {
         signature.0.verify_slice(&self.0, data) == crypto::Verification::Success
     }
 
+    /// verify with the acceptance rules of `mode`, see [`crypto::VerifyMode`].
+    pub fn verify_with_mode(
+        &self,
+        data: &[u8],
+        signature: &Ed25519Signature,
+        mode: crypto::VerifyMode,
+    ) -> bool {
+        signature.0.verify_slice_with_mode(&self.0, data, mode) == crypto::Verification::Success
+    }
+
+    /// verify `(public key, data, signature)` entries with the rules of
+    /// `mode`, the results are the ones of `verify_with_mode` in the same
+    /// order as the entries. Only `VerifyMode::Zip215` is faster than one
+    /// call per entry, see [`crypto::batch`].
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        entries: &[(PublicKey, &[u8], Ed25519Signature)],
+        mode: crypto::VerifyMode,
+        rng: R,
+    ) -> Vec<bool> {
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|(public, data, signature)| (&public.0, *data, &signature.0))
+            .collect();
+        crypto::verify_batch(&entries, mode, rng)
+            .into_iter()
+            .map(|verification| verification == crypto::Verification::Success)
+            .collect()
+    }
+
     pub fn hash(&self) -> Ed25519KeyHash {
         Ed25519KeyHash::from(blake2b224(self.as_bytes().as_ref()))
     }
@@ -383,7 +467,7 @@ macro_rules! impl_signature {
             }
 
             pub fn to_bech32(&self) -> String {
-                self.0.to_bech32_str()
+                bech32_encode(&self.0)
             }
 
             pub fn to_hex(&self) -> String {
@@ -391,7 +475,7 @@ macro_rules! impl_signature {
             }
 
             pub fn from_bech32(bech32_str: &str) -> Result<$name, JsError> {
-                crypto::Signature::try_from_bech32_str(&bech32_str)
+                bech32_decode(bech32_str)
                     .map($name)
                     .map_err(|e| JsError::from_str(&format!("{}", e)))
             }
@@ -417,9 +501,22 @@ impl_signature!(Ed25519Signature, Vec<u8>, crypto::Ed25519);
 
 macro_rules! impl_hash_type {
     ($name:ident, $byte_count:expr) => {
-        #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[derive(Debug, Clone, Eq, Ord, PartialOrd)]
         pub struct $name(pub(crate) [u8; $byte_count]);
 
+        // compared in constant time
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                constant_time::eq(&self.0, &other.0)
+            }
+        }
+
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
         // TODO: fix
         // hash types are the only types in this library to not expect the entire CBOR structure.
         // There is no CBOR binary tag here just the raw hash bytes.
@@ -448,29 +545,26 @@ macro_rules! impl_hash_type {
                 self.0.to_vec()
             }
 
-            pub fn to_bech32(&self, prefix: &str) -> Result<String, JsError> {
-                bech32::encode(&prefix, self.to_bytes().to_base32(), Variant::Bech32)
-                    .map_err(|e| JsError::from_str(&format! {"{:?}", e}))
+            pub fn from_bytes(bytes: &[u8]) -> Result<$name, JsError> {
+                bytes.try_into().map($name).map_err(|_| {
+                    JsError::from_str(&format!(
+                        "{} length should be {} bytes, but data length is {}",
+                        stringify!($name),
+                        $byte_count,
+                        bytes.len()
+                    ))
+                })
             }
 
-            // TODO: fix
-            // pub fn from_bech32(bech_str: &str) -> Result<$name, JsError> {
-            //     let (_hrp, u5data) =
-            //         bech32::decode(bech_str).map_err(|e| JsError::from_str(&e.to_string()))?;
-            //     let data: Vec<u8> = bech32::FromBase32::from_base32(&u5data).unwrap();
-            //     Ok(Self::from_bytes(data)?)
-            // }
-
             pub fn to_hex(&self) -> String {
                 hex::encode(&self.0)
             }
 
-            // TODO: fix
-            // pub fn from_hex(hex: &str) -> Result<$name, JsError> {
-            //     let bytes = hex::decode(hex)
-            //         .map_err(|e| JsError::from_str(&format!("hex decode failed: {}", e)))?;
-            //     Self::from_bytes(bytes).map_err(|e| JsError::from_str(&format!("{:?}", e)))
-            // }
+            pub fn from_hex(hex: &str) -> Result<$name, JsError> {
+                let bytes = hex::decode(hex)
+                    .map_err(|e| JsError::from_str(&format!("hex decode failed: {}", e)))?;
+                Self::from_bytes(&bytes)
+            }
         }
 
         // associated consts are not supported in wasm_bindgen
@@ -489,6 +583,70 @@ macro_rules! impl_hash_type {
 
 impl_hash_type!(Ed25519KeyHash, 28);
 
+impl Cip5 for Bip32PrivateKey {
+    const PREFIXES: &'static [Prefix] = Prefix::EXTENDED_SIGNING_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes).ok()
+    }
+}
+
+impl Cip5 for Bip32PublicKey {
+    const PREFIXES: &'static [Prefix] = Prefix::EXTENDED_VERIFICATION_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes).ok()
+    }
+}
+
+// normal (32 bytes) or extended (64 bytes) private key
+impl Cip5 for PrivateKey {
+    const PREFIXES: &'static [Prefix] = Prefix::SIGNING_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            64 => Self::from_extended_bytes(bytes).ok(),
+            _ => Self::from_normal_bytes(bytes).ok(),
+        }
+    }
+}
+
+impl Cip5 for PublicKey {
+    const PREFIXES: &'static [Prefix] = Prefix::VERIFICATION_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.as_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes).ok()
+    }
+}
+
+impl Cip5 for Ed25519KeyHash {
+    const PREFIXES: &'static [Prefix] = Prefix::KEY_HASHES;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(bytes).ok()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
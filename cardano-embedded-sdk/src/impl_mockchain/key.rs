@@ -5,7 +5,6 @@ use crate::chain_crypto as crypto;
 use crate::chain_crypto::SecretKey;
 use rand_core::{CryptoRng, RngCore};
 
-#[derive(Clone)]
 pub enum EitherEd25519SecretKey {
     Extended(crypto::SecretKey<crypto::Ed25519Extended>),
     Normal(crypto::SecretKey<crypto::Ed25519>),
@@ -2,6 +2,7 @@
 #![feature(test)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
 #[macro_use]
 extern crate cfg_if;
 
@@ -14,23 +15,41 @@ extern crate quickcheck;
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 extern crate hex;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod api;
+#[cfg(feature = "alloc")]
 pub mod types;
 
+#[cfg(feature = "alloc")]
 pub mod bip;
+#[cfg(feature = "alloc")]
 pub mod chain_crypto;
+#[cfg(feature = "alloc")]
 pub mod crypto;
+#[cfg(feature = "alloc")]
 pub mod error;
+#[cfg(feature = "alloc")]
 pub mod impl_mockchain;
+pub mod rng;
+#[cfg(feature = "alloc")]
 pub mod typed_bytes;
 
+#[cfg(feature = "std")]
+pub mod text_envelope;
+#[cfg(feature = "alloc")]
+pub mod tx;
+#[cfg(feature = "alloc")]
 pub mod tx_stream;
 pub mod util;
 
+#[cfg(feature = "alloc")]
 #[macro_use]
 mod serialization_macros;
 
+#[cfg(feature = "alloc")]
 use error::*;
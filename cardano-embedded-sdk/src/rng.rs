@@ -0,0 +1,501 @@
+//! Health tested random generator
+//!
+//! Hardware random generators of microcontrollers can fail silently (stuck
+//! bits, a disconnected noise source...). [`HealthTestedRng`] wraps such a
+//! noise source and:
+//!
+//! * runs the NIST SP 800-90B continuous health tests (repetition count and
+//!   adaptive proportion) on every raw sample;
+//! * runs a startup self-test: a known answer test of the DRBG and the
+//!   health tests over 1024 raw samples before any output is produced;
+//! * conditions the raw samples through an HMAC-DRBG on SHA-512
+//!   (NIST SP 800-90A) which is reseeded periodically.
+//!
+//! Once a health test fails the generator is latched in the failed state and
+//! never produces output again. [`HealthTestedRng::try_fill`] reports the
+//! typed [`Error`]; through [`RngCore`] the error is reported by
+//! `try_fill_bytes` and `fill_bytes` panics, so no key is ever generated
+//! from a faulty source.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate rand;
+//! use cardano_embedded_sdk::bip::bip39::{Entropy, Type};
+//! use cardano_embedded_sdk::rng::{HealthConfig, HealthTestedRng};
+//!
+//! let mut rng = HealthTestedRng::new(rand::rngs::OsRng, HealthConfig::default())
+//!     .expect("the noise source passes the startup tests");
+//! let entropy = Entropy::try_generate(Type::Type24Words, &mut rng).unwrap();
+//! ```
+//!
+use core::{error, fmt, num::NonZeroU32};
+
+use cryptoxide::hmac::Hmac;
+use cryptoxide::mac::Mac;
+use cryptoxide::sha2::Sha512;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::util::securemem;
+
+/// number of raw samples tested at startup before producing any output.
+pub const STARTUP_SAMPLES: usize = 1024;
+
+/// window size of the adaptive proportion test for non binary samples
+/// (SP 800-90B, section 4.4.2).
+pub const APT_WINDOW: u16 = 1024;
+
+/// number of DRBG requests served before reseeding from the noise source.
+pub const RESEED_INTERVAL: u32 = 1024;
+
+/// security strength of HMAC-DRBG with SHA-512, in bits.
+const SECURITY_STRENGTH: usize = 256;
+
+/// largest request served by one DRBG call (2^19 bits).
+const MAX_REQUEST_BYTES: usize = 1 << 16;
+
+/// Error regarding the health of the random generator
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Error {
+    /// the same raw sample was repeated too many times in a row.
+    RepetitionCount,
+
+    /// a raw sample value occurred too often within a window.
+    AdaptiveProportion,
+
+    /// the DRBG known answer test failed.
+    SelfTest,
+
+    /// the noise source itself reported an error.
+    Source,
+}
+impl Error {
+    const CODE_BASE: u32 = rand_core::Error::CUSTOM_START + 0x9b;
+
+    fn code(self) -> NonZeroU32 {
+        let offset = match self {
+            Error::RepetitionCount => 0,
+            Error::AdaptiveProportion => 1,
+            Error::SelfTest => 2,
+            Error::Source => 3,
+        };
+        NonZeroU32::new(Self::CODE_BASE + offset).unwrap()
+    }
+
+    /// recover the health error from a `rand_core::Error`, if it was
+    /// emitted by a [`HealthTestedRng`].
+    pub fn from_rand_error(e: &rand_core::Error) -> Option<Self> {
+        match e.code()?.get().checked_sub(Self::CODE_BASE)? {
+            0 => Some(Error::RepetitionCount),
+            1 => Some(Error::AdaptiveProportion),
+            2 => Some(Error::SelfTest),
+            3 => Some(Error::Source),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::RepetitionCount => write!(f, "RNG health failure: repetition count test"),
+            Error::AdaptiveProportion => {
+                write!(f, "RNG health failure: adaptive proportion test")
+            }
+            Error::SelfTest => write!(f, "RNG health failure: DRBG self-test"),
+            Error::Source => write!(f, "RNG health failure: noise source error"),
+        }
+    }
+}
+impl error::Error for Error {}
+impl From<Error> for rand_core::Error {
+    fn from(e: Error) -> Self {
+        rand_core::Error::from(e.code())
+    }
+}
+
+/// Parameters of the continuous health tests, for a noise source
+/// producing bytes with the claimed min-entropy per byte.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HealthConfig {
+    /// claimed min-entropy of one raw byte, in bits (1 to 8).
+    pub min_entropy: u8,
+    /// repetition count test cutoff.
+    pub rct_cutoff: u16,
+    /// adaptive proportion test cutoff, for a window of
+    /// [`APT_WINDOW`](./constant.APT_WINDOW.html) samples.
+    pub apt_cutoff: u16,
+}
+impl HealthConfig {
+    /// cutoffs for a false positive probability of 2^-20 (SP 800-90B,
+    /// sections 4.4.1 and 4.4.2). The claimed entropy is rounded down to
+    /// 1, 2, 4 or 8 bits per byte.
+    pub const fn new(min_entropy: u8) -> Self {
+        let h = match min_entropy {
+            0 | 1 => 1,
+            2 | 3 => 2,
+            4..=7 => 4,
+            _ => 8,
+        };
+        let apt_cutoff = match h {
+            1 => 589,
+            2 => 325,
+            4 => 105,
+            _ => 18,
+        };
+        HealthConfig {
+            min_entropy: h,
+            rct_cutoff: 1 + 20u16.div_ceil(h as u16),
+            apt_cutoff,
+        }
+    }
+
+    /// number of raw bytes carrying at least `bits` of min-entropy.
+    fn samples_for(&self, bits: usize) -> usize {
+        bits.div_ceil(self.min_entropy as usize)
+    }
+}
+impl Default for HealthConfig {
+    /// conservative default of 2 bits of min-entropy per raw byte.
+    fn default() -> Self {
+        HealthConfig::new(2)
+    }
+}
+
+struct HealthTests {
+    config: HealthConfig,
+    last: u8,
+    repetitions: u16,
+    apt_reference: u8,
+    apt_count: u16,
+    apt_position: u16,
+}
+impl HealthTests {
+    fn new(config: HealthConfig) -> Self {
+        HealthTests {
+            config,
+            last: 0,
+            repetitions: 0,
+            apt_reference: 0,
+            apt_count: 0,
+            apt_position: 0,
+        }
+    }
+
+    fn check(&mut self, sample: u8) -> Result<(), Error> {
+        // repetition count test
+        if self.repetitions > 0 && sample == self.last {
+            self.repetitions += 1;
+            if self.repetitions >= self.config.rct_cutoff {
+                return Err(Error::RepetitionCount);
+            }
+        } else {
+            self.last = sample;
+            self.repetitions = 1;
+        }
+
+        // adaptive proportion test
+        if self.apt_position == 0 {
+            self.apt_reference = sample;
+            self.apt_count = 1;
+        } else if sample == self.apt_reference {
+            self.apt_count += 1;
+            if self.apt_count >= self.config.apt_cutoff {
+                return Err(Error::AdaptiveProportion);
+            }
+        }
+        self.apt_position = (self.apt_position + 1) % APT_WINDOW;
+        Ok(())
+    }
+}
+
+/// HMAC-DRBG with SHA-512 as specified in NIST SP 800-90A.
+struct HmacDrbg {
+    k: [u8; 64],
+    v: [u8; 64],
+    reseed_counter: u32,
+}
+impl HmacDrbg {
+    fn new(seed_material: &[u8]) -> Self {
+        let mut drbg = HmacDrbg {
+            k: [0x00; 64],
+            v: [0x01; 64],
+            reseed_counter: 1,
+        };
+        drbg.update(seed_material);
+        drbg
+    }
+
+    fn hmac_v(&mut self) {
+        let mut mac = Hmac::new(Sha512::new(), &self.k);
+        mac.input(&self.v);
+        mac.raw_result(&mut self.v);
+    }
+
+    fn update(&mut self, provided: &[u8]) {
+        for round in [0x00u8, 0x01] {
+            if round == 0x01 && provided.is_empty() {
+                break;
+            }
+            let mut mac = Hmac::new(Sha512::new(), &self.k);
+            mac.input(&self.v);
+            mac.input(&[round]);
+            mac.input(provided);
+            mac.raw_result(&mut self.k);
+            self.hmac_v();
+        }
+    }
+
+    fn reseed(&mut self, seed_material: &[u8]) {
+        self.update(seed_material);
+        self.reseed_counter = 1;
+    }
+
+    fn generate(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(64) {
+            self.hmac_v();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[]);
+        self.reseed_counter += 1;
+    }
+}
+impl Drop for HmacDrbg {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.k);
+        securemem::zero(&mut self.v);
+    }
+}
+
+/// Run the HMAC-DRBG known answer test.
+fn drbg_self_test() -> Result<(), Error> {
+    const ENTROPY: [u8; 48] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+    const EXPECTED: [u8; 16] = [
+        0x44, 0x05, 0x0f, 0x74, 0x43, 0x42, 0xd8, 0xe9, 0xf0, 0x46, 0x6a, 0xc6, 0x09, 0x52, 0x68,
+        0x6e,
+    ];
+    let mut drbg = HmacDrbg::new(&ENTROPY);
+    let mut out = [0u8; 64];
+    drbg.generate(&mut out);
+    drbg.generate(&mut out);
+    if out[..16] == EXPECTED {
+        Ok(())
+    } else {
+        Err(Error::SelfTest)
+    }
+}
+
+/// Random generator conditioning a health tested noise source through
+/// an HMAC-DRBG. See the module documentation.
+pub struct HealthTestedRng<R> {
+    source: R,
+    tests: HealthTests,
+    drbg: HmacDrbg,
+    failure: Option<Error>,
+}
+impl<R: RngCore> HealthTestedRng<R> {
+    /// run the startup self-tests on the given noise source and
+    /// instantiate the DRBG from it.
+    ///
+    /// # Error
+    ///
+    /// fails if the DRBG known answer test fails, or if the noise source
+    /// fails the health tests during startup.
+    pub fn new(source: R, config: HealthConfig) -> Result<Self, Error> {
+        drbg_self_test()?;
+        let mut rng = HealthTestedRng {
+            source,
+            tests: HealthTests::new(config),
+            drbg: HmacDrbg::new(&[]),
+            failure: None,
+        };
+        let mut discard = [0u8; 64];
+        for _ in 0..STARTUP_SAMPLES / discard.len() {
+            rng.sample(&mut discard)?;
+        }
+        securemem::zero(&mut discard);
+        rng.reseed()?;
+        Ok(rng)
+    }
+
+    /// the health test failure, if the generator has failed.
+    pub fn failure(&self) -> Option<Error> {
+        self.failure
+    }
+
+    /// fill `dest` with conditioned random bytes.
+    ///
+    /// # Error
+    ///
+    /// fails, and keeps failing, once the noise source failed a health test.
+    pub fn try_fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if let Some(e) = self.failure {
+            return Err(e);
+        }
+        for chunk in dest.chunks_mut(MAX_REQUEST_BYTES) {
+            if self.drbg.reseed_counter > RESEED_INTERVAL {
+                self.reseed()?;
+            }
+            self.drbg.generate(chunk);
+        }
+        Ok(())
+    }
+
+    /// read raw samples from the noise source through the health tests.
+    fn sample(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let result = match self.source.try_fill_bytes(buf) {
+            Ok(()) => buf.iter().try_for_each(|b| self.tests.check(*b)),
+            Err(_) => Err(Error::Source),
+        };
+        if let Err(e) = result {
+            self.failure = Some(e);
+        }
+        result
+    }
+
+    fn reseed(&mut self) -> Result<(), Error> {
+        // entropy input and nonce: 1.5 times the security strength
+        let needed = self.tests.config.samples_for(SECURITY_STRENGTH * 3 / 2);
+        let mut seed_material = [0u8; 64];
+        let mut remaining = needed;
+        while remaining > 0 {
+            let n = remaining.min(seed_material.len());
+            self.sample(&mut seed_material[..n])?;
+            self.drbg.reseed(&seed_material[..n]);
+            remaining -= n;
+        }
+        securemem::zero(&mut seed_material);
+        Ok(())
+    }
+}
+impl<R: RngCore> RngCore for HealthTestedRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    /// # Panics
+    ///
+    /// panics if the noise source failed a health test.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.try_fill(dest) {
+            panic!("{}", e)
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.try_fill(dest).map_err(rand_core::Error::from)
+    }
+}
+impl<R: RngCore> CryptoRng for HealthTestedRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    /// noise source replaying a fixed pattern
+    struct Pattern<F: FnMut(usize) -> u8>(usize, F);
+    impl<F: FnMut(usize) -> u8> RngCore for Pattern<F> {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest.iter_mut() {
+                *b = (self.1)(self.0);
+                self.0 += 1;
+            }
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn config_cutoffs() {
+        let c = HealthConfig::new(8);
+        assert_eq!((c.rct_cutoff, c.apt_cutoff), (4, 18));
+        let c = HealthConfig::new(3);
+        assert_eq!((c.min_entropy, c.rct_cutoff, c.apt_cutoff), (2, 11, 325));
+        let c = HealthConfig::new(1);
+        assert_eq!((c.rct_cutoff, c.apt_cutoff), (21, 589));
+    }
+
+    #[test]
+    fn self_test_passes() {
+        assert_eq!(drbg_self_test(), Ok(()));
+    }
+
+    #[test]
+    fn healthy_source() {
+        let source = ChaChaRng::from_seed([1; 32]);
+        let mut rng = HealthTestedRng::new(source, HealthConfig::new(8)).unwrap();
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        for _ in 0..(2 * RESEED_INTERVAL) {
+            rng.try_fill(&mut a).unwrap();
+        }
+        rng.try_fill(&mut b).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(rng.failure(), None);
+    }
+
+    #[test]
+    fn stuck_source_fails_at_startup() {
+        let stuck = Pattern(0, |_| 0xaa);
+        assert_eq!(
+            HealthTestedRng::new(stuck, HealthConfig::default()).err(),
+            Some(Error::RepetitionCount)
+        );
+    }
+
+    #[test]
+    fn biased_source_fails_at_startup() {
+        // never repeats twice in a row but half the samples are 0
+        let biased = Pattern(0, |i| if i % 2 == 0 { 0 } else { i as u8 | 1 });
+        assert_eq!(
+            HealthTestedRng::new(biased, HealthConfig::new(4)).err(),
+            Some(Error::AdaptiveProportion)
+        );
+    }
+
+    #[test]
+    fn failure_is_latched() {
+        let mut healthy = ChaChaRng::from_seed([2; 32]);
+        // behaves until the startup tests are done, then gets stuck
+        let limit = STARTUP_SAMPLES + 64;
+        let source = Pattern(0, move |i| {
+            if i < limit {
+                healthy.next_u32() as u8
+            } else {
+                0
+            }
+        });
+        let mut rng = HealthTestedRng::new(source, HealthConfig::new(8)).unwrap();
+        let mut buf = [0u8; 64];
+        let mut result = Ok(());
+        for _ in 0..(4 * RESEED_INTERVAL) {
+            result = rng.try_fill(&mut buf);
+            if result.is_err() {
+                break;
+            }
+        }
+        assert_eq!(result, Err(Error::RepetitionCount));
+        assert_eq!(rng.try_fill(&mut buf), Err(Error::RepetitionCount));
+
+        let e = rng.try_fill_bytes(&mut buf).unwrap_err();
+        assert_eq!(Error::from_rand_error(&e), Some(Error::RepetitionCount));
+    }
+}
@@ -0,0 +1,598 @@
+//! cardano-cli text envelopes
+//!
+//! The JSON files cardano-cli and cardano-hw-cli use for keys, witnesses
+//! and transactions:
+//!
+//! ```json
+//! {
+//!     "type": "PaymentExtendedSigningKeyShelley_ed25519_bip32",
+//!     "description": "Payment Signing Key",
+//!     "cborHex": "5880..."
+//! }
+//! ```
+//!
+//! The `type` is checked when reading an envelope, an envelope of another
+//! type is an error instead of bytes read in the wrong layout.
+//!
+//! Needs the `std` feature.
+use std::{
+    fmt, format, fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto::PublicKey,
+    types::{from_cbor_bytes, to_cbor_bytes, KeyFormat, KeyFormatError, XPrvKey, XPubKey},
+    util::securemem,
+};
+
+/// Owner of a key, in the name of its type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRole {
+    Payment,
+    Stake,
+}
+
+impl KeyRole {
+    fn name(self) -> &'static str {
+        match self {
+            KeyRole::Payment => "Payment",
+            KeyRole::Stake => "Stake",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Era {
+    Babbage,
+    Conway,
+}
+
+impl Era {
+    fn name(self) -> &'static str {
+        match self {
+            Era::Babbage => "Babbage",
+            Era::Conway => "Conway",
+        }
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}Era", self.name())
+    }
+}
+
+/// `type` of an envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeType {
+    /// `PaymentSigningKeyShelley_ed25519`: 32 bytes seed
+    SigningKey(KeyRole),
+    /// `PaymentVerificationKeyShelley_ed25519`: 32 bytes public key
+    VerificationKey(KeyRole),
+    /// `PaymentExtendedSigningKeyShelley_ed25519_bip32`: `kL | kR | A | cc`
+    ExtendedSigningKey(KeyRole),
+    /// `PaymentExtendedVerificationKeyShelley_ed25519_bip32`: `A | cc`
+    ExtendedVerificationKey(KeyRole),
+    /// `Tx BabbageEra`, written by older cardano-cli
+    Tx(Era),
+    /// `TxBodyBabbage`, transaction body of older cardano-cli
+    /// `transaction build-raw`: `[body, [], [], [], true, null]`
+    TxBody(Era),
+    /// `Unwitnessed Tx ConwayEra`
+    UnwitnessedTx(Era),
+    /// `Witnessed Tx ConwayEra`
+    WitnessedTx(Era),
+    /// `TxWitness ConwayEra`
+    TxWitness(Era),
+}
+
+impl EnvelopeType {
+    pub const ALL: [EnvelopeType; 18] = [
+        EnvelopeType::SigningKey(KeyRole::Payment),
+        EnvelopeType::VerificationKey(KeyRole::Payment),
+        EnvelopeType::ExtendedSigningKey(KeyRole::Payment),
+        EnvelopeType::ExtendedVerificationKey(KeyRole::Payment),
+        EnvelopeType::SigningKey(KeyRole::Stake),
+        EnvelopeType::VerificationKey(KeyRole::Stake),
+        EnvelopeType::ExtendedSigningKey(KeyRole::Stake),
+        EnvelopeType::ExtendedVerificationKey(KeyRole::Stake),
+        EnvelopeType::Tx(Era::Babbage),
+        EnvelopeType::TxBody(Era::Babbage),
+        EnvelopeType::UnwitnessedTx(Era::Babbage),
+        EnvelopeType::WitnessedTx(Era::Babbage),
+        EnvelopeType::TxWitness(Era::Babbage),
+        EnvelopeType::Tx(Era::Conway),
+        EnvelopeType::TxBody(Era::Conway),
+        EnvelopeType::UnwitnessedTx(Era::Conway),
+        EnvelopeType::WitnessedTx(Era::Conway),
+        EnvelopeType::TxWitness(Era::Conway),
+    ];
+
+    /// description cardano-cli writes for this type
+    pub fn default_description(&self) -> String {
+        match self {
+            EnvelopeType::SigningKey(role) | EnvelopeType::ExtendedSigningKey(role) => {
+                format!("{} Signing Key", role.name())
+            }
+            EnvelopeType::VerificationKey(role) | EnvelopeType::ExtendedVerificationKey(role) => {
+                format!("{} Verification Key", role.name())
+            }
+            EnvelopeType::Tx(_) | EnvelopeType::UnwitnessedTx(_) | EnvelopeType::WitnessedTx(_) => {
+                "Ledger Cddl Format".to_string()
+            }
+            EnvelopeType::TxBody(_) => String::new(),
+            EnvelopeType::TxWitness(_) => "Key Witness ShelleyEra".to_string(),
+        }
+    }
+
+    /// true for the types holding a private key
+    pub fn is_secret(&self) -> bool {
+        matches!(
+            self,
+            EnvelopeType::SigningKey(_) | EnvelopeType::ExtendedSigningKey(_)
+        )
+    }
+}
+
+impl fmt::Display for EnvelopeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnvelopeType::SigningKey(role) => {
+                write!(f, "{}SigningKeyShelley_ed25519", role.name())
+            }
+            EnvelopeType::VerificationKey(role) => {
+                write!(f, "{}VerificationKeyShelley_ed25519", role.name())
+            }
+            EnvelopeType::ExtendedSigningKey(role) => {
+                write!(f, "{}ExtendedSigningKeyShelley_ed25519_bip32", role.name())
+            }
+            EnvelopeType::ExtendedVerificationKey(role) => {
+                write!(
+                    f,
+                    "{}ExtendedVerificationKeyShelley_ed25519_bip32",
+                    role.name()
+                )
+            }
+            EnvelopeType::Tx(era) => write!(f, "Tx {era}"),
+            EnvelopeType::TxBody(era) => write!(f, "TxBody{}", era.name()),
+            EnvelopeType::UnwitnessedTx(era) => write!(f, "Unwitnessed Tx {era}"),
+            EnvelopeType::WitnessedTx(era) => write!(f, "Witnessed Tx {era}"),
+            EnvelopeType::TxWitness(era) => write!(f, "TxWitness {era}"),
+        }
+    }
+}
+
+impl FromStr for EnvelopeType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EnvelopeType::ALL
+            .into_iter()
+            .find(|envelope_type| envelope_type.to_string() == s)
+            .ok_or_else(|| Error::UnknownType(s.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// `type` is not one of [`EnvelopeType::ALL`]
+    UnknownType(String),
+    /// the envelope holds something else than what was asked for
+    UnexpectedType(EnvelopeType),
+    InvalidHex,
+    Key(KeyFormatError),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "cannot access the envelope file: {err}"),
+            Error::Json(err) => write!(f, "invalid envelope: {err}"),
+            Error::UnknownType(envelope_type) => {
+                write!(f, "unknown envelope type \"{envelope_type}\"")
+            }
+            Error::UnexpectedType(envelope_type) => {
+                write!(f, "unexpected envelope type \"{envelope_type}\"")
+            }
+            Error::InvalidHex => write!(f, "cborHex is not hexadecimal"),
+            Error::Key(err) => write!(f, "invalid key in the envelope: {err}"),
+        }
+    }
+}
+impl core::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+impl From<KeyFormatError> for Error {
+    fn from(err: KeyFormatError) -> Self {
+        Error::Key(err)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawEnvelope {
+    #[serde(rename = "type")]
+    envelope_type: String,
+    description: String,
+    #[serde(rename = "cborHex")]
+    cbor_hex: String,
+}
+
+impl Drop for RawEnvelope {
+    fn drop(&mut self) {
+        let mut cbor_hex = core::mem::take(&mut self.cbor_hex).into_bytes();
+        securemem::zero(&mut cbor_hex);
+    }
+}
+
+/// Envelope with a known type and a CBOR payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawEnvelope", into = "RawEnvelope")]
+pub struct TextEnvelope {
+    envelope_type: EnvelopeType,
+    description: String,
+    cbor: Vec<u8>,
+}
+
+impl TryFrom<RawEnvelope> for TextEnvelope {
+    type Error = Error;
+
+    fn try_from(raw: RawEnvelope) -> Result<Self, Self::Error> {
+        Ok(TextEnvelope {
+            envelope_type: raw.envelope_type.parse()?,
+            description: raw.description.clone(),
+            cbor: hex::decode(&raw.cbor_hex).map_err(|_| Error::InvalidHex)?,
+        })
+    }
+}
+
+impl From<TextEnvelope> for RawEnvelope {
+    fn from(envelope: TextEnvelope) -> Self {
+        RawEnvelope {
+            envelope_type: envelope.envelope_type.to_string(),
+            description: envelope.description.clone(),
+            cbor_hex: hex::encode(&envelope.cbor),
+        }
+    }
+}
+
+impl TextEnvelope {
+    /// Envelope of `cbor` with the description cardano-cli gives to the type.
+    pub fn new(envelope_type: EnvelopeType, cbor: Vec<u8>) -> Self {
+        TextEnvelope {
+            envelope_type,
+            description: envelope_type.default_description(),
+            cbor,
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn envelope_type(&self) -> EnvelopeType {
+        self.envelope_type
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// payload, whatever the type
+    pub fn cbor(&self) -> &[u8] {
+        &self.cbor
+    }
+
+    /// `PaymentExtendedSigningKeyShelley_ed25519_bip32` (or `Stake`) of `key`
+    pub fn signing_key(key: &XPrvKey, role: KeyRole) -> Self {
+        Self::new(
+            EnvelopeType::ExtendedSigningKey(role),
+            key.to_format(KeyFormat::Cbor),
+        )
+    }
+
+    /// `PaymentExtendedVerificationKeyShelley_ed25519_bip32` (or `Stake`) of `key`
+    pub fn extended_verification_key(key: &XPubKey, role: KeyRole) -> Self {
+        let cbor = key
+            .to_format(KeyFormat::Cbor)
+            .expect("public keys are serialized as CBOR");
+        Self::new(EnvelopeType::ExtendedVerificationKey(role), cbor)
+    }
+
+    /// `PaymentVerificationKeyShelley_ed25519` (or `Stake`) of `key`, without
+    /// the chain code
+    pub fn verification_key(key: &XPubKey, role: KeyRole) -> Self {
+        let raw = key
+            .to_format(KeyFormat::Raw)
+            .expect("public keys are serialized raw");
+        Self::new(EnvelopeType::VerificationKey(role), to_cbor_bytes(&raw))
+    }
+
+    /// `Unwitnessed Tx` of a transaction without signatures
+    pub fn unsigned_tx(era: Era, tx_cbor: &[u8]) -> Self {
+        Self::new(EnvelopeType::UnwitnessedTx(era), tx_cbor.to_vec())
+    }
+
+    /// `Witnessed Tx` of a signed transaction
+    pub fn signed_tx(era: Era, tx_cbor: &[u8]) -> Self {
+        Self::new(EnvelopeType::WitnessedTx(era), tx_cbor.to_vec())
+    }
+
+    /// `TxWitness`, `witness_cbor` is the `[0, vkeywitness]` or
+    /// `[1, bootstrap_witness]` of cardano-cli
+    pub fn tx_witness(era: Era, witness_cbor: &[u8]) -> Self {
+        Self::new(EnvelopeType::TxWitness(era), witness_cbor.to_vec())
+    }
+
+    /// Read an extended signing key of any role.
+    pub fn to_signing_key(&self) -> Result<(XPrvKey, KeyRole), Error> {
+        match self.envelope_type {
+            EnvelopeType::ExtendedSigningKey(role) => {
+                Ok((XPrvKey::from_format(&self.cbor, KeyFormat::Cbor)?, role))
+            }
+            envelope_type => Err(Error::UnexpectedType(envelope_type)),
+        }
+    }
+
+    /// Read an extended verification key of any role.
+    pub fn to_extended_verification_key(&self) -> Result<(XPubKey, KeyRole), Error> {
+        match self.envelope_type {
+            EnvelopeType::ExtendedVerificationKey(role) => {
+                Ok((XPubKey::from_format(&self.cbor, KeyFormat::Cbor)?, role))
+            }
+            envelope_type => Err(Error::UnexpectedType(envelope_type)),
+        }
+    }
+
+    /// Read the public key of a verification key, extended or not.
+    pub fn to_verification_key(&self) -> Result<(PublicKey, KeyRole), Error> {
+        let (raw, role) = match self.envelope_type {
+            EnvelopeType::VerificationKey(role) => (from_cbor_bytes(&self.cbor)?.to_vec(), role),
+            EnvelopeType::ExtendedVerificationKey(_) => {
+                let (key, role) = self.to_extended_verification_key()?;
+                (key.to_format(KeyFormat::Raw)?, role)
+            }
+            envelope_type => return Err(Error::UnexpectedType(envelope_type)),
+        };
+        PublicKey::from_bytes(&raw)
+            .map(|key| (key, role))
+            .map_err(|_| Error::Key(KeyFormatError::InvalidKey))
+    }
+
+    /// CBOR of the transaction of a `Tx`, `TxBody`, `Unwitnessed Tx` or
+    /// `Witnessed Tx`. Both layouts are read by
+    /// [`TxId::from_tx_cbor`](crate::types::TxId::from_tx_cbor).
+    pub fn to_tx(&self) -> Result<(&[u8], Era), Error> {
+        match self.envelope_type {
+            EnvelopeType::Tx(era)
+            | EnvelopeType::TxBody(era)
+            | EnvelopeType::UnwitnessedTx(era)
+            | EnvelopeType::WitnessedTx(era) => Ok((&self.cbor, era)),
+            envelope_type => Err(Error::UnexpectedType(envelope_type)),
+        }
+    }
+
+    /// CBOR of the witness of a `TxWitness`.
+    pub fn to_tx_witness(&self) -> Result<(&[u8], Era), Error> {
+        match self.envelope_type {
+            EnvelopeType::TxWitness(era) => Ok((&self.cbor, era)),
+            envelope_type => Err(Error::UnexpectedType(envelope_type)),
+        }
+    }
+
+    /// JSON with the layout of cardano-cli (4 spaces indentation).
+    pub fn to_json(&self) -> String {
+        let mut json = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut json, formatter);
+        self.serialize(&mut serializer)
+            .expect("envelopes serialize to JSON");
+        String::from_utf8(json).expect("serde_json writes UTF-8")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the envelope to `path`. As with cardano-cli, a signing key file
+    /// is only readable by its owner (mode 0600 on unix), an existing file is
+    /// restricted before the key is written.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        if self.envelope_type.is_secret() {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut json = self.to_json().into_bytes();
+        json.push(b'\n');
+        let written = options.open(path).and_then(|mut file| {
+            // `mode` only applies when the file is created
+            #[cfg(unix)]
+            if self.envelope_type.is_secret() {
+                use std::os::unix::fs::PermissionsExt;
+                file.set_permissions(fs::Permissions::from_mode(0o600))?;
+            }
+            file.write_all(&json)
+        });
+        securemem::zero(&mut json);
+        Ok(written?)
+    }
+
+    pub fn read_from<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut json = fs::read(path)?;
+        let envelope = serde_json::from_slice(&json).map_err(Error::Json);
+        securemem::zero(&mut json);
+        envelope
+    }
+}
+
+impl Drop for TextEnvelope {
+    fn drop(&mut self) {
+        securemem::zero(&mut self.cbor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::slip14;
+
+    #[cfg(feature = "bip39-english")]
+    #[test]
+    fn test_key_envelopes() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+
+        let envelope = TextEnvelope::signing_key(&prv_key, KeyRole::Payment);
+        let json = envelope.to_json();
+        assert!(json.starts_with(
+            "{\n    \"type\": \"PaymentExtendedSigningKeyShelley_ed25519_bip32\",\n    \
+             \"description\": \"Payment Signing Key\",\n    \"cborHex\": \"5880"
+        ));
+        let (read, role) = TextEnvelope::from_json(&json)
+            .unwrap()
+            .to_signing_key()
+            .unwrap();
+        assert_eq!((read.to_hex(), role), (prv_key.to_hex(), KeyRole::Payment));
+
+        let envelope = TextEnvelope::extended_verification_key(&pub_key, KeyRole::Stake);
+        assert_eq!(
+            envelope.envelope_type().to_string(),
+            "StakeExtendedVerificationKeyShelley_ed25519_bip32"
+        );
+        assert_eq!(envelope.cbor()[..2], [0x58, 0x40]);
+        let (read, role) = envelope.to_extended_verification_key().unwrap();
+        assert_eq!((read.to_hex(), role), (pub_key.to_hex(), KeyRole::Stake));
+
+        let envelope = TextEnvelope::verification_key(&pub_key, KeyRole::Payment);
+        assert_eq!(envelope.cbor()[..2], [0x58, 0x20]);
+        let (public_key, _) = envelope.to_verification_key().unwrap();
+        let (extended, _) = TextEnvelope::extended_verification_key(&pub_key, KeyRole::Payment)
+            .to_verification_key()
+            .unwrap();
+        assert_eq!(public_key.as_bytes(), extended.as_bytes());
+        assert_eq!(
+            public_key.as_bytes(),
+            pub_key.to_format(KeyFormat::Raw).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_read_cardano_cli_envelope() {
+        let json = r#"{
+            "type": "TxWitness ConwayEra",
+            "description": "Key Witness ShelleyEra",
+            "cborHex": "8200825820aa"
+        }"#;
+        let envelope = TextEnvelope::from_json(json).unwrap();
+        assert_eq!(
+            envelope.to_tx_witness().unwrap(),
+            (&[0x82, 0x00, 0x82, 0x58, 0x20, 0xaa][..], Era::Conway)
+        );
+        assert!(matches!(
+            envelope.to_tx(),
+            Err(Error::UnexpectedType(EnvelopeType::TxWitness(Era::Conway)))
+        ));
+        assert!(matches!(
+            envelope.to_signing_key(),
+            Err(Error::UnexpectedType(_))
+        ));
+
+        let json = json.replace("TxWitness", "TxWitnes");
+        assert!(matches!(
+            TextEnvelope::from_json(&json),
+            Err(Error::Json(_))
+        ));
+        assert!(matches!(
+            "TxWitnes ConwayEra".parse::<EnvelopeType>(),
+            Err(Error::UnknownType(_))
+        ));
+        let json = r#"{"type": "Tx BabbageEra", "description": "", "cborHex": "8x"}"#;
+        assert!(TextEnvelope::from_json(json).is_err());
+    }
+
+    #[test]
+    fn test_type_names() {
+        for envelope_type in EnvelopeType::ALL {
+            assert_eq!(
+                envelope_type.to_string().parse::<EnvelopeType>().unwrap(),
+                envelope_type
+            );
+        }
+        assert_eq!(
+            EnvelopeType::UnwitnessedTx(Era::Conway).to_string(),
+            "Unwitnessed Tx ConwayEra"
+        );
+        assert_eq!(
+            EnvelopeType::SigningKey(KeyRole::Stake).to_string(),
+            "StakeSigningKeyShelley_ed25519"
+        );
+    }
+
+    #[test]
+    fn test_tx_envelopes() {
+        let tx = [0x84, 0xa0, 0xa0, 0xf5, 0xf6];
+        let envelope = TextEnvelope::signed_tx(Era::Conway, &tx).with_description("");
+        let read = TextEnvelope::from_json(&envelope.to_json()).unwrap();
+        assert_eq!(read, envelope);
+        assert_eq!(read.to_tx().unwrap(), (&tx[..], Era::Conway));
+        assert_eq!(
+            TextEnvelope::unsigned_tx(Era::Babbage, &tx).description(),
+            "Ledger Cddl Format"
+        );
+    }
+
+    #[test]
+    fn test_tx_body_envelope() {
+        let envelope = TextEnvelope::from_json(include_str!(
+            "../../slip14-data/txs/tx-bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb.raw"
+        ))
+        .unwrap();
+        assert_eq!(envelope.envelope_type(), EnvelopeType::TxBody(Era::Babbage));
+        assert_eq!(envelope.description(), "");
+        let (tx, era) = envelope.to_tx().unwrap();
+        assert_eq!(era, Era::Babbage);
+        assert_eq!(
+            crate::types::TxId::from_tx_cbor(tx).unwrap().to_hex(),
+            "bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb"
+        );
+    }
+
+    #[cfg(all(unix, feature = "bip39-english"))]
+    #[test]
+    fn test_signing_key_file_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (prv_key, _) = slip14::make_address_keys();
+        let envelope = TextEnvelope::signing_key(&prv_key, KeyRole::Payment);
+        let path = std::env::temp_dir().join(format!("embedano-{}.skey", std::process::id()));
+        // an existing file readable by others is restricted too
+        fs::write(&path, b"").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        envelope.write_to(&path).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let read = TextEnvelope::read_from(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(read.unwrap(), envelope);
+    }
+}
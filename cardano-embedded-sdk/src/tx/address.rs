@@ -0,0 +1,333 @@
+//! Addresses
+//!
+//! Shelley addresses start with a header byte: the address type in the high
+//! nibble and the network id in the low one, followed by the hashes of the
+//! payment and stake credentials. Byron addresses are CBOR, they are only
+//! carried and shown in base58.
+use alloc::{string::String, vec::Vec};
+use core::{error, fmt};
+
+use minicbor::{decode, encode, Decode, Decoder, Encode, Encoder};
+
+use crate::chain_crypto::cip5::{self, Prefix};
+use crate::util::base58;
+
+/// network id of the mainnet
+pub const MAINNET: u8 = 1;
+/// network id of the test networks (preprod, preview)
+pub const TESTNET: u8 = 0;
+
+const HASH_SIZE: usize = 28;
+
+/// Payment or stake credential: the hash of a key or of a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Credential {
+    KeyHash([u8; HASH_SIZE]),
+    ScriptHash([u8; HASH_SIZE]),
+}
+
+impl Credential {
+    pub fn hash(&self) -> &[u8; HASH_SIZE] {
+        match self {
+            Credential::KeyHash(hash) | Credential::ScriptHash(hash) => hash,
+        }
+    }
+
+    pub fn is_script(&self) -> bool {
+        matches!(self, Credential::ScriptHash(_))
+    }
+
+    fn from_hash(hash: &[u8], is_script: bool) -> Self {
+        let hash = hash.try_into().expect("credentials are 28 bytes hashes");
+        if is_script {
+            Credential::ScriptHash(hash)
+        } else {
+            Credential::KeyHash(hash)
+        }
+    }
+}
+
+impl<'b, C> Decode<'b, C> for Credential {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        if d.array()? != Some(2) {
+            return Err(decode::Error::message("credential is not a 2 items array"));
+        }
+        let is_script = match d.u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(decode::Error::message("unknown credential type")),
+        };
+        let hash = d.bytes()?;
+        if hash.len() != HASH_SIZE {
+            return Err(decode::Error::message("credential hash is not 28 bytes"));
+        }
+        Ok(Credential::from_hash(hash, is_script))
+    }
+}
+
+impl<C> Encode<C> for Credential {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(2)?.u8(self.is_script() as u8)?.bytes(self.hash())?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    /// payment and stake credentials
+    Base,
+    /// payment credential and pointer to a stake registration
+    Pointer,
+    /// payment credential only
+    Enterprise,
+    /// stake credential only, where rewards are withdrawn from
+    Reward,
+    Byron,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressError {
+    UnknownType(u8),
+    InvalidSize(usize),
+    InvalidBech32,
+}
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::UnknownType(header) => {
+                write!(f, "unknown address type in header {header:#04x}")
+            }
+            AddressError::InvalidSize(size) => write!(f, "invalid address size {size}"),
+            AddressError::InvalidBech32 => write!(f, "invalid bech32 address"),
+        }
+    }
+}
+impl error::Error for AddressError {}
+
+/// Address as found in transaction outputs and withdrawals
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address(Vec<u8>);
+
+impl Address {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, AddressError> {
+        let header = *bytes.first().ok_or(AddressError::InvalidSize(0))?;
+        let valid_size = match header >> 4 {
+            0..=3 => bytes.len() == 1 + 2 * HASH_SIZE,
+            // payment credential and 3 variable length naturals
+            4 | 5 => bytes.len() > 1 + HASH_SIZE + 2,
+            6 | 7 | 14 | 15 => bytes.len() == 1 + HASH_SIZE,
+            8 => true,
+            _ => return Err(AddressError::UnknownType(header)),
+        };
+        if !valid_size {
+            return Err(AddressError::InvalidSize(bytes.len()));
+        }
+        Ok(Address(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn base(network_id: u8, payment: Credential, stake: Credential) -> Self {
+        let header = (payment.is_script() as u8) << 4 | (stake.is_script() as u8) << 5;
+        Address(
+            [
+                &[header | network_id & 0x0f][..],
+                payment.hash(),
+                stake.hash(),
+            ]
+            .concat(),
+        )
+    }
+
+    pub fn enterprise(network_id: u8, payment: Credential) -> Self {
+        let header = 0x60 | (payment.is_script() as u8) << 4;
+        Address([&[header | network_id & 0x0f][..], payment.hash()].concat())
+    }
+
+    pub fn reward(network_id: u8, stake: Credential) -> Self {
+        let header = 0xe0 | (stake.is_script() as u8) << 4;
+        Address([&[header | network_id & 0x0f][..], stake.hash()].concat())
+    }
+
+    fn address_type(&self) -> u8 {
+        self.0[0] >> 4
+    }
+
+    pub fn kind(&self) -> AddressKind {
+        match self.address_type() {
+            0..=3 => AddressKind::Base,
+            4 | 5 => AddressKind::Pointer,
+            6 | 7 => AddressKind::Enterprise,
+            8 => AddressKind::Byron,
+            _ => AddressKind::Reward,
+        }
+    }
+
+    /// network id of Shelley addresses, Byron addresses have a protocol
+    /// magic in their attributes instead
+    pub fn network_id(&self) -> Option<u8> {
+        match self.kind() {
+            AddressKind::Byron => None,
+            _ => Some(self.0[0] & 0x0f),
+        }
+    }
+
+    pub fn payment_credential(&self) -> Option<Credential> {
+        match self.kind() {
+            AddressKind::Base | AddressKind::Pointer | AddressKind::Enterprise => {
+                let is_script = self.address_type() & 1 == 1;
+                Some(Credential::from_hash(&self.0[1..1 + HASH_SIZE], is_script))
+            }
+            AddressKind::Reward | AddressKind::Byron => None,
+        }
+    }
+
+    pub fn stake_credential(&self) -> Option<Credential> {
+        match self.kind() {
+            AddressKind::Base => {
+                let is_script = self.address_type() & 2 == 2;
+                Some(Credential::from_hash(&self.0[1 + HASH_SIZE..], is_script))
+            }
+            AddressKind::Reward => {
+                let is_script = self.address_type() & 1 == 1;
+                Some(Credential::from_hash(&self.0[1..], is_script))
+            }
+            _ => None,
+        }
+    }
+
+    /// `addr1…`, `stake_test1…`, or base58 for Byron addresses
+    pub fn to_bech32(&self) -> String {
+        let prefix = match (self.kind(), self.network_id()) {
+            (AddressKind::Byron, _) => return base58::encode(&self.0),
+            (AddressKind::Reward, Some(MAINNET)) => Prefix::Stake,
+            (AddressKind::Reward, _) => Prefix::StakeTest,
+            (_, Some(MAINNET)) => Prefix::Addr,
+            _ => Prefix::AddrTest,
+        };
+        cip5::encode(prefix, &self.0)
+    }
+
+    pub fn from_bech32(bech32_str: &str) -> Result<Self, AddressError> {
+        match cip5::decode(bech32_str) {
+            Ok((Prefix::Addr | Prefix::AddrTest | Prefix::Stake | Prefix::StakeTest, bytes)) => {
+                Address::from_bytes(bytes)
+            }
+            _ => Err(AddressError::InvalidBech32),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_bech32())
+    }
+}
+
+impl<'b, C> Decode<'b, C> for Address {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        Address::from_bytes(d.bytes()?.to_vec()).map_err(decode::Error::message)
+    }
+}
+
+impl<C> Encode<C> for Address {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.bytes(&self.0)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CIP-19 test vectors
+    const PAYMENT_KEY_HASH: &str = "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e";
+    const STAKE_KEY_HASH: &str = "337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251";
+    const SCRIPT_HASH: &str = "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f";
+
+    fn credential(hash: &str, is_script: bool) -> Credential {
+        Credential::from_hash(&hex::decode(hash).unwrap(), is_script)
+    }
+
+    #[test]
+    fn test_cip19_addresses() {
+        let payment = credential(PAYMENT_KEY_HASH, false);
+        let stake = credential(STAKE_KEY_HASH, false);
+        let script = credential(SCRIPT_HASH, true);
+
+        let base = Address::base(MAINNET, payment, stake);
+        assert_eq!(
+            base.to_bech32(),
+            "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x"
+        );
+        let base_script = Address::base(MAINNET, script, stake);
+        assert_eq!(
+            base_script.to_bech32(),
+            "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh"
+        );
+        assert_eq!(
+            Address::enterprise(TESTNET, payment).to_bech32(),
+            "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz"
+        );
+        let reward = Address::reward(MAINNET, stake);
+        assert_eq!(
+            reward.to_bech32(),
+            "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw"
+        );
+
+        assert_eq!(Address::from_bech32(&base.to_bech32()), Ok(base.clone()));
+        assert_eq!(base.payment_credential(), Some(payment));
+        assert_eq!(base.stake_credential(), Some(stake));
+        assert_eq!(base_script.payment_credential(), Some(script));
+        assert_eq!(reward.stake_credential(), Some(stake));
+        assert_eq!(reward.payment_credential(), None);
+        assert_eq!(reward.kind(), AddressKind::Reward);
+    }
+
+    #[test]
+    fn test_byron_address() {
+        // CIP-19 Byron test vector
+        let byron = hex::decode(
+            "82d818584983581c7e9ee4a9527dea9091e2d580edd6716888c42f75d96276290f98fe0ba201581e581c0cdf39b531d1ac0963cbd183f63e43d895d16a9c567c95e1056e28bd02451a4170cb17001a53249b67",
+        )
+        .unwrap();
+        let byron = Address::from_bytes(byron).unwrap();
+        assert_eq!(byron.kind(), AddressKind::Byron);
+        assert_eq!(byron.network_id(), None);
+        assert_eq!(
+            byron.to_bech32(),
+            "37btjrVyb4KDXBNC4haBVPCrro8AQPHwvCMp3RFhhSVWwfFmZ6wwzSK6JK1hY6wHNmtrpTf1kdbva8TCneM2YsiXT7mrzT21EacHnPpz5YyUdj64na"
+        );
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        assert_eq!(
+            Address::from_bytes(Vec::new()),
+            Err(AddressError::InvalidSize(0))
+        );
+        assert_eq!(
+            Address::from_bytes([0x61; 30].to_vec()),
+            Err(AddressError::InvalidSize(30))
+        );
+        assert_eq!(
+            Address::from_bytes([0x91; 29].to_vec()),
+            Err(AddressError::UnknownType(0x91))
+        );
+        assert_eq!(
+            Address::from_bech32("pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy"),
+            Err(AddressError::InvalidBech32)
+        );
+    }
+}
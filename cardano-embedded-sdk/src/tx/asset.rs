@@ -0,0 +1,425 @@
+//! Native assets
+//!
+//! An asset is identified by the policy id of its minting script and a name
+//! of up to 32 bytes. Users know it by its CIP-14 fingerprint, `asset1…`.
+//! Names may start with a CIP-67 label telling the kind of token, like the
+//! CIP-68 reference NFT and user tokens.
+//!
+//! Multiassets are encoded in the canonical order of CBOR maps: shorter
+//! asset names first, then bytewise.
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+use cryptoxide::blake2b::Blake2b;
+use minicbor::{data::Type, decode, encode, Decode, Decoder, Encode, Encoder};
+
+use super::body::{decode_map, expect_hash};
+use crate::chain_crypto::{
+    cip5::{self, Prefix},
+    PolicyId,
+};
+
+pub const MAX_ASSET_NAME_SIZE: usize = 32;
+
+pub type AssetName = Vec<u8>;
+
+/// `policy id → asset name → quantity`, `u64` in outputs and `i64` in mint
+pub type MultiAsset<T> = BTreeMap<PolicyId, BTreeMap<AssetName, T>>;
+
+/// CIP-14 fingerprint: bech32 of the Blake2b-160 of policy id and name
+pub fn fingerprint(policy_id: &PolicyId, asset_name: &[u8]) -> String {
+    let mut hash = [0; 20];
+    Blake2b::blake2b(&mut hash, &[policy_id.as_ref(), asset_name].concat(), &[]);
+    cip5::encode(Prefix::Asset, &hash)
+}
+
+/// CIP-67 label of an asset name, in its first 4 bytes: `0000`, the label
+/// on 16 bits, its CRC-8 and `0000`
+pub fn asset_label(asset_name: &[u8]) -> Option<u16> {
+    let prefix = u32::from_be_bytes(asset_name.get(..4)?.try_into().ok()?);
+    let label = (prefix >> 12) as u16;
+    (label_prefix(label) == prefix.to_be_bytes()).then_some(label)
+}
+
+/// first 4 bytes of the names of assets with the CIP-67 `label`
+pub fn label_prefix(label: u16) -> [u8; 4] {
+    let prefix = (label as u32) << 12 | (crc8(&label.to_be_bytes()) as u32) << 4;
+    prefix.to_be_bytes()
+}
+
+// CRC-8 with the polynomial 0x07
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| match crc & 0x80 {
+            0 => crc << 1,
+            _ => crc << 1 ^ 0x07,
+        })
+    })
+}
+
+/// CIP-68 tokens, whose metadata is in the datum of a reference NFT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cip68 {
+    /// holds the metadata of the user token with the same name
+    ReferenceNft,
+    Nft,
+    Ft,
+    /// rich fungible token
+    Rft,
+}
+
+impl Cip68 {
+    pub const fn label(self) -> u16 {
+        match self {
+            Cip68::ReferenceNft => 100,
+            Cip68::Nft => 222,
+            Cip68::Ft => 333,
+            Cip68::Rft => 444,
+        }
+    }
+
+    pub fn from_label(label: u16) -> Option<Self> {
+        [Cip68::ReferenceNft, Cip68::Nft, Cip68::Ft, Cip68::Rft]
+            .into_iter()
+            .find(|token| token.label() == label)
+    }
+
+    pub fn of_asset_name(asset_name: &[u8]) -> Option<Self> {
+        asset_label(asset_name).and_then(Self::from_label)
+    }
+}
+
+impl fmt::Display for Cip68 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cip68::ReferenceNft => write!(f, "CIP-68 reference NFT"),
+            Cip68::Nft => write!(f, "CIP-68 NFT"),
+            Cip68::Ft => write!(f, "CIP-68 FT"),
+            Cip68::Rft => write!(f, "CIP-68 RFT"),
+        }
+    }
+}
+
+/// Lovelace and native assets, as held by an output. The arithmetic is
+/// checked and drops the assets whose quantity reaches zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Value {
+    pub coin: u64,
+    pub assets: MultiAsset<u64>,
+}
+
+impl Value {
+    pub fn new(coin: u64) -> Self {
+        Value {
+            coin,
+            assets: MultiAsset::new(),
+        }
+    }
+
+    /// add `quantity` of an asset, `None` on overflow or if the name is
+    /// longer than 32 bytes
+    pub fn with_asset(
+        mut self,
+        policy_id: PolicyId,
+        asset_name: &[u8],
+        quantity: u64,
+    ) -> Option<Self> {
+        if asset_name.len() > MAX_ASSET_NAME_SIZE {
+            return None;
+        }
+        if quantity > 0 {
+            let held = self
+                .assets
+                .entry(policy_id)
+                .or_default()
+                .entry(asset_name.to_vec())
+                .or_default();
+            *held = held.checked_add(quantity)?;
+        }
+        Some(self)
+    }
+
+    pub fn asset(&self, policy_id: &PolicyId, asset_name: &[u8]) -> u64 {
+        self.assets
+            .get(policy_id)
+            .and_then(|assets| assets.get(asset_name))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coin == 0
+            && self
+                .assets
+                .values()
+                .flat_map(BTreeMap::values)
+                .all(|&q| q == 0)
+    }
+
+    pub fn checked_add(&self, other: &Value) -> Option<Value> {
+        let mut sum = self.clone();
+        sum.coin = sum.coin.checked_add(other.coin)?;
+        for (policy_id, assets) in &other.assets {
+            for (name, &quantity) in assets {
+                sum = sum.with_asset(policy_id.clone(), name, quantity)?;
+            }
+        }
+        Some(sum)
+    }
+
+    /// `None` if `other` has more lovelace or more of any asset
+    pub fn checked_sub(&self, other: &Value) -> Option<Value> {
+        let mut difference = self.clone();
+        difference.coin = difference.coin.checked_sub(other.coin)?;
+        for (policy_id, assets) in &other.assets {
+            for (name, &quantity) in assets {
+                difference.sub_asset(policy_id, name, quantity)?;
+            }
+        }
+        Some(difference)
+    }
+
+    /// add the minted quantities and remove the burnt ones
+    pub fn checked_add_mint(&self, mint: &MultiAsset<i64>) -> Option<Value> {
+        let mut value = self.clone();
+        for (policy_id, assets) in mint {
+            for (name, &quantity) in assets {
+                match quantity {
+                    0.. => value = value.with_asset(policy_id.clone(), name, quantity as u64)?,
+                    _ => value.sub_asset(policy_id, name, quantity.unsigned_abs())?,
+                }
+            }
+        }
+        Some(value)
+    }
+
+    fn sub_asset(&mut self, policy_id: &PolicyId, asset_name: &[u8], quantity: u64) -> Option<()> {
+        if quantity == 0 {
+            return Some(());
+        }
+        let assets = self.assets.get_mut(policy_id)?;
+        let held = assets.get_mut(asset_name)?;
+        *held = held.checked_sub(quantity)?;
+        if *held == 0 {
+            assets.remove(asset_name);
+            if assets.is_empty() {
+                self.assets.remove(policy_id);
+            }
+        }
+        Some(())
+    }
+}
+
+/// `coin` or `[coin, multiasset]`
+impl<'b, C> Decode<'b, C> for Value {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        match d.datatype()? {
+            Type::Array | Type::ArrayIndef => {
+                if d.array()? != Some(2) {
+                    return Err(decode::Error::message("value is not a 2 items array"));
+                }
+                Ok(Value {
+                    coin: d.u64()?,
+                    assets: decode_multiasset(d)?,
+                })
+            }
+            _ => Ok(Value::new(d.u64()?)),
+        }
+    }
+}
+
+impl<C> Encode<C> for Value {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        if self.assets.is_empty() {
+            e.u64(self.coin)?;
+            return Ok(());
+        }
+        e.array(2)?.u64(self.coin)?;
+        encode_multiasset(&self.assets, e)
+    }
+}
+
+pub(crate) fn decode_multiasset<'b, T: Decode<'b, ()>>(
+    d: &mut Decoder<'b>,
+) -> Result<MultiAsset<T>, decode::Error> {
+    let mut multiasset = MultiAsset::new();
+    decode_map(d, |d| {
+        let policy_id = PolicyId::from(expect_hash::<28>(d)?);
+        let mut assets = BTreeMap::new();
+        decode_map(d, |d| {
+            let name = d.bytes()?;
+            if name.len() > MAX_ASSET_NAME_SIZE {
+                return Err(decode::Error::message("asset name longer than 32 bytes"));
+            }
+            if assets.insert(name.to_vec(), d.decode()?).is_some() {
+                return Err(decode::Error::message("duplicate asset name"));
+            }
+            Ok(())
+        })?;
+        if multiasset.insert(policy_id, assets).is_some() {
+            return Err(decode::Error::message("duplicate policy id"));
+        }
+        Ok(())
+    })?;
+    Ok(multiasset)
+}
+
+pub(crate) fn encode_multiasset<T: Encode<()>, W: encode::Write>(
+    multiasset: &MultiAsset<T>,
+    e: &mut Encoder<W>,
+) -> Result<(), encode::Error<W::Error>> {
+    e.map(multiasset.len() as u64)?;
+    for (policy_id, assets) in multiasset {
+        e.bytes(policy_id.as_ref())?.map(assets.len() as u64)?;
+        let mut assets: Vec<_> = assets.iter().collect();
+        assets.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then(a.cmp(b)));
+        for (name, quantity) in assets {
+            e.bytes(name)?.encode(quantity)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_cip14_fingerprints() {
+        let fingerprint_of = |policy_id: &str, name: &str| {
+            fingerprint(
+                &PolicyId::from_hex(policy_id).unwrap(),
+                &hex::decode(name).unwrap(),
+            )
+        };
+        assert_eq!(
+            fingerprint_of(
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                ""
+            ),
+            "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3"
+        );
+        assert_eq!(
+            fingerprint_of(
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc37e",
+                ""
+            ),
+            "asset1nl0puwxmhas8fawxp8nx4e2q3wekg969n2auw3"
+        );
+        assert_eq!(
+            fingerprint_of(
+                "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                "504154415445"
+            ),
+            "asset1hv4p5tv2a837mzqrst04d0dcptdjmluqvdx9k3"
+        );
+    }
+
+    fn policy(byte: u8) -> PolicyId {
+        PolicyId::from([byte; 28])
+    }
+
+    #[test]
+    fn test_cip67_labels() {
+        // CIP-67 examples
+        assert_eq!(label_prefix(100), [0x00, 0x06, 0x43, 0xb0]);
+        assert_eq!(label_prefix(222), [0x00, 0x0d, 0xe1, 0x40]);
+        assert_eq!(label_prefix(333), [0x00, 0x14, 0xdf, 0x10]);
+        assert_eq!(label_prefix(444), [0x00, 0x1b, 0xc2, 0x80]);
+
+        let name = [&label_prefix(333)[..], b"TOKEN"].concat();
+        assert_eq!(asset_label(&name), Some(333));
+        assert_eq!(Cip68::of_asset_name(&name), Some(Cip68::Ft));
+        assert_eq!(Cip68::Ft.to_string(), "CIP-68 FT");
+        assert_eq!(Cip68::of_asset_name(&label_prefix(1)), None);
+        assert_eq!(asset_label(&label_prefix(1)), Some(1));
+
+        // bad checksum, non zero padding, too short
+        assert_eq!(asset_label(&[0x00, 0x06, 0x43, 0xa0]), None);
+        assert_eq!(asset_label(&[0x10, 0x06, 0x43, 0xb0]), None);
+        assert_eq!(asset_label(&[0x00, 0x06, 0x43, 0xb1]), None);
+        assert_eq!(asset_label(&[0x00, 0x06, 0x43]), None);
+        assert_eq!(asset_label(b"PATATE"), None);
+    }
+
+    #[test]
+    fn test_value_arithmetic() {
+        let a = Value::new(5).with_asset(policy(1), b"x", 10).unwrap();
+        let b = Value::new(2).with_asset(policy(1), b"x", 10).unwrap();
+        assert_eq!(a.checked_sub(&b), Some(Value::new(3)));
+        assert_eq!(b.checked_sub(&a), None);
+        assert_eq!(
+            Value::new(0).checked_sub(&Value::new(0).with_asset(policy(2), b"", 1).unwrap()),
+            None
+        );
+
+        let sum = a.checked_add(&b).unwrap();
+        assert_eq!(sum.coin, 7);
+        assert_eq!(sum.asset(&policy(1), b"x"), 20);
+        assert_eq!(Value::new(u64::MAX).checked_add(&Value::new(1)), None);
+        assert_eq!(a.clone().with_asset(policy(1), b"x", u64::MAX), None);
+        assert_eq!(a.clone().with_asset(policy(1), &[0; 33], 1), None);
+
+        let mint = [(policy(1), [(b"x".to_vec(), -10), (b"y".to_vec(), 3)].into())].into();
+        let minted = a.checked_add_mint(&mint).unwrap();
+        assert_eq!(minted.asset(&policy(1), b"x"), 0);
+        assert_eq!(minted.asset(&policy(1), b"y"), 3);
+        assert_eq!(minted.assets[&policy(1)].len(), 1);
+        let burn_too_much = [(policy(1), [(b"x".to_vec(), -11)].into())].into();
+        assert_eq!(a.checked_add_mint(&burn_too_much), None);
+        assert!(Value::default().is_zero());
+    }
+
+    #[quickcheck]
+    fn value_add_sub_round_trips(coins: (u32, u32), quantities: Vec<(u8, Vec<u8>, u32)>) -> bool {
+        let value = |coin, quantities: &[(u8, Vec<u8>, u32)]| {
+            quantities
+                .iter()
+                .try_fold(Value::new(coin), |value, (policy_id, name, quantity)| {
+                    value.with_asset(
+                        policy(*policy_id % 4),
+                        &name[..name.len().min(32)],
+                        *quantity as u64,
+                    )
+                })
+        };
+        let (half, _) = quantities.split_at(quantities.len() / 2);
+        let a = value(coins.0 as u64, &quantities).unwrap();
+        let b = value(coins.1 as u64, half).unwrap();
+        let sum = a.checked_add(&b).unwrap();
+        sum.checked_sub(&b) == Some(a.clone()) && sum.checked_sub(&a) == Some(b)
+    }
+
+    #[test]
+    fn test_value_canonical_encoding() {
+        let value = Value::new(1)
+            .with_asset(policy(1), b"bb", 1)
+            .and_then(|value| value.with_asset(policy(1), b"c", 2))
+            .and_then(|value| value.with_asset(policy(1), b"ab", 3))
+            .unwrap();
+        let cbor = minicbor::to_vec(&value).unwrap();
+        let names: Vec<_> = {
+            let mut d = Decoder::new(&cbor);
+            d.array().unwrap();
+            d.u64().unwrap();
+            d.map().unwrap();
+            d.bytes().unwrap();
+            let len = d.map().unwrap().unwrap();
+            (0..len)
+                .map(|_| {
+                    let name = d.bytes().unwrap().to_vec();
+                    d.u64().unwrap();
+                    name
+                })
+                .collect()
+        };
+        assert_eq!(names, [b"c".to_vec(), b"ab".to_vec(), b"bb".to_vec()]);
+        assert_eq!(minicbor::decode::<Value>(&cbor).unwrap(), value);
+        assert_eq!(minicbor::to_vec(Value::new(1)).unwrap(), [0x01]);
+    }
+}
@@ -0,0 +1,794 @@
+//! Balance and fee checks
+//!
+//! The device does not know the amounts of the inputs it spends, the host
+//! gives them, each bound to its UTxO reference. With them the device checks
+//! that the transaction is balanced, what it consumes is what it produces:
+//!
+//! `inputs + withdrawals + refunds + mint = outputs + fee + deposits + donation`
+//!
+//! The stake key deposit of the certificate 0 and the deposit of pool
+//! registrations are not in the certificates and need the
+//! [`ProtocolParams`]. The refund of the certificate 1 is the deposit paid
+//! when the credential was registered, and the re-registration of a pool
+//! pays no deposit, the host gives both in a [`BalanceContext`].
+//!
+//! A streamed body is fed to a [`BalanceCheck`] one part at a time,
+//! [`check_balance`] does the same with a whole [`TxBody`].
+//!
+//! The fee, checked by [`check_fee`], only needs the fee and the size of the
+//! transaction, it works for streamed transactions too.
+//!
+//! The minimum lovelace of the outputs is in [`min_utxo`](super::min_utxo).
+use alloc::{string::ToString, vec::Vec};
+use core::{error, fmt};
+
+use minicbor::{decode, Decoder};
+
+use super::address::Credential;
+use super::asset::{MultiAsset, Value};
+use super::body::{decode_set, expect_hash, Certificate, TxBody};
+use super::summary::{format_ada, DisplayItem, Severity};
+use crate::chain_crypto::PoolKeyHash;
+use crate::types::TransactionInput;
+
+/// body key of the proposal procedures
+const PROPOSALS_KEY: u64 = 20;
+/// type of the pool registration certificate
+const POOL_REGISTRATION: u64 = 3;
+/// bytes of reference scripts at one price, the next ones cost 1.2 times more
+const REF_SCRIPT_TIER_SIZE: u128 = 25_600;
+
+/// Protocol parameters used by the checks, amounts in lovelace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolParams {
+    /// fee per byte of the transaction (`txFeePerByte`)
+    pub min_fee_a: u64,
+    /// constant fee (`txFeeFixed`)
+    pub min_fee_b: u64,
+    /// `stakeAddressDeposit`
+    pub key_deposit: u64,
+    /// `stakePoolDeposit`
+    pub pool_deposit: u64,
+    /// `coinsPerUTxOByte`
+    pub coins_per_utxo_byte: u64,
+    /// `minFeeRefScriptCostPerByte`
+    pub min_fee_ref_script_cost_per_byte: u64,
+}
+
+impl ProtocolParams {
+    /// Parameters of mainnet, and of preprod, since the start of the Conway
+    /// era. They change with governance actions, the host should query the
+    /// current ones before a transaction depends on them.
+    pub const CONWAY_MAINNET: ProtocolParams = ProtocolParams {
+        min_fee_a: 44,
+        min_fee_b: 155_381,
+        key_deposit: 2_000_000,
+        pool_deposit: 500_000_000,
+        coins_per_utxo_byte: 4_310,
+        min_fee_ref_script_cost_per_byte: 15,
+    };
+
+    /// linear fee of a transaction of `tx_size` bytes: `a * size + b`
+    pub fn min_fee(&self, tx_size: usize) -> u64 {
+        self.min_fee_a
+            .saturating_mul(tx_size as u64)
+            .saturating_add(self.min_fee_b)
+    }
+
+    /// Conway fee of `size` bytes of reference scripts, the inputs and the
+    /// reference inputs of the transaction. The price per byte starts at
+    /// `minFeeRefScriptCostPerByte` and is multiplied by 1.2 every 25 KiB,
+    /// the sum is rounded down.
+    pub fn ref_script_fee(&self, size: usize) -> u64 {
+        // exact with the prices over 5^tier
+        let fee = || -> Option<u128> {
+            let mut price = u128::from(self.min_fee_ref_script_cost_per_byte);
+            let (mut size, mut fee, mut denominator) = (size as u128, 0u128, 1u128);
+            loop {
+                fee = fee.checked_add(size.min(REF_SCRIPT_TIER_SIZE).checked_mul(price)?)?;
+                if size <= REF_SCRIPT_TIER_SIZE {
+                    return Some(fee / denominator);
+                }
+                size -= REF_SCRIPT_TIER_SIZE;
+                fee = fee.checked_mul(5)?;
+                price = price.checked_mul(6)?;
+                denominator = denominator.checked_mul(5)?;
+            }
+        };
+        fee()
+            .and_then(|fee| u64::try_from(fee).ok())
+            .unwrap_or(u64::MAX)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BalanceError {
+    /// an input of the body has no amount
+    MissingInput(TransactionInput),
+    /// an amount is given for an input that is not in the body, or twice
+    UnexpectedInput(TransactionInput),
+    /// the certificates have implicit deposits, the protocol parameters are
+    /// needed
+    MissingParams,
+    /// a deregistered stake credential has no deposit in the context
+    MissingDeposit(Credential),
+    InvalidProposals,
+    InvalidPoolRegistration,
+    Overflow,
+    Unbalanced {
+        consumed: Value,
+        produced: Value,
+    },
+}
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BalanceError::MissingInput(input) => write!(
+                f,
+                "no amount for input {}#{}",
+                input.transaction_id.to_hex(),
+                input.index
+            ),
+            BalanceError::UnexpectedInput(input) => write!(
+                f,
+                "unexpected amount for input {}#{}",
+                input.transaction_id.to_hex(),
+                input.index
+            ),
+            BalanceError::MissingParams => {
+                write!(f, "protocol parameters are needed for the deposits")
+            }
+            BalanceError::MissingDeposit(stake) => write!(
+                f,
+                "no deposit for the deregistered stake credential {}",
+                hex::encode(stake.hash())
+            ),
+            BalanceError::InvalidProposals => write!(f, "invalid proposal procedures"),
+            BalanceError::InvalidPoolRegistration => write!(f, "invalid pool registration"),
+            BalanceError::Overflow => write!(f, "amounts overflow"),
+            BalanceError::Unbalanced { consumed, produced } => write!(
+                f,
+                "transaction consumes {} but produces {}",
+                format_ada(consumed.coin),
+                format_ada(produced.coin)
+            ),
+        }
+    }
+}
+impl error::Error for BalanceError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeError {
+    /// below the linear fee, the ledger would reject the transaction
+    TooLow { fee: u64, min_fee: u64 },
+    /// above the ceiling set by the caller
+    TooHigh { fee: u64, max_fee: u64 },
+}
+impl fmt::Display for FeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FeeError::TooLow { fee, min_fee } => write!(
+                f,
+                "fee {} is below the minimum {}",
+                format_ada(*fee),
+                format_ada(*min_fee)
+            ),
+            FeeError::TooHigh { fee, max_fee } => write!(
+                f,
+                "fee {} is above the maximum {}",
+                format_ada(*fee),
+                format_ada(*max_fee)
+            ),
+        }
+    }
+}
+impl error::Error for FeeError {}
+
+impl FeeError {
+    /// warning to show before the confirmation of the fee
+    pub fn to_display_item(&self) -> DisplayItem {
+        DisplayItem::new("Fee", self.to_string(), Severity::Warning)
+    }
+}
+
+/// Check the fee of a transaction of `tx_size` bytes, with its witnesses,
+/// against the linear fee of `params` and the ceiling `max_fee`.
+pub fn check_fee(
+    fee: u64,
+    tx_size: usize,
+    params: Option<&ProtocolParams>,
+    max_fee: Option<u64>,
+) -> Result<(), FeeError> {
+    if let Some(min_fee) = params.map(|params| params.min_fee(tx_size)) {
+        if fee < min_fee {
+            return Err(FeeError::TooLow { fee, min_fee });
+        }
+    }
+    match max_fee {
+        Some(max_fee) if fee > max_fee => Err(FeeError::TooHigh { fee, max_fee }),
+        _ => Ok(()),
+    }
+}
+
+/// What the ledger knows about the deposits of a transaction and the body
+/// does not, given by the host.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BalanceContext {
+    params: Option<ProtocolParams>,
+    registered_pools: Vec<PoolKeyHash>,
+    stake_deposits: Vec<(Credential, u64)>,
+}
+
+impl BalanceContext {
+    pub fn new(params: Option<&ProtocolParams>) -> Self {
+        BalanceContext {
+            params: params.copied(),
+            ..BalanceContext::default()
+        }
+    }
+
+    /// pools already registered, their registration certificates update the
+    /// parameters and pay no deposit
+    pub fn with_registered_pools(mut self, pools: &[PoolKeyHash]) -> Self {
+        self.registered_pools.extend_from_slice(pools);
+        self
+    }
+
+    /// registered stake credentials with the deposit paid when they were
+    /// registered, the refund of their deregistration
+    pub fn with_stake_deposits(mut self, deposits: &[(Credential, u64)]) -> Self {
+        self.stake_deposits.extend_from_slice(deposits);
+        self
+    }
+
+    fn params(&self) -> Result<&ProtocolParams, BalanceError> {
+        self.params.as_ref().ok_or(BalanceError::MissingParams)
+    }
+}
+
+/// Balance of a transaction fed one part of the body at a time, for bodies
+/// that are streamed to the device and never held whole.
+#[derive(Debug, Clone)]
+pub struct BalanceCheck {
+    ctx: BalanceContext,
+    inputs: Vec<TransactionInput>,
+    withdrawals: u64,
+    produced: Value,
+    mint: MultiAsset<i64>,
+    deposits: u64,
+    refunds: u64,
+}
+
+impl BalanceCheck {
+    pub fn new(ctx: BalanceContext) -> Self {
+        BalanceCheck {
+            ctx,
+            inputs: Vec::new(),
+            withdrawals: 0,
+            produced: Value::default(),
+            mint: MultiAsset::new(),
+            deposits: 0,
+            refunds: 0,
+        }
+    }
+
+    pub fn input(&mut self, input: &TransactionInput) -> Result<(), BalanceError> {
+        if self.inputs.contains(input) {
+            return Err(BalanceError::UnexpectedInput(input.clone()));
+        }
+        self.inputs.push(input.clone());
+        Ok(())
+    }
+
+    pub fn output(&mut self, value: &Value) -> Result<(), BalanceError> {
+        self.produce(value)
+    }
+
+    pub fn fee(&mut self, fee: u64) -> Result<(), BalanceError> {
+        self.produce(&Value::new(fee))
+    }
+
+    pub fn donation(&mut self, donation: u64) -> Result<(), BalanceError> {
+        self.produce(&Value::new(donation))
+    }
+
+    pub fn withdrawal(&mut self, coin: u64) -> Result<(), BalanceError> {
+        self.withdrawals = self
+            .withdrawals
+            .checked_add(coin)
+            .ok_or(BalanceError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn mint(&mut self, mint: &MultiAsset<i64>) -> Result<(), BalanceError> {
+        for (policy_id, assets) in mint {
+            for (name, quantity) in assets {
+                let minted = self
+                    .mint
+                    .entry(policy_id.clone())
+                    .or_default()
+                    .entry(name.clone())
+                    .or_default();
+                *minted = minted
+                    .checked_add(*quantity)
+                    .ok_or(BalanceError::Overflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the deposit or the refund of `certificate`. The deposits of stake
+    /// credentials registered by the body are refunded by its later
+    /// deregistrations.
+    pub fn certificate(&mut self, certificate: &Certificate) -> Result<(), BalanceError> {
+        use Certificate::*;
+
+        let (deposit, refund) = match certificate {
+            StakeRegistration(stake) => {
+                let deposit = self.ctx.params()?.key_deposit;
+                self.ctx.stake_deposits.push((*stake, deposit));
+                (deposit, 0)
+            }
+            StakeDeregistration(stake) => (0, self.stake_deposit(stake)?),
+            Registration(stake, deposit)
+            | StakeRegistrationDelegation(stake, _, deposit)
+            | VoteRegistrationDelegation(stake, _, deposit)
+            | StakeVoteRegistrationDelegation(stake, _, _, deposit) => {
+                self.ctx.stake_deposits.push((*stake, *deposit));
+                (*deposit, 0)
+            }
+            DRepRegistration(_, deposit, _) => (*deposit, 0),
+            Unregistration(stake, refund) => {
+                self.stake_deposit(stake)?;
+                (0, *refund)
+            }
+            DRepUnregistration(_, refund) => (0, *refund),
+            Other {
+                tag: POOL_REGISTRATION,
+                cbor,
+            } => {
+                let pool = registered_pool(cbor)?;
+                if self.ctx.registered_pools.contains(&pool) {
+                    (0, 0)
+                } else {
+                    let deposit = self.ctx.params()?.pool_deposit;
+                    self.ctx.registered_pools.push(pool);
+                    (deposit, 0)
+                }
+            }
+            _ => (0, 0),
+        };
+        self.deposits = self
+            .deposits
+            .checked_add(deposit)
+            .ok_or(BalanceError::Overflow)?;
+        self.refunds = self
+            .refunds
+            .checked_add(refund)
+            .ok_or(BalanceError::Overflow)?;
+        Ok(())
+    }
+
+    /// Add the deposits of the proposal procedures, the CBOR of the body
+    /// field 20.
+    pub fn proposals(&mut self, proposals: &[u8]) -> Result<(), BalanceError> {
+        let proposal_deposits = decode_set(&mut Decoder::new(proposals), |d| {
+            // [deposit, reward_account, gov_action, anchor]
+            if d.array()? != Some(4) {
+                return Err(decode::Error::message("invalid proposal procedure"));
+            }
+            let deposit = d.u64()?;
+            (0..3).try_for_each(|_| d.skip())?;
+            Ok(deposit)
+        })
+        .map_err(|_| BalanceError::InvalidProposals)?;
+        for deposit in proposal_deposits {
+            self.deposits = self
+                .deposits
+                .checked_add(deposit)
+                .ok_or(BalanceError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// deposits paid and refunded so far, in lovelace
+    pub fn deposits(&self) -> (u64, u64) {
+        (self.deposits, self.refunds)
+    }
+
+    /// Check the balance once the whole body is fed, `inputs` are the values
+    /// of its inputs as given by the host. Every input needs exactly one
+    /// value.
+    pub fn finish(self, inputs: &[(TransactionInput, Value)]) -> Result<(), BalanceError> {
+        for (position, (input, _)) in inputs.iter().enumerate() {
+            if !self.inputs.contains(input) || inputs[..position].iter().any(|(i, _)| i == input) {
+                return Err(BalanceError::UnexpectedInput(input.clone()));
+            }
+        }
+        if let Some(missing) = self
+            .inputs
+            .iter()
+            .find(|input| !inputs.iter().any(|(i, _)| i == *input))
+        {
+            return Err(BalanceError::MissingInput(missing.clone()));
+        }
+
+        let mut consumed = Value::new(self.refunds);
+        for (_, value) in inputs {
+            consumed = consumed.checked_add(value).ok_or(BalanceError::Overflow)?;
+        }
+        consumed = consumed
+            .checked_add(&Value::new(self.withdrawals))
+            .ok_or(BalanceError::Overflow)?;
+        let produced = self
+            .produced
+            .checked_add(&Value::new(self.deposits))
+            .ok_or(BalanceError::Overflow)?;
+
+        // burning more than the inputs hold cannot balance
+        let balanced = consumed
+            .checked_add_mint(&self.mint)
+            .is_some_and(|consumed| consumed == produced);
+        if balanced {
+            Ok(())
+        } else {
+            Err(BalanceError::Unbalanced { consumed, produced })
+        }
+    }
+
+    fn produce(&mut self, value: &Value) -> Result<(), BalanceError> {
+        self.produced = self
+            .produced
+            .checked_add(value)
+            .ok_or(BalanceError::Overflow)?;
+        Ok(())
+    }
+
+    fn stake_deposit(&self, stake: &Credential) -> Result<u64, BalanceError> {
+        self.ctx
+            .stake_deposits
+            .iter()
+            .rev()
+            .find(|(registered, _)| registered == stake)
+            .map(|(_, deposit)| *deposit)
+            .ok_or(BalanceError::MissingDeposit(*stake))
+    }
+}
+
+/// operator of a pool registration: `[3, operator, vrf_keyhash, ...]`
+fn registered_pool(cbor: &[u8]) -> Result<PoolKeyHash, BalanceError> {
+    let operator = |d: &mut Decoder| -> Result<PoolKeyHash, decode::Error> {
+        d.array()?;
+        d.u64()?;
+        expect_hash::<28>(d).map(PoolKeyHash::from)
+    };
+    operator(&mut Decoder::new(cbor)).map_err(|_| BalanceError::InvalidPoolRegistration)
+}
+
+/// Feed the whole `body` to a new [`BalanceCheck`].
+fn feed_body(body: &TxBody, ctx: &BalanceContext) -> Result<BalanceCheck, BalanceError> {
+    let mut check = BalanceCheck::new(ctx.clone());
+    for input in &body.inputs {
+        check.input(input)?;
+    }
+    for output in &body.outputs {
+        check.output(&output.value)?;
+    }
+    check.fee(body.fee)?;
+    check.donation(body.donation.unwrap_or(0))?;
+    for (_, coin) in &body.withdrawals {
+        check.withdrawal(*coin)?;
+    }
+    check.mint(&body.mint)?;
+    for certificate in &body.certificates {
+        check.certificate(certificate)?;
+    }
+    for (_, proposals) in body.other.iter().filter(|(key, _)| *key == PROPOSALS_KEY) {
+        check.proposals(proposals)?;
+    }
+    Ok(check)
+}
+
+/// Deposits paid and refunded by the certificates and the proposals of
+/// `body`, in lovelace.
+pub fn deposits(body: &TxBody, ctx: &BalanceContext) -> Result<(u64, u64), BalanceError> {
+    feed_body(body, ctx).map(|check| check.deposits())
+}
+
+/// Check that `body` is balanced, `inputs` are the values of its inputs as
+/// given by the host. Every input needs exactly one value.
+pub fn check_balance(
+    body: &TxBody,
+    inputs: &[(TransactionInput, Value)],
+    ctx: &BalanceContext,
+) -> Result<(), BalanceError> {
+    feed_body(body, ctx)?.finish(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_crypto::PolicyId;
+    use crate::tx::{
+        address::{Address, MAINNET},
+        body::TxOutput,
+    };
+    use crate::types::TxId;
+    use alloc::vec;
+
+    const PARAMS: ProtocolParams = ProtocolParams::CONWAY_MAINNET;
+
+    fn input(index: u32) -> TransactionInput {
+        TransactionInput {
+            transaction_id: TxId::from_bytes(&[index as u8; 32]).unwrap(),
+            index,
+        }
+    }
+
+    fn address() -> Address {
+        Address::enterprise(MAINNET, Credential::KeyHash([1; 28]))
+    }
+
+    #[cfg(feature = "std")]
+    fn slip14_tx() -> alloc::vec::Vec<u8> {
+        let envelope = crate::text_envelope::TextEnvelope::from_json(include_str!(
+            "../../../slip14-data/txs/tx-bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb.signed"
+        ))
+        .unwrap();
+        envelope.to_tx().unwrap().0.to_vec()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_slip14_transaction_is_balanced() {
+        let tx = slip14_tx();
+        let body = TxBody::from_tx_cbor(&tx).unwrap();
+        let spent = body.inputs[0].clone();
+
+        // outputs 111_000_000 and 888_853_600, fee 146_400
+        let inputs = [(spent.clone(), Value::new(1_000_000_000))];
+        assert_eq!(
+            check_balance(&body, &inputs, &BalanceContext::default()),
+            Ok(())
+        );
+
+        let inputs = [(spent.clone(), Value::new(1_000_000_001))];
+        assert!(matches!(
+            check_balance(&body, &inputs, &BalanceContext::default()),
+            Err(BalanceError::Unbalanced { .. })
+        ));
+        assert_eq!(
+            check_balance(&body, &[], &BalanceContext::default()),
+            Err(BalanceError::MissingInput(spent.clone()))
+        );
+        let inputs = [
+            (spent.clone(), Value::new(1_000_000_000)),
+            (input(7), Value::new(1)),
+        ];
+        assert_eq!(
+            check_balance(&body, &inputs, &BalanceContext::default()),
+            Err(BalanceError::UnexpectedInput(input(7)))
+        );
+        let inputs = [
+            (spent.clone(), Value::new(500_000_000)),
+            (spent.clone(), Value::new(500_000_000)),
+        ];
+        assert_eq!(
+            check_balance(&body, &inputs, &BalanceContext::default()),
+            Err(BalanceError::UnexpectedInput(spent))
+        );
+    }
+
+    #[test]
+    fn test_deposits_refunds_and_mint() {
+        let stake = Credential::KeyHash([2; 28]);
+        let policy_id = PolicyId::from([3; 28]);
+        let mut output = TxOutput::new(address(), 10_000_000);
+        output.value = output
+            .value
+            .with_asset(policy_id.clone(), b"minted", 5)
+            .unwrap();
+        let body = TxBody {
+            inputs: vec![input(0), input(1)],
+            outputs: vec![output],
+            fee: 200_000,
+            certificates: vec![
+                Certificate::StakeRegistration(stake),
+                Certificate::DRepUnregistration(stake, 500_000_000),
+            ],
+            withdrawals: vec![(Address::reward(MAINNET, stake), 1_000_000)],
+            mint: [(
+                policy_id.clone(),
+                [(b"minted".to_vec(), 5), (b"burnt".to_vec(), -1)].into(),
+            )]
+            .into(),
+            donation: Some(800_000),
+            ..TxBody::default()
+        };
+        let inputs = [
+            (input(0), Value::new(1_000_000)),
+            (
+                input(1),
+                Value::new(0).with_asset(policy_id, b"burnt", 1).unwrap(),
+            ),
+        ];
+        // 1 + 1 + 500 = 10 + 0.2 + 2 + 0.8 + 489.0 ADA
+        assert_eq!(
+            check_balance(&body, &inputs, &BalanceContext::default()),
+            Err(BalanceError::MissingParams)
+        );
+        assert_eq!(
+            deposits(&body, &BalanceContext::new(Some(&PARAMS))),
+            Ok((2_000_000, 500_000_000))
+        );
+
+        let mut balanced = body.clone();
+        balanced.outputs.push(TxOutput::new(address(), 489_000_000));
+        assert_eq!(
+            check_balance(&balanced, &inputs, &BalanceContext::new(Some(&PARAMS))),
+            Ok(())
+        );
+
+        // burning an asset the inputs do not hold
+        balanced.mint.values_mut().for_each(|assets| {
+            assets.insert(b"other".to_vec(), -1);
+        });
+        assert!(check_balance(&balanced, &inputs, &BalanceContext::new(Some(&PARAMS))).is_err());
+    }
+
+    #[test]
+    fn test_streamed_balance() {
+        let inputs = [(input(0), Value::new(10_000_000))];
+        let mut check = BalanceCheck::new(BalanceContext::default());
+        check.fee(200_000).unwrap();
+        check.output(&Value::new(9_800_000)).unwrap();
+        check.input(&input(0)).unwrap();
+        assert_eq!(
+            check.input(&input(0)),
+            Err(BalanceError::UnexpectedInput(input(0)))
+        );
+        assert_eq!(check.clone().finish(&inputs), Ok(()));
+        assert_eq!(
+            check.clone().finish(&[]),
+            Err(BalanceError::MissingInput(input(0)))
+        );
+
+        check.output(&Value::new(1)).unwrap();
+        assert!(matches!(
+            check.finish(&inputs),
+            Err(BalanceError::Unbalanced { .. })
+        ));
+    }
+
+    #[test]
+    fn test_pool_and_stake_deposits_from_context() {
+        // [3, operator, vrf, pledge, cost, 30([1, 10]), reward account, [], [], null]
+        let pool = PoolKeyHash::from([5; 28]);
+        let mut cbor = vec![0x8a, 0x03, 0x58, 28];
+        cbor.extend([5; 28]);
+        cbor.extend([0x58, 32]);
+        cbor.extend([6; 32]);
+        cbor.extend([
+            0x00, 0x1a, 0x02, 0x16, 0x0e, 0xc0, 0xd8, 0x1e, 0x82, 0x01, 0x0a,
+        ]);
+        cbor.extend([0x58, 29, 0xe1]);
+        cbor.extend([2; 28]);
+        cbor.extend([0x80, 0x80, 0xf6]);
+        let stake = Credential::KeyHash([2; 28]);
+        let body = TxBody {
+            inputs: vec![input(0)],
+            outputs: vec![TxOutput::new(address(), 10_800_000)],
+            fee: 200_000,
+            certificates: vec![
+                Certificate::Other { tag: 3, cbor },
+                Certificate::StakeDeregistration(stake),
+            ],
+            ..TxBody::default()
+        };
+        let inputs = [(input(0), Value::new(10_000_000))];
+
+        let ctx = BalanceContext::new(Some(&PARAMS));
+        assert_eq!(
+            check_balance(&body, &inputs, &ctx),
+            Err(BalanceError::MissingDeposit(stake))
+        );
+        // the deposit paid at registration, not the current `key_deposit`
+        let ctx = ctx.with_stake_deposits(&[(stake, 1_000_000)]);
+        assert_eq!(deposits(&body, &ctx), Ok((500_000_000, 1_000_000)));
+        let ctx = ctx.with_registered_pools(&[pool]);
+        assert_eq!(deposits(&body, &ctx), Ok((0, 1_000_000)));
+        assert_eq!(check_balance(&body, &inputs, &ctx), Ok(()));
+
+        // registered and deregistered by the same body
+        let other = Credential::KeyHash([4; 28]);
+        let body = TxBody {
+            certificates: vec![
+                Certificate::StakeRegistration(other),
+                Certificate::StakeDeregistration(other),
+            ],
+            ..TxBody::default()
+        };
+        let ctx = BalanceContext::new(Some(&PARAMS));
+        assert_eq!(deposits(&body, &ctx), Ok((2_000_000, 2_000_000)));
+
+        let body = TxBody {
+            certificates: vec![Certificate::Other {
+                tag: 3,
+                cbor: vec![0x82, 0x03, 0x41, 0x00],
+            }],
+            ..TxBody::default()
+        };
+        assert_eq!(
+            deposits(&body, &ctx),
+            Err(BalanceError::InvalidPoolRegistration)
+        );
+    }
+
+    #[test]
+    fn test_proposal_deposits() {
+        // 258([[100_000_000_000, h'e1…', [6], [\"\", h'00…']]])
+        let mut proposals = vec![0xd9, 0x01, 0x02, 0x81, 0x84, 0x1b];
+        proposals.extend(100_000_000_000u64.to_be_bytes());
+        proposals.extend([0x58, 29, 0xe1]);
+        proposals.extend([0; 28]);
+        proposals.extend([0x81, 0x06, 0x82, 0x60, 0x58, 32]);
+        proposals.extend([0; 32]);
+        let body = TxBody {
+            other: vec![(PROPOSALS_KEY, proposals)],
+            ..TxBody::default()
+        };
+        assert_eq!(
+            deposits(&body, &BalanceContext::default()),
+            Ok((100_000_000_000, 0))
+        );
+
+        let body = TxBody {
+            other: vec![(PROPOSALS_KEY, vec![0x81, 0x01])],
+            ..TxBody::default()
+        };
+        assert_eq!(
+            deposits(&body, &BalanceContext::default()),
+            Err(BalanceError::InvalidProposals)
+        );
+    }
+
+    #[test]
+    fn test_check_fee() {
+        assert_eq!(PARAMS.min_fee(300), 168_581);
+        assert_eq!(check_fee(168_581, 300, Some(&PARAMS), None), Ok(()));
+        assert_eq!(
+            check_fee(168_580, 300, Some(&PARAMS), None),
+            Err(FeeError::TooLow {
+                fee: 168_580,
+                min_fee: 168_581
+            })
+        );
+        assert_eq!(check_fee(50_000_000, 300, None, None), Ok(()));
+        let too_high = check_fee(50_000_000, 300, Some(&PARAMS), Some(5_000_000));
+        assert_eq!(
+            too_high,
+            Err(FeeError::TooHigh {
+                fee: 50_000_000,
+                max_fee: 5_000_000
+            })
+        );
+        assert_eq!(
+            too_high.unwrap_err().to_display_item().to_string(),
+            "[warning] Fee: fee 50.000000 ADA is above the maximum 5.000000 ADA"
+        );
+    }
+
+    #[test]
+    fn test_ref_script_fee() {
+        assert_eq!(PARAMS.ref_script_fee(0), 0);
+        assert_eq!(PARAMS.ref_script_fee(1), 15);
+        assert_eq!(PARAMS.ref_script_fee(25_600), 384_000);
+        // 25_600 * 15 + 4_400 * 18
+        assert_eq!(PARAMS.ref_script_fee(30_000), 463_200);
+        // 25_600 * 15 + 25_600 * 18 + 1_000 * 21.6
+        assert_eq!(PARAMS.ref_script_fee(52_200), 866_400);
+        // maxRefScriptSizePerTx, rounded down from 6_335_648.56…
+        assert_eq!(PARAMS.ref_script_fee(204_800), 6_335_648);
+        assert_eq!(PARAMS.ref_script_fee(usize::MAX), u64::MAX);
+    }
+}
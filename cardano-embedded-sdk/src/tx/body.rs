@@ -0,0 +1,827 @@
+//! Transaction body
+//!
+//! Decodes the fields a signer has to review. Fields that are not modeled
+//! (voting procedures, proposals, treasury value and any future field) are
+//! kept as raw CBOR in [`TxBody::other`] so they can still be shown, and
+//! re-encoded unchanged.
+//!
+//! Sets are accepted with or without the tag 258 of the Conway era and are
+//! encoded as plain arrays. The id of a transaction has to be computed from
+//! the bytes it was received as, see [`TxId::from_tx_cbor`].
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::convert::Infallible;
+
+use minicbor::{
+    data::{Tag, Type},
+    decode, encode, Decode, Decoder, Encode, Encoder,
+};
+
+use super::address::{Address, Credential};
+use super::asset::{decode_multiasset, encode_multiasset, MultiAsset, Value};
+use crate::chain_crypto::{AuxiliaryDataHash, DatumHash, PoolKeyHash, ScriptDataHash};
+use crate::crypto::Ed25519KeyHash;
+use crate::types::{tx_body_cbor, TransactionInput, TxId};
+
+const SET_TAG: u64 = 258;
+
+/// call `f` on every entry of a definite or indefinite map, `f` decodes
+/// both the key and the value
+pub(crate) fn decode_map<'b>(
+    d: &mut Decoder<'b>,
+    mut f: impl FnMut(&mut Decoder<'b>) -> Result<(), decode::Error>,
+) -> Result<(), decode::Error> {
+    let len = d.map()?;
+    decode_items(d, len, &mut f)
+}
+
+/// call `f` on every item of a definite or indefinite array
+pub(crate) fn decode_array<'b>(
+    d: &mut Decoder<'b>,
+    mut f: impl FnMut(&mut Decoder<'b>) -> Result<(), decode::Error>,
+) -> Result<(), decode::Error> {
+    let len = d.array()?;
+    decode_items(d, len, &mut f)
+}
+
+fn decode_items<'b>(
+    d: &mut Decoder<'b>,
+    len: Option<u64>,
+    f: &mut impl FnMut(&mut Decoder<'b>) -> Result<(), decode::Error>,
+) -> Result<(), decode::Error> {
+    match len {
+        Some(len) => (0..len).try_for_each(|_| f(d)),
+        None => {
+            while d.datatype()? != Type::Break {
+                f(d)?;
+            }
+            d.set_position(d.position() + 1);
+            Ok(())
+        }
+    }
+}
+
+/// array, tagged with 258 or not
+pub(crate) fn decode_set<'b, T>(
+    d: &mut Decoder<'b>,
+    mut f: impl FnMut(&mut Decoder<'b>) -> Result<T, decode::Error>,
+) -> Result<Vec<T>, decode::Error> {
+    if d.datatype()? == Type::Tag && d.tag()? != Tag::Unassigned(SET_TAG) {
+        return Err(decode::Error::message("unexpected tag on a set"));
+    }
+    let mut items = Vec::new();
+    decode_array(d, |d| {
+        items.push(f(d)?);
+        Ok(())
+    })?;
+    Ok(items)
+}
+
+pub(crate) fn expect_hash<const N: usize>(d: &mut Decoder) -> Result<[u8; N], decode::Error> {
+    d.bytes()?
+        .try_into()
+        .map_err(|_| decode::Error::message("hash of unexpected size"))
+}
+
+/// bytes of the next CBOR item
+fn raw_item<'b>(d: &mut Decoder<'b>) -> Result<&'b [u8], decode::Error> {
+    let start = d.position();
+    d.skip()?;
+    Ok(&d.input()[start..d.position()])
+}
+
+fn set_once<T>(field: &mut Option<T>, value: T) -> Result<(), decode::Error> {
+    match field.replace(value) {
+        None => Ok(()),
+        Some(_) => Err(decode::Error::message("duplicate field")),
+    }
+}
+
+fn decode_nullable<'b, T>(
+    d: &mut Decoder<'b>,
+    f: impl FnOnce(&mut Decoder<'b>) -> Result<T, decode::Error>,
+) -> Result<Option<T>, decode::Error> {
+    if d.datatype()? == Type::Null {
+        d.skip()?;
+        return Ok(None);
+    }
+    f(d).map(Some)
+}
+
+/// bytes wrapped in the tag 24, CBOR encoded in a bytestring
+fn decode_cbor_in_cbor(d: &mut Decoder) -> Result<Vec<u8>, decode::Error> {
+    if d.tag()? != Tag::Cbor {
+        return Err(decode::Error::message("expected embedded CBOR"));
+    }
+    Ok(d.bytes()?.to_vec())
+}
+
+pub(crate) fn to_cbor_with(
+    f: impl FnOnce(&mut Encoder<Vec<u8>>) -> Result<(), encode::Error<Infallible>>,
+) -> Vec<u8> {
+    let mut encoder = Encoder::new(Vec::new());
+    f(&mut encoder).expect("writing to a Vec does not fail");
+    encoder.into_writer()
+}
+
+pub(crate) fn write_raw<W: encode::Write>(
+    e: &mut Encoder<W>,
+    cbor: &[u8],
+) -> Result<(), encode::Error<W::Error>> {
+    e.writer_mut().write_all(cbor).map_err(encode::Error::write)
+}
+
+impl<'b, C> Decode<'b, C> for TransactionInput {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        if d.array()? != Some(2) {
+            return Err(decode::Error::message("input is not a 2 items array"));
+        }
+        let transaction_id = TxId::from_bytes(d.bytes()?)
+            .map_err(|_| decode::Error::message("transaction id is not 32 bytes"))?;
+        Ok(TransactionInput {
+            transaction_id,
+            index: d.u32()?,
+        })
+    }
+}
+
+impl<C> Encode<C> for TransactionInput {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(2)?
+            .bytes(self.transaction_id.to_bytes())?
+            .u32(self.index)?;
+        Ok(())
+    }
+}
+
+/// Datum attached to an output, spending it from a script address needs it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Datum {
+    Hash(DatumHash),
+    /// CBOR of the plutus data
+    Inline(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOutput {
+    pub address: Address,
+    pub value: Value,
+    pub datum: Option<Datum>,
+    /// CBOR of the script that transactions can reference instead of
+    /// including it
+    pub script_ref: Option<Vec<u8>>,
+}
+
+impl TxOutput {
+    /// output of `coin` lovelace, without assets
+    pub fn new(address: Address, coin: u64) -> Self {
+        TxOutput {
+            address,
+            value: Value::new(coin),
+            datum: None,
+            script_ref: None,
+        }
+    }
+}
+
+fn decode_datum(d: &mut Decoder) -> Result<Datum, decode::Error> {
+    if d.array()? != Some(2) {
+        return Err(decode::Error::message("datum is not a 2 items array"));
+    }
+    match d.u8()? {
+        0 => Ok(Datum::Hash(DatumHash::from(expect_hash::<32>(d)?))),
+        1 => Ok(Datum::Inline(decode_cbor_in_cbor(d)?)),
+        _ => Err(decode::Error::message("unknown datum type")),
+    }
+}
+
+impl<'b, C> Decode<'b, C> for TxOutput {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        match d.datatype()? {
+            // legacy format: `[address, value, ? datum_hash]`
+            Type::Array | Type::ArrayIndef => {
+                let len = d.array()?;
+                if !matches!(len, Some(2 | 3)) {
+                    return Err(decode::Error::message("output is not a 2 or 3 items array"));
+                }
+                let address = d.decode()?;
+                let value = d.decode()?;
+                let datum = match len {
+                    Some(3) => Some(Datum::Hash(DatumHash::from(expect_hash::<32>(d)?))),
+                    _ => None,
+                };
+                Ok(TxOutput {
+                    address,
+                    value,
+                    datum,
+                    script_ref: None,
+                })
+            }
+            Type::Map | Type::MapIndef => {
+                let (mut address, mut value, mut datum, mut script_ref) = (None, None, None, None);
+                decode_map(d, |d| match d.u64()? {
+                    0 => set_once(&mut address, d.decode()?),
+                    1 => set_once(&mut value, d.decode()?),
+                    2 => set_once(&mut datum, decode_datum(d)?),
+                    3 => set_once(&mut script_ref, decode_cbor_in_cbor(d)?),
+                    _ => Err(decode::Error::message("unknown output field")),
+                })?;
+                let address = address.ok_or(decode::Error::message("output without address"))?;
+                let value = value.ok_or(decode::Error::message("output without value"))?;
+                Ok(TxOutput {
+                    address,
+                    value,
+                    datum,
+                    script_ref,
+                })
+            }
+            _ => Err(decode::Error::message("output is not an array or a map")),
+        }
+    }
+}
+
+impl<C> Encode<C> for TxOutput {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        let len = 2 + self.datum.is_some() as u64 + self.script_ref.is_some() as u64;
+        e.map(len)?
+            .u8(0)?
+            .encode(&self.address)?
+            .u8(1)?
+            .encode(&self.value)?;
+        match &self.datum {
+            Some(Datum::Hash(hash)) => {
+                e.u8(2)?.array(2)?.u8(0)?.bytes(hash.as_ref())?;
+            }
+            Some(Datum::Inline(data)) => {
+                e.u8(2)?.array(2)?.u8(1)?.tag(Tag::Cbor)?.bytes(data)?;
+            }
+            None => {}
+        }
+        if let Some(script) = &self.script_ref {
+            e.u8(3)?.tag(Tag::Cbor)?.bytes(script)?;
+        }
+        Ok(())
+    }
+}
+
+/// Delegated representative for governance votes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DRep {
+    KeyHash([u8; 28]),
+    ScriptHash([u8; 28]),
+    AlwaysAbstain,
+    AlwaysNoConfidence,
+}
+
+impl<'b, C> Decode<'b, C> for DRep {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let len = d.array()?;
+        let drep = match (len, d.u8()?) {
+            (Some(2), 0) => DRep::KeyHash(expect_hash(d)?),
+            (Some(2), 1) => DRep::ScriptHash(expect_hash(d)?),
+            (Some(1), 2) => DRep::AlwaysAbstain,
+            (Some(1), 3) => DRep::AlwaysNoConfidence,
+            _ => return Err(decode::Error::message("invalid DRep")),
+        };
+        Ok(drep)
+    }
+}
+
+impl<C> Encode<C> for DRep {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        match self {
+            DRep::KeyHash(hash) => e.array(2)?.u8(0)?.bytes(hash)?,
+            DRep::ScriptHash(hash) => e.array(2)?.u8(1)?.bytes(hash)?,
+            DRep::AlwaysAbstain => e.array(1)?.u8(2)?,
+            DRep::AlwaysNoConfidence => e.array(1)?.u8(3)?,
+        };
+        Ok(())
+    }
+}
+
+/// Link to the metadata of a DRep, with the hash of its content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anchor {
+    pub url: String,
+    pub data_hash: [u8; 32],
+}
+
+impl<'b, C> Decode<'b, C> for Anchor {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        if d.array()? != Some(2) {
+            return Err(decode::Error::message("anchor is not a 2 items array"));
+        }
+        Ok(Anchor {
+            url: d.str()?.into(),
+            data_hash: expect_hash(d)?,
+        })
+    }
+}
+
+impl<C> Encode<C> for Anchor {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(2)?.str(&self.url)?.bytes(&self.data_hash)?;
+        Ok(())
+    }
+}
+
+/// Certificates, deposits and refunds are in lovelace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Certificate {
+    StakeRegistration(Credential),
+    StakeDeregistration(Credential),
+    StakeDelegation(Credential, PoolKeyHash),
+    /// pool id and epoch of the retirement
+    PoolRetirement(PoolKeyHash, u64),
+    /// stake registration with an explicit deposit (Conway)
+    Registration(Credential, u64),
+    Unregistration(Credential, u64),
+    VoteDelegation(Credential, DRep),
+    StakeVoteDelegation(Credential, PoolKeyHash, DRep),
+    StakeRegistrationDelegation(Credential, PoolKeyHash, u64),
+    VoteRegistrationDelegation(Credential, DRep, u64),
+    StakeVoteRegistrationDelegation(Credential, PoolKeyHash, DRep, u64),
+    DRepRegistration(Credential, u64, Option<Anchor>),
+    DRepUnregistration(Credential, u64),
+    DRepUpdate(Credential, Option<Anchor>),
+    /// pool registration, genesis delegation, committee certificates...,
+    /// with the type and the CBOR of the whole certificate
+    Other {
+        tag: u64,
+        cbor: Vec<u8>,
+    },
+}
+
+fn pool_key_hash(d: &mut Decoder) -> Result<PoolKeyHash, decode::Error> {
+    expect_hash::<28>(d).map(PoolKeyHash::from)
+}
+
+impl<'b, C> Decode<'b, C> for Certificate {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        use Certificate::*;
+
+        let start = d.position();
+        let len = d.array()?;
+        if len == Some(0) {
+            return Err(decode::Error::message("empty certificate"));
+        }
+        let tag = d.u64()?;
+        let (certificate, items) = match tag {
+            0 => (StakeRegistration(d.decode()?), 2),
+            1 => (StakeDeregistration(d.decode()?), 2),
+            2 => (StakeDelegation(d.decode()?, pool_key_hash(d)?), 3),
+            4 => (PoolRetirement(pool_key_hash(d)?, d.u64()?), 3),
+            7 => (Registration(d.decode()?, d.u64()?), 3),
+            8 => (Unregistration(d.decode()?, d.u64()?), 3),
+            9 => (VoteDelegation(d.decode()?, d.decode()?), 3),
+            10 => (
+                StakeVoteDelegation(d.decode()?, pool_key_hash(d)?, d.decode()?),
+                4,
+            ),
+            11 => (
+                StakeRegistrationDelegation(d.decode()?, pool_key_hash(d)?, d.u64()?),
+                4,
+            ),
+            12 => (
+                VoteRegistrationDelegation(d.decode()?, d.decode()?, d.u64()?),
+                4,
+            ),
+            13 => (
+                StakeVoteRegistrationDelegation(
+                    d.decode()?,
+                    pool_key_hash(d)?,
+                    d.decode()?,
+                    d.u64()?,
+                ),
+                5,
+            ),
+            16 => (
+                DRepRegistration(d.decode()?, d.u64()?, decode_nullable(d, |d| d.decode())?),
+                4,
+            ),
+            17 => (DRepUnregistration(d.decode()?, d.u64()?), 3),
+            18 => (
+                DRepUpdate(d.decode()?, decode_nullable(d, |d| d.decode())?),
+                3,
+            ),
+            _ => {
+                decode_items(d, len.map(|len| len - 1), &mut |d| d.skip())?;
+                let cbor = d.input()[start..d.position()].to_vec();
+                return Ok(Other { tag, cbor });
+            }
+        };
+        match len {
+            Some(len) if len == items => {}
+            None if d.datatype()? == Type::Break => d.set_position(d.position() + 1),
+            _ => return Err(decode::Error::message("invalid certificate size")),
+        }
+        Ok(certificate)
+    }
+}
+
+impl<C> Encode<C> for Certificate {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        use Certificate::*;
+
+        match self {
+            StakeRegistration(stake) => {
+                e.array(2)?.u8(0)?.encode(stake)?;
+            }
+            StakeDeregistration(stake) => {
+                e.array(2)?.u8(1)?.encode(stake)?;
+            }
+            StakeDelegation(stake, pool) => {
+                e.array(3)?.u8(2)?.encode(stake)?.bytes(pool.as_ref())?;
+            }
+            PoolRetirement(pool, epoch) => {
+                e.array(3)?.u8(4)?.bytes(pool.as_ref())?.u64(*epoch)?;
+            }
+            Registration(stake, deposit) => {
+                e.array(3)?.u8(7)?.encode(stake)?.u64(*deposit)?;
+            }
+            Unregistration(stake, refund) => {
+                e.array(3)?.u8(8)?.encode(stake)?.u64(*refund)?;
+            }
+            VoteDelegation(stake, drep) => {
+                e.array(3)?.u8(9)?.encode(stake)?.encode(drep)?;
+            }
+            StakeVoteDelegation(stake, pool, drep) => {
+                e.array(4)?
+                    .u8(10)?
+                    .encode(stake)?
+                    .bytes(pool.as_ref())?
+                    .encode(drep)?;
+            }
+            StakeRegistrationDelegation(stake, pool, deposit) => {
+                e.array(4)?
+                    .u8(11)?
+                    .encode(stake)?
+                    .bytes(pool.as_ref())?
+                    .u64(*deposit)?;
+            }
+            VoteRegistrationDelegation(stake, drep, deposit) => {
+                e.array(4)?
+                    .u8(12)?
+                    .encode(stake)?
+                    .encode(drep)?
+                    .u64(*deposit)?;
+            }
+            StakeVoteRegistrationDelegation(stake, pool, drep, deposit) => {
+                e.array(5)?
+                    .u8(13)?
+                    .encode(stake)?
+                    .bytes(pool.as_ref())?
+                    .encode(drep)?
+                    .u64(*deposit)?;
+            }
+            DRepRegistration(drep, deposit, anchor) => {
+                e.array(4)?
+                    .u8(16)?
+                    .encode(drep)?
+                    .u64(*deposit)?
+                    .encode(anchor)?;
+            }
+            DRepUnregistration(drep, refund) => {
+                e.array(3)?.u8(17)?.encode(drep)?.u64(*refund)?;
+            }
+            DRepUpdate(drep, anchor) => {
+                e.array(3)?.u8(18)?.encode(drep)?.encode(anchor)?;
+            }
+            Other { cbor, .. } => write_raw(e, cbor)?,
+        }
+        Ok(())
+    }
+}
+
+/// Body of a transaction, the part that is hashed and signed. Amounts are in
+/// lovelace and validity bounds in slots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxBody {
+    pub inputs: Vec<TransactionInput>,
+    pub outputs: Vec<TxOutput>,
+    pub fee: u64,
+    /// the transaction is invalid from this slot on
+    pub ttl: Option<u64>,
+    pub certificates: Vec<Certificate>,
+    /// reward addresses and withdrawn amounts
+    pub withdrawals: Vec<(Address, u64)>,
+    pub auxiliary_data_hash: Option<AuxiliaryDataHash>,
+    /// the transaction is invalid before this slot
+    pub validity_start: Option<u64>,
+    /// positive quantities are minted, negative ones burnt
+    pub mint: MultiAsset<i64>,
+    pub script_data_hash: Option<ScriptDataHash>,
+    pub collateral: Vec<TransactionInput>,
+    pub required_signers: Vec<Ed25519KeyHash>,
+    pub network_id: Option<u8>,
+    pub collateral_return: Option<TxOutput>,
+    pub total_collateral: Option<u64>,
+    pub reference_inputs: Vec<TransactionInput>,
+    /// donation to the treasury
+    pub donation: Option<u64>,
+    /// fields that are not modeled, by key, as raw CBOR
+    pub other: Vec<(u64, Vec<u8>)>,
+}
+
+impl TxBody {
+    /// Decode the CBOR of a body, trailing bytes are rejected.
+    pub fn from_cbor(body_cbor: &[u8]) -> Result<Self, decode::Error> {
+        let mut decoder = Decoder::new(body_cbor);
+        let body = decoder.decode()?;
+        if decoder.position() != body_cbor.len() {
+            return Err(decode::Error::message("trailing bytes after the body"));
+        }
+        Ok(body)
+    }
+
+    /// Decode the body of a whole transaction.
+    pub fn from_tx_cbor(tx_cbor: &[u8]) -> Result<Self, decode::Error> {
+        Self::from_cbor(tx_body_cbor(tx_cbor).map_err(decode::Error::message)?)
+    }
+
+    /// Encode the body with its keys in ascending order.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        minicbor::to_vec(self).expect("writing to a Vec does not fail")
+    }
+
+    fn encoded_fields(&self) -> Vec<(u64, Vec<u8>)> {
+        fn array<T: Encode<()>>(items: &[T]) -> Vec<u8> {
+            to_cbor_with(|e| {
+                e.array(items.len() as u64)?;
+                items.iter().try_for_each(|item| e.encode(item).map(|_| ()))
+            })
+        }
+
+        let mut fields = Vec::new();
+        let mut field = |key, cbor| fields.push((key, cbor));
+        field(0, array(&self.inputs));
+        field(1, array(&self.outputs));
+        field(2, to_cbor_with(|e| e.u64(self.fee).map(|_| ())));
+        if let Some(ttl) = self.ttl {
+            field(3, to_cbor_with(|e| e.u64(ttl).map(|_| ())));
+        }
+        if !self.certificates.is_empty() {
+            field(4, array(&self.certificates));
+        }
+        if !self.withdrawals.is_empty() {
+            field(
+                5,
+                to_cbor_with(|e| {
+                    e.map(self.withdrawals.len() as u64)?;
+                    for (address, coin) in &self.withdrawals {
+                        e.encode(address)?.u64(*coin)?;
+                    }
+                    Ok(())
+                }),
+            );
+        }
+        if let Some(hash) = &self.auxiliary_data_hash {
+            field(7, to_cbor_with(|e| e.bytes(hash.as_ref()).map(|_| ())));
+        }
+        if let Some(slot) = self.validity_start {
+            field(8, to_cbor_with(|e| e.u64(slot).map(|_| ())));
+        }
+        if !self.mint.is_empty() {
+            field(9, to_cbor_with(|e| encode_multiasset(&self.mint, e)));
+        }
+        if let Some(hash) = &self.script_data_hash {
+            field(11, to_cbor_with(|e| e.bytes(hash.as_ref()).map(|_| ())));
+        }
+        if !self.collateral.is_empty() {
+            field(13, array(&self.collateral));
+        }
+        if !self.required_signers.is_empty() {
+            field(
+                14,
+                to_cbor_with(|e| {
+                    e.array(self.required_signers.len() as u64)?;
+                    for signer in &self.required_signers {
+                        e.bytes(&signer.0)?;
+                    }
+                    Ok(())
+                }),
+            );
+        }
+        if let Some(network_id) = self.network_id {
+            field(15, to_cbor_with(|e| e.u8(network_id).map(|_| ())));
+        }
+        if let Some(output) = &self.collateral_return {
+            field(16, to_cbor_with(|e| e.encode(output).map(|_| ())));
+        }
+        if let Some(coin) = self.total_collateral {
+            field(17, to_cbor_with(|e| e.u64(coin).map(|_| ())));
+        }
+        if !self.reference_inputs.is_empty() {
+            field(18, array(&self.reference_inputs));
+        }
+        if let Some(coin) = self.donation {
+            field(22, to_cbor_with(|e| e.u64(coin).map(|_| ())));
+        }
+        fields.extend(self.other.iter().cloned());
+        fields.sort_by_key(|(key, _)| *key);
+        fields
+    }
+}
+
+impl<'b, C> Decode<'b, C> for TxBody {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let mut body = TxBody::default();
+        let (mut inputs, mut outputs, mut fee) = (None, None, None);
+        let mut keys = BTreeSet::new();
+        decode_map(d, |d| {
+            let key = d.u64()?;
+            if !keys.insert(key) {
+                return Err(decode::Error::message("duplicate body field"));
+            }
+            match key {
+                0 => inputs = Some(decode_set(d, |d| d.decode())?),
+                1 => {
+                    let mut items = Vec::new();
+                    decode_array(d, |d| {
+                        items.push(d.decode()?);
+                        Ok(())
+                    })?;
+                    outputs = Some(items);
+                }
+                2 => fee = Some(d.u64()?),
+                3 => body.ttl = Some(d.u64()?),
+                4 => body.certificates = decode_set(d, |d| d.decode())?,
+                5 => decode_map(d, |d| {
+                    body.withdrawals.push((d.decode()?, d.u64()?));
+                    Ok(())
+                })?,
+                7 => body.auxiliary_data_hash = Some(expect_hash::<32>(d)?.into()),
+                8 => body.validity_start = Some(d.u64()?),
+                9 => body.mint = decode_multiasset(d)?,
+                11 => body.script_data_hash = Some(expect_hash::<32>(d)?.into()),
+                13 => body.collateral = decode_set(d, |d| d.decode())?,
+                14 => {
+                    body.required_signers =
+                        decode_set(d, |d| expect_hash::<28>(d).map(Ed25519KeyHash::from))?
+                }
+                15 => body.network_id = Some(d.u8()?),
+                16 => body.collateral_return = Some(d.decode()?),
+                17 => body.total_collateral = Some(d.u64()?),
+                18 => body.reference_inputs = decode_set(d, |d| d.decode())?,
+                22 => body.donation = Some(d.u64()?),
+                _ => body.other.push((key, raw_item(d)?.to_vec())),
+            }
+            Ok(())
+        })?;
+        body.inputs = inputs.ok_or(decode::Error::message("body without inputs"))?;
+        body.outputs = outputs.ok_or(decode::Error::message("body without outputs"))?;
+        body.fee = fee.ok_or(decode::Error::message("body without fee"))?;
+        Ok(body)
+    }
+}
+
+impl<C> Encode<C> for TxBody {
+    fn encode<W: encode::Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        let fields = self.encoded_fields();
+        e.map(fields.len() as u64)?;
+        for (key, cbor) in fields {
+            e.u64(key)?;
+            write_raw(e, &cbor)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::address::MAINNET;
+    use alloc::vec;
+
+    // slip14-data/txs/tx-bb1eb401….signed
+    const SLIP14_TX: &str = "84a40081825820fb03abe73ddca76bc2f4a4fd18fde3b8e7844d7d1e3049042b4ed0875e7a6e04010182a200581d61abde0f5259efacac08c88bd8c951eaad7b15d898a2a482f0ba3b7f16011a069db9c0a200581d6180f9e2c88e6c817008f3a812ed889b4a4da8e0bd103f86e7335422aa011a34fad460021a00023be00e81581c80f9e2c88e6c817008f3a812ed889b4a4da8e0bd103f86e7335422aaa100818258205d010cf16fdeff40955633d6c565f3844a288a24967cf6b76acbeb271b4f13c15840e6766adf71231ec80faddbe12dcea623fd6bc31982cdbc69e90fb8c4dd937d4cdc87c2d3287a1c62be928a4ec01b970099410301adba27ca20fee0c08f68e50af5f6";
+
+    #[test]
+    fn test_decode_slip14_body() {
+        let tx = hex::decode(SLIP14_TX).unwrap();
+        let body = TxBody::from_tx_cbor(&tx).unwrap();
+
+        assert_eq!(body.inputs.len(), 1);
+        assert_eq!(
+            body.inputs[0].transaction_id.to_hex(),
+            "fb03abe73ddca76bc2f4a4fd18fde3b8e7844d7d1e3049042b4ed0875e7a6e04"
+        );
+        assert_eq!(body.inputs[0].index, 1);
+        assert_eq!(body.outputs.len(), 2);
+        assert_eq!(body.outputs[0].value.coin, 111_000_000);
+        assert_eq!(body.outputs[1].address.network_id(), Some(MAINNET));
+        assert_eq!(body.fee, 146_400);
+        assert_eq!(body.ttl, None);
+        assert_eq!(body.required_signers.len(), 1);
+
+        // the body is canonical, it encodes back to the same bytes
+        let body_cbor = tx_body_cbor(&tx).unwrap();
+        assert_eq!(body.to_cbor(), body_cbor);
+        assert_eq!(
+            TxId::from_tx_body_cbor(&body.to_cbor()).to_hex(),
+            "bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb"
+        );
+    }
+
+    #[test]
+    fn test_body_round_trip() {
+        let stake = Credential::KeyHash([1; 28]);
+        let address = Address::base(MAINNET, Credential::ScriptHash([2; 28]), stake);
+        let mut output = TxOutput::new(address.clone(), 2_000_000);
+        output
+            .value
+            .assets
+            .entry([3; 28].into())
+            .or_default()
+            .insert(b"token".to_vec(), 10);
+        output.datum = Some(Datum::Inline(vec![0x80]));
+        output.script_ref = Some(vec![0x82, 0x01, 0x41, 0x00]);
+
+        let body = TxBody {
+            inputs: vec![TransactionInput {
+                transaction_id: TxId::from_bytes(&[4; 32]).unwrap(),
+                index: 0,
+            }],
+            outputs: vec![output],
+            fee: 170_000,
+            ttl: Some(1000),
+            certificates: vec![
+                Certificate::StakeDelegation(stake, [5; 28].into()),
+                Certificate::VoteDelegation(stake, DRep::AlwaysAbstain),
+                Certificate::DRepRegistration(
+                    stake,
+                    500_000_000,
+                    Some(Anchor {
+                        url: "https://example.com".into(),
+                        data_hash: [6; 32],
+                    }),
+                ),
+            ],
+            withdrawals: vec![(Address::reward(MAINNET, stake), 42)],
+            validity_start: Some(10),
+            mint: [([7; 28].into(), [(b"burnt".to_vec(), -1)].into())].into(),
+            network_id: Some(MAINNET),
+            donation: Some(1),
+            other: vec![(21, vec![0x00])],
+            ..TxBody::default()
+        };
+        let cbor = body.to_cbor();
+        assert_eq!(TxBody::from_cbor(&cbor).unwrap(), body);
+    }
+
+    #[test]
+    fn test_decode_conway_sets_and_unknown_certificates() {
+        // {0: 258([[h'00…', 0]]), 1: [[h'61…', 1]], 2: 0, 4: [[3, …]]}
+        let mut body = to_cbor_with(|e| {
+            e.map(4)?.u8(0)?.tag(Tag::Unassigned(SET_TAG))?.array(1)?;
+            e.array(2)?.bytes(&[0; 32])?.u8(0)?;
+            e.u8(1)?.array(1)?.array(2)?.bytes(&[0x61; 29])?.u8(1)?;
+            e.u8(2)?.u8(0)?;
+            e.u8(4)?.array(1)?.array(2)?.u8(3)?.str("pool params")?;
+            Ok(())
+        });
+        let decoded = TxBody::from_cbor(&body).unwrap();
+        assert_eq!(decoded.inputs.len(), 1);
+        assert_eq!(decoded.outputs[0].value.coin, 1);
+        assert!(matches!(
+            &decoded.certificates[..],
+            [Certificate::Other { tag: 3, cbor }] if cbor[..] == body[body.len() - 14..]
+        ));
+
+        body.push(0);
+        assert!(TxBody::from_cbor(&body).is_err());
+    }
+
+    #[test]
+    fn test_reject_duplicate_fields() {
+        let body = to_cbor_with(|e| {
+            e.map(4)?.u8(0)?.array(0)?.u8(1)?.array(0)?;
+            e.u8(2)?.u8(0)?.u8(2)?.u8(1)?;
+            Ok(())
+        });
+        assert!(TxBody::from_cbor(&body).is_err());
+    }
+}
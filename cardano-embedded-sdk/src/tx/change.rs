@@ -0,0 +1,164 @@
+//! Change outputs
+//!
+//! The host marks an output as change by giving the derivation path of its
+//! payment key. The device does not take its word for it: it derives the
+//! base address of the path from its own keys, with the stake key of the
+//! same account (`m/1852'/1815'/account'/2/0`), and only an output paying to
+//! exactly this address is shown as change. See
+//! [`SummaryContext::with_change`](super::summary::SummaryContext::with_change).
+//!
+//! # Example
+//! ```
+//! use cardano_embedded_sdk::api::session::UnlockSession;
+//! use cardano_embedded_sdk::bip::bip39::Entropy;
+//! use cardano_embedded_sdk::tx::{address::MAINNET, change};
+//! use derivation_path::DerivationPath;
+//!
+//! let entropy = Entropy::from_slice(&[0x5a; 16]).unwrap();
+//! let mut session = UnlockSession::<1>::new(60);
+//! session.unlock(&entropy, b"embedano", 0).unwrap();
+//!
+//! let path: DerivationPath = "m/1852'/1815'/0'/1/0".parse().unwrap();
+//! let address = change::base_address(&path, MAINNET, |path| session.public_key(path, 1).ok());
+//! assert!(address.unwrap().to_bech32().starts_with("addr1q"));
+//! ```
+use core::{error, fmt};
+
+use derivation_path::{ChildIndex, DerivationPath};
+
+use super::address::{Address, Credential};
+use crate::types::XPubKey;
+
+const PURPOSE: u32 = 1852;
+const COIN_TYPE: u32 = 1815;
+const EXTERNAL_ROLE: u32 = 0;
+const INTERNAL_ROLE: u32 = 1;
+const STAKE_ROLE: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeError {
+    /// not the path of a payment key: `m/1852'/1815'/account'/(0|1)/index`
+    InvalidPath,
+    /// the device could not derive the keys of the path
+    KeyUnavailable,
+}
+impl fmt::Display for ChangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChangeError::InvalidPath => write!(f, "not the path of a payment key"),
+            ChangeError::KeyUnavailable => write!(f, "keys of the path are not available"),
+        }
+    }
+}
+impl error::Error for ChangeError {}
+
+/// account of a payment key path
+fn payment_account(path: &DerivationPath) -> Result<u32, ChangeError> {
+    use ChildIndex::{Hardened, Normal};
+
+    match *path.path() {
+        [Hardened(PURPOSE), Hardened(COIN_TYPE), Hardened(account), Normal(role), Normal(_)]
+            if role == EXTERNAL_ROLE || role == INTERNAL_ROLE =>
+        {
+            Ok(account)
+        }
+        _ => Err(ChangeError::InvalidPath),
+    }
+}
+
+/// path of the stake key of the account of `payment_path`
+pub fn stake_path(payment_path: &DerivationPath) -> Result<DerivationPath, ChangeError> {
+    let account = payment_account(payment_path)?;
+    Ok(DerivationPath::new([
+        ChildIndex::Hardened(PURPOSE),
+        ChildIndex::Hardened(COIN_TYPE),
+        ChildIndex::Hardened(account),
+        ChildIndex::Normal(STAKE_ROLE),
+        ChildIndex::Normal(0),
+    ]))
+}
+
+/// Base address of the payment key of `path` and the stake key of its
+/// account. `public_key` gives the extended public keys of the device, from
+/// an [`UnlockSession`](crate::api::session::UnlockSession) or
+/// [`derive_key_pair`](crate::api::derive_key_pair).
+pub fn base_address(
+    path: &DerivationPath,
+    network_id: u8,
+    mut public_key: impl FnMut(&DerivationPath) -> Option<XPubKey>,
+) -> Result<Address, ChangeError> {
+    let stake_path = stake_path(path)?;
+    let mut credential = |path| {
+        public_key(path)
+            .map(|key| Credential::KeyHash(key.hash().0))
+            .ok_or(ChangeError::KeyUnavailable)
+    };
+    let payment = credential(path)?;
+    let stake = credential(&stake_path)?;
+    Ok(Address::base(network_id, payment, stake))
+}
+
+#[cfg(all(test, feature = "bip39-english"))]
+mod tests {
+    use super::*;
+    use crate::tx::address::MAINNET;
+    use crate::util::slip14;
+
+    fn path(s: &str) -> DerivationPath {
+        s.parse().unwrap()
+    }
+
+    fn slip14_key(path: &DerivationPath) -> Option<XPubKey> {
+        Some(slip14::make_keys_for(path).1)
+    }
+
+    #[test]
+    fn test_slip14_base_address() {
+        let address = base_address(&path("m/1852'/1815'/0'/0/0"), MAINNET, slip14_key).unwrap();
+
+        // enterprise address of the payment key, slip14-data/README.md
+        let enterprise =
+            Address::from_bech32("addr1vxq0nckg3ekgzuqg7w5p9mvgnd9ym28qh5grlph8xd2z92su77c6m")
+                .unwrap();
+        assert_eq!(
+            address.payment_credential(),
+            enterprise.payment_credential()
+        );
+        let stake_key = slip14_key(&path("m/1852'/1815'/0'/2/0")).unwrap();
+        assert_eq!(
+            address.stake_credential(),
+            Some(Credential::KeyHash(stake_key.hash().0))
+        );
+        assert_eq!(address.network_id(), Some(MAINNET));
+    }
+
+    #[test]
+    fn test_invalid_paths() {
+        for invalid in [
+            "m/44'/1815'/0'/0/0",
+            "m/1852'/1815'/0'/2/0",
+            "m/1852'/1815'/0/1/0",
+            "m/1852'/1815'/0'/1'/0",
+            "m/1852'/1815'/0'/1",
+            "m/1852'/1815'/0'/1/0/0",
+        ] {
+            assert_eq!(
+                base_address(&path(invalid), MAINNET, slip14_key),
+                Err(ChangeError::InvalidPath),
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            base_address(&path("m/1852'/1815'/0'/1/0"), MAINNET, |_| None),
+            Err(ChangeError::KeyUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_stake_path() {
+        assert_eq!(
+            stake_path(&path("m/1852'/1815'/7'/1/3")),
+            Ok(path("m/1852'/1815'/7'/2/0"))
+        );
+    }
+}
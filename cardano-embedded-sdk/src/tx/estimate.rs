@@ -0,0 +1,437 @@
+//! Size and fee estimation
+//!
+//! The fee is in the body it pays for, and the witnesses, added once the
+//! transaction is signed, count in its size. To set the fee before streaming
+//! a transaction to the device, the host serializes the body with placeholder
+//! witnesses: a key witness per signing key, the native scripts, and a
+//! bootstrap witness per Byron address spent from. Keys and signatures have
+//! fixed sizes, the estimate is the size of the signed transaction when its
+//! witness set is written the same way. A signer that writes it otherwise,
+//! with indefinite lengths or without the auxiliary data encoded as given,
+//! makes a transaction of another size.
+//!
+//! Since Conway the sets can have the tag 258, 3 more bytes per set.
+//! cardano-cli and the serialization libs tag the witness sets when they
+//! tag the sets of the body, the estimator does the same by default, see
+//! [`SetEncoding`].
+//!
+//! # Example
+//! ```
+//! use cardano_embedded_sdk::tx::{balance::ProtocolParams, estimate::FeeEstimator, TxBody};
+//! use derivation_path::DerivationPath;
+//!
+//! let params = ProtocolParams::CONWAY_MAINNET;
+//! let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
+//! let estimator = FeeEstimator::new(params).with_key_paths(&[path]);
+//!
+//! let mut body = TxBody::default();
+//! let fee = estimator.set_min_fee(&mut body);
+//! assert_eq!(fee, estimator.estimate(&body.to_cbor()).min_fee());
+//! ```
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::{error, fmt};
+
+use derivation_path::DerivationPath;
+use minicbor::{
+    data::{Tag, Type},
+    decode, encode, Decoder, Encoder,
+};
+
+use super::address::{Address, AddressKind};
+use super::balance::ProtocolParams;
+use super::body::{decode_map, to_cbor_with, write_raw, TxBody};
+
+const PUBLIC_KEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+const CHAIN_CODE_SIZE: usize = 32;
+const SET_TAG: u64 = 258;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstimateError {
+    /// a bootstrap witness is for a Byron address
+    NotByron,
+    InvalidByronAddress,
+}
+impl fmt::Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EstimateError::NotByron => write!(f, "not a Byron address"),
+            EstimateError::InvalidByronAddress => write!(f, "invalid Byron address"),
+        }
+    }
+}
+impl error::Error for EstimateError {}
+
+/// Size and minimum fee of a signed transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// bytes of the transaction with its witnesses
+    pub size: usize,
+    /// `minFeeA * size + minFeeB`
+    pub linear_fee: u64,
+    /// tiered fee of the reference scripts
+    pub ref_script_fee: u64,
+}
+
+impl FeeEstimate {
+    pub fn min_fee(&self) -> u64 {
+        self.linear_fee.saturating_add(self.ref_script_fee)
+    }
+}
+
+/// Encoding of the witness sets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SetEncoding {
+    /// tagged when the inputs of the body are, or when the body cannot be
+    /// read
+    #[default]
+    FollowBody,
+    /// arrays without the tag, like the sets of [`TxBody::to_cbor`]
+    Array,
+    /// arrays with the tag 258
+    Tagged,
+}
+
+/// Witnesses and parts of a transaction outside of its body, to estimate
+/// its fee
+#[derive(Debug, Clone)]
+pub struct FeeEstimator {
+    params: ProtocolParams,
+    key_paths: Vec<DerivationPath>,
+    native_scripts: Vec<Vec<u8>>,
+    bootstrap_attributes: Vec<Vec<u8>>,
+    auxiliary_data: Option<Vec<u8>>,
+    ref_scripts_size: usize,
+    set_encoding: SetEncoding,
+}
+
+impl FeeEstimator {
+    pub fn new(params: ProtocolParams) -> Self {
+        FeeEstimator {
+            params,
+            key_paths: Vec::new(),
+            native_scripts: Vec::new(),
+            bootstrap_attributes: Vec::new(),
+            auxiliary_data: None,
+            ref_scripts_size: 0,
+            set_encoding: SetEncoding::default(),
+        }
+    }
+
+    /// paths of the keys signing the transaction, a key signs once
+    pub fn with_key_paths(mut self, paths: &[DerivationPath]) -> Self {
+        for path in paths {
+            if !self.key_paths.contains(path) {
+                self.key_paths.push(path.clone());
+            }
+        }
+        self
+    }
+
+    /// CBOR of a native script witness
+    pub fn with_native_script(mut self, script: Vec<u8>) -> Self {
+        self.native_scripts.push(script);
+        self
+    }
+
+    /// bootstrap witness spending from the Byron `address`, it carries the
+    /// attributes of the address
+    pub fn with_bootstrap(mut self, address: &Address) -> Result<Self, EstimateError> {
+        self.bootstrap_attributes.push(byron_attributes(address)?);
+        Ok(self)
+    }
+
+    /// CBOR of the auxiliary data, the metadata of the transaction
+    pub fn with_auxiliary_data(mut self, auxiliary_data: Vec<u8>) -> Self {
+        self.auxiliary_data = Some(auxiliary_data);
+        self
+    }
+
+    /// total size of the reference scripts of the inputs and the reference
+    /// inputs
+    pub fn with_ref_scripts_size(mut self, size: usize) -> Self {
+        self.ref_scripts_size = size;
+        self
+    }
+
+    /// encoding of the witness sets, they follow the body by default
+    pub fn with_set_encoding(mut self, set_encoding: SetEncoding) -> Self {
+        self.set_encoding = set_encoding;
+        self
+    }
+
+    /// Transaction of `body_cbor` with placeholder witnesses, of the size of
+    /// the signed one.
+    pub fn tx_cbor(&self, body_cbor: &[u8]) -> Vec<u8> {
+        let tagged = match self.set_encoding {
+            SetEncoding::FollowBody => has_tagged_inputs(body_cbor),
+            SetEncoding::Array => false,
+            SetEncoding::Tagged => true,
+        };
+        to_cbor_with(|e| {
+            e.array(4)?;
+            write_raw(e, body_cbor)?;
+            self.encode_witness_set(e, tagged)?;
+            e.bool(true)?;
+            match &self.auxiliary_data {
+                Some(auxiliary_data) => write_raw(e, auxiliary_data),
+                None => e.null().map(|_| ()),
+            }
+        })
+    }
+
+    /// size and minimum fee of the signed transaction of `body_cbor`
+    pub fn estimate(&self, body_cbor: &[u8]) -> FeeEstimate {
+        let size = self.tx_cbor(body_cbor).len();
+        FeeEstimate {
+            size,
+            linear_fee: self.params.min_fee(size),
+            ref_script_fee: self.params.ref_script_fee(self.ref_scripts_size),
+        }
+    }
+
+    /// Raise the fee of `body` to its minimum and return it. A larger fee can
+    /// take more bytes, this repeats until the fee pays for the size of the
+    /// body holding it. A fee already above the minimum is kept.
+    pub fn set_min_fee(&self, body: &mut TxBody) -> u64 {
+        loop {
+            let min_fee = self.estimate(&body.to_cbor()).min_fee();
+            if body.fee >= min_fee {
+                return body.fee;
+            }
+            body.fee = min_fee;
+        }
+    }
+
+    fn encode_witness_set(
+        &self,
+        e: &mut Encoder<Vec<u8>>,
+        tagged: bool,
+    ) -> Result<(), encode::Error<Infallible>> {
+        let set = |e: &mut Encoder<Vec<u8>>, len: usize| {
+            if tagged {
+                e.tag(Tag::Unassigned(SET_TAG))?;
+            }
+            e.array(len as u64).map(|_| ())
+        };
+        let len = !self.key_paths.is_empty() as u64
+            + !self.native_scripts.is_empty() as u64
+            + !self.bootstrap_attributes.is_empty() as u64;
+        e.map(len)?;
+        if !self.key_paths.is_empty() {
+            set(e.u8(0)?, self.key_paths.len())?;
+            for _ in &self.key_paths {
+                e.array(2)?
+                    .bytes(&[0; PUBLIC_KEY_SIZE])?
+                    .bytes(&[0; SIGNATURE_SIZE])?;
+            }
+        }
+        if !self.native_scripts.is_empty() {
+            set(e.u8(1)?, self.native_scripts.len())?;
+            for script in &self.native_scripts {
+                write_raw(e, script)?;
+            }
+        }
+        if !self.bootstrap_attributes.is_empty() {
+            set(e.u8(2)?, self.bootstrap_attributes.len())?;
+            for attributes in &self.bootstrap_attributes {
+                e.array(4)?
+                    .bytes(&[0; PUBLIC_KEY_SIZE])?
+                    .bytes(&[0; SIGNATURE_SIZE])?
+                    .bytes(&[0; CHAIN_CODE_SIZE])?
+                    .bytes(attributes)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// whether the inputs of the body, its field 0, are a set with the tag 258
+fn has_tagged_inputs(body_cbor: &[u8]) -> bool {
+    let mut tagged = None;
+    let result = decode_map(&mut Decoder::new(body_cbor), |d| {
+        if d.u64()? == 0 {
+            tagged = Some(d.datatype()? == Type::Tag);
+        }
+        d.skip()
+    });
+    match result {
+        Ok(()) => tagged.unwrap_or(false),
+        Err(_) => true,
+    }
+}
+
+/// CBOR of the attributes of a Byron address:
+/// `[24(<<[root, attributes, type]>>), crc]`
+fn byron_attributes(address: &Address) -> Result<Vec<u8>, EstimateError> {
+    if address.kind() != AddressKind::Byron {
+        return Err(EstimateError::NotByron);
+    }
+    let attributes = || -> Result<Vec<u8>, decode::Error> {
+        let mut d = Decoder::new(address.as_bytes());
+        if d.array()? != Some(2) || d.tag()? != Tag::Cbor {
+            return Err(decode::Error::message("not a Byron address"));
+        }
+        let payload = d.bytes()?;
+        let mut d = Decoder::new(payload);
+        if d.array()? != Some(3) {
+            return Err(decode::Error::message("not a Byron address payload"));
+        }
+        d.skip()?;
+        let start = d.position();
+        d.skip()?;
+        Ok(payload[start..d.position()].to_vec())
+    };
+    attributes().map_err(|_| EstimateError::InvalidByronAddress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::address::{Credential, MAINNET};
+    use alloc::vec;
+
+    const PARAMS: ProtocolParams = ProtocolParams::CONWAY_MAINNET;
+
+    #[cfg(feature = "std")]
+    fn path(s: &str) -> DerivationPath {
+        s.parse().unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    fn slip14_signed_tx() -> Vec<u8> {
+        let envelope = crate::text_envelope::TextEnvelope::from_json(include_str!(
+            "../../../slip14-data/txs/tx-bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb.signed"
+        ))
+        .unwrap();
+        envelope.to_tx().unwrap().0.to_vec()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_slip14_size_is_exact() {
+        use crate::types::tx_body_cbor;
+
+        let signed = slip14_signed_tx();
+        let estimator = FeeEstimator::new(PARAMS)
+            .with_key_paths(&[path("m/1852'/1815'/0'/0/0"), path("m/1852'/1815'/0'/0/0")]);
+        let tx = estimator.tx_cbor(tx_body_cbor(&signed).unwrap());
+
+        // same bytes but the key and the signature of the witness
+        assert_eq!(tx.len(), signed.len());
+        assert_eq!(tx[..164], signed[..164]);
+        assert_eq!(tx[196..198], signed[196..198]);
+        assert_eq!(tx[262..], signed[262..]);
+
+        let estimate = estimator.estimate(tx_body_cbor(&signed).unwrap());
+        assert_eq!(
+            estimate,
+            FeeEstimate {
+                size: 264,
+                linear_fee: 44 * 264 + 155_381,
+                ref_script_fee: 0,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_set_min_fee() {
+        let estimator = FeeEstimator::new(PARAMS).with_key_paths(&[path("m/1852'/1815'/0'/0/0")]);
+        let mut body = TxBody::from_tx_cbor(&slip14_signed_tx()).unwrap();
+        assert_eq!(estimator.set_min_fee(&mut body), 166_997);
+
+        // from 1 byte to 5 bytes
+        body.fee = 0;
+        let fee = estimator.set_min_fee(&mut body);
+        assert_eq!(fee, body.fee);
+        assert_eq!(fee, estimator.estimate(&body.to_cbor()).min_fee());
+        assert_eq!(fee, 166_997);
+
+        let estimator = estimator.with_ref_scripts_size(30_000);
+        assert_eq!(estimator.set_min_fee(&mut body), 166_997 + 463_200);
+
+        body.fee = 1_000_000;
+        assert_eq!(estimator.set_min_fee(&mut body), 1_000_000);
+    }
+
+    #[test]
+    fn test_native_script_and_bootstrap_witnesses() {
+        // CIP-19 Byron test vector
+        let byron = Address::from_bytes(
+            hex::decode(
+                "82d818584983581c7e9ee4a9527dea9091e2d580edd6716888c42f75d96276290f98fe0ba201581e581c0cdf39b531d1ac0963cbd183f63e43d895d16a9c567c95e1056e28bd02451a4170cb17001a53249b67",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let attributes = hex::decode(
+            "a201581e581c0cdf39b531d1ac0963cbd183f63e43d895d16a9c567c95e1056e28bd02451a4170cb17",
+        )
+        .unwrap();
+        // [0, h'00…'], a signature script
+        let mut script = vec![0x82, 0x00, 0x58, 0x1c];
+        script.extend([0; 28]);
+
+        let estimator = FeeEstimator::new(PARAMS)
+            .with_native_script(script.clone())
+            .with_bootstrap(&byron)
+            .unwrap()
+            .with_auxiliary_data(vec![0xa0]);
+        let tx = estimator.tx_cbor(&[0xa0]);
+
+        let mut expected = vec![0x84, 0xa0, 0xa2, 0x01, 0x81];
+        expected.extend(&script);
+        expected.extend([0x02, 0x81, 0x84, 0x58, 32]);
+        expected.extend([0; 32]);
+        expected.extend([0x58, 64]);
+        expected.extend([0; 64]);
+        expected.extend([0x58, 32]);
+        expected.extend([0; 32]);
+        expected.extend([0x58, 41]);
+        expected.extend(&attributes);
+        expected.extend([0xf5, 0xa0]);
+        assert_eq!(tx, expected);
+
+        let shelley = Address::enterprise(MAINNET, Credential::KeyHash([0; 28]));
+        assert_eq!(
+            FeeEstimator::new(PARAMS)
+                .with_bootstrap(&shelley)
+                .unwrap_err(),
+            EstimateError::NotByron
+        );
+    }
+
+    #[test]
+    fn test_set_encoding() {
+        // {0: 258([[h'00…', 0]])} and the same inputs without the tag
+        let mut tagged = vec![0xa1, 0x00, 0xd9, 0x01, 0x02, 0x81, 0x82, 0x58, 32];
+        tagged.extend([0; 32]);
+        tagged.push(0x00);
+        let array = [&tagged[..2], &tagged[5..]].concat();
+        let mut script = vec![0x82, 0x00, 0x58, 0x1c];
+        script.extend([0; 28]);
+
+        let estimator = FeeEstimator::new(PARAMS).with_native_script(script);
+        let size = estimator.estimate(&array).size;
+        // the body is 3 bytes longer, and so is the witness set
+        assert_eq!(estimator.estimate(&tagged).size, size + 6);
+        let tx = estimator.tx_cbor(&tagged);
+        assert_eq!(
+            tx[1 + tagged.len()..][..6],
+            [0xa1, 0x01, 0xd9, 0x01, 0x02, 0x81]
+        );
+
+        let estimator = estimator.with_set_encoding(SetEncoding::Tagged);
+        assert_eq!(estimator.estimate(&array).size, size + 3);
+        let estimator = estimator.with_set_encoding(SetEncoding::Array);
+        assert_eq!(estimator.estimate(&tagged).size, size + 3);
+        // a body that cannot be read has the tag budgeted
+        let estimator = estimator.with_set_encoding(SetEncoding::FollowBody);
+        assert_eq!(
+            estimator.estimate(&array[..10]).size,
+            size - array.len() + 10 + 3
+        );
+    }
+}
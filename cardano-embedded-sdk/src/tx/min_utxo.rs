@@ -0,0 +1,158 @@
+//! Minimum lovelace of an output
+//!
+//! Since Babbage an output holds at least `(size + 160) * coinsPerUTxOByte`
+//! lovelace, the size being the bytes of the output as it is on chain and
+//! 160 the bytes of its UTxO entry that are not in the output. A larger coin
+//! can take more bytes, a builder looks for the coin that covers the
+//! minimum of the output it is put in.
+//!
+//! [`ProtocolParams::min_utxo`] measures the map form the SDK encodes, a few
+//! bytes more than the legacy array form, [`ProtocolParams::raw_min_utxo`]
+//! measures the bytes of an output in any form.
+use alloc::vec::Vec;
+
+use minicbor::{decode, Decoder};
+
+use super::balance::ProtocolParams;
+use super::body::TxOutput;
+
+/// bytes of a UTxO entry that are not in its output
+const UTXO_ENTRY_OVERHEAD: u64 = 160;
+
+impl ProtocolParams {
+    /// minimum lovelace of an output of `size` bytes
+    pub fn min_utxo_of_size(&self, size: usize) -> u64 {
+        (size as u64)
+            .saturating_add(UTXO_ENTRY_OVERHEAD)
+            .saturating_mul(self.coins_per_utxo_byte)
+    }
+
+    /// Minimum lovelace of `output`, with the size of its map form as
+    /// [`TxOutput`] encodes it.
+    pub fn min_utxo(&self, output: &TxOutput) -> u64 {
+        let size = minicbor::to_vec(output)
+            .expect("writing to a Vec does not fail")
+            .len();
+        self.min_utxo_of_size(size)
+    }
+
+    /// Minimum lovelace of the output `output_cbor`, measured on its bytes.
+    /// They have to be one output and nothing more.
+    pub fn raw_min_utxo(&self, output_cbor: &[u8]) -> Result<u64, decode::Error> {
+        let mut decoder = Decoder::new(output_cbor);
+        decoder.decode::<TxOutput>()?;
+        if decoder.position() != output_cbor.len() {
+            return Err(decode::Error::message("trailing bytes after the output"));
+        }
+        Ok(self.min_utxo_of_size(output_cbor.len()))
+    }
+
+    /// Raise the coin of `output` to its minimum and return it. A larger coin
+    /// can take more bytes, this repeats until the output holds the minimum
+    /// of its own size. A coin already above the minimum is kept.
+    pub fn set_min_utxo(&self, output: &mut TxOutput) -> u64 {
+        loop {
+            let min_utxo = self.min_utxo(output);
+            if output.value.coin >= min_utxo {
+                return output.value.coin;
+            }
+            output.value.coin = min_utxo;
+        }
+    }
+
+    /// Smallest coin that covers the minimum of the output it is put in,
+    /// `encode` gives the bytes of the output holding a coin, in the form it
+    /// is written on chain.
+    pub fn raw_min_coin(
+        &self,
+        mut encode: impl FnMut(u64) -> Vec<u8>,
+    ) -> Result<u64, decode::Error> {
+        let mut coin = 0;
+        loop {
+            let min_utxo = self.raw_min_utxo(&encode(coin))?;
+            if coin >= min_utxo {
+                return Ok(coin);
+            }
+            coin = min_utxo;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_crypto::PolicyId;
+    use crate::tx::address::{Address, Credential, MAINNET};
+    use crate::tx::body::Datum;
+    use alloc::vec;
+
+    const PARAMS: ProtocolParams = ProtocolParams::CONWAY_MAINNET;
+
+    fn address() -> Address {
+        Address::enterprise(MAINNET, Credential::KeyHash([1; 28]))
+    }
+
+    #[test]
+    fn test_min_utxo() {
+        // {0: h'61…' (29 bytes), 1: coin}, 35 bytes with a coin below 24
+        let mut output = TxOutput::new(address(), 0);
+        assert_eq!(PARAMS.min_utxo(&output), 195 * 4_310);
+
+        // 840_450 takes 4 more bytes: 199 * 4_310
+        assert_eq!(PARAMS.set_min_utxo(&mut output), 857_690);
+        assert_eq!(output.value.coin, 857_690);
+        assert_eq!(PARAMS.min_utxo(&output), 857_690);
+
+        // a base address output is 67 bytes, 2 more than the legacy form
+        let base = Address::base(
+            MAINNET,
+            Credential::KeyHash([1; 28]),
+            Credential::KeyHash([2; 28]),
+        );
+        let mut output = TxOutput::new(base, 0);
+        assert_eq!(PARAMS.set_min_utxo(&mut output), 227 * 4_310);
+
+        let mut output = TxOutput::new(address(), 5_000_000);
+        assert_eq!(PARAMS.set_min_utxo(&mut output), 5_000_000);
+    }
+
+    #[test]
+    fn test_min_utxo_grows_with_assets_and_datum() {
+        let mut output = TxOutput::new(address(), 0);
+        let ada_only = PARAMS.set_min_utxo(&mut output);
+
+        output.value = output
+            .value
+            .with_asset(PolicyId::from([3; 28]), b"token", u64::MAX)
+            .unwrap();
+        output.datum = Some(Datum::Inline(vec![0x40; 100]));
+        let min_utxo = PARAMS.set_min_utxo(&mut output);
+        let size = minicbor::to_vec(&output).unwrap().len() as u64;
+        assert!(min_utxo > ada_only);
+        assert_eq!(min_utxo, (size + 160) * 4_310);
+        assert_eq!(output.value.coin, min_utxo);
+    }
+
+    #[test]
+    fn test_raw_min_utxo() {
+        // legacy `[h'61…', coin]`, 2 bytes less than the map form
+        let legacy = |coin| {
+            let mut e = minicbor::Encoder::new(Vec::new());
+            e.array(2).unwrap();
+            e.bytes(address().as_bytes()).unwrap().u64(coin).unwrap();
+            e.into_writer()
+        };
+        assert_eq!(PARAMS.raw_min_utxo(&legacy(0)).unwrap(), 193 * 4_310);
+        let output = TxOutput::new(address(), 0);
+        let map = minicbor::to_vec(&output).unwrap();
+        assert_eq!(PARAMS.raw_min_utxo(&map).unwrap(), PARAMS.min_utxo(&output));
+
+        // 831_830 takes 4 more bytes: 197 * 4_310
+        assert_eq!(PARAMS.raw_min_coin(legacy).unwrap(), 849_070);
+        assert_eq!(PARAMS.set_min_utxo(&mut output.clone()), 857_690);
+
+        assert!(PARAMS.raw_min_utxo(&[legacy(0), vec![0]].concat()).is_err());
+        assert!(PARAMS.raw_min_utxo(&legacy(0)[..10]).is_err());
+        assert!(PARAMS.raw_min_coin(|_| vec![0x80]).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+//! Transactions as the signer reviews them
+//!
+//! - [`address`]: Shelley and Byron addresses, with their bech32 form
+//! - [`asset`]: native assets, values, CIP-14 fingerprints and CIP-67 labels
+//! - [`balance`]: balance and fee checks
+//! - [`body`]: decoding of the transaction body
+//! - [`change`]: addresses of the device, to recognize change outputs
+//! - [`estimate`]: size and fee of a transaction before it is signed
+//! - [`min_utxo`]: minimum lovelace of an output
+//! - [`summary`]: display items of a body for the confirmation screens
+pub mod address;
+pub mod asset;
+pub mod balance;
+pub mod body;
+pub mod change;
+pub mod estimate;
+pub mod min_utxo;
+pub mod summary;
+
+pub use address::{Address, AddressError, AddressKind, Credential};
+pub use asset::{Cip68, MultiAsset, Value};
+pub use body::{Certificate, TxBody, TxOutput};
+pub use summary::{DisplayItem, Severity, SummaryContext, TxSummary};
@@ -0,0 +1,813 @@
+//! What the signer reviews before approving a transaction
+//!
+//! [`TxSummary`] turns a decoded [`TxBody`] into [`DisplayItem`]s: a title, a
+//! text ready to show, and a [`Severity`] telling the UI which items need
+//! the attention of the user. All boards render the same reviewed text.
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use derivation_path::DerivationPath;
+
+use super::address::{Address, AddressKind, Credential};
+use super::asset::{asset_label, fingerprint, Cip68, Value};
+use super::balance::{check_balance, BalanceContext};
+use super::body::{Certificate, DRep, TxBody, TxOutput};
+use super::change::{self, ChangeError};
+use crate::chain_crypto::{
+    cip5::{self, Prefix},
+    PolicyId, PoolKeyHash,
+};
+use crate::types::{TransactionInput, XPubKey};
+
+const LOVELACE_PER_ADA: u64 = 1_000_000;
+
+/// `1.500000 ADA` from an amount of lovelace
+pub fn format_ada(lovelace: u64) -> String {
+    format!(
+        "{}.{:06} ADA",
+        lovelace / LOVELACE_PER_ADA,
+        lovelace % LOVELACE_PER_ADA
+    )
+}
+
+/// `+1.500000 ADA` or `-1.500000 ADA`
+pub fn format_ada_change(lovelace: i128) -> String {
+    let sign = if lovelace < 0 { '-' } else { '+' };
+    let lovelace = lovelace.unsigned_abs();
+    let ada = lovelace / LOVELACE_PER_ADA as u128;
+    let decimals = lovelace % LOVELACE_PER_ADA as u128;
+    format!("{sign}{ada}.{decimals:06} ADA")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Info,
+    /// legitimate, but not part of a simple payment
+    Unusual,
+    /// may move funds or stake in a way the user does not expect
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Unusual => write!(f, "unusual"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// One screen of the review: `Fee: 0.170000 ADA`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayItem {
+    pub title: &'static str,
+    pub text: String,
+    pub severity: Severity,
+}
+
+impl DisplayItem {
+    pub fn new(title: &'static str, text: impl Into<String>, severity: Severity) -> Self {
+        DisplayItem {
+            title,
+            text: text.into(),
+            severity,
+        }
+    }
+
+    fn info(title: &'static str, text: impl Into<String>) -> Self {
+        Self::new(title, text, Severity::Info)
+    }
+
+    fn unusual(title: &'static str, text: impl Into<String>) -> Self {
+        Self::new(title, text, Severity::Unusual)
+    }
+
+    fn warning(title: &'static str, text: impl Into<String>) -> Self {
+        Self::new(title, text, Severity::Warning)
+    }
+
+    /// `Input: <tx id>#<index>`
+    pub fn input(input: &TransactionInput) -> Self {
+        Self::info("Input", format_input(input))
+    }
+
+    pub fn fee(fee: u64) -> Self {
+        Self::info("Fee", format_ada(fee))
+    }
+}
+
+impl fmt::Display for DisplayItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.severity != Severity::Info {
+            write!(f, "[{}] ", self.severity)?;
+        }
+        write!(f, "{}: {}", self.title, self.text)
+    }
+}
+
+fn format_input(input: &TransactionInput) -> String {
+    format!("{}#{}", input.transaction_id.to_hex(), input.index)
+}
+
+/// `asset1… (CIP-68 FT TOKEN) × 500`: the kind of token, when the name has
+/// a CIP-67 label, and the name, when it is printable
+fn format_asset(policy_id: &PolicyId, name: &[u8], quantity: impl fmt::Display) -> String {
+    let fingerprint = fingerprint(policy_id, name);
+    let (kind, name) = match asset_label(name) {
+        Some(label) => {
+            let kind = match Cip68::from_label(label) {
+                Some(token) => token.to_string(),
+                None => format!("CIP-67 label {label}"),
+            };
+            (Some(kind), &name[4..])
+        }
+        None => (None, name),
+    };
+    let name = core::str::from_utf8(name)
+        .ok()
+        .filter(|name| !name.is_empty() && !name.chars().any(char::is_control));
+    let description: Vec<&str> = kind.as_deref().into_iter().chain(name).collect();
+    match description.is_empty() {
+        true => format!("{fingerprint} × {quantity}"),
+        false => format!("{fingerprint} ({}) × {quantity}", description.join(" ")),
+    }
+}
+
+fn format_pool(pool: &PoolKeyHash) -> String {
+    cip5::encode(Prefix::Pool, pool.as_ref())
+}
+
+fn format_drep(drep: &DRep) -> String {
+    match drep {
+        DRep::KeyHash(hash) => cip5::encode(Prefix::Drep, hash),
+        DRep::ScriptHash(hash) => cip5::encode(Prefix::DrepScript, hash),
+        DRep::AlwaysAbstain => "always abstain".into(),
+        DRep::AlwaysNoConfidence => "always no confidence".into(),
+    }
+}
+
+fn format_drep_credential(credential: &Credential) -> String {
+    match credential {
+        Credential::KeyHash(hash) => format_drep(&DRep::KeyHash(*hash)),
+        Credential::ScriptHash(hash) => format_drep(&DRep::ScriptHash(*hash)),
+    }
+}
+
+/// Output the host claims to be change, with the address derived by the
+/// device for the claimed path
+#[derive(Debug, Clone)]
+struct ChangeClaim {
+    output_index: usize,
+    path: String,
+    address: Result<Address, ChangeError>,
+}
+
+/// What the device knows about the signer, given by the host
+#[derive(Debug, Clone)]
+pub struct SummaryContext {
+    network_id: u8,
+    own_addresses: Vec<Address>,
+    own_inputs: Vec<(TransactionInput, u64)>,
+    change: Vec<ChangeClaim>,
+    balance: Option<(BalanceContext, Vec<(TransactionInput, Value)>)>,
+}
+
+impl SummaryContext {
+    pub fn new(network_id: u8) -> Self {
+        SummaryContext {
+            network_id,
+            own_addresses: Vec::new(),
+            own_inputs: Vec::new(),
+            change: Vec::new(),
+            balance: None,
+        }
+    }
+
+    /// addresses of the signer, payment and reward ones
+    pub fn with_own_addresses(mut self, addresses: &[Address]) -> Self {
+        self.own_addresses.extend_from_slice(addresses);
+        self
+    }
+
+    /// inputs of the signer with their amount of lovelace, the other inputs
+    /// of the body are taken as not the signer's.
+    pub fn with_own_inputs(mut self, inputs: &[(TransactionInput, u64)]) -> Self {
+        self.own_inputs.extend_from_slice(inputs);
+        self
+    }
+
+    /// The host claims output `output_index` is change to the payment key of
+    /// `path`. It is shown as change only if it pays to the base address the
+    /// device derives for `path` with `public_key`, see [`change`].
+    pub fn with_change(
+        mut self,
+        output_index: usize,
+        path: &DerivationPath,
+        public_key: impl FnMut(&DerivationPath) -> Option<XPubKey>,
+    ) -> Self {
+        let address = change::base_address(path, self.network_id, public_key);
+        self.change.push(ChangeClaim {
+            output_index,
+            path: path.to_string(),
+            address,
+        });
+        self
+    }
+
+    /// Check the balance of the body with the values of all its inputs, see
+    /// [`check_balance`]. An unbalanced body is shown as a warning.
+    pub fn with_balance(
+        mut self,
+        inputs: &[(TransactionInput, Value)],
+        ctx: BalanceContext,
+    ) -> Self {
+        self.balance = Some((ctx, inputs.to_vec()));
+        self
+    }
+
+    fn is_own(&self, address: &Address) -> bool {
+        self.own_addresses.contains(address)
+            || self
+                .change
+                .iter()
+                .any(|claim| claim.address.as_ref() == Ok(address))
+    }
+
+    fn stake_address(&self, stake: &Credential) -> String {
+        Address::reward(self.network_id, *stake).to_bech32()
+    }
+
+    fn is_other_network(&self, address: &Address) -> bool {
+        address
+            .network_id()
+            .is_some_and(|network_id| network_id != self.network_id)
+    }
+}
+
+/// Display items of a transaction body, in the order they are shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSummary {
+    pub items: Vec<DisplayItem>,
+}
+
+impl TxSummary {
+    pub fn new(body: &TxBody, ctx: &SummaryContext) -> Self {
+        let mut items = Vec::new();
+
+        if let Some(network_id) = body.network_id.filter(|&id| id != ctx.network_id) {
+            items.push(DisplayItem::warning(
+                "Network",
+                format!("transaction is for network {network_id}"),
+            ));
+        }
+        items.extend(body.inputs.iter().map(DisplayItem::input));
+        items.extend(
+            body.outputs
+                .iter()
+                .enumerate()
+                .map(|(index, output)| output_item(index, output, ctx)),
+        );
+        for claim in &ctx.change {
+            if claim.output_index >= body.outputs.len() {
+                let text = format!("output {} does not exist", claim.output_index);
+                items.push(DisplayItem::warning("Change", text));
+            }
+        }
+        items.push(DisplayItem::fee(body.fee));
+        if let Some((balance, inputs)) = &ctx.balance {
+            if let Err(e) = check_balance(body, inputs, balance) {
+                items.push(DisplayItem::warning("Balance", e.to_string()));
+            }
+        }
+        for (address, coin) in &body.withdrawals {
+            let text = format!("{} from {address}", format_ada(*coin));
+            items.push(match ctx.is_other_network(address) {
+                true => DisplayItem::warning("Withdrawal", text),
+                false => DisplayItem::info("Withdrawal", text),
+            });
+        }
+        items.extend(
+            body.certificates
+                .iter()
+                .map(|certificate| certificate_item(certificate, ctx)),
+        );
+        for (policy_id, assets) in &body.mint {
+            for (name, &quantity) in assets {
+                let title = if quantity < 0 { "Burn" } else { "Mint" };
+                let asset = format_asset(policy_id, name, quantity.unsigned_abs());
+                items.push(DisplayItem::unusual(title, asset));
+            }
+        }
+        if let Some(slot) = body.validity_start {
+            items.push(DisplayItem::info("Valid from", format!("slot {slot}")));
+        }
+        items.push(match body.ttl {
+            Some(slot) => DisplayItem::info("Valid until", format!("slot {slot}")),
+            None => DisplayItem::unusual("Valid until", "no expiry"),
+        });
+        if let Some(hash) = &body.auxiliary_data_hash {
+            items.push(DisplayItem::info("Metadata", hash.to_hex()));
+        }
+        if let Some(hash) = &body.script_data_hash {
+            items.push(DisplayItem::unusual("Script data", hash.to_hex()));
+        }
+        for input in &body.collateral {
+            items.push(DisplayItem::unusual("Collateral", format_input(input)));
+        }
+        if let Some(output) = &body.collateral_return {
+            let text = format!("{} to {}", format_ada(output.value.coin), output.address);
+            items.push(DisplayItem::unusual("Collateral return", text));
+        }
+        if let Some(coin) = body.total_collateral {
+            items.push(DisplayItem::unusual("Total collateral", format_ada(coin)));
+        }
+        for signer in &body.required_signers {
+            let signer = cip5::encode(Prefix::ReqSignerVkh, &signer.to_bytes());
+            items.push(DisplayItem::unusual("Required signer", signer));
+        }
+        for input in &body.reference_inputs {
+            items.push(DisplayItem::unusual("Reference input", format_input(input)));
+        }
+        if let Some(coin) = body.donation {
+            items.push(DisplayItem::unusual("Donation", format_ada(coin)));
+        }
+        for (key, _) in &body.other {
+            items.push(match key {
+                19 => DisplayItem::unusual("Votes", "governance votes are cast"),
+                20 => DisplayItem::unusual("Proposals", "governance actions are proposed"),
+                21 => DisplayItem::unusual("Treasury", "current treasury value is checked"),
+                _ => DisplayItem::warning("Unknown field", format!("field {key} cannot be shown")),
+            });
+        }
+        items.extend(net_change(body, ctx));
+
+        TxSummary { items }
+    }
+
+    /// highest severity of the items, to pick the style of the final screen
+    pub fn max_severity(&self) -> Severity {
+        self.items
+            .iter()
+            .map(|item| item.severity)
+            .max()
+            .unwrap_or(Severity::Info)
+    }
+}
+
+fn output_item(index: usize, output: &TxOutput, ctx: &SummaryContext) -> DisplayItem {
+    let mut text = format!("{} to {}", format_ada(output.value.coin), output.address);
+    for (policy_id, assets) in &output.value.assets {
+        for (name, quantity) in assets {
+            text.push('\n');
+            text.push_str(&format_asset(policy_id, name, quantity));
+        }
+    }
+    if output.datum.is_some() {
+        text.push_str("\nwith datum");
+    }
+    if output.script_ref.is_some() {
+        text.push_str("\nwith reference script");
+    }
+
+    let is_script = output
+        .address
+        .payment_credential()
+        .is_some_and(|credential| credential.is_script());
+    let claim = ctx.change.iter().find(|claim| claim.output_index == index);
+    let is_change = match claim {
+        Some(ChangeClaim {
+            address: Ok(address),
+            ..
+        }) if *address == output.address => true,
+        Some(ChangeClaim {
+            address: Ok(_),
+            path,
+            ..
+        }) => {
+            text.push_str(&format!(
+                "\nclaimed as change, but not the address of {path}"
+            ));
+            false
+        }
+        Some(ChangeClaim {
+            address: Err(err),
+            path,
+            ..
+        }) => {
+            text.push_str(&format!("\nclaimed as change of {path}: {err}"));
+            false
+        }
+        None => false,
+    };
+    let title = if is_change { "Change" } else { "Output" };
+
+    let severity = if ctx.is_other_network(&output.address) || (claim.is_some() && !is_change) {
+        Severity::Warning
+    } else if is_script
+        || output.address.kind() == AddressKind::Byron
+        || output.datum.is_some()
+        || output.script_ref.is_some()
+    {
+        Severity::Unusual
+    } else {
+        Severity::Info
+    };
+    DisplayItem::new(title, text, severity)
+}
+
+fn certificate_item(certificate: &Certificate, ctx: &SummaryContext) -> DisplayItem {
+    use Certificate::*;
+
+    let title = "Certificate";
+    let stake = |credential| ctx.stake_address(credential);
+    let with_anchor = |text: String, anchor: &Option<_>| match anchor {
+        Some(super::body::Anchor { url, .. }) => format!("{text}\n{url}"),
+        None => text,
+    };
+    match certificate {
+        StakeRegistration(credential) => {
+            DisplayItem::info(title, format!("Register {}", stake(credential)))
+        }
+        StakeDeregistration(credential) => {
+            DisplayItem::unusual(title, format!("Deregister {}", stake(credential)))
+        }
+        StakeDelegation(credential, pool) => DisplayItem::info(
+            title,
+            format!("Delegate {} to {}", stake(credential), format_pool(pool)),
+        ),
+        PoolRetirement(pool, epoch) => DisplayItem::warning(
+            title,
+            format!("Retire {} at epoch {epoch}", format_pool(pool)),
+        ),
+        Registration(credential, deposit) => DisplayItem::info(
+            title,
+            format!(
+                "Register {}, deposit {}",
+                stake(credential),
+                format_ada(*deposit)
+            ),
+        ),
+        Unregistration(credential, refund) => DisplayItem::unusual(
+            title,
+            format!(
+                "Deregister {}, refund {}",
+                stake(credential),
+                format_ada(*refund)
+            ),
+        ),
+        VoteDelegation(credential, drep) => DisplayItem::info(
+            title,
+            format!(
+                "Delegate votes of {} to {}",
+                stake(credential),
+                format_drep(drep)
+            ),
+        ),
+        StakeVoteDelegation(credential, pool, drep) => DisplayItem::info(
+            title,
+            format!(
+                "Delegate {} to {} and its votes to {}",
+                stake(credential),
+                format_pool(pool),
+                format_drep(drep)
+            ),
+        ),
+        StakeRegistrationDelegation(credential, pool, deposit) => DisplayItem::info(
+            title,
+            format!(
+                "Register {}, deposit {}, and delegate to {}",
+                stake(credential),
+                format_ada(*deposit),
+                format_pool(pool)
+            ),
+        ),
+        VoteRegistrationDelegation(credential, drep, deposit) => DisplayItem::info(
+            title,
+            format!(
+                "Register {}, deposit {}, and delegate its votes to {}",
+                stake(credential),
+                format_ada(*deposit),
+                format_drep(drep)
+            ),
+        ),
+        StakeVoteRegistrationDelegation(credential, pool, drep, deposit) => DisplayItem::info(
+            title,
+            format!(
+                "Register {}, deposit {}, delegate to {} and its votes to {}",
+                stake(credential),
+                format_ada(*deposit),
+                format_pool(pool),
+                format_drep(drep)
+            ),
+        ),
+        DRepRegistration(credential, deposit, anchor) => DisplayItem::unusual(
+            title,
+            with_anchor(
+                format!(
+                    "Register DRep {}, deposit {}",
+                    format_drep_credential(credential),
+                    format_ada(*deposit)
+                ),
+                anchor,
+            ),
+        ),
+        DRepUnregistration(credential, refund) => DisplayItem::unusual(
+            title,
+            format!(
+                "Deregister DRep {}, refund {}",
+                format_drep_credential(credential),
+                format_ada(*refund)
+            ),
+        ),
+        DRepUpdate(credential, anchor) => DisplayItem::unusual(
+            title,
+            with_anchor(
+                format!("Update DRep {}", format_drep_credential(credential)),
+                anchor,
+            ),
+        ),
+        Other { tag, .. } => {
+            DisplayItem::warning(title, format!("certificate of type {tag} cannot be shown"))
+        }
+    }
+}
+
+/// Change of the funds of the signer: what its addresses receive, minus its
+/// inputs and the rewards it withdraws. `None` when nothing is known about
+/// the signer, unknown when no amount is given for any input of the body: a
+/// spend would be shown as a gain.
+fn net_change(body: &TxBody, ctx: &SummaryContext) -> Option<DisplayItem> {
+    if ctx.own_addresses.is_empty() && ctx.own_inputs.is_empty() && ctx.change.is_empty() {
+        return None;
+    }
+    let own_input = |input: &TransactionInput| ctx.own_inputs.iter().any(|(own, _)| own == input);
+    if !body.inputs.is_empty() && !body.inputs.iter().any(own_input) {
+        return Some(DisplayItem::unusual(
+            "Net change",
+            "unknown, no amount is given for the inputs of the signer",
+        ));
+    }
+    let received: i128 = body
+        .outputs
+        .iter()
+        .filter(|output| ctx.is_own(&output.address))
+        .map(|output| output.value.coin as i128)
+        .sum();
+    let spent: i128 = ctx
+        .own_inputs
+        .iter()
+        .filter(|(input, _)| body.inputs.contains(input))
+        .map(|(_, coin)| *coin as i128)
+        .sum();
+    let withdrawn: i128 = body
+        .withdrawals
+        .iter()
+        .filter(|(address, _)| ctx.is_own(address))
+        .map(|(_, coin)| *coin as i128)
+        .sum();
+    Some(DisplayItem::info(
+        "Net change",
+        format_ada_change(received - spent - withdrawn),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::address::{MAINNET, TESTNET};
+    use crate::tx::body::Anchor;
+    use crate::types::TxId;
+    use alloc::{string::ToString, vec};
+
+    fn input(byte: u8, index: u32) -> TransactionInput {
+        TransactionInput {
+            transaction_id: TxId::from_bytes(&[byte; 32]).unwrap(),
+            index,
+        }
+    }
+
+    #[test]
+    fn test_format_ada() {
+        assert_eq!(format_ada(0), "0.000000 ADA");
+        assert_eq!(format_ada(1_500_000), "1.500000 ADA");
+        assert_eq!(format_ada(170_001), "0.170001 ADA");
+        assert_eq!(format_ada_change(-2_000_000), "-2.000000 ADA");
+        assert_eq!(format_ada_change(42), "+0.000042 ADA");
+    }
+
+    #[test]
+    fn test_format_asset() {
+        use crate::tx::asset::label_prefix;
+
+        let policy_id = PolicyId::from([1; 28]);
+        let ft = [&label_prefix(333)[..], b"TOKEN"].concat();
+        assert_eq!(
+            format_asset(&policy_id, &ft, 500),
+            format!("{} (CIP-68 FT TOKEN) × 500", fingerprint(&policy_id, &ft))
+        );
+        let labeled = label_prefix(1);
+        assert_eq!(
+            format_asset(&policy_id, &labeled, 1),
+            format!("{} (CIP-67 label 1) × 1", fingerprint(&policy_id, &labeled))
+        );
+        assert_eq!(
+            format_asset(&policy_id, &[0xff, 0x00], 2),
+            format!("{} × 2", fingerprint(&policy_id, &[0xff, 0x00]))
+        );
+    }
+
+    #[test]
+    fn test_display_items() {
+        assert_eq!(
+            DisplayItem::input(&input(0xab, 3)).to_string(),
+            "Input: abababababababababababababababababababababababababababababababab#3"
+        );
+        assert_eq!(DisplayItem::fee(170_000).to_string(), "Fee: 0.170000 ADA");
+        assert_eq!(
+            DisplayItem::unusual("Valid until", "no expiry").to_string(),
+            "[unusual] Valid until: no expiry"
+        );
+    }
+
+    #[test]
+    fn test_payment_summary() {
+        let own = Address::enterprise(MAINNET, Credential::KeyHash([1; 28]));
+        let other = Address::enterprise(MAINNET, Credential::KeyHash([2; 28]));
+        let body = TxBody {
+            inputs: vec![input(0, 0), input(0, 1)],
+            outputs: vec![
+                TxOutput::new(other.clone(), 3_000_000),
+                TxOutput::new(own.clone(), 6_830_000),
+            ],
+            fee: 170_000,
+            ttl: Some(500),
+            ..TxBody::default()
+        };
+        let ctx = SummaryContext::new(MAINNET)
+            .with_own_addresses(&[own])
+            .with_own_inputs(&[(input(0, 0), 10_000_000), (input(9, 9), 1)]);
+        let summary = TxSummary::new(&body, &ctx);
+
+        let texts: Vec<_> = summary.items.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            texts[2],
+            format!("Output: 3.000000 ADA to {}", other.to_bech32())
+        );
+        assert_eq!(texts[4], "Fee: 0.170000 ADA");
+        assert_eq!(texts[5], "Valid until: slot 500");
+        assert_eq!(texts[6], "Net change: -3.170000 ADA");
+        assert_eq!(texts.len(), 7);
+        assert_eq!(summary.max_severity(), Severity::Info);
+
+        let inputs = [
+            (input(0, 0), Value::new(10_000_000)),
+            (input(0, 1), Value::new(0)),
+        ];
+        let balanced = SummaryContext::new(MAINNET).with_balance(&inputs[..1], Default::default());
+        let summary = TxSummary::new(&body, &balanced);
+        assert_eq!(
+            summary.items[5].to_string(),
+            format!(
+                "[warning] Balance: no amount for input {}#1",
+                "00".repeat(32)
+            )
+        );
+        let balanced = SummaryContext::new(MAINNET).with_balance(&inputs, Default::default());
+        let summary = TxSummary::new(&body, &balanced);
+        assert_eq!(summary.items.len(), 6);
+        assert_eq!(summary.max_severity(), Severity::Info);
+    }
+
+    #[test]
+    fn test_severities() {
+        let stake = Credential::KeyHash([1; 28]);
+        let script = Address::enterprise(MAINNET, Credential::ScriptHash([2; 28]));
+        let mut output = TxOutput::new(script, 2_000_000);
+        output
+            .value
+            .assets
+            .entry([3; 28].into())
+            .or_default()
+            .insert(b"NFT".to_vec(), 1);
+        let body = TxBody {
+            inputs: vec![input(0, 0)],
+            outputs: vec![
+                output,
+                TxOutput::new(Address::enterprise(TESTNET, stake), 1),
+            ],
+            certificates: vec![
+                Certificate::StakeDelegation(stake, [4; 28].into()),
+                Certificate::DRepUpdate(
+                    stake,
+                    Some(Anchor {
+                        url: "https://drep.example".into(),
+                        data_hash: [0; 32],
+                    }),
+                ),
+                Certificate::Other {
+                    tag: 3,
+                    cbor: vec![0x81, 0x03],
+                },
+            ],
+            mint: [([3; 28].into(), [(b"NFT".to_vec(), -1)].into())].into(),
+            other: vec![(19, vec![0xa0])],
+            ..TxBody::default()
+        };
+        let summary = TxSummary::new(&body, &SummaryContext::new(MAINNET));
+        let severities: Vec<_> = summary
+            .items
+            .iter()
+            .map(|item| (item.title, item.severity))
+            .collect();
+        assert_eq!(
+            severities,
+            [
+                ("Input", Severity::Info),
+                ("Output", Severity::Unusual),
+                ("Output", Severity::Warning),
+                ("Fee", Severity::Info),
+                ("Certificate", Severity::Info),
+                ("Certificate", Severity::Unusual),
+                ("Certificate", Severity::Warning),
+                ("Burn", Severity::Unusual),
+                ("Valid until", Severity::Unusual),
+                ("Votes", Severity::Unusual),
+            ]
+        );
+        assert_eq!(summary.max_severity(), Severity::Warning);
+
+        let nft = fingerprint(&[3; 28].into(), b"NFT");
+        assert!(summary.items[1]
+            .text
+            .ends_with(&format!("\n{nft} (NFT) × 1")));
+        assert_eq!(summary.items[7].text, format!("{nft} (NFT) × 1"));
+        assert!(summary.items[4].text.starts_with("Delegate stake1"));
+        assert!(summary.items[5].text.ends_with("\nhttps://drep.example"));
+    }
+
+    #[cfg(feature = "bip39-english")]
+    #[test]
+    fn test_change_outputs() {
+        use crate::util::slip14;
+
+        let key = |path: &DerivationPath| Some(slip14::make_keys_for(path).1);
+        let address = |path: &str| change::base_address(&path.parse().unwrap(), MAINNET, key);
+        let path = |path: &str| path.parse::<DerivationPath>().unwrap();
+
+        let other = Address::enterprise(MAINNET, Credential::KeyHash([2; 28]));
+        let body = TxBody {
+            inputs: vec![input(0, 0)],
+            outputs: vec![
+                TxOutput::new(other, 1_000_000),
+                TxOutput::new(address("m/1852'/1815'/0'/1/0").unwrap(), 2_000_000),
+                TxOutput::new(address("m/1852'/1815'/0'/1/1").unwrap(), 3_000_000),
+            ],
+            fee: 170_000,
+            ttl: Some(500),
+            ..TxBody::default()
+        };
+        let ctx = SummaryContext::new(MAINNET)
+            .with_change(0, &path("m/44'/1815'/0'/1/0"), key)
+            .with_change(1, &path("m/1852'/1815'/0'/1/0"), key)
+            .with_change(2, &path("m/1852'/1815'/0'/1/2"), key)
+            .with_change(3, &path("m/1852'/1815'/0'/1/0"), key);
+        let summary = TxSummary::new(&body, &ctx);
+
+        let outputs: Vec<_> = summary.items[1..5]
+            .iter()
+            .map(|item| (item.title, item.severity))
+            .collect();
+        assert_eq!(
+            outputs,
+            [
+                ("Output", Severity::Warning),
+                ("Change", Severity::Info),
+                ("Output", Severity::Warning),
+                ("Change", Severity::Warning),
+            ]
+        );
+        assert!(summary.items[1]
+            .text
+            .ends_with("claimed as change of m/44'/1815'/0'/1/0: not the path of a payment key"));
+        assert!(summary.items[3]
+            .text
+            .ends_with("claimed as change, but not the address of m/1852'/1815'/0'/1/2"));
+        assert_eq!(summary.items[4].text, "output 3 does not exist");
+        // the spent input is not known, neither is the net change
+        assert_eq!(
+            summary.items.last().unwrap().to_string(),
+            "[unusual] Net change: unknown, no amount is given for the inputs of the signer"
+        );
+        // only the verified change is counted as own
+        let summary = TxSummary::new(&body, &ctx.with_own_inputs(&[(input(0, 0), 5_170_000)]));
+        assert_eq!(
+            summary.items.last().unwrap().to_string(),
+            "Net change: -3.170000 ADA"
+        );
+    }
+}
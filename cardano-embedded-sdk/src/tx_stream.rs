@@ -7,6 +7,9 @@ pub enum TxEntry {
     TxInput(#[n(0)] Vec<u8>, #[n(1)] u32),
     #[n(1)]
     Fee(#[n(0)] u64),
+    /// address bytes and lovelace of an output
+    #[n(2)]
+    Output(#[n(0)] Vec<u8>, #[n(1)] u64),
 }
 
 #[derive(Clone, Debug, Encode, Decode)]
@@ -15,4 +18,13 @@ pub enum TxStream {
     Entry(#[n(0)] TxEntry),
     #[n(1)]
     Done(#[n(0)] Vec<u8>, #[n(1)] String), // password and key path
+    /// lovelace of a spent input, given by the host for the balance check,
+    /// it is not part of the body
+    #[n(2)]
+    InputAmount(#[n(0)] Vec<u8>, #[n(1)] u32, #[n(2)] u64),
+    /// what the host knows and the body does not, sent before the entries:
+    /// size in bytes of the signed transaction for the fee check, pools
+    /// already registered and deposits of the registered stake key hashes
+    #[n(3)]
+    Context(#[n(0)] u64, #[n(1)] Vec<Vec<u8>>, #[n(2)] Vec<(Vec<u8>, u64)>),
 }
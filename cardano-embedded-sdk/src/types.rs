@@ -1,13 +1,23 @@
 use crate::{
     bip::bip39::Entropy,
-    crypto::{Bip32PrivateKey, Bip32PublicKey, Ed25519KeyHash, Ed25519Signature},
+    chain_crypto::{
+        cip5::{Cip5, Prefix},
+        VerifyMode,
+    },
+    crypto::{
+        blake2b256, Bip32PrivateKey, Bip32PublicKey, Ed25519KeyHash, Ed25519Signature, PublicKey,
+    },
+    util::{nfkd, securemem},
 };
 
 use alloc::{
+    borrow::Cow,
     format,
     string::{String, ToString},
     vec::Vec,
 };
+use core::{error, fmt};
+use rand_core::{CryptoRng, RngCore};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -15,7 +25,7 @@ pub struct TxIdParseError {
     message: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TxId([u8; 32]);
 
 impl TxId {
@@ -47,11 +57,73 @@ impl TxId {
     pub fn to_hex(&self) -> String {
         hex::encode(self.0)
     }
+
+    /// Id of a transaction: Blake2b-256 of its body, hashed with the bytes
+    /// of `tx_cbor` so a body not encoded canonically keeps its id.
+    pub fn from_tx_cbor(tx_cbor: &[u8]) -> Result<TxId, TxIdParseError> {
+        tx_body_cbor(tx_cbor)
+            .map(Self::from_tx_body_cbor)
+            .map_err(|message| TxIdParseError {
+                message: message.to_string(),
+            })
+    }
+
+    /// Id of a transaction from the CBOR of its body.
+    pub fn from_tx_body_cbor(body_cbor: &[u8]) -> TxId {
+        TxId(blake2b256(body_cbor))
+    }
+}
+
+/// Bytes of the body, the first item of the transaction array, which has
+/// at least the body, the witness set and the validity flag or metadata.
+pub(crate) fn tx_body_cbor(tx_cbor: &[u8]) -> Result<&[u8], &'static str> {
+    use minicbor::data::Type;
+
+    const MIN_ITEMS: u64 = 3;
+    let too_short = "Transaction has less than 3 items";
+    let mut decoder = minicbor::Decoder::new(tx_cbor);
+    let invalid = |_| "Transaction is not valid CBOR";
+    let len = decoder.array().map_err(invalid)?;
+    if len.is_some_and(|len| len < MIN_ITEMS) {
+        return Err(too_short);
+    }
+    if len.is_none() && decoder.datatype().map_err(invalid)? == Type::Break {
+        return Err(too_short);
+    }
+    let start = decoder.position();
+    match decoder.datatype().map_err(invalid)? {
+        Type::Map | Type::MapIndef => decoder.skip().map_err(invalid)?,
+        _ => return Err("Transaction body is not a map"),
+    }
+    let end = decoder.position();
+
+    match len {
+        Some(len) => {
+            for _ in 1..len {
+                decoder.skip().map_err(invalid)?;
+            }
+        }
+        None => {
+            let mut items = 1;
+            while decoder.datatype().map_err(invalid)? != Type::Break {
+                decoder.skip().map_err(invalid)?;
+                items += 1;
+            }
+            if items < MIN_ITEMS {
+                return Err(too_short);
+            }
+            decoder.set_position(decoder.position() + 1);
+        }
+    }
+    if decoder.position() != tx_cbor.len() {
+        return Err("Trailing bytes after the transaction");
+    }
+    Ok(&tx_cbor[start..end])
 }
 
 type TransactionIndex = u32;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionInput {
     pub transaction_id: TxId,
     pub index: TransactionIndex,
@@ -65,13 +137,24 @@ pub struct KeyParseError {
 pub struct XPrvKey(Bip32PrivateKey);
 
 impl XPrvKey {
-    pub fn from_entropy(entropy: &Entropy, password: &[u8]) -> Self {
-        XPrvKey(Bip32PrivateKey::from_bip39_entropy(entropy, password))
+    /// root key of the wallet, fails if the password has characters of a
+    /// disabled `nfkd-*` feature, it would give the key of another wallet.
+    pub fn from_entropy(
+        entropy: &Entropy,
+        password: &[u8],
+    ) -> Result<Self, nfkd::UnsupportedError> {
+        nfkd::check_bytes(password)?;
+        let mut password = nfkd::normalize_bytes(password);
+        let key = XPrvKey(Bip32PrivateKey::from_bip39_entropy(entropy, &password));
+        if let Cow::Owned(password) = &mut password {
+            securemem::zero(password);
+        }
+        Ok(key)
     }
 
     pub fn to_hex(&self) -> String {
         let XPrvKey(key) = self;
-        hex::encode(key.as_bytes())
+        key.to_hex()
     }
 
     pub fn derive(&self, index: u32) -> Self {
@@ -87,8 +170,9 @@ impl XPrvKey {
         self.0.to_raw_key().sign(message)
     }
 
+    /// check, in constant time, that `pub_key` is the public key of this key.
     pub fn is_pair_of(&self, pub_key: &XPubKey) -> bool {
-        self.to_public().as_bytes() == pub_key.as_bytes()
+        self.to_public().0 .0.ct_eq(&pub_key.0 .0)
     }
 }
 
@@ -122,6 +206,35 @@ impl XPubKey {
         self.0.to_raw_key().verify(data, signature)
     }
 
+    /// Verify signature with explicit acceptance rules, e.g.
+    /// `VerifyMode::Libsodium` for the rules of the ledger.
+    pub fn verify_with_mode(
+        &self,
+        data: &[u8],
+        signature: &Ed25519Signature,
+        mode: VerifyMode,
+    ) -> bool {
+        self.0.to_raw_key().verify_with_mode(data, signature, mode)
+    }
+
+    /// Verify many signatures with the rules of `mode`, the results are the
+    /// ones of `verify_with_mode(.., mode)` in the same order as the entries.
+    ///
+    /// `VerifyMode::Zip215` signatures are checked at once, faster than one
+    /// call per entry; the other modes are checked one by one, see
+    /// [`chain_crypto::batch`](../chain_crypto/batch/index.html).
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        entries: &[(XPubKey, &[u8], Ed25519Signature)],
+        mode: VerifyMode,
+        rng: R,
+    ) -> Vec<bool> {
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|(key, data, signature)| (key.0.to_raw_key(), *data, signature.clone()))
+            .collect();
+        PublicKey::verify_batch(&entries, mode, rng)
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         self.0.as_bytes()
     }
@@ -133,20 +246,480 @@ impl XPubKey {
     pub fn hash_hex(&self) -> String {
         self.0.to_raw_key().hash().to_hex()
     }
+
+    /// position of the first candidate equal to this key. All the candidates
+    /// are compared in constant time, see
+    /// [`PublicKey::position_in`](../chain_crypto/key/struct.PublicKey.html#method.position_in).
+    pub fn position_in<I>(&self, candidates: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = XPubKey>,
+    {
+        self.0
+             .0
+            .position_in(candidates.into_iter().map(|key| key.0 .0))
+    }
+}
+
+/// Serialization layouts of CIP-16 for extended (BIP32-Ed25519) keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFormat {
+    /// key without chain code: `kL | kR` (64 bytes) or `A` (32 bytes), it
+    /// cannot be imported back as an extended key
+    Raw,
+    /// `kL | kR | cc` (96 bytes) or `A | cc` (64 bytes)
+    Extended,
+    /// `kL | kR | A | cc` (128 bytes), for software that cannot compute the
+    /// public key. Private keys only
+    Legacy128,
+    /// CBOR bytestring of the `cborHex` of cardano-cli key files:
+    /// `Legacy128` for private keys and `Extended` for public keys
+    Cbor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyFormatError {
+    InvalidSize {
+        expected: usize,
+        got: usize,
+    },
+    /// not a single CBOR bytestring
+    InvalidCbor,
+    /// the bytes are not a valid key
+    InvalidKey,
+    /// the embedded public key is not the one of the private key
+    PublicKeyMismatch,
+    /// raw keys have no chain code and cannot be imported as extended keys
+    MissingChainCode,
+    /// the format does not apply to this type of key
+    Unsupported(KeyFormat),
+}
+impl fmt::Display for KeyFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyFormatError::InvalidSize { expected, got } => {
+                write!(
+                    f,
+                    "invalid key size, expected {expected} but received {got} bytes"
+                )
+            }
+            KeyFormatError::InvalidCbor => write!(f, "key is not a CBOR bytestring"),
+            KeyFormatError::InvalidKey => write!(f, "invalid key"),
+            KeyFormatError::PublicKeyMismatch => {
+                write!(f, "embedded public key does not match the private key")
+            }
+            KeyFormatError::MissingChainCode => write!(f, "raw key has no chain code"),
+            KeyFormatError::Unsupported(format) => {
+                write!(f, "format {format:?} is not supported for this key")
+            }
+        }
+    }
+}
+impl error::Error for KeyFormatError {}
+
+fn check_size(bytes: &[u8], expected: usize) -> Result<(), KeyFormatError> {
+    match bytes.len() {
+        got if got == expected => Ok(()),
+        got => Err(KeyFormatError::InvalidSize { expected, got }),
+    }
 }
 
-/// Harden derivation index.
-pub fn harden(i: u32) -> u32 {
-    i + 0x80000000
+pub(crate) fn to_cbor_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = minicbor::Encoder::new(Vec::with_capacity(bytes.len() + 3));
+    encoder
+        .bytes(bytes)
+        .expect("writing to a Vec does not fail");
+    encoder.into_writer()
+}
+
+pub(crate) fn from_cbor_bytes(cbor: &[u8]) -> Result<&[u8], KeyFormatError> {
+    let mut decoder = minicbor::Decoder::new(cbor);
+    let bytes = decoder.bytes().map_err(|_| KeyFormatError::InvalidCbor)?;
+    if decoder.position() != cbor.len() {
+        return Err(KeyFormatError::InvalidCbor);
+    }
+    Ok(bytes)
+}
+
+impl XPrvKey {
+    /// Serialize the key in one of the CIP-16 layouts. The returned bytes
+    /// hold the private key, zero them once used.
+    pub fn to_format(&self, format: KeyFormat) -> Vec<u8> {
+        match format {
+            KeyFormat::Raw => self.0.to_raw_key().as_bytes(),
+            KeyFormat::Extended => self.0.as_bytes(),
+            KeyFormat::Legacy128 => self.0.to_128_xprv(),
+            KeyFormat::Cbor => {
+                let mut xprv = self.0.to_128_xprv();
+                let cbor = to_cbor_bytes(&xprv);
+                securemem::zero(&mut xprv);
+                cbor
+            }
+        }
+    }
+
+    /// Import a key serialized in one of the CIP-16 layouts, the embedded
+    /// public key of `Legacy128` and `Cbor` has to match the private key.
+    pub fn from_format(bytes: &[u8], format: KeyFormat) -> Result<Self, KeyFormatError> {
+        match format {
+            KeyFormat::Raw => {
+                check_size(bytes, 64)?;
+                Err(KeyFormatError::MissingChainCode)
+            }
+            KeyFormat::Extended => {
+                check_size(bytes, 96)?;
+                Bip32PrivateKey::from_bytes(bytes)
+                    .map(XPrvKey)
+                    .map_err(|_| KeyFormatError::InvalidKey)
+            }
+            KeyFormat::Legacy128 => {
+                check_size(bytes, 128)?;
+                Bip32PrivateKey::from_128_xprv(bytes)
+                    .map(XPrvKey)
+                    .map_err(|_| {
+                        // the extended secret key starts both layouts, when it
+                        // is valid the embedded public key is the wrong one
+                        match Bip32PrivateKey::from_bytes(&bytes[..96]) {
+                            Ok(_) => KeyFormatError::PublicKeyMismatch,
+                            Err(_) => KeyFormatError::InvalidKey,
+                        }
+                    })
+            }
+            KeyFormat::Cbor => Self::from_format(from_cbor_bytes(bytes)?, KeyFormat::Legacy128),
+        }
+    }
 }
 
-#[cfg(test)]
+impl XPubKey {
+    /// Serialize the key in one of the CIP-16 layouts, `Legacy128` is for
+    /// private keys only.
+    pub fn to_format(&self, format: KeyFormat) -> Result<Vec<u8>, KeyFormatError> {
+        match format {
+            KeyFormat::Raw => Ok(self.0.to_raw_key().as_bytes()),
+            KeyFormat::Extended => Ok(self.0.as_bytes()),
+            KeyFormat::Legacy128 => Err(KeyFormatError::Unsupported(format)),
+            KeyFormat::Cbor => Ok(to_cbor_bytes(&self.0.as_bytes())),
+        }
+    }
+
+    /// Import a key serialized in one of the CIP-16 layouts.
+    pub fn from_format(bytes: &[u8], format: KeyFormat) -> Result<Self, KeyFormatError> {
+        match format {
+            KeyFormat::Raw => {
+                check_size(bytes, 32)?;
+                Err(KeyFormatError::MissingChainCode)
+            }
+            KeyFormat::Extended => {
+                check_size(bytes, 64)?;
+                Bip32PublicKey::from_bytes(bytes)
+                    .map(XPubKey)
+                    .map_err(|_| KeyFormatError::InvalidKey)
+            }
+            KeyFormat::Legacy128 => Err(KeyFormatError::Unsupported(format)),
+            KeyFormat::Cbor => Self::from_format(from_cbor_bytes(bytes)?, KeyFormat::Extended),
+        }
+    }
+}
+
+impl Cip5 for XPrvKey {
+    const PREFIXES: &'static [Prefix] = Prefix::EXTENDED_SIGNING_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.0.cip5_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Bip32PrivateKey::from_cip5_bytes(bytes).map(XPrvKey)
+    }
+}
+
+impl Cip5 for XPubKey {
+    const PREFIXES: &'static [Prefix] = Prefix::EXTENDED_VERIFICATION_KEYS;
+
+    fn cip5_bytes(&self) -> Vec<u8> {
+        self.0.cip5_bytes()
+    }
+
+    fn from_cip5_bytes(bytes: &[u8]) -> Option<Self> {
+        Bip32PublicKey::from_cip5_bytes(bytes).map(XPubKey)
+    }
+}
+
+pub use crate::api::fixed::harden;
+
+#[cfg(all(test, feature = "bip39-english"))]
 mod tests {
+    use super::*;
+    use crate::chain_crypto::cip5::Error;
     use crate::util::slip14;
+    use bech32::{ToBase32, Variant};
 
     #[test]
     fn test_pair_check() {
         let (account_prv_key, account_pub_key) = slip14::make_address_keys();
         assert!(account_prv_key.is_pair_of(&account_pub_key))
     }
+
+    #[test]
+    fn test_key_formats_round_trip() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+        for format in [KeyFormat::Extended, KeyFormat::Legacy128, KeyFormat::Cbor] {
+            let bytes = prv_key.to_format(format);
+            let imported = XPrvKey::from_format(&bytes, format).unwrap();
+            assert_eq!(imported.to_hex(), prv_key.to_hex());
+        }
+        for format in [KeyFormat::Extended, KeyFormat::Cbor] {
+            let bytes = pub_key.to_format(format).unwrap();
+            let imported = XPubKey::from_format(&bytes, format).unwrap();
+            assert_eq!(imported.to_hex(), pub_key.to_hex());
+        }
+    }
+
+    #[test]
+    fn test_key_formats_layouts() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+        let extended = prv_key.to_format(KeyFormat::Extended);
+        let public = pub_key.to_format(KeyFormat::Extended).unwrap();
+        let raw = prv_key.to_format(KeyFormat::Raw);
+        assert_eq!(raw, extended[..64]);
+        assert_eq!(pub_key.to_format(KeyFormat::Raw).unwrap(), public[..32]);
+
+        let legacy = prv_key.to_format(KeyFormat::Legacy128);
+        assert_eq!(legacy[..64], extended[..64]);
+        assert_eq!(legacy[64..96], public[..32]);
+        assert_eq!(legacy[96..], extended[64..]);
+
+        // as in the `cborHex` of cardano-cli extended key files
+        let cbor = prv_key.to_format(KeyFormat::Cbor);
+        assert_eq!(cbor[..2], [0x58, 0x80]);
+        assert_eq!(cbor[2..], legacy[..]);
+        let cbor = pub_key.to_format(KeyFormat::Cbor).unwrap();
+        assert_eq!(cbor[..2], [0x58, 0x40]);
+        assert_eq!(cbor[2..], public[..]);
+    }
+
+    #[test]
+    fn test_tx_id_keeps_body_encoding() {
+        // same body `{0: []}`, with a definite and an indefinite length map
+        let canonical = hex::decode("84a10080a0f5f6").unwrap();
+        let indefinite = hex::decode("9fbf0080ffa0f5f6ff").unwrap();
+        assert_eq!(
+            TxId::from_tx_cbor(&canonical).unwrap().to_hex(),
+            hex::encode(blake2b256(&canonical[1..4]))
+        );
+        assert_eq!(
+            TxId::from_tx_cbor(&indefinite).unwrap().to_hex(),
+            hex::encode(blake2b256(&indefinite[1..5]))
+        );
+
+        assert!(TxId::from_tx_cbor(&canonical[..6]).is_err());
+        assert!(TxId::from_tx_cbor(&indefinite[..8]).is_err());
+        assert!(TxId::from_tx_cbor(&[&canonical[..], &[0]].concat()).is_err());
+        assert!(TxId::from_tx_cbor(&hex::decode("8280a0").unwrap()).is_err());
+        assert!(TxId::from_tx_cbor(&hex::decode("a0").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_tx_id_rejects_short_arrays() {
+        // the map must not be read from after the end of the array
+        for short in [
+            "80a0",
+            "81a0",
+            "82a0f6",
+            "9fff",
+            "9fa0ff",
+            "9fa080ff",
+            "80a080f5f6",
+        ] {
+            assert_eq!(
+                tx_body_cbor(&hex::decode(short).unwrap()).err(),
+                Some("Transaction has less than 3 items"),
+                "{short}"
+            );
+        }
+        assert!(TxId::from_tx_cbor(&hex::decode("83a0a0f6").unwrap()).is_ok());
+        assert!(TxId::from_tx_cbor(&hex::decode("9fa0a0f6ff").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_key_formats_errors() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+
+        let mut legacy = prv_key.to_format(KeyFormat::Legacy128);
+        legacy[70] ^= 1;
+        assert_eq!(
+            XPrvKey::from_format(&legacy, KeyFormat::Legacy128).err(),
+            Some(KeyFormatError::PublicKeyMismatch)
+        );
+        let mut cbor = prv_key.to_format(KeyFormat::Cbor);
+        cbor[70] ^= 1;
+        assert_eq!(
+            XPrvKey::from_format(&cbor, KeyFormat::Cbor).err(),
+            Some(KeyFormatError::PublicKeyMismatch)
+        );
+        assert!(Bip32PrivateKey::from_128_xprv(&legacy).is_err());
+        legacy[0] |= 1;
+        assert_eq!(
+            XPrvKey::from_format(&legacy, KeyFormat::Legacy128).err(),
+            Some(KeyFormatError::InvalidKey)
+        );
+        assert!(Bip32PrivateKey::from_128_xprv(&legacy[..100]).is_err());
+
+        let mut cbor = pub_key.to_format(KeyFormat::Cbor).unwrap();
+        cbor.push(0);
+        assert_eq!(
+            XPubKey::from_format(&cbor, KeyFormat::Cbor).err(),
+            Some(KeyFormatError::InvalidCbor)
+        );
+        assert_eq!(
+            XPrvKey::from_format(&prv_key.to_format(KeyFormat::Raw), KeyFormat::Raw).err(),
+            Some(KeyFormatError::MissingChainCode)
+        );
+        assert_eq!(
+            XPrvKey::from_format(&[0; 95], KeyFormat::Extended).err(),
+            Some(KeyFormatError::InvalidSize {
+                expected: 96,
+                got: 95
+            })
+        );
+        assert_eq!(
+            pub_key.to_format(KeyFormat::Legacy128),
+            Err(KeyFormatError::Unsupported(KeyFormat::Legacy128))
+        );
+    }
+
+    #[test]
+    fn test_cip5_round_trip() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+
+        let xsk = prv_key.to_bech32(Prefix::AddrXsk).unwrap();
+        assert!(xsk.starts_with("addr_xsk1"));
+        let decoded = XPrvKey::from_bech32(&xsk, Prefix::AddrXsk).unwrap();
+        assert_eq!(decoded.to_hex(), prv_key.to_hex());
+
+        let xvk = pub_key.to_bech32(Prefix::AcctXvk).unwrap();
+        let (decoded, prefix) = XPubKey::from_bech32_any(&xvk).unwrap();
+        assert_eq!(
+            (decoded.to_hex(), prefix),
+            (pub_key.to_hex(), Prefix::AcctXvk)
+        );
+    }
+
+    #[test]
+    fn test_cip5_wrong_prefix() {
+        let (prv_key, pub_key) = slip14::make_address_keys();
+        assert_eq!(
+            pub_key.to_bech32(Prefix::AddrXsk).err(),
+            Some(Error::PrefixNotAllowed(Prefix::AddrXsk))
+        );
+
+        let xvk = pub_key.to_bech32(Prefix::AddrXvk).unwrap();
+        assert_eq!(
+            XPubKey::from_bech32(&xvk, Prefix::StakeXvk).err(),
+            Some(Error::PrefixMismatch {
+                expected: Prefix::StakeXvk,
+                actual: Prefix::AddrXvk
+            })
+        );
+        assert_eq!(
+            XPrvKey::from_bech32_any(&xvk).err(),
+            Some(Error::PrefixNotAllowed(Prefix::AddrXvk))
+        );
+
+        let xsk = prv_key.to_bech32(Prefix::RootXsk).unwrap();
+        assert_eq!(
+            XPubKey::from_bech32(&xsk, Prefix::RootXsk).err(),
+            Some(Error::PrefixNotAllowed(Prefix::RootXsk))
+        );
+    }
+
+    #[test]
+    fn test_cip5_key_hash() {
+        let (_, pub_key) = slip14::make_address_keys();
+        let hash = pub_key.hash();
+        let encode = |hrp, variant| bech32::encode(hrp, hash.to_bytes().to_base32(), variant);
+        let vkh = hash.to_bech32(Prefix::AddrVkh).unwrap();
+        assert_eq!(vkh, encode("addr_vkh", Variant::Bech32).unwrap());
+        assert_eq!(
+            Ed25519KeyHash::from_bech32(&vkh, Prefix::AddrVkh).unwrap(),
+            hash
+        );
+        assert_eq!(
+            Ed25519KeyHash::from_bech32_any(&vkh).unwrap(),
+            (hash.clone(), Prefix::AddrVkh)
+        );
+        for (bech32_str, error) in [
+            (
+                encode("bc", Variant::Bech32).unwrap(),
+                Error::UnknownPrefix("bc".into()),
+            ),
+            (
+                encode("addr_vk", Variant::Bech32).unwrap(),
+                Error::PrefixNotAllowed(Prefix::AddrVk),
+            ),
+            (
+                encode("addr_vkh", Variant::Bech32m).unwrap(),
+                Error::Bech32Malformed,
+            ),
+        ] {
+            assert_eq!(Ed25519KeyHash::from_bech32_any(&bech32_str), Err(error));
+        }
+        assert_eq!(Ed25519KeyHash::from_hex(&hash.to_hex()).unwrap(), hash);
+        assert!(Ed25519KeyHash::from_hex("abcd").is_err());
+    }
+
+    #[test]
+    fn test_verify_batch_follows_mode() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaChaRng;
+
+        // cases 0 (small order key and R, S = 0) and 6 (S above the group
+        // order) of tests/ed25519-test-data/speccheck-cases.json
+        let small_order = (
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa",
+            "8c93255d71dcab10e8f379c26200f3c7bd5f09d9bc3068d3ef4edeb4853022b6",
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a0000000000000000000000000000000000000000000000000000000000000000",
+        );
+        let large_s = (
+            "442aad9f089ad9e14647b1ef9099a1ff4798d78589e66f28eca69c11f582a623",
+            "85e241a07d148b41e47d62c63f830dc7a6851a0b1f33ae4bb2f507fb6cffec40",
+            "e96f66be976d82e60150baecff9906684aebb1ef181f67a7189ac78ea23b6c0e547f7690a0e2ddcd04d87dbc3490dc19b3b3052f7ff0538cb68afb369ba3a514",
+        );
+        let key = |public: &str| {
+            let extended = hex::decode(alloc::format!("{public}{}", "00".repeat(32))).unwrap();
+            XPubKey::from_format(&extended, KeyFormat::Extended).unwrap()
+        };
+        let signature = |signature: &str| {
+            Ed25519Signature::from_bytes(hex::decode(signature).unwrap()).unwrap()
+        };
+        let batch = |cases: &[(&str, &str, &str)], mode| {
+            let msgs: Vec<_> = cases
+                .iter()
+                .map(|(_, msg, _)| hex::decode(msg).unwrap())
+                .collect();
+            let entries: Vec<_> = cases
+                .iter()
+                .zip(&msgs)
+                .map(|((public, _, sig), msg)| (key(public), msg.as_slice(), signature(sig)))
+                .collect();
+            XPubKey::verify_batch(&entries, mode, ChaChaRng::seed_from_u64(0))
+        };
+
+        for (case, expected) in [(small_order, true), (large_s, false)] {
+            let (public, msg, sig) = case;
+            let (key, msg, sig) = (key(public), hex::decode(msg).unwrap(), signature(sig));
+            assert_eq!(
+                key.verify_with_mode(&msg, &sig, VerifyMode::Zip215),
+                expected
+            );
+            assert!(!key.verify_with_mode(&msg, &sig, VerifyMode::Libsodium));
+            // `verify` accepts both
+            assert!(key.verify(&msg, &sig));
+        }
+        let zip215 = VerifyMode::Zip215;
+        assert_eq!(batch(&[small_order], zip215), [true]);
+        assert_eq!(batch(&[large_s], zip215), [false]);
+        assert_eq!(batch(&[small_order, large_s], zip215), [true, false]);
+        let libsodium = VerifyMode::Libsodium;
+        assert_eq!(batch(&[small_order, large_s], libsodium), [false, false]);
+    }
 }
@@ -0,0 +1,39 @@
+//! Constant time helpers
+//!
+//! Comparisons of keys, hashes and signatures should not leak, through the
+//! time they take, how many leading bytes matched. The lengths of the
+//! compared values are not considered secret.
+//!
+use cryptoxide::constant_time::CtEqual;
+
+/// compare the two slices in a time only depending on their length.
+pub fn eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.ct_eq(b).into()
+}
+
+/// return `a` if `choice` is true, `b` otherwise, without branching on
+/// `choice`.
+pub fn select(choice: bool, a: usize, b: usize) -> usize {
+    let mask = (choice as usize).wrapping_neg();
+    (a & mask) | (b & !mask)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn equality() {
+        assert!(eq(b"", b""));
+        assert!(eq(&[1, 2, 3], &[1, 2, 3]));
+        assert!(!eq(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!eq(&[1, 2, 3], &[1, 2]));
+    }
+
+    #[test]
+    fn selection() {
+        assert_eq!(select(true, 7, 42), 7);
+        assert_eq!(select(false, 7, 42), 42);
+        assert_eq!(select(true, usize::MAX, 0), usize::MAX);
+    }
+}
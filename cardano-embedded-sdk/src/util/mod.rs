@@ -1,5 +1,11 @@
+#[cfg(feature = "alloc")]
 pub mod base58;
+#[cfg(feature = "alloc")]
 pub mod bits;
+pub mod constant_time;
+pub mod nfkd;
+mod nfkd_tables;
 pub mod securemem;
+#[cfg(feature = "bip39-english")]
 pub mod slip14;
 pub mod try_from_slice;
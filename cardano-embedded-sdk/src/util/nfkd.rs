@@ -0,0 +1,414 @@
+//! Unicode NFKD normalization
+//!
+//! BIP39 requires the mnemonic sentence and the passphrase to be normalized
+//! to NFKD before being fed to PBKDF2, otherwise the same (visually) phrase
+//! or password may give a different seed. This module provides a `no_std`
+//! normalizer whose decomposition tables are split per script range, each
+//! behind a cargo feature to keep the flash footprint small:
+//!
+//! | feature               | code points                                         |
+//! | --------------------- | --------------------------------------------------- |
+//! | `nfkd-latin`          | Latin, combining marks, punctuation and symbols     |
+//! | `nfkd-greek-cyrillic` | Greek, Cyrillic                                     |
+//! | `nfkd-cjk`            | CJK symbols, kana, full/halfwidth forms, CJK compat |
+//! | `nfkd-hangul`         | Hangul syllables and compatibility jamo             |
+//! | `nfkd-other`          | everything else with a decomposition                |
+//!
+//! The BIP39 language features enable the script they need (for example
+//! `bip39-japanese` enables `nfkd-cjk`). Characters of a disabled script
+//! range are left untouched: use [`is_supported`](./fn.is_supported.html)
+//! or [`check`](./fn.check.html) to refuse such inputs rather than derive a
+//! wrong seed. The seed and key derivations of the SDK do so.
+//!
+//! The tables are generated from the `unicode-normalization` crate, see
+//! `tests/nfkd-tables-gen.rs`.
+//!
+//! # Example
+//!
+//! ```
+//! use cardano_embedded_sdk::util::nfkd;
+//!
+//! assert_eq!(nfkd::normalize("caf\u{e9}"), "cafe\u{301}");
+//! assert_eq!(nfkd::normalize("\u{ff21}\u{3000}\u{304c}"), "A \u{304b}\u{3099}");
+//!
+//! // without allocating
+//! let mut buf = [0; 16];
+//! assert_eq!(nfkd::normalize_into("caf\u{e9}", &mut buf), Some("cafe\u{301}"));
+//! ```
+//!
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{error, fmt, str};
+
+use super::nfkd_tables::*;
+
+/// version of the Unicode standard the tables were generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = super::nfkd_tables::UNICODE_VERSION;
+
+/// Script ranges of the decomposition tables
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Script {
+    /// code points without any compatibility decomposition (ASCII,
+    /// CJK unified ideographs...), always supported.
+    Common,
+    Latin,
+    GreekCyrillic,
+    Cjk,
+    Hangul,
+    Other,
+}
+impl Script {
+    /// true if the decompositions of this script are compiled in.
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Script::Common => true,
+            Script::Latin => cfg!(feature = "nfkd-latin"),
+            Script::GreekCyrillic => cfg!(feature = "nfkd-greek-cyrillic"),
+            Script::Cjk => cfg!(feature = "nfkd-cjk"),
+            Script::Hangul => cfg!(feature = "nfkd-hangul"),
+            Script::Other => cfg!(feature = "nfkd-other"),
+        }
+    }
+
+    /// (code point, offset, length) entries sorted by code point, and the
+    /// concatenated decompositions the offset and length refer to.
+    fn table(self) -> (&'static [(u32, u16, u8)], &'static str) {
+        match self {
+            #[cfg(feature = "nfkd-latin")]
+            Script::Latin => (LATIN_DECOMPOSITION, LATIN_DECOMPOSED),
+            #[cfg(feature = "nfkd-greek-cyrillic")]
+            Script::GreekCyrillic => (GREEK_CYRILLIC_DECOMPOSITION, GREEK_CYRILLIC_DECOMPOSED),
+            #[cfg(feature = "nfkd-cjk")]
+            Script::Cjk => (CJK_DECOMPOSITION, CJK_DECOMPOSED),
+            #[cfg(feature = "nfkd-hangul")]
+            Script::Hangul => (HANGUL_DECOMPOSITION, HANGUL_DECOMPOSED),
+            #[cfg(feature = "nfkd-other")]
+            Script::Other => (OTHER_DECOMPOSITION, OTHER_DECOMPOSED),
+            _ => (&[], ""),
+        }
+    }
+}
+
+/// script range of the given character.
+pub fn script_of(c: char) -> Script {
+    match c as u32 {
+        0x1100..=0x11ff | 0x3130..=0x318f | 0xac00..=0xd7a3 | 0xffa0..=0xffdc => Script::Hangul,
+        0x0080..=0x036f | 0x1d00..=0x1eff | 0x2000..=0x2bff | 0xfb00..=0xfb06 => Script::Latin,
+        0x0370..=0x052f | 0x1f00..=0x1fff => Script::GreekCyrillic,
+        0x2e80..=0x33ff
+        | 0xf900..=0xfaff
+        | 0xfe30..=0xfe4f
+        | 0xff00..=0xffef
+        | 0x1f100..=0x1f2ff
+        | 0x2f800..=0x2fa1f => Script::Cjk,
+        0x0530..=0x1cff
+        | 0x2c00..=0x2e7f
+        | 0xa000..=0xabff
+        | 0xfb07..=0xfeff
+        | 0x10000..=0x1ffff => Script::Other,
+        _ => Script::Common,
+    }
+}
+
+/// true if every character of `s` belongs to an enabled script range, i.e.
+/// [`normalize`](./fn.normalize.html) returns the exact NFKD form of `s`.
+pub fn is_supported(s: &str) -> bool {
+    check(s).is_ok()
+}
+
+/// Character of a disabled script range, its NFKD form is not compiled in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UnsupportedError(pub char);
+impl fmt::Display for UnsupportedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "U+{:04X} cannot be normalized, its nfkd feature is disabled",
+            self.0 as u32
+        )
+    }
+}
+impl error::Error for UnsupportedError {}
+
+/// like [`is_supported`](./fn.is_supported.html), with the first character
+/// of a disabled script range as error.
+pub fn check(s: &str) -> Result<(), UnsupportedError> {
+    match s.chars().find(|c| !script_of(*c).is_enabled()) {
+        Some(c) => Err(UnsupportedError(c)),
+        None => Ok(()),
+    }
+}
+
+/// [`check`](./fn.check.html) of a password given as bytes. Bytes that are
+/// not valid UTF-8 are not normalized, they are always supported.
+pub fn check_bytes(bytes: &[u8]) -> Result<(), UnsupportedError> {
+    match str::from_utf8(bytes) {
+        Ok(s) => check(s),
+        Err(_) => Ok(()),
+    }
+}
+
+/// canonical combining class of the given character.
+pub fn combining_class(c: char) -> u8 {
+    let cp = c as u32;
+    let i = COMBINING_CLASS.partition_point(|(start, _, _)| *start <= cp);
+    match i.checked_sub(1).map(|i| COMBINING_CLASS[i]) {
+        Some((start, len, class)) if cp <= start + len as u32 => class,
+        _ => 0,
+    }
+}
+
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = 21 * T_COUNT;
+const S_COUNT: u32 = 19 * N_COUNT;
+
+/// call `emit` with each character of the full compatibility decomposition
+/// of `c`.
+fn decompose(c: char, mut emit: impl FnMut(char)) {
+    let cp = c as u32;
+    let script = script_of(c);
+    if script == Script::Common || !script.is_enabled() {
+        emit(c);
+        return;
+    }
+
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        // Hangul syllables are decomposed algorithmically
+        let s = cp - S_BASE;
+        let jamo = [
+            L_BASE + s / N_COUNT,
+            V_BASE + (s % N_COUNT) / T_COUNT,
+            T_BASE + s % T_COUNT,
+        ];
+        let n = if jamo[2] == T_BASE { 2 } else { 3 };
+        // the jamo are valid code points by construction
+        jamo[..n]
+            .iter()
+            .for_each(|j| emit(char::from_u32(*j).unwrap()));
+        return;
+    }
+
+    let (entries, decomposed) = script.table();
+    match entries.binary_search_by_key(&cp, |(cp, _, _)| *cp) {
+        Ok(i) => {
+            let (_, offset, len) = entries[i];
+            let start = offset as usize;
+            decomposed[start..start + len as usize]
+                .chars()
+                .for_each(emit);
+        }
+        Err(_) => emit(c),
+    }
+}
+
+/// sort the runs of combining characters by combining class (stable).
+#[cfg(feature = "alloc")]
+fn reorder(chars: &mut [char]) {
+    let mut i = 0;
+    while i < chars.len() {
+        if combining_class(chars[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        // runs of combining marks are short, an insertion sort is fine
+        let run = &mut chars[start..i];
+        for j in 1..run.len() {
+            let mut k = j;
+            while k > 0 && combining_class(run[k - 1]) > combining_class(run[k]) {
+                run.swap(k - 1, k);
+                k -= 1;
+            }
+        }
+    }
+}
+
+/// NFKD normalization of `s`. ASCII strings are returned as is.
+#[cfg(feature = "alloc")]
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, |d| chars.push(d));
+    }
+    reorder(&mut chars);
+    // sized up front so that growing does not leave copies of a password
+    let mut normalized = String::with_capacity(chars.iter().map(|c| c.len_utf8()).sum());
+    normalized.extend(chars.iter());
+    // '\0' is a valid `char`, and the length is the number of `char` of
+    // the vector, as in `securemem::zero`.
+    unsafe { core::ptr::write_bytes(chars.as_mut_ptr(), 0, chars.len()) }
+    Cow::Owned(normalized)
+}
+
+/// NFKD normalization of a password given as bytes. Bytes that are not
+/// valid UTF-8 are returned as is.
+#[cfg(feature = "alloc")]
+pub fn normalize_bytes(bytes: &[u8]) -> Cow<'_, [u8]> {
+    match str::from_utf8(bytes) {
+        Ok(s) => match normalize(s) {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        },
+        Err(_) => Cow::Borrowed(bytes),
+    }
+}
+
+/// NFKD normalization of `s` in the caller's buffer, without allocating.
+/// Return `None` if the normalized string does not fit in `buf`.
+pub fn normalize_into<'a>(s: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let mut len = 0;
+    let mut fits = true;
+    for c in s.chars() {
+        decompose(c, |d| fits = fits && insert_ordered(buf, &mut len, d));
+        if !fits {
+            return None;
+        }
+    }
+    str::from_utf8(&buf[..len]).ok()
+}
+
+/// insert `c` at the end of the UTF-8 string `buf[..*len]`, before the
+/// trailing combining characters of a higher combining class (the same
+/// stable ordering as `reorder`).
+fn insert_ordered(buf: &mut [u8], len: &mut usize, c: char) -> bool {
+    let class = combining_class(c);
+    let mut at = *len;
+    while class != 0 && at > 0 {
+        // start of the previous character: skip the continuation bytes
+        let start = (0..at).rev().find(|i| buf[*i] & 0xc0 != 0x80).unwrap_or(0);
+        let prev = match str::from_utf8(&buf[start..at])
+            .ok()
+            .and_then(|p| p.chars().next())
+        {
+            Some(prev) => prev,
+            None => break,
+        };
+        if combining_class(prev) <= class {
+            break;
+        }
+        at = start;
+    }
+    let n = c.len_utf8();
+    if *len + n > buf.len() {
+        return false;
+    }
+    buf.copy_within(at..*len, at + n);
+    c.encode_utf8(&mut buf[at..at + n]);
+    *len += n;
+    true
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    extern crate unicode_normalization;
+    use self::unicode_normalization::UnicodeNormalization;
+
+    #[test]
+    fn ascii_is_borrowed() {
+        assert!(matches!(normalize("all all all"), Cow::Borrowed(_)));
+        assert!(matches!(normalize_bytes(b"\xff\xfe"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn combining_classes() {
+        assert_eq!(combining_class('a'), 0);
+        assert_eq!(combining_class('\u{301}'), 230);
+        assert_eq!(combining_class('\u{323}'), 220);
+        assert_eq!(combining_class('\u{3099}'), 8);
+    }
+
+    #[cfg(feature = "nfkd-latin")]
+    #[test]
+    fn canonical_ordering() {
+        // dot above (230) must come after dot below (220)
+        assert_eq!(normalize("\u{1e0b}\u{323}"), "d\u{323}\u{307}");
+        assert_eq!(normalize("\u{fb01}ance\u{301}e"), "fiance\u{301}e");
+    }
+
+    #[cfg(feature = "nfkd-latin")]
+    #[test]
+    fn normalize_in_buffer() {
+        let mut buf = [0; 16];
+        assert_eq!(normalize_into("all", &mut buf), Some("all"));
+        assert_eq!(
+            normalize_into("\u{1e0b}\u{323}", &mut buf),
+            Some("d\u{323}\u{307}")
+        );
+        assert_eq!(normalize_into("caf\u{e9}", &mut buf[..4]), None);
+        assert_eq!(
+            normalize_into("caf\u{e9}", &mut buf[..6]),
+            Some("cafe\u{301}")
+        );
+    }
+
+    #[cfg(feature = "nfkd-hangul")]
+    #[test]
+    fn hangul_syllables() {
+        assert_eq!(normalize("\u{ac00}"), "\u{1100}\u{1161}");
+        assert_eq!(normalize("\u{d7a3}"), "\u{1112}\u{1175}\u{11c2}");
+    }
+
+    #[test]
+    fn disabled_scripts_are_reported() {
+        assert!(is_supported("all all all"));
+        assert!(is_supported("\u{4e00}\u{4e8c}"));
+        assert_eq!(is_supported("\u{e9}"), cfg!(feature = "nfkd-latin"));
+        assert_eq!(is_supported("\u{304c}"), cfg!(feature = "nfkd-cjk"));
+        assert_eq!(check_bytes(b"\xff\xfe"), Ok(()));
+        if !cfg!(feature = "nfkd-latin") {
+            assert_eq!(check("caf\u{e9}"), Err(UnsupportedError('\u{e9}')));
+            assert_eq!(
+                check_bytes("caf\u{e9}".as_bytes()),
+                Err(UnsupportedError('\u{e9}'))
+            );
+        }
+    }
+
+    /// compare with the reference implementation for every code point of
+    /// the enabled scripts.
+    #[test]
+    fn matches_reference_implementation() {
+        // the dev-dependency is pinned to the release the tables come from
+        assert_eq!(
+            UNICODE_VERSION,
+            self::unicode_normalization::UNICODE_VERSION
+        );
+        for cp in 0..0x110000u32 {
+            let c = match char::from_u32(cp) {
+                Some(c) if script_of(c).is_enabled() => c,
+                _ => continue,
+            };
+            let s = c.to_string();
+            let expected = s.nfkd().collect::<String>();
+            assert_eq!(normalize(&s), expected, "U+{cp:04X}");
+            assert_eq!(normalize_into(&s, &mut [0; 64]), Some(expected.as_str()));
+            assert_eq!(
+                combining_class(c),
+                self::unicode_normalization::char::canonical_combining_class(c),
+                "U+{cp:04X}"
+            );
+        }
+        let mixed = "Ｔｈｅ ｃａｆé ｏｆ １０ ｍ² ﬁｎｅ ｇａ ｶﾞ ｷﾞ 한국어 ῷ ǅ";
+        if is_supported(mixed) {
+            let expected = mixed.nfkd().collect::<String>();
+            assert_eq!(normalize(mixed), expected);
+            assert_eq!(
+                normalize_into(mixed, &mut [0; 256]),
+                Some(expected.as_str())
+            );
+        }
+    }
+}
@@ -0,0 +1,6365 @@
+// @generated by tests/nfkd-tables-gen.rs, do not edit.
+
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// (first code point, number of code points - 1, class)
+#[rustfmt::skip]
+pub(super) const COMBINING_CLASS: &[(u32, u8, u8)] = &[
+    (0x300, 20, 230),
+    (0x315, 0, 232),
+    (0x316, 3, 220),
+    (0x31a, 0, 232),
+    (0x31b, 0, 216),
+    (0x31c, 4, 220),
+    (0x321, 1, 202),
+    (0x323, 3, 220),
+    (0x327, 1, 202),
+    (0x329, 10, 220),
+    (0x334, 4, 1),
+    (0x339, 3, 220),
+    (0x33d, 7, 230),
+    (0x345, 0, 240),
+    (0x346, 0, 230),
+    (0x347, 2, 220),
+    (0x34a, 2, 230),
+    (0x34d, 1, 220),
+    (0x350, 2, 230),
+    (0x353, 3, 220),
+    (0x357, 0, 230),
+    (0x358, 0, 232),
+    (0x359, 1, 220),
+    (0x35b, 0, 230),
+    (0x35c, 0, 233),
+    (0x35d, 1, 234),
+    (0x35f, 0, 233),
+    (0x360, 1, 234),
+    (0x362, 0, 233),
+    (0x363, 12, 230),
+    (0x483, 4, 230),
+    (0x591, 0, 220),
+    (0x592, 3, 230),
+    (0x596, 0, 220),
+    (0x597, 2, 230),
+    (0x59a, 0, 222),
+    (0x59b, 0, 220),
+    (0x59c, 5, 230),
+    (0x5a2, 5, 220),
+    (0x5a8, 1, 230),
+    (0x5aa, 0, 220),
+    (0x5ab, 1, 230),
+    (0x5ad, 0, 222),
+    (0x5ae, 0, 228),
+    (0x5af, 0, 230),
+    (0x5b0, 0, 10),
+    (0x5b1, 0, 11),
+    (0x5b2, 0, 12),
+    (0x5b3, 0, 13),
+    (0x5b4, 0, 14),
+    (0x5b5, 0, 15),
+    (0x5b6, 0, 16),
+    (0x5b7, 0, 17),
+    (0x5b8, 0, 18),
+    (0x5b9, 1, 19),
+    (0x5bb, 0, 20),
+    (0x5bc, 0, 21),
+    (0x5bd, 0, 22),
+    (0x5bf, 0, 23),
+    (0x5c1, 0, 24),
+    (0x5c2, 0, 25),
+    (0x5c4, 0, 230),
+    (0x5c5, 0, 220),
+    (0x5c7, 0, 18),
+    (0x610, 7, 230),
+    (0x618, 0, 30),
+    (0x619, 0, 31),
+    (0x61a, 0, 32),
+    (0x64b, 0, 27),
+    (0x64c, 0, 28),
+    (0x64d, 0, 29),
+    (0x64e, 0, 30),
+    (0x64f, 0, 31),
+    (0x650, 0, 32),
+    (0x651, 0, 33),
+    (0x652, 0, 34),
+    (0x653, 1, 230),
+    (0x655, 1, 220),
+    (0x657, 4, 230),
+    (0x65c, 0, 220),
+    (0x65d, 1, 230),
+    (0x65f, 0, 220),
+    (0x670, 0, 35),
+    (0x6d6, 6, 230),
+    (0x6df, 3, 230),
+    (0x6e3, 0, 220),
+    (0x6e4, 0, 230),
+    (0x6e7, 1, 230),
+    (0x6ea, 0, 220),
+    (0x6eb, 1, 230),
+    (0x6ed, 0, 220),
+    (0x711, 0, 36),
+    (0x730, 0, 230),
+    (0x731, 0, 220),
+    (0x732, 1, 230),
+    (0x734, 0, 220),
+    (0x735, 1, 230),
+    (0x737, 2, 220),
+    (0x73a, 0, 230),
+    (0x73b, 1, 220),
+    (0x73d, 0, 230),
+    (0x73e, 0, 220),
+    (0x73f, 2, 230),
+    (0x742, 0, 220),
+    (0x743, 0, 230),
+    (0x744, 0, 220),
+    (0x745, 0, 230),
+    (0x746, 0, 220),
+    (0x747, 0, 230),
+    (0x748, 0, 220),
+    (0x749, 1, 230),
+    (0x7eb, 6, 230),
+    (0x7f2, 0, 220),
+    (0x7f3, 0, 230),
+    (0x7fd, 0, 220),
+    (0x816, 3, 230),
+    (0x81b, 8, 230),
+    (0x825, 2, 230),
+    (0x829, 4, 230),
+    (0x859, 2, 220),
+    (0x897, 1, 230),
+    (0x899, 2, 220),
+    (0x89c, 3, 230),
+    (0x8ca, 4, 230),
+    (0x8cf, 4, 220),
+    (0x8d4, 13, 230),
+    (0x8e3, 0, 220),
+    (0x8e4, 1, 230),
+    (0x8e6, 0, 220),
+    (0x8e7, 1, 230),
+    (0x8e9, 0, 220),
+    (0x8ea, 2, 230),
+    (0x8ed, 2, 220),
+    (0x8f0, 0, 27),
+    (0x8f1, 0, 28),
+    (0x8f2, 0, 29),
+    (0x8f3, 2, 230),
+    (0x8f6, 0, 220),
+    (0x8f7, 1, 230),
+    (0x8f9, 1, 220),
+    (0x8fb, 4, 230),
+    (0x93c, 0, 7),
+    (0x94d, 0, 9),
+    (0x951, 0, 230),
+    (0x952, 0, 220),
+    (0x953, 1, 230),
+    (0x9bc, 0, 7),
+    (0x9cd, 0, 9),
+    (0x9fe, 0, 230),
+    (0xa3c, 0, 7),
+    (0xa4d, 0, 9),
+    (0xabc, 0, 7),
+    (0xacd, 0, 9),
+    (0xb3c, 0, 7),
+    (0xb4d, 0, 9),
+    (0xbcd, 0, 9),
+    (0xc3c, 0, 7),
+    (0xc4d, 0, 9),
+    (0xc55, 0, 84),
+    (0xc56, 0, 91),
+    (0xcbc, 0, 7),
+    (0xccd, 0, 9),
+    (0xd3b, 1, 9),
+    (0xd4d, 0, 9),
+    (0xdca, 0, 9),
+    (0xe38, 1, 103),
+    (0xe3a, 0, 9),
+    (0xe48, 3, 107),
+    (0xeb8, 1, 118),
+    (0xeba, 0, 9),
+    (0xec8, 3, 122),
+    (0xf18, 1, 220),
+    (0xf35, 0, 220),
+    (0xf37, 0, 220),
+    (0xf39, 0, 216),
+    (0xf71, 0, 129),
+    (0xf72, 0, 130),
+    (0xf74, 0, 132),
+    (0xf7a, 3, 130),
+    (0xf80, 0, 130),
+    (0xf82, 1, 230),
+    (0xf84, 0, 9),
+    (0xf86, 1, 230),
+    (0xfc6, 0, 220),
+    (0x1037, 0, 7),
+    (0x1039, 1, 9),
+    (0x108d, 0, 220),
+    (0x135d, 2, 230),
+    (0x1714, 1, 9),
+    (0x1734, 0, 9),
+    (0x17d2, 0, 9),
+    (0x17dd, 0, 230),
+    (0x18a9, 0, 228),
+    (0x1939, 0, 222),
+    (0x193a, 0, 230),
+    (0x193b, 0, 220),
+    (0x1a17, 0, 230),
+    (0x1a18, 0, 220),
+    (0x1a60, 0, 9),
+    (0x1a75, 7, 230),
+    (0x1a7f, 0, 220),
+    (0x1ab0, 4, 230),
+    (0x1ab5, 5, 220),
+    (0x1abb, 1, 230),
+    (0x1abd, 0, 220),
+    (0x1abf, 1, 220),
+    (0x1ac1, 1, 230),
+    (0x1ac3, 1, 220),
+    (0x1ac5, 4, 230),
+    (0x1aca, 0, 220),
+    (0x1acb, 17, 230),
+    (0x1add, 0, 220),
+    (0x1ae0, 5, 230),
+    (0x1ae6, 0, 220),
+    (0x1ae7, 3, 230),
+    (0x1aeb, 0, 234),
+    (0x1b34, 0, 7),
+    (0x1b44, 0, 9),
+    (0x1b6b, 0, 230),
+    (0x1b6c, 0, 220),
+    (0x1b6d, 6, 230),
+    (0x1baa, 1, 9),
+    (0x1be6, 0, 7),
+    (0x1bf2, 1, 9),
+    (0x1c37, 0, 7),
+    (0x1cd0, 2, 230),
+    (0x1cd4, 0, 1),
+    (0x1cd5, 4, 220),
+    (0x1cda, 1, 230),
+    (0x1cdc, 3, 220),
+    (0x1ce0, 0, 230),
+    (0x1ce2, 6, 1),
+    (0x1ced, 0, 220),
+    (0x1cf4, 0, 230),
+    (0x1cf8, 1, 230),
+    (0x1dc0, 1, 230),
+    (0x1dc2, 0, 220),
+    (0x1dc3, 6, 230),
+    (0x1dca, 0, 220),
+    (0x1dcb, 1, 230),
+    (0x1dcd, 0, 234),
+    (0x1dce, 0, 214),
+    (0x1dcf, 0, 220),
+    (0x1dd0, 0, 202),
+    (0x1dd1, 36, 230),
+    (0x1df6, 0, 232),
+    (0x1df7, 1, 228),
+    (0x1df9, 0, 220),
+    (0x1dfa, 0, 218),
+    (0x1dfb, 0, 230),
+    (0x1dfc, 0, 233),
+    (0x1dfd, 0, 220),
+    (0x1dfe, 0, 230),
+    (0x1dff, 0, 220),
+    (0x20d0, 1, 230),
+    (0x20d2, 1, 1),
+    (0x20d4, 3, 230),
+    (0x20d8, 2, 1),
+    (0x20db, 1, 230),
+    (0x20e1, 0, 230),
+    (0x20e5, 1, 1),
+    (0x20e7, 0, 230),
+    (0x20e8, 0, 220),
+    (0x20e9, 0, 230),
+    (0x20ea, 1, 1),
+    (0x20ec, 3, 220),
+    (0x20f0, 0, 230),
+    (0x2cef, 2, 230),
+    (0x2d7f, 0, 9),
+    (0x2de0, 31, 230),
+    (0x302a, 0, 218),
+    (0x302b, 0, 228),
+    (0x302c, 0, 232),
+    (0x302d, 0, 222),
+    (0x302e, 1, 224),
+    (0x3099, 1, 8),
+    (0xa66f, 0, 230),
+    (0xa674, 9, 230),
+    (0xa69e, 1, 230),
+    (0xa6f0, 1, 230),
+    (0xa806, 0, 9),
+    (0xa82c, 0, 9),
+    (0xa8c4, 0, 9),
+    (0xa8e0, 17, 230),
+    (0xa92b, 2, 220),
+    (0xa953, 0, 9),
+    (0xa9b3, 0, 7),
+    (0xa9c0, 0, 9),
+    (0xaab0, 0, 230),
+    (0xaab2, 1, 230),
+    (0xaab4, 0, 220),
+    (0xaab7, 1, 230),
+    (0xaabe, 1, 230),
+    (0xaac1, 0, 230),
+    (0xaaf6, 0, 9),
+    (0xabed, 0, 9),
+    (0xfb1e, 0, 26),
+    (0xfe20, 6, 230),
+    (0xfe27, 6, 220),
+    (0xfe2e, 1, 230),
+    (0x101fd, 0, 220),
+    (0x102e0, 0, 220),
+    (0x10376, 4, 230),
+    (0x10a0d, 0, 220),
+    (0x10a0f, 0, 230),
+    (0x10a38, 0, 230),
+    (0x10a39, 0, 1),
+    (0x10a3a, 0, 220),
+    (0x10a3f, 0, 9),
+    (0x10ae5, 0, 230),
+    (0x10ae6, 0, 220),
+    (0x10d24, 3, 230),
+    (0x10d69, 4, 230),
+    (0x10eab, 1, 230),
+    (0x10efa, 1, 220),
+    (0x10efd, 2, 220),
+    (0x10f46, 1, 220),
+    (0x10f48, 2, 230),
+    (0x10f4b, 0, 220),
+    (0x10f4c, 0, 230),
+    (0x10f4d, 3, 220),
+    (0x10f82, 0, 230),
+    (0x10f83, 0, 220),
+    (0x10f84, 0, 230),
+    (0x10f85, 0, 220),
+    (0x11046, 0, 9),
+    (0x11070, 0, 9),
+    (0x1107f, 0, 9),
+    (0x110b9, 0, 9),
+    (0x110ba, 0, 7),
+    (0x11100, 2, 230),
+    (0x11133, 1, 9),
+    (0x11173, 0, 7),
+    (0x111c0, 0, 9),
+    (0x111ca, 0, 7),
+    (0x11235, 0, 9),
+    (0x11236, 0, 7),
+    (0x112e9, 0, 7),
+    (0x112ea, 0, 9),
+    (0x1133b, 1, 7),
+    (0x1134d, 0, 9),
+    (0x11366, 6, 230),
+    (0x11370, 4, 230),
+    (0x113ce, 2, 9),
+    (0x11442, 0, 9),
+    (0x11446, 0, 7),
+    (0x1145e, 0, 230),
+    (0x114c2, 0, 9),
+    (0x114c3, 0, 7),
+    (0x115bf, 0, 9),
+    (0x115c0, 0, 7),
+    (0x1163f, 0, 9),
+    (0x116b6, 0, 9),
+    (0x116b7, 0, 7),
+    (0x1172b, 0, 9),
+    (0x11839, 0, 9),
+    (0x1183a, 0, 7),
+    (0x1193d, 1, 9),
+    (0x11943, 0, 7),
+    (0x119e0, 0, 9),
+    (0x11a34, 0, 9),
+    (0x11a47, 0, 9),
+    (0x11a99, 0, 9),
+    (0x11c3f, 0, 9),
+    (0x11d42, 0, 7),
+    (0x11d44, 1, 9),
+    (0x11d97, 0, 9),
+    (0x11f41, 1, 9),
+    (0x1612f, 0, 9),
+    (0x16af0, 4, 1),
+    (0x16b30, 6, 230),
+    (0x16ff0, 1, 6),
+    (0x1bc9e, 0, 1),
+    (0x1d165, 1, 216),
+    (0x1d167, 2, 1),
+    (0x1d16d, 0, 226),
+    (0x1d16e, 4, 216),
+    (0x1d17b, 7, 220),
+    (0x1d185, 4, 230),
+    (0x1d18a, 1, 220),
+    (0x1d1aa, 3, 230),
+    (0x1d242, 2, 230),
+    (0x1e000, 6, 230),
+    (0x1e008, 16, 230),
+    (0x1e01b, 6, 230),
+    (0x1e023, 1, 230),
+    (0x1e026, 4, 230),
+    (0x1e08f, 0, 230),
+    (0x1e130, 6, 230),
+    (0x1e2ae, 0, 230),
+    (0x1e2ec, 3, 230),
+    (0x1e4ec, 1, 232),
+    (0x1e4ee, 0, 220),
+    (0x1e4ef, 0, 230),
+    (0x1e5ee, 0, 230),
+    (0x1e5ef, 0, 220),
+    (0x1e6e3, 0, 230),
+    (0x1e6e6, 0, 230),
+    (0x1e6ee, 1, 230),
+    (0x1e6f5, 0, 230),
+    (0x1e8d0, 6, 220),
+    (0x1e944, 5, 230),
+    (0x1e94a, 0, 7),
+];
+
+#[cfg(feature = "nfkd-latin")]
+#[rustfmt::skip]
+pub(super) const LATIN_DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0xa0, 0, 1),
+    (0xa8, 1, 3),
+    (0xaa, 4, 1),
+    (0xaf, 5, 3),
+    (0xb2, 8, 1),
+    (0xb3, 9, 1),
+    (0xb4, 10, 3),
+    (0xb5, 13, 2),
+    (0xb8, 15, 3),
+    (0xb9, 18, 1),
+    (0xba, 19, 1),
+    (0xbc, 20, 5),
+    (0xbd, 25, 5),
+    (0xbe, 30, 5),
+    (0xc0, 35, 3),
+    (0xc1, 38, 3),
+    (0xc2, 41, 3),
+    (0xc3, 44, 3),
+    (0xc4, 47, 3),
+    (0xc5, 50, 3),
+    (0xc7, 53, 3),
+    (0xc8, 56, 3),
+    (0xc9, 59, 3),
+    (0xca, 62, 3),
+    (0xcb, 65, 3),
+    (0xcc, 68, 3),
+    (0xcd, 71, 3),
+    (0xce, 74, 3),
+    (0xcf, 77, 3),
+    (0xd1, 80, 3),
+    (0xd2, 83, 3),
+    (0xd3, 86, 3),
+    (0xd4, 89, 3),
+    (0xd5, 92, 3),
+    (0xd6, 95, 3),
+    (0xd9, 98, 3),
+    (0xda, 101, 3),
+    (0xdb, 104, 3),
+    (0xdc, 107, 3),
+    (0xdd, 110, 3),
+    (0xe0, 113, 3),
+    (0xe1, 116, 3),
+    (0xe2, 119, 3),
+    (0xe3, 122, 3),
+    (0xe4, 125, 3),
+    (0xe5, 128, 3),
+    (0xe7, 131, 3),
+    (0xe8, 134, 3),
+    (0xe9, 137, 3),
+    (0xea, 140, 3),
+    (0xeb, 143, 3),
+    (0xec, 146, 3),
+    (0xed, 149, 3),
+    (0xee, 152, 3),
+    (0xef, 155, 3),
+    (0xf1, 158, 3),
+    (0xf2, 161, 3),
+    (0xf3, 164, 3),
+    (0xf4, 167, 3),
+    (0xf5, 170, 3),
+    (0xf6, 173, 3),
+    (0xf9, 176, 3),
+    (0xfa, 179, 3),
+    (0xfb, 182, 3),
+    (0xfc, 185, 3),
+    (0xfd, 188, 3),
+    (0xff, 191, 3),
+    (0x100, 194, 3),
+    (0x101, 197, 3),
+    (0x102, 200, 3),
+    (0x103, 203, 3),
+    (0x104, 206, 3),
+    (0x105, 209, 3),
+    (0x106, 212, 3),
+    (0x107, 215, 3),
+    (0x108, 218, 3),
+    (0x109, 221, 3),
+    (0x10a, 224, 3),
+    (0x10b, 227, 3),
+    (0x10c, 230, 3),
+    (0x10d, 233, 3),
+    (0x10e, 236, 3),
+    (0x10f, 239, 3),
+    (0x112, 242, 3),
+    (0x113, 245, 3),
+    (0x114, 248, 3),
+    (0x115, 251, 3),
+    (0x116, 254, 3),
+    (0x117, 257, 3),
+    (0x118, 260, 3),
+    (0x119, 263, 3),
+    (0x11a, 266, 3),
+    (0x11b, 269, 3),
+    (0x11c, 272, 3),
+    (0x11d, 275, 3),
+    (0x11e, 278, 3),
+    (0x11f, 281, 3),
+    (0x120, 284, 3),
+    (0x121, 287, 3),
+    (0x122, 290, 3),
+    (0x123, 293, 3),
+    (0x124, 296, 3),
+    (0x125, 299, 3),
+    (0x128, 302, 3),
+    (0x129, 305, 3),
+    (0x12a, 308, 3),
+    (0x12b, 311, 3),
+    (0x12c, 314, 3),
+    (0x12d, 317, 3),
+    (0x12e, 320, 3),
+    (0x12f, 323, 3),
+    (0x130, 326, 3),
+    (0x132, 329, 2),
+    (0x133, 331, 2),
+    (0x134, 333, 3),
+    (0x135, 336, 3),
+    (0x136, 339, 3),
+    (0x137, 342, 3),
+    (0x139, 345, 3),
+    (0x13a, 348, 3),
+    (0x13b, 351, 3),
+    (0x13c, 354, 3),
+    (0x13d, 357, 3),
+    (0x13e, 360, 3),
+    (0x13f, 363, 3),
+    (0x140, 366, 3),
+    (0x143, 369, 3),
+    (0x144, 372, 3),
+    (0x145, 375, 3),
+    (0x146, 378, 3),
+    (0x147, 381, 3),
+    (0x148, 384, 3),
+    (0x149, 387, 3),
+    (0x14c, 390, 3),
+    (0x14d, 393, 3),
+    (0x14e, 396, 3),
+    (0x14f, 399, 3),
+    (0x150, 402, 3),
+    (0x151, 405, 3),
+    (0x154, 408, 3),
+    (0x155, 411, 3),
+    (0x156, 414, 3),
+    (0x157, 417, 3),
+    (0x158, 420, 3),
+    (0x159, 423, 3),
+    (0x15a, 426, 3),
+    (0x15b, 429, 3),
+    (0x15c, 432, 3),
+    (0x15d, 435, 3),
+    (0x15e, 438, 3),
+    (0x15f, 441, 3),
+    (0x160, 444, 3),
+    (0x161, 447, 3),
+    (0x162, 450, 3),
+    (0x163, 453, 3),
+    (0x164, 456, 3),
+    (0x165, 459, 3),
+    (0x168, 462, 3),
+    (0x169, 465, 3),
+    (0x16a, 468, 3),
+    (0x16b, 471, 3),
+    (0x16c, 474, 3),
+    (0x16d, 477, 3),
+    (0x16e, 480, 3),
+    (0x16f, 483, 3),
+    (0x170, 486, 3),
+    (0x171, 489, 3),
+    (0x172, 492, 3),
+    (0x173, 495, 3),
+    (0x174, 498, 3),
+    (0x175, 501, 3),
+    (0x176, 504, 3),
+    (0x177, 507, 3),
+    (0x178, 510, 3),
+    (0x179, 513, 3),
+    (0x17a, 516, 3),
+    (0x17b, 519, 3),
+    (0x17c, 522, 3),
+    (0x17d, 525, 3),
+    (0x17e, 528, 3),
+    (0x17f, 531, 1),
+    (0x1a0, 532, 3),
+    (0x1a1, 535, 3),
+    (0x1af, 538, 3),
+    (0x1b0, 541, 3),
+    (0x1c4, 544, 4),
+    (0x1c5, 548, 4),
+    (0x1c6, 552, 4),
+    (0x1c7, 556, 2),
+    (0x1c8, 558, 2),
+    (0x1c9, 560, 2),
+    (0x1ca, 562, 2),
+    (0x1cb, 564, 2),
+    (0x1cc, 566, 2),
+    (0x1cd, 568, 3),
+    (0x1ce, 571, 3),
+    (0x1cf, 574, 3),
+    (0x1d0, 577, 3),
+    (0x1d1, 580, 3),
+    (0x1d2, 583, 3),
+    (0x1d3, 586, 3),
+    (0x1d4, 589, 3),
+    (0x1d5, 592, 5),
+    (0x1d6, 597, 5),
+    (0x1d7, 602, 5),
+    (0x1d8, 607, 5),
+    (0x1d9, 612, 5),
+    (0x1da, 617, 5),
+    (0x1db, 622, 5),
+    (0x1dc, 627, 5),
+    (0x1de, 632, 5),
+    (0x1df, 637, 5),
+    (0x1e0, 642, 5),
+    (0x1e1, 647, 5),
+    (0x1e2, 652, 4),
+    (0x1e3, 656, 4),
+    (0x1e6, 660, 3),
+    (0x1e7, 663, 3),
+    (0x1e8, 666, 3),
+    (0x1e9, 669, 3),
+    (0x1ea, 672, 3),
+    (0x1eb, 675, 3),
+    (0x1ec, 678, 5),
+    (0x1ed, 683, 5),
+    (0x1ee, 688, 4),
+    (0x1ef, 692, 4),
+    (0x1f0, 696, 3),
+    (0x1f1, 699, 2),
+    (0x1f2, 701, 2),
+    (0x1f3, 703, 2),
+    (0x1f4, 705, 3),
+    (0x1f5, 708, 3),
+    (0x1f8, 711, 3),
+    (0x1f9, 714, 3),
+    (0x1fa, 717, 5),
+    (0x1fb, 722, 5),
+    (0x1fc, 727, 4),
+    (0x1fd, 731, 4),
+    (0x1fe, 735, 4),
+    (0x1ff, 739, 4),
+    (0x200, 743, 3),
+    (0x201, 746, 3),
+    (0x202, 749, 3),
+    (0x203, 752, 3),
+    (0x204, 755, 3),
+    (0x205, 758, 3),
+    (0x206, 761, 3),
+    (0x207, 764, 3),
+    (0x208, 767, 3),
+    (0x209, 770, 3),
+    (0x20a, 773, 3),
+    (0x20b, 776, 3),
+    (0x20c, 779, 3),
+    (0x20d, 782, 3),
+    (0x20e, 785, 3),
+    (0x20f, 788, 3),
+    (0x210, 791, 3),
+    (0x211, 794, 3),
+    (0x212, 797, 3),
+    (0x213, 800, 3),
+    (0x214, 803, 3),
+    (0x215, 806, 3),
+    (0x216, 809, 3),
+    (0x217, 812, 3),
+    (0x218, 815, 3),
+    (0x219, 818, 3),
+    (0x21a, 821, 3),
+    (0x21b, 824, 3),
+    (0x21e, 827, 3),
+    (0x21f, 830, 3),
+    (0x226, 833, 3),
+    (0x227, 836, 3),
+    (0x228, 839, 3),
+    (0x229, 842, 3),
+    (0x22a, 845, 5),
+    (0x22b, 850, 5),
+    (0x22c, 855, 5),
+    (0x22d, 860, 5),
+    (0x22e, 865, 3),
+    (0x22f, 868, 3),
+    (0x230, 871, 5),
+    (0x231, 876, 5),
+    (0x232, 881, 3),
+    (0x233, 884, 3),
+    (0x2b0, 887, 1),
+    (0x2b1, 888, 2),
+    (0x2b2, 890, 1),
+    (0x2b3, 891, 1),
+    (0x2b4, 892, 2),
+    (0x2b5, 894, 2),
+    (0x2b6, 896, 2),
+    (0x2b7, 898, 1),
+    (0x2b8, 899, 1),
+    (0x2d8, 900, 3),
+    (0x2d9, 903, 3),
+    (0x2da, 906, 3),
+    (0x2db, 909, 3),
+    (0x2dc, 912, 3),
+    (0x2dd, 915, 3),
+    (0x2e0, 918, 2),
+    (0x2e1, 920, 1),
+    (0x2e2, 921, 1),
+    (0x2e3, 922, 1),
+    (0x2e4, 923, 2),
+    (0x340, 925, 2),
+    (0x341, 927, 2),
+    (0x343, 929, 2),
+    (0x344, 931, 4),
+    (0x1d2c, 935, 1),
+    (0x1d2d, 936, 2),
+    (0x1d2e, 938, 1),
+    (0x1d30, 939, 1),
+    (0x1d31, 940, 1),
+    (0x1d32, 941, 2),
+    (0x1d33, 943, 1),
+    (0x1d34, 944, 1),
+    (0x1d35, 945, 1),
+    (0x1d36, 946, 1),
+    (0x1d37, 947, 1),
+    (0x1d38, 948, 1),
+    (0x1d39, 949, 1),
+    (0x1d3a, 950, 1),
+    (0x1d3c, 951, 1),
+    (0x1d3d, 952, 2),
+    (0x1d3e, 954, 1),
+    (0x1d3f, 955, 1),
+    (0x1d40, 956, 1),
+    (0x1d41, 957, 1),
+    (0x1d42, 958, 1),
+    (0x1d43, 959, 1),
+    (0x1d44, 960, 2),
+    (0x1d45, 962, 2),
+    (0x1d46, 964, 3),
+    (0x1d47, 967, 1),
+    (0x1d48, 968, 1),
+    (0x1d49, 969, 1),
+    (0x1d4a, 970, 2),
+    (0x1d4b, 972, 2),
+    (0x1d4c, 974, 2),
+    (0x1d4d, 976, 1),
+    (0x1d4f, 977, 1),
+    (0x1d50, 978, 1),
+    (0x1d51, 979, 2),
+    (0x1d52, 981, 1),
+    (0x1d53, 982, 2),
+    (0x1d54, 984, 3),
+    (0x1d55, 987, 3),
+    (0x1d56, 990, 1),
+    (0x1d57, 991, 1),
+    (0x1d58, 992, 1),
+    (0x1d59, 993, 3),
+    (0x1d5a, 996, 2),
+    (0x1d5b, 998, 1),
+    (0x1d5c, 999, 3),
+    (0x1d5d, 1002, 2),
+    (0x1d5e, 1004, 2),
+    (0x1d5f, 1006, 2),
+    (0x1d60, 1008, 2),
+    (0x1d61, 1010, 2),
+    (0x1d62, 1012, 1),
+    (0x1d63, 1013, 1),
+    (0x1d64, 1014, 1),
+    (0x1d65, 1015, 1),
+    (0x1d66, 1016, 2),
+    (0x1d67, 1018, 2),
+    (0x1d68, 1020, 2),
+    (0x1d69, 1022, 2),
+    (0x1d6a, 1024, 2),
+    (0x1d78, 1026, 2),
+    (0x1d9b, 1028, 2),
+    (0x1d9c, 1030, 1),
+    (0x1d9d, 1031, 2),
+    (0x1d9e, 1033, 2),
+    (0x1d9f, 1035, 2),
+    (0x1da0, 1037, 1),
+    (0x1da1, 1038, 2),
+    (0x1da2, 1040, 2),
+    (0x1da3, 1042, 2),
+    (0x1da4, 1044, 2),
+    (0x1da5, 1046, 2),
+    (0x1da6, 1048, 2),
+    (0x1da7, 1050, 3),
+    (0x1da8, 1053, 2),
+    (0x1da9, 1055, 2),
+    (0x1daa, 1057, 3),
+    (0x1dab, 1060, 2),
+    (0x1dac, 1062, 2),
+    (0x1dad, 1064, 2),
+    (0x1dae, 1066, 2),
+    (0x1daf, 1068, 2),
+    (0x1db0, 1070, 2),
+    (0x1db1, 1072, 2),
+    (0x1db2, 1074, 2),
+    (0x1db3, 1076, 2),
+    (0x1db4, 1078, 2),
+    (0x1db5, 1080, 2),
+    (0x1db6, 1082, 2),
+    (0x1db7, 1084, 2),
+    (0x1db8, 1086, 3),
+    (0x1db9, 1089, 2),
+    (0x1dba, 1091, 2),
+    (0x1dbb, 1093, 1),
+    (0x1dbc, 1094, 2),
+    (0x1dbd, 1096, 2),
+    (0x1dbe, 1098, 2),
+    (0x1dbf, 1100, 2),
+    (0x1e00, 1102, 3),
+    (0x1e01, 1105, 3),
+    (0x1e02, 1108, 3),
+    (0x1e03, 1111, 3),
+    (0x1e04, 1114, 3),
+    (0x1e05, 1117, 3),
+    (0x1e06, 1120, 3),
+    (0x1e07, 1123, 3),
+    (0x1e08, 1126, 5),
+    (0x1e09, 1131, 5),
+    (0x1e0a, 1136, 3),
+    (0x1e0b, 1139, 3),
+    (0x1e0c, 1142, 3),
+    (0x1e0d, 1145, 3),
+    (0x1e0e, 1148, 3),
+    (0x1e0f, 1151, 3),
+    (0x1e10, 1154, 3),
+    (0x1e11, 1157, 3),
+    (0x1e12, 1160, 3),
+    (0x1e13, 1163, 3),
+    (0x1e14, 1166, 5),
+    (0x1e15, 1171, 5),
+    (0x1e16, 1176, 5),
+    (0x1e17, 1181, 5),
+    (0x1e18, 1186, 3),
+    (0x1e19, 1189, 3),
+    (0x1e1a, 1192, 3),
+    (0x1e1b, 1195, 3),
+    (0x1e1c, 1198, 5),
+    (0x1e1d, 1203, 5),
+    (0x1e1e, 1208, 3),
+    (0x1e1f, 1211, 3),
+    (0x1e20, 1214, 3),
+    (0x1e21, 1217, 3),
+    (0x1e22, 1220, 3),
+    (0x1e23, 1223, 3),
+    (0x1e24, 1226, 3),
+    (0x1e25, 1229, 3),
+    (0x1e26, 1232, 3),
+    (0x1e27, 1235, 3),
+    (0x1e28, 1238, 3),
+    (0x1e29, 1241, 3),
+    (0x1e2a, 1244, 3),
+    (0x1e2b, 1247, 3),
+    (0x1e2c, 1250, 3),
+    (0x1e2d, 1253, 3),
+    (0x1e2e, 1256, 5),
+    (0x1e2f, 1261, 5),
+    (0x1e30, 1266, 3),
+    (0x1e31, 1269, 3),
+    (0x1e32, 1272, 3),
+    (0x1e33, 1275, 3),
+    (0x1e34, 1278, 3),
+    (0x1e35, 1281, 3),
+    (0x1e36, 1284, 3),
+    (0x1e37, 1287, 3),
+    (0x1e38, 1290, 5),
+    (0x1e39, 1295, 5),
+    (0x1e3a, 1300, 3),
+    (0x1e3b, 1303, 3),
+    (0x1e3c, 1306, 3),
+    (0x1e3d, 1309, 3),
+    (0x1e3e, 1312, 3),
+    (0x1e3f, 1315, 3),
+    (0x1e40, 1318, 3),
+    (0x1e41, 1321, 3),
+    (0x1e42, 1324, 3),
+    (0x1e43, 1327, 3),
+    (0x1e44, 1330, 3),
+    (0x1e45, 1333, 3),
+    (0x1e46, 1336, 3),
+    (0x1e47, 1339, 3),
+    (0x1e48, 1342, 3),
+    (0x1e49, 1345, 3),
+    (0x1e4a, 1348, 3),
+    (0x1e4b, 1351, 3),
+    (0x1e4c, 1354, 5),
+    (0x1e4d, 1359, 5),
+    (0x1e4e, 1364, 5),
+    (0x1e4f, 1369, 5),
+    (0x1e50, 1374, 5),
+    (0x1e51, 1379, 5),
+    (0x1e52, 1384, 5),
+    (0x1e53, 1389, 5),
+    (0x1e54, 1394, 3),
+    (0x1e55, 1397, 3),
+    (0x1e56, 1400, 3),
+    (0x1e57, 1403, 3),
+    (0x1e58, 1406, 3),
+    (0x1e59, 1409, 3),
+    (0x1e5a, 1412, 3),
+    (0x1e5b, 1415, 3),
+    (0x1e5c, 1418, 5),
+    (0x1e5d, 1423, 5),
+    (0x1e5e, 1428, 3),
+    (0x1e5f, 1431, 3),
+    (0x1e60, 1434, 3),
+    (0x1e61, 1437, 3),
+    (0x1e62, 1440, 3),
+    (0x1e63, 1443, 3),
+    (0x1e64, 1446, 5),
+    (0x1e65, 1451, 5),
+    (0x1e66, 1456, 5),
+    (0x1e67, 1461, 5),
+    (0x1e68, 1466, 5),
+    (0x1e69, 1471, 5),
+    (0x1e6a, 1476, 3),
+    (0x1e6b, 1479, 3),
+    (0x1e6c, 1482, 3),
+    (0x1e6d, 1485, 3),
+    (0x1e6e, 1488, 3),
+    (0x1e6f, 1491, 3),
+    (0x1e70, 1494, 3),
+    (0x1e71, 1497, 3),
+    (0x1e72, 1500, 3),
+    (0x1e73, 1503, 3),
+    (0x1e74, 1506, 3),
+    (0x1e75, 1509, 3),
+    (0x1e76, 1512, 3),
+    (0x1e77, 1515, 3),
+    (0x1e78, 1518, 5),
+    (0x1e79, 1523, 5),
+    (0x1e7a, 1528, 5),
+    (0x1e7b, 1533, 5),
+    (0x1e7c, 1538, 3),
+    (0x1e7d, 1541, 3),
+    (0x1e7e, 1544, 3),
+    (0x1e7f, 1547, 3),
+    (0x1e80, 1550, 3),
+    (0x1e81, 1553, 3),
+    (0x1e82, 1556, 3),
+    (0x1e83, 1559, 3),
+    (0x1e84, 1562, 3),
+    (0x1e85, 1565, 3),
+    (0x1e86, 1568, 3),
+    (0x1e87, 1571, 3),
+    (0x1e88, 1574, 3),
+    (0x1e89, 1577, 3),
+    (0x1e8a, 1580, 3),
+    (0x1e8b, 1583, 3),
+    (0x1e8c, 1586, 3),
+    (0x1e8d, 1589, 3),
+    (0x1e8e, 1592, 3),
+    (0x1e8f, 1595, 3),
+    (0x1e90, 1598, 3),
+    (0x1e91, 1601, 3),
+    (0x1e92, 1604, 3),
+    (0x1e93, 1607, 3),
+    (0x1e94, 1610, 3),
+    (0x1e95, 1613, 3),
+    (0x1e96, 1616, 3),
+    (0x1e97, 1619, 3),
+    (0x1e98, 1622, 3),
+    (0x1e99, 1625, 3),
+    (0x1e9a, 1628, 3),
+    (0x1e9b, 1631, 3),
+    (0x1ea0, 1634, 3),
+    (0x1ea1, 1637, 3),
+    (0x1ea2, 1640, 3),
+    (0x1ea3, 1643, 3),
+    (0x1ea4, 1646, 5),
+    (0x1ea5, 1651, 5),
+    (0x1ea6, 1656, 5),
+    (0x1ea7, 1661, 5),
+    (0x1ea8, 1666, 5),
+    (0x1ea9, 1671, 5),
+    (0x1eaa, 1676, 5),
+    (0x1eab, 1681, 5),
+    (0x1eac, 1686, 5),
+    (0x1ead, 1691, 5),
+    (0x1eae, 1696, 5),
+    (0x1eaf, 1701, 5),
+    (0x1eb0, 1706, 5),
+    (0x1eb1, 1711, 5),
+    (0x1eb2, 1716, 5),
+    (0x1eb3, 1721, 5),
+    (0x1eb4, 1726, 5),
+    (0x1eb5, 1731, 5),
+    (0x1eb6, 1736, 5),
+    (0x1eb7, 1741, 5),
+    (0x1eb8, 1746, 3),
+    (0x1eb9, 1749, 3),
+    (0x1eba, 1752, 3),
+    (0x1ebb, 1755, 3),
+    (0x1ebc, 1758, 3),
+    (0x1ebd, 1761, 3),
+    (0x1ebe, 1764, 5),
+    (0x1ebf, 1769, 5),
+    (0x1ec0, 1774, 5),
+    (0x1ec1, 1779, 5),
+    (0x1ec2, 1784, 5),
+    (0x1ec3, 1789, 5),
+    (0x1ec4, 1794, 5),
+    (0x1ec5, 1799, 5),
+    (0x1ec6, 1804, 5),
+    (0x1ec7, 1809, 5),
+    (0x1ec8, 1814, 3),
+    (0x1ec9, 1817, 3),
+    (0x1eca, 1820, 3),
+    (0x1ecb, 1823, 3),
+    (0x1ecc, 1826, 3),
+    (0x1ecd, 1829, 3),
+    (0x1ece, 1832, 3),
+    (0x1ecf, 1835, 3),
+    (0x1ed0, 1838, 5),
+    (0x1ed1, 1843, 5),
+    (0x1ed2, 1848, 5),
+    (0x1ed3, 1853, 5),
+    (0x1ed4, 1858, 5),
+    (0x1ed5, 1863, 5),
+    (0x1ed6, 1868, 5),
+    (0x1ed7, 1873, 5),
+    (0x1ed8, 1878, 5),
+    (0x1ed9, 1883, 5),
+    (0x1eda, 1888, 5),
+    (0x1edb, 1893, 5),
+    (0x1edc, 1898, 5),
+    (0x1edd, 1903, 5),
+    (0x1ede, 1908, 5),
+    (0x1edf, 1913, 5),
+    (0x1ee0, 1918, 5),
+    (0x1ee1, 1923, 5),
+    (0x1ee2, 1928, 5),
+    (0x1ee3, 1933, 5),
+    (0x1ee4, 1938, 3),
+    (0x1ee5, 1941, 3),
+    (0x1ee6, 1944, 3),
+    (0x1ee7, 1947, 3),
+    (0x1ee8, 1950, 5),
+    (0x1ee9, 1955, 5),
+    (0x1eea, 1960, 5),
+    (0x1eeb, 1965, 5),
+    (0x1eec, 1970, 5),
+    (0x1eed, 1975, 5),
+    (0x1eee, 1980, 5),
+    (0x1eef, 1985, 5),
+    (0x1ef0, 1990, 5),
+    (0x1ef1, 1995, 5),
+    (0x1ef2, 2000, 3),
+    (0x1ef3, 2003, 3),
+    (0x1ef4, 2006, 3),
+    (0x1ef5, 2009, 3),
+    (0x1ef6, 2012, 3),
+    (0x1ef7, 2015, 3),
+    (0x1ef8, 2018, 3),
+    (0x1ef9, 2021, 3),
+    (0x2000, 2024, 1),
+    (0x2001, 2025, 1),
+    (0x2002, 2026, 1),
+    (0x2003, 2027, 1),
+    (0x2004, 2028, 1),
+    (0x2005, 2029, 1),
+    (0x2006, 2030, 1),
+    (0x2007, 2031, 1),
+    (0x2008, 2032, 1),
+    (0x2009, 2033, 1),
+    (0x200a, 2034, 1),
+    (0x2011, 2035, 3),
+    (0x2017, 2038, 3),
+    (0x2024, 2041, 1),
+    (0x2025, 2042, 2),
+    (0x2026, 2044, 3),
+    (0x202f, 2047, 1),
+    (0x2033, 2048, 6),
+    (0x2034, 2054, 9),
+    (0x2036, 2063, 6),
+    (0x2037, 2069, 9),
+    (0x203c, 2078, 2),
+    (0x203e, 2080, 3),
+    (0x2047, 2083, 2),
+    (0x2048, 2085, 2),
+    (0x2049, 2087, 2),
+    (0x2057, 2089, 12),
+    (0x205f, 2101, 1),
+    (0x2070, 2102, 1),
+    (0x2071, 2103, 1),
+    (0x2074, 2104, 1),
+    (0x2075, 2105, 1),
+    (0x2076, 2106, 1),
+    (0x2077, 2107, 1),
+    (0x2078, 2108, 1),
+    (0x2079, 2109, 1),
+    (0x207a, 2110, 1),
+    (0x207b, 2111, 3),
+    (0x207c, 2114, 1),
+    (0x207d, 2115, 1),
+    (0x207e, 2116, 1),
+    (0x207f, 2117, 1),
+    (0x2080, 2118, 1),
+    (0x2081, 2119, 1),
+    (0x2082, 2120, 1),
+    (0x2083, 2121, 1),
+    (0x2084, 2122, 1),
+    (0x2085, 2123, 1),
+    (0x2086, 2124, 1),
+    (0x2087, 2125, 1),
+    (0x2088, 2126, 1),
+    (0x2089, 2127, 1),
+    (0x208a, 2128, 1),
+    (0x208b, 2129, 3),
+    (0x208c, 2132, 1),
+    (0x208d, 2133, 1),
+    (0x208e, 2134, 1),
+    (0x2090, 2135, 1),
+    (0x2091, 2136, 1),
+    (0x2092, 2137, 1),
+    (0x2093, 2138, 1),
+    (0x2094, 2139, 2),
+    (0x2095, 2141, 1),
+    (0x2096, 2142, 1),
+    (0x2097, 2143, 1),
+    (0x2098, 2144, 1),
+    (0x2099, 2145, 1),
+    (0x209a, 2146, 1),
+    (0x209b, 2147, 1),
+    (0x209c, 2148, 1),
+    (0x20a8, 2149, 2),
+    (0x2100, 2151, 3),
+    (0x2101, 2154, 3),
+    (0x2102, 2157, 1),
+    (0x2103, 2158, 3),
+    (0x2105, 2161, 3),
+    (0x2106, 2164, 3),
+    (0x2107, 2167, 2),
+    (0x2109, 2169, 3),
+    (0x210a, 2172, 1),
+    (0x210b, 2173, 1),
+    (0x210c, 2174, 1),
+    (0x210d, 2175, 1),
+    (0x210e, 2176, 1),
+    (0x210f, 2177, 2),
+    (0x2110, 2179, 1),
+    (0x2111, 2180, 1),
+    (0x2112, 2181, 1),
+    (0x2113, 2182, 1),
+    (0x2115, 2183, 1),
+    (0x2116, 2184, 2),
+    (0x2119, 2186, 1),
+    (0x211a, 2187, 1),
+    (0x211b, 2188, 1),
+    (0x211c, 2189, 1),
+    (0x211d, 2190, 1),
+    (0x2120, 2191, 2),
+    (0x2121, 2193, 3),
+    (0x2122, 2196, 2),
+    (0x2124, 2198, 1),
+    (0x2126, 2199, 2),
+    (0x2128, 2201, 1),
+    (0x212a, 2202, 1),
+    (0x212b, 2203, 3),
+    (0x212c, 2206, 1),
+    (0x212d, 2207, 1),
+    (0x212f, 2208, 1),
+    (0x2130, 2209, 1),
+    (0x2131, 2210, 1),
+    (0x2133, 2211, 1),
+    (0x2134, 2212, 1),
+    (0x2135, 2213, 2),
+    (0x2136, 2215, 2),
+    (0x2137, 2217, 2),
+    (0x2138, 2219, 2),
+    (0x2139, 2221, 1),
+    (0x213b, 2222, 3),
+    (0x213c, 2225, 2),
+    (0x213d, 2227, 2),
+    (0x213e, 2229, 2),
+    (0x213f, 2231, 2),
+    (0x2140, 2233, 3),
+    (0x2145, 2236, 1),
+    (0x2146, 2237, 1),
+    (0x2147, 2238, 1),
+    (0x2148, 2239, 1),
+    (0x2149, 2240, 1),
+    (0x2150, 2241, 5),
+    (0x2151, 2246, 5),
+    (0x2152, 2251, 6),
+    (0x2153, 2257, 5),
+    (0x2154, 2262, 5),
+    (0x2155, 2267, 5),
+    (0x2156, 2272, 5),
+    (0x2157, 2277, 5),
+    (0x2158, 2282, 5),
+    (0x2159, 2287, 5),
+    (0x215a, 2292, 5),
+    (0x215b, 2297, 5),
+    (0x215c, 2302, 5),
+    (0x215d, 2307, 5),
+    (0x215e, 2312, 5),
+    (0x215f, 2317, 4),
+    (0x2160, 2321, 1),
+    (0x2161, 2322, 2),
+    (0x2162, 2324, 3),
+    (0x2163, 2327, 2),
+    (0x2164, 2329, 1),
+    (0x2165, 2330, 2),
+    (0x2166, 2332, 3),
+    (0x2167, 2335, 4),
+    (0x2168, 2339, 2),
+    (0x2169, 2341, 1),
+    (0x216a, 2342, 2),
+    (0x216b, 2344, 3),
+    (0x216c, 2347, 1),
+    (0x216d, 2348, 1),
+    (0x216e, 2349, 1),
+    (0x216f, 2350, 1),
+    (0x2170, 2351, 1),
+    (0x2171, 2352, 2),
+    (0x2172, 2354, 3),
+    (0x2173, 2357, 2),
+    (0x2174, 2359, 1),
+    (0x2175, 2360, 2),
+    (0x2176, 2362, 3),
+    (0x2177, 2365, 4),
+    (0x2178, 2369, 2),
+    (0x2179, 2371, 1),
+    (0x217a, 2372, 2),
+    (0x217b, 2374, 3),
+    (0x217c, 2377, 1),
+    (0x217d, 2378, 1),
+    (0x217e, 2379, 1),
+    (0x217f, 2380, 1),
+    (0x2189, 2381, 5),
+    (0x219a, 2386, 5),
+    (0x219b, 2391, 5),
+    (0x21ae, 2396, 5),
+    (0x21cd, 2401, 5),
+    (0x21ce, 2406, 5),
+    (0x21cf, 2411, 5),
+    (0x2204, 2416, 5),
+    (0x2209, 2421, 5),
+    (0x220c, 2426, 5),
+    (0x2224, 2431, 5),
+    (0x2226, 2436, 5),
+    (0x222c, 2441, 6),
+    (0x222d, 2447, 9),
+    (0x222f, 2456, 6),
+    (0x2230, 2462, 9),
+    (0x2241, 2471, 5),
+    (0x2244, 2476, 5),
+    (0x2247, 2481, 5),
+    (0x2249, 2486, 5),
+    (0x2260, 2491, 3),
+    (0x2262, 2494, 5),
+    (0x226d, 2499, 5),
+    (0x226e, 2504, 3),
+    (0x226f, 2507, 3),
+    (0x2270, 2510, 5),
+    (0x2271, 2515, 5),
+    (0x2274, 2520, 5),
+    (0x2275, 2525, 5),
+    (0x2278, 2530, 5),
+    (0x2279, 2535, 5),
+    (0x2280, 2540, 5),
+    (0x2281, 2545, 5),
+    (0x2284, 2550, 5),
+    (0x2285, 2555, 5),
+    (0x2288, 2560, 5),
+    (0x2289, 2565, 5),
+    (0x22ac, 2570, 5),
+    (0x22ad, 2575, 5),
+    (0x22ae, 2580, 5),
+    (0x22af, 2585, 5),
+    (0x22e0, 2590, 5),
+    (0x22e1, 2595, 5),
+    (0x22e2, 2600, 5),
+    (0x22e3, 2605, 5),
+    (0x22ea, 2610, 5),
+    (0x22eb, 2615, 5),
+    (0x22ec, 2620, 5),
+    (0x22ed, 2625, 5),
+    (0x2329, 2630, 3),
+    (0x232a, 2633, 3),
+    (0x2460, 2636, 1),
+    (0x2461, 2637, 1),
+    (0x2462, 2638, 1),
+    (0x2463, 2639, 1),
+    (0x2464, 2640, 1),
+    (0x2465, 2641, 1),
+    (0x2466, 2642, 1),
+    (0x2467, 2643, 1),
+    (0x2468, 2644, 1),
+    (0x2469, 2645, 2),
+    (0x246a, 2647, 2),
+    (0x246b, 2649, 2),
+    (0x246c, 2651, 2),
+    (0x246d, 2653, 2),
+    (0x246e, 2655, 2),
+    (0x246f, 2657, 2),
+    (0x2470, 2659, 2),
+    (0x2471, 2661, 2),
+    (0x2472, 2663, 2),
+    (0x2473, 2665, 2),
+    (0x2474, 2667, 3),
+    (0x2475, 2670, 3),
+    (0x2476, 2673, 3),
+    (0x2477, 2676, 3),
+    (0x2478, 2679, 3),
+    (0x2479, 2682, 3),
+    (0x247a, 2685, 3),
+    (0x247b, 2688, 3),
+    (0x247c, 2691, 3),
+    (0x247d, 2694, 4),
+    (0x247e, 2698, 4),
+    (0x247f, 2702, 4),
+    (0x2480, 2706, 4),
+    (0x2481, 2710, 4),
+    (0x2482, 2714, 4),
+    (0x2483, 2718, 4),
+    (0x2484, 2722, 4),
+    (0x2485, 2726, 4),
+    (0x2486, 2730, 4),
+    (0x2487, 2734, 4),
+    (0x2488, 2738, 2),
+    (0x2489, 2740, 2),
+    (0x248a, 2742, 2),
+    (0x248b, 2744, 2),
+    (0x248c, 2746, 2),
+    (0x248d, 2748, 2),
+    (0x248e, 2750, 2),
+    (0x248f, 2752, 2),
+    (0x2490, 2754, 2),
+    (0x2491, 2756, 3),
+    (0x2492, 2759, 3),
+    (0x2493, 2762, 3),
+    (0x2494, 2765, 3),
+    (0x2495, 2768, 3),
+    (0x2496, 2771, 3),
+    (0x2497, 2774, 3),
+    (0x2498, 2777, 3),
+    (0x2499, 2780, 3),
+    (0x249a, 2783, 3),
+    (0x249b, 2786, 3),
+    (0x249c, 2789, 3),
+    (0x249d, 2792, 3),
+    (0x249e, 2795, 3),
+    (0x249f, 2798, 3),
+    (0x24a0, 2801, 3),
+    (0x24a1, 2804, 3),
+    (0x24a2, 2807, 3),
+    (0x24a3, 2810, 3),
+    (0x24a4, 2813, 3),
+    (0x24a5, 2816, 3),
+    (0x24a6, 2819, 3),
+    (0x24a7, 2822, 3),
+    (0x24a8, 2825, 3),
+    (0x24a9, 2828, 3),
+    (0x24aa, 2831, 3),
+    (0x24ab, 2834, 3),
+    (0x24ac, 2837, 3),
+    (0x24ad, 2840, 3),
+    (0x24ae, 2843, 3),
+    (0x24af, 2846, 3),
+    (0x24b0, 2849, 3),
+    (0x24b1, 2852, 3),
+    (0x24b2, 2855, 3),
+    (0x24b3, 2858, 3),
+    (0x24b4, 2861, 3),
+    (0x24b5, 2864, 3),
+    (0x24b6, 2867, 1),
+    (0x24b7, 2868, 1),
+    (0x24b8, 2869, 1),
+    (0x24b9, 2870, 1),
+    (0x24ba, 2871, 1),
+    (0x24bb, 2872, 1),
+    (0x24bc, 2873, 1),
+    (0x24bd, 2874, 1),
+    (0x24be, 2875, 1),
+    (0x24bf, 2876, 1),
+    (0x24c0, 2877, 1),
+    (0x24c1, 2878, 1),
+    (0x24c2, 2879, 1),
+    (0x24c3, 2880, 1),
+    (0x24c4, 2881, 1),
+    (0x24c5, 2882, 1),
+    (0x24c6, 2883, 1),
+    (0x24c7, 2884, 1),
+    (0x24c8, 2885, 1),
+    (0x24c9, 2886, 1),
+    (0x24ca, 2887, 1),
+    (0x24cb, 2888, 1),
+    (0x24cc, 2889, 1),
+    (0x24cd, 2890, 1),
+    (0x24ce, 2891, 1),
+    (0x24cf, 2892, 1),
+    (0x24d0, 2893, 1),
+    (0x24d1, 2894, 1),
+    (0x24d2, 2895, 1),
+    (0x24d3, 2896, 1),
+    (0x24d4, 2897, 1),
+    (0x24d5, 2898, 1),
+    (0x24d6, 2899, 1),
+    (0x24d7, 2900, 1),
+    (0x24d8, 2901, 1),
+    (0x24d9, 2902, 1),
+    (0x24da, 2903, 1),
+    (0x24db, 2904, 1),
+    (0x24dc, 2905, 1),
+    (0x24dd, 2906, 1),
+    (0x24de, 2907, 1),
+    (0x24df, 2908, 1),
+    (0x24e0, 2909, 1),
+    (0x24e1, 2910, 1),
+    (0x24e2, 2911, 1),
+    (0x24e3, 2912, 1),
+    (0x24e4, 2913, 1),
+    (0x24e5, 2914, 1),
+    (0x24e6, 2915, 1),
+    (0x24e7, 2916, 1),
+    (0x24e8, 2917, 1),
+    (0x24e9, 2918, 1),
+    (0x24ea, 2919, 1),
+    (0x2a0c, 2920, 12),
+    (0x2a74, 2932, 3),
+    (0x2a75, 2935, 2),
+    (0x2a76, 2937, 3),
+    (0x2adc, 2940, 5),
+    (0xfb00, 2945, 2),
+    (0xfb01, 2947, 2),
+    (0xfb02, 2949, 2),
+    (0xfb03, 2951, 3),
+    (0xfb04, 2954, 3),
+    (0xfb05, 2957, 2),
+    (0xfb06, 2959, 2),
+];
+#[cfg(feature = "nfkd-latin")]
+#[rustfmt::skip]
+pub(super) const LATIN_DECOMPOSED: &str = "  \u{308}a \u{304}23 \u{301}\u{3bc} \u{327}1o1\u{2044}41\u{2044}23\u{2044}4A\u{300}A\u{301}A\u{302}A\u{303}A\u{308}A\u{30a}C\u{327}E\u{300}E\u{301}E\u{302}E\u{308}I\u{300}I\u{301}I\u{302}I\u{308}N\u{303}O\u{300}O\u{301}O\u{302}O\u{303}O\u{308}U\u{300}U\u{301}U\u{302}U\u{308}Y\u{301}a\u{300}a\u{301}a\u{302}a\u{303}a\u{308}a\u{30a}c\u{327}e\u{300}e\u{301}e\u{302}e\u{308}i\u{300}i\u{301}i\u{302}i\u{308}n\u{303}o\u{300}o\u{301}o\u{302}o\u{303}o\u{308}u\u{300}u\u{301}u\u{302}u\u{308}y\u{301}y\u{308}A\u{304}a\u{304}A\u{306}a\u{306}A\u{328}a\u{328}C\u{301}c\u{301}C\u{302}c\u{302}C\u{307}c\u{307}C\u{30c}c\u{30c}D\u{30c}d\u{30c}E\u{304}e\u{304}E\u{306}e\u{306}E\u{307}e\u{307}E\u{328}e\u{328}E\u{30c}e\u{30c}G\u{302}g\u{302}G\u{306}g\u{306}G\u{307}g\u{307}G\u{327}g\u{327}H\u{302}h\u{302}I\u{303}i\u{303}I\u{304}i\u{304}I\u{306}i\u{306}I\u{328}i\u{328}I\u{307}IJijJ\u{302}j\u{302}K\u{327}k\u{327}L\u{301}l\u{301}L\u{327}l\u{327}L\u{30c}l\u{30c}L\u{b7}l\u{b7}N\u{301}n\u{301}N\u{327}n\u{327}N\u{30c}n\u{30c}\u{2bc}nO\u{304}o\u{304}O\u{306}o\u{306}O\u{30b}o\u{30b}R\u{301}r\u{301}R\u{327}r\u{327}R\u{30c}r\u{30c}S\u{301}s\u{301}S\u{302}s\u{302}S\u{327}s\u{327}S\u{30c}s\u{30c}T\u{327}t\u{327}T\u{30c}t\u{30c}U\u{303}u\u{303}U\u{304}u\u{304}U\u{306}u\u{306}U\u{30a}u\u{30a}U\u{30b}u\u{30b}U\u{328}u\u{328}W\u{302}w\u{302}Y\u{302}y\u{302}Y\u{308}Z\u{301}z\u{301}Z\u{307}z\u{307}Z\u{30c}z\u{30c}sO\u{31b}o\u{31b}U\u{31b}u\u{31b}DZ\u{30c}Dz\u{30c}dz\u{30c}LJLjljNJNjnjA\u{30c}a\u{30c}I\u{30c}i\u{30c}O\u{30c}o\u{30c}U\u{30c}u\u{30c}U\u{308}\u{304}u\u{308}\u{304}U\u{308}\u{301}u\u{308}\u{301}U\u{308}\u{30c}u\u{308}\u{30c}U\u{308}\u{300}u\u{308}\u{300}A\u{308}\u{304}a\u{308}\u{304}A\u{307}\u{304}a\u{307}\u{304}\u{c6}\u{304}\u{e6}\u{304}G\u{30c}g\u{30c}K\u{30c}k\u{30c}O\u{328}o\u{328}O\u{328}\u{304}o\u{328}\u{304}\u{1b7}\u{30c}\u{292}\u{30c}j\u{30c}DZDzdzG\u{301}g\u{301}N\u{300}n\u{300}A\u{30a}\u{301}a\u{30a}\u{301}\u{c6}\u{301}\u{e6}\u{301}\u{d8}\u{301}\u{f8}\u{301}A\u{30f}a\u{30f}A\u{311}a\u{311}E\u{30f}e\u{30f}E\u{311}e\u{311}I\u{30f}i\u{30f}I\u{311}i\u{311}O\u{30f}o\u{30f}O\u{311}o\u{311}R\u{30f}r\u{30f}R\u{311}r\u{311}U\u{30f}u\u{30f}U\u{311}u\u{311}S\u{326}s\u{326}T\u{326}t\u{326}H\u{30c}h\u{30c}A\u{307}a\u{307}E\u{327}e\u{327}O\u{308}\u{304}o\u{308}\u{304}O\u{303}\u{304}o\u{303}\u{304}O\u{307}o\u{307}O\u{307}\u{304}o\u{307}\u{304}Y\u{304}y\u{304}h\u{266}jr\u{279}\u{27b}\u{281}wy \u{306} \u{307} \u{30a} \u{328} \u{303} \u{30b}\u{263}lsx\u{295}\u{300}\u{301}\u{313}\u{308}\u{301}A\u{c6}BDE\u{18e}GHIJKLMNO\u{222}PRTUWa\u{250}\u{251}\u{1d02}bde\u{259}\u{25b}\u{25c}gkm\u{14b}o\u{254}\u{1d16}\u{1d17}ptu\u{1d1d}\u{26f}v\u{1d25}\u{3b2}\u{3b3}\u{3b4}\u{3c6}\u{3c7}iruv\u{3b2}\u{3b3}\u{3c1}\u{3c6}\u{3c7}\u{43d}\u{252}c\u{255}\u{f0}\u{25c}f\u{25f}\u{261}\u{265}\u{268}\u{269}\u{26a}\u{1d7b}\u{29d}\u{26d}\u{1d85}\u{29f}\u{271}\u{270}\u{272}\u{273}\u{274}\u{275}\u{278}\u{282}\u{283}\u{1ab}\u{289}\u{28a}\u{1d1c}\u{28b}\u{28c}z\u{290}\u{291}\u{292}\u{3b8}A\u{325}a\u{325}B\u{307}b\u{307}B\u{323}b\u{323}B\u{331}b\u{331}C\u{327}\u{301}c\u{327}\u{301}D\u{307}d\u{307}D\u{323}d\u{323}D\u{331}d\u{331}D\u{327}d\u{327}D\u{32d}d\u{32d}E\u{304}\u{300}e\u{304}\u{300}E\u{304}\u{301}e\u{304}\u{301}E\u{32d}e\u{32d}E\u{330}e\u{330}E\u{327}\u{306}e\u{327}\u{306}F\u{307}f\u{307}G\u{304}g\u{304}H\u{307}h\u{307}H\u{323}h\u{323}H\u{308}h\u{308}H\u{327}h\u{327}H\u{32e}h\u{32e}I\u{330}i\u{330}I\u{308}\u{301}i\u{308}\u{301}K\u{301}k\u{301}K\u{323}k\u{323}K\u{331}k\u{331}L\u{323}l\u{323}L\u{323}\u{304}l\u{323}\u{304}L\u{331}l\u{331}L\u{32d}l\u{32d}M\u{301}m\u{301}M\u{307}m\u{307}M\u{323}m\u{323}N\u{307}n\u{307}N\u{323}n\u{323}N\u{331}n\u{331}N\u{32d}n\u{32d}O\u{303}\u{301}o\u{303}\u{301}O\u{303}\u{308}o\u{303}\u{308}O\u{304}\u{300}o\u{304}\u{300}O\u{304}\u{301}o\u{304}\u{301}P\u{301}p\u{301}P\u{307}p\u{307}R\u{307}r\u{307}R\u{323}r\u{323}R\u{323}\u{304}r\u{323}\u{304}R\u{331}r\u{331}S\u{307}s\u{307}S\u{323}s\u{323}S\u{301}\u{307}s\u{301}\u{307}S\u{30c}\u{307}s\u{30c}\u{307}S\u{323}\u{307}s\u{323}\u{307}T\u{307}t\u{307}T\u{323}t\u{323}T\u{331}t\u{331}T\u{32d}t\u{32d}U\u{324}u\u{324}U\u{330}u\u{330}U\u{32d}u\u{32d}U\u{303}\u{301}u\u{303}\u{301}U\u{304}\u{308}u\u{304}\u{308}V\u{303}v\u{303}V\u{323}v\u{323}W\u{300}w\u{300}W\u{301}w\u{301}W\u{308}w\u{308}W\u{307}w\u{307}W\u{323}w\u{323}X\u{307}x\u{307}X\u{308}x\u{308}Y\u{307}y\u{307}Z\u{302}z\u{302}Z\u{323}z\u{323}Z\u{331}z\u{331}h\u{331}t\u{308}w\u{30a}y\u{30a}a\u{2be}s\u{307}A\u{323}a\u{323}A\u{309}a\u{309}A\u{302}\u{301}a\u{302}\u{301}A\u{302}\u{300}a\u{302}\u{300}A\u{302}\u{309}a\u{302}\u{309}A\u{302}\u{303}a\u{302}\u{303}A\u{323}\u{302}a\u{323}\u{302}A\u{306}\u{301}a\u{306}\u{301}A\u{306}\u{300}a\u{306}\u{300}A\u{306}\u{309}a\u{306}\u{309}A\u{306}\u{303}a\u{306}\u{303}A\u{323}\u{306}a\u{323}\u{306}E\u{323}e\u{323}E\u{309}e\u{309}E\u{303}e\u{303}E\u{302}\u{301}e\u{302}\u{301}E\u{302}\u{300}e\u{302}\u{300}E\u{302}\u{309}e\u{302}\u{309}E\u{302}\u{303}e\u{302}\u{303}E\u{323}\u{302}e\u{323}\u{302}I\u{309}i\u{309}I\u{323}i\u{323}O\u{323}o\u{323}O\u{309}o\u{309}O\u{302}\u{301}o\u{302}\u{301}O\u{302}\u{300}o\u{302}\u{300}O\u{302}\u{309}o\u{302}\u{309}O\u{302}\u{303}o\u{302}\u{303}O\u{323}\u{302}o\u{323}\u{302}O\u{31b}\u{301}o\u{31b}\u{301}O\u{31b}\u{300}o\u{31b}\u{300}O\u{31b}\u{309}o\u{31b}\u{309}O\u{31b}\u{303}o\u{31b}\u{303}O\u{31b}\u{323}o\u{31b}\u{323}U\u{323}u\u{323}U\u{309}u\u{309}U\u{31b}\u{301}u\u{31b}\u{301}U\u{31b}\u{300}u\u{31b}\u{300}U\u{31b}\u{309}u\u{31b}\u{309}U\u{31b}\u{303}u\u{31b}\u{303}U\u{31b}\u{323}u\u{31b}\u{323}Y\u{300}y\u{300}Y\u{323}y\u{323}Y\u{309}y\u{309}Y\u{303}y\u{303}           \u{2010} \u{333}...... \u{2032}\u{2032}\u{2032}\u{2032}\u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}!! \u{305}???!!?\u{2032}\u{2032}\u{2032}\u{2032} 0i456789+\u{2212}=()n0123456789+\u{2212}=()aeox\u{259}hklmnpstRsa/ca/sC\u{b0}Cc/oc/u\u{190}\u{b0}FgHHHh\u{127}IILlNNoPQRRRSMTELTMZ\u{3a9}ZKA\u{30a}BCeEFMo\u{5d0}\u{5d1}\u{5d2}\u{5d3}iFAX\u{3c0}\u{3b3}\u{393}\u{3a0}\u{2211}Ddeij1\u{2044}71\u{2044}91\u{2044}101\u{2044}32\u{2044}31\u{2044}52\u{2044}53\u{2044}54\u{2044}51\u{2044}65\u{2044}61\u{2044}83\u{2044}85\u{2044}87\u{2044}81\u{2044}IIIIIIIVVVIVIIVIIIIXXXIXIILCDMiiiiiiivvviviiviiiixxxixiilcdm0\u{2044}3\u{2190}\u{338}\u{2192}\u{338}\u{2194}\u{338}\u{21d0}\u{338}\u{21d4}\u{338}\u{21d2}\u{338}\u{2203}\u{338}\u{2208}\u{338}\u{220b}\u{338}\u{2223}\u{338}\u{2225}\u{338}\u{222b}\u{222b}\u{222b}\u{222b}\u{222b}\u{222e}\u{222e}\u{222e}\u{222e}\u{222e}\u{223c}\u{338}\u{2243}\u{338}\u{2245}\u{338}\u{2248}\u{338}=\u{338}\u{2261}\u{338}\u{224d}\u{338}<\u{338}>\u{338}\u{2264}\u{338}\u{2265}\u{338}\u{2272}\u{338}\u{2273}\u{338}\u{2276}\u{338}\u{2277}\u{338}\u{227a}\u{338}\u{227b}\u{338}\u{2282}\u{338}\u{2283}\u{338}\u{2286}\u{338}\u{2287}\u{338}\u{22a2}\u{338}\u{22a8}\u{338}\u{22a9}\u{338}\u{22ab}\u{338}\u{227c}\u{338}\u{227d}\u{338}\u{2291}\u{338}\u{2292}\u{338}\u{22b2}\u{338}\u{22b3}\u{338}\u{22b4}\u{338}\u{22b5}\u{338}\u{3008}\u{3009}1234567891011121314151617181920(1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(12)(13)(14)(15)(16)(17)(18)(19)(20)1.2.3.4.5.6.7.8.9.10.11.12.13.14.15.16.17.18.19.20.(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)(m)(n)(o)(p)(q)(r)(s)(t)(u)(v)(w)(x)(y)(z)ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0\u{222b}\u{222b}\u{222b}\u{222b}::======\u{2add}\u{338}fffiflffifflstst";
+
+#[cfg(feature = "nfkd-greek-cyrillic")]
+#[rustfmt::skip]
+pub(super) const GREEK_CYRILLIC_DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0x374, 0, 2),
+    (0x37a, 2, 3),
+    (0x37e, 5, 1),
+    (0x384, 6, 3),
+    (0x385, 9, 5),
+    (0x386, 14, 4),
+    (0x387, 18, 2),
+    (0x388, 20, 4),
+    (0x389, 24, 4),
+    (0x38a, 28, 4),
+    (0x38c, 32, 4),
+    (0x38e, 36, 4),
+    (0x38f, 40, 4),
+    (0x390, 44, 6),
+    (0x3aa, 50, 4),
+    (0x3ab, 54, 4),
+    (0x3ac, 58, 4),
+    (0x3ad, 62, 4),
+    (0x3ae, 66, 4),
+    (0x3af, 70, 4),
+    (0x3b0, 74, 6),
+    (0x3ca, 80, 4),
+    (0x3cb, 84, 4),
+    (0x3cc, 88, 4),
+    (0x3cd, 92, 4),
+    (0x3ce, 96, 4),
+    (0x3d0, 100, 2),
+    (0x3d1, 102, 2),
+    (0x3d2, 104, 2),
+    (0x3d3, 106, 4),
+    (0x3d4, 110, 4),
+    (0x3d5, 114, 2),
+    (0x3d6, 116, 2),
+    (0x3f0, 118, 2),
+    (0x3f1, 120, 2),
+    (0x3f2, 122, 2),
+    (0x3f4, 124, 2),
+    (0x3f5, 126, 2),
+    (0x3f9, 128, 2),
+    (0x400, 130, 4),
+    (0x401, 134, 4),
+    (0x403, 138, 4),
+    (0x407, 142, 4),
+    (0x40c, 146, 4),
+    (0x40d, 150, 4),
+    (0x40e, 154, 4),
+    (0x419, 158, 4),
+    (0x439, 162, 4),
+    (0x450, 166, 4),
+    (0x451, 170, 4),
+    (0x453, 174, 4),
+    (0x457, 178, 4),
+    (0x45c, 182, 4),
+    (0x45d, 186, 4),
+    (0x45e, 190, 4),
+    (0x476, 194, 4),
+    (0x477, 198, 4),
+    (0x4c1, 202, 4),
+    (0x4c2, 206, 4),
+    (0x4d0, 210, 4),
+    (0x4d1, 214, 4),
+    (0x4d2, 218, 4),
+    (0x4d3, 222, 4),
+    (0x4d6, 226, 4),
+    (0x4d7, 230, 4),
+    (0x4da, 234, 4),
+    (0x4db, 238, 4),
+    (0x4dc, 242, 4),
+    (0x4dd, 246, 4),
+    (0x4de, 250, 4),
+    (0x4df, 254, 4),
+    (0x4e2, 258, 4),
+    (0x4e3, 262, 4),
+    (0x4e4, 266, 4),
+    (0x4e5, 270, 4),
+    (0x4e6, 274, 4),
+    (0x4e7, 278, 4),
+    (0x4ea, 282, 4),
+    (0x4eb, 286, 4),
+    (0x4ec, 290, 4),
+    (0x4ed, 294, 4),
+    (0x4ee, 298, 4),
+    (0x4ef, 302, 4),
+    (0x4f0, 306, 4),
+    (0x4f1, 310, 4),
+    (0x4f2, 314, 4),
+    (0x4f3, 318, 4),
+    (0x4f4, 322, 4),
+    (0x4f5, 326, 4),
+    (0x4f8, 330, 4),
+    (0x4f9, 334, 4),
+    (0x1f00, 338, 4),
+    (0x1f01, 342, 4),
+    (0x1f02, 346, 6),
+    (0x1f03, 352, 6),
+    (0x1f04, 358, 6),
+    (0x1f05, 364, 6),
+    (0x1f06, 370, 6),
+    (0x1f07, 376, 6),
+    (0x1f08, 382, 4),
+    (0x1f09, 386, 4),
+    (0x1f0a, 390, 6),
+    (0x1f0b, 396, 6),
+    (0x1f0c, 402, 6),
+    (0x1f0d, 408, 6),
+    (0x1f0e, 414, 6),
+    (0x1f0f, 420, 6),
+    (0x1f10, 426, 4),
+    (0x1f11, 430, 4),
+    (0x1f12, 434, 6),
+    (0x1f13, 440, 6),
+    (0x1f14, 446, 6),
+    (0x1f15, 452, 6),
+    (0x1f18, 458, 4),
+    (0x1f19, 462, 4),
+    (0x1f1a, 466, 6),
+    (0x1f1b, 472, 6),
+    (0x1f1c, 478, 6),
+    (0x1f1d, 484, 6),
+    (0x1f20, 490, 4),
+    (0x1f21, 494, 4),
+    (0x1f22, 498, 6),
+    (0x1f23, 504, 6),
+    (0x1f24, 510, 6),
+    (0x1f25, 516, 6),
+    (0x1f26, 522, 6),
+    (0x1f27, 528, 6),
+    (0x1f28, 534, 4),
+    (0x1f29, 538, 4),
+    (0x1f2a, 542, 6),
+    (0x1f2b, 548, 6),
+    (0x1f2c, 554, 6),
+    (0x1f2d, 560, 6),
+    (0x1f2e, 566, 6),
+    (0x1f2f, 572, 6),
+    (0x1f30, 578, 4),
+    (0x1f31, 582, 4),
+    (0x1f32, 586, 6),
+    (0x1f33, 592, 6),
+    (0x1f34, 598, 6),
+    (0x1f35, 604, 6),
+    (0x1f36, 610, 6),
+    (0x1f37, 616, 6),
+    (0x1f38, 622, 4),
+    (0x1f39, 626, 4),
+    (0x1f3a, 630, 6),
+    (0x1f3b, 636, 6),
+    (0x1f3c, 642, 6),
+    (0x1f3d, 648, 6),
+    (0x1f3e, 654, 6),
+    (0x1f3f, 660, 6),
+    (0x1f40, 666, 4),
+    (0x1f41, 670, 4),
+    (0x1f42, 674, 6),
+    (0x1f43, 680, 6),
+    (0x1f44, 686, 6),
+    (0x1f45, 692, 6),
+    (0x1f48, 698, 4),
+    (0x1f49, 702, 4),
+    (0x1f4a, 706, 6),
+    (0x1f4b, 712, 6),
+    (0x1f4c, 718, 6),
+    (0x1f4d, 724, 6),
+    (0x1f50, 730, 4),
+    (0x1f51, 734, 4),
+    (0x1f52, 738, 6),
+    (0x1f53, 744, 6),
+    (0x1f54, 750, 6),
+    (0x1f55, 756, 6),
+    (0x1f56, 762, 6),
+    (0x1f57, 768, 6),
+    (0x1f59, 774, 4),
+    (0x1f5b, 778, 6),
+    (0x1f5d, 784, 6),
+    (0x1f5f, 790, 6),
+    (0x1f60, 796, 4),
+    (0x1f61, 800, 4),
+    (0x1f62, 804, 6),
+    (0x1f63, 810, 6),
+    (0x1f64, 816, 6),
+    (0x1f65, 822, 6),
+    (0x1f66, 828, 6),
+    (0x1f67, 834, 6),
+    (0x1f68, 840, 4),
+    (0x1f69, 844, 4),
+    (0x1f6a, 848, 6),
+    (0x1f6b, 854, 6),
+    (0x1f6c, 860, 6),
+    (0x1f6d, 866, 6),
+    (0x1f6e, 872, 6),
+    (0x1f6f, 878, 6),
+    (0x1f70, 884, 4),
+    (0x1f71, 888, 4),
+    (0x1f72, 892, 4),
+    (0x1f73, 896, 4),
+    (0x1f74, 900, 4),
+    (0x1f75, 904, 4),
+    (0x1f76, 908, 4),
+    (0x1f77, 912, 4),
+    (0x1f78, 916, 4),
+    (0x1f79, 920, 4),
+    (0x1f7a, 924, 4),
+    (0x1f7b, 928, 4),
+    (0x1f7c, 932, 4),
+    (0x1f7d, 936, 4),
+    (0x1f80, 940, 6),
+    (0x1f81, 946, 6),
+    (0x1f82, 952, 8),
+    (0x1f83, 960, 8),
+    (0x1f84, 968, 8),
+    (0x1f85, 976, 8),
+    (0x1f86, 984, 8),
+    (0x1f87, 992, 8),
+    (0x1f88, 1000, 6),
+    (0x1f89, 1006, 6),
+    (0x1f8a, 1012, 8),
+    (0x1f8b, 1020, 8),
+    (0x1f8c, 1028, 8),
+    (0x1f8d, 1036, 8),
+    (0x1f8e, 1044, 8),
+    (0x1f8f, 1052, 8),
+    (0x1f90, 1060, 6),
+    (0x1f91, 1066, 6),
+    (0x1f92, 1072, 8),
+    (0x1f93, 1080, 8),
+    (0x1f94, 1088, 8),
+    (0x1f95, 1096, 8),
+    (0x1f96, 1104, 8),
+    (0x1f97, 1112, 8),
+    (0x1f98, 1120, 6),
+    (0x1f99, 1126, 6),
+    (0x1f9a, 1132, 8),
+    (0x1f9b, 1140, 8),
+    (0x1f9c, 1148, 8),
+    (0x1f9d, 1156, 8),
+    (0x1f9e, 1164, 8),
+    (0x1f9f, 1172, 8),
+    (0x1fa0, 1180, 6),
+    (0x1fa1, 1186, 6),
+    (0x1fa2, 1192, 8),
+    (0x1fa3, 1200, 8),
+    (0x1fa4, 1208, 8),
+    (0x1fa5, 1216, 8),
+    (0x1fa6, 1224, 8),
+    (0x1fa7, 1232, 8),
+    (0x1fa8, 1240, 6),
+    (0x1fa9, 1246, 6),
+    (0x1faa, 1252, 8),
+    (0x1fab, 1260, 8),
+    (0x1fac, 1268, 8),
+    (0x1fad, 1276, 8),
+    (0x1fae, 1284, 8),
+    (0x1faf, 1292, 8),
+    (0x1fb0, 1300, 4),
+    (0x1fb1, 1304, 4),
+    (0x1fb2, 1308, 6),
+    (0x1fb3, 1314, 4),
+    (0x1fb4, 1318, 6),
+    (0x1fb6, 1324, 4),
+    (0x1fb7, 1328, 6),
+    (0x1fb8, 1334, 4),
+    (0x1fb9, 1338, 4),
+    (0x1fba, 1342, 4),
+    (0x1fbb, 1346, 4),
+    (0x1fbc, 1350, 4),
+    (0x1fbd, 1354, 3),
+    (0x1fbe, 1357, 2),
+    (0x1fbf, 1359, 3),
+    (0x1fc0, 1362, 3),
+    (0x1fc1, 1365, 5),
+    (0x1fc2, 1370, 6),
+    (0x1fc3, 1376, 4),
+    (0x1fc4, 1380, 6),
+    (0x1fc6, 1386, 4),
+    (0x1fc7, 1390, 6),
+    (0x1fc8, 1396, 4),
+    (0x1fc9, 1400, 4),
+    (0x1fca, 1404, 4),
+    (0x1fcb, 1408, 4),
+    (0x1fcc, 1412, 4),
+    (0x1fcd, 1416, 5),
+    (0x1fce, 1421, 5),
+    (0x1fcf, 1426, 5),
+    (0x1fd0, 1431, 4),
+    (0x1fd1, 1435, 4),
+    (0x1fd2, 1439, 6),
+    (0x1fd3, 1445, 6),
+    (0x1fd6, 1451, 4),
+    (0x1fd7, 1455, 6),
+    (0x1fd8, 1461, 4),
+    (0x1fd9, 1465, 4),
+    (0x1fda, 1469, 4),
+    (0x1fdb, 1473, 4),
+    (0x1fdd, 1477, 5),
+    (0x1fde, 1482, 5),
+    (0x1fdf, 1487, 5),
+    (0x1fe0, 1492, 4),
+    (0x1fe1, 1496, 4),
+    (0x1fe2, 1500, 6),
+    (0x1fe3, 1506, 6),
+    (0x1fe4, 1512, 4),
+    (0x1fe5, 1516, 4),
+    (0x1fe6, 1520, 4),
+    (0x1fe7, 1524, 6),
+    (0x1fe8, 1530, 4),
+    (0x1fe9, 1534, 4),
+    (0x1fea, 1538, 4),
+    (0x1feb, 1542, 4),
+    (0x1fec, 1546, 4),
+    (0x1fed, 1550, 5),
+    (0x1fee, 1555, 5),
+    (0x1fef, 1560, 1),
+    (0x1ff2, 1561, 6),
+    (0x1ff3, 1567, 4),
+    (0x1ff4, 1571, 6),
+    (0x1ff6, 1577, 4),
+    (0x1ff7, 1581, 6),
+    (0x1ff8, 1587, 4),
+    (0x1ff9, 1591, 4),
+    (0x1ffa, 1595, 4),
+    (0x1ffb, 1599, 4),
+    (0x1ffc, 1603, 4),
+    (0x1ffd, 1607, 3),
+    (0x1ffe, 1610, 3),
+];
+#[cfg(feature = "nfkd-greek-cyrillic")]
+#[rustfmt::skip]
+pub(super) const GREEK_CYRILLIC_DECOMPOSED: &str = "\u{2b9} \u{345}; \u{301} \u{308}\u{301}\u{391}\u{301}\u{b7}\u{395}\u{301}\u{397}\u{301}\u{399}\u{301}\u{39f}\u{301}\u{3a5}\u{301}\u{3a9}\u{301}\u{3b9}\u{308}\u{301}\u{399}\u{308}\u{3a5}\u{308}\u{3b1}\u{301}\u{3b5}\u{301}\u{3b7}\u{301}\u{3b9}\u{301}\u{3c5}\u{308}\u{301}\u{3b9}\u{308}\u{3c5}\u{308}\u{3bf}\u{301}\u{3c5}\u{301}\u{3c9}\u{301}\u{3b2}\u{3b8}\u{3a5}\u{3a5}\u{301}\u{3a5}\u{308}\u{3c6}\u{3c0}\u{3ba}\u{3c1}\u{3c2}\u{398}\u{3b5}\u{3a3}\u{415}\u{300}\u{415}\u{308}\u{413}\u{301}\u{406}\u{308}\u{41a}\u{301}\u{418}\u{300}\u{423}\u{306}\u{418}\u{306}\u{438}\u{306}\u{435}\u{300}\u{435}\u{308}\u{433}\u{301}\u{456}\u{308}\u{43a}\u{301}\u{438}\u{300}\u{443}\u{306}\u{474}\u{30f}\u{475}\u{30f}\u{416}\u{306}\u{436}\u{306}\u{410}\u{306}\u{430}\u{306}\u{410}\u{308}\u{430}\u{308}\u{415}\u{306}\u{435}\u{306}\u{4d8}\u{308}\u{4d9}\u{308}\u{416}\u{308}\u{436}\u{308}\u{417}\u{308}\u{437}\u{308}\u{418}\u{304}\u{438}\u{304}\u{418}\u{308}\u{438}\u{308}\u{41e}\u{308}\u{43e}\u{308}\u{4e8}\u{308}\u{4e9}\u{308}\u{42d}\u{308}\u{44d}\u{308}\u{423}\u{304}\u{443}\u{304}\u{423}\u{308}\u{443}\u{308}\u{423}\u{30b}\u{443}\u{30b}\u{427}\u{308}\u{447}\u{308}\u{42b}\u{308}\u{44b}\u{308}\u{3b1}\u{313}\u{3b1}\u{314}\u{3b1}\u{313}\u{300}\u{3b1}\u{314}\u{300}\u{3b1}\u{313}\u{301}\u{3b1}\u{314}\u{301}\u{3b1}\u{313}\u{342}\u{3b1}\u{314}\u{342}\u{391}\u{313}\u{391}\u{314}\u{391}\u{313}\u{300}\u{391}\u{314}\u{300}\u{391}\u{313}\u{301}\u{391}\u{314}\u{301}\u{391}\u{313}\u{342}\u{391}\u{314}\u{342}\u{3b5}\u{313}\u{3b5}\u{314}\u{3b5}\u{313}\u{300}\u{3b5}\u{314}\u{300}\u{3b5}\u{313}\u{301}\u{3b5}\u{314}\u{301}\u{395}\u{313}\u{395}\u{314}\u{395}\u{313}\u{300}\u{395}\u{314}\u{300}\u{395}\u{313}\u{301}\u{395}\u{314}\u{301}\u{3b7}\u{313}\u{3b7}\u{314}\u{3b7}\u{313}\u{300}\u{3b7}\u{314}\u{300}\u{3b7}\u{313}\u{301}\u{3b7}\u{314}\u{301}\u{3b7}\u{313}\u{342}\u{3b7}\u{314}\u{342}\u{397}\u{313}\u{397}\u{314}\u{397}\u{313}\u{300}\u{397}\u{314}\u{300}\u{397}\u{313}\u{301}\u{397}\u{314}\u{301}\u{397}\u{313}\u{342}\u{397}\u{314}\u{342}\u{3b9}\u{313}\u{3b9}\u{314}\u{3b9}\u{313}\u{300}\u{3b9}\u{314}\u{300}\u{3b9}\u{313}\u{301}\u{3b9}\u{314}\u{301}\u{3b9}\u{313}\u{342}\u{3b9}\u{314}\u{342}\u{399}\u{313}\u{399}\u{314}\u{399}\u{313}\u{300}\u{399}\u{314}\u{300}\u{399}\u{313}\u{301}\u{399}\u{314}\u{301}\u{399}\u{313}\u{342}\u{399}\u{314}\u{342}\u{3bf}\u{313}\u{3bf}\u{314}\u{3bf}\u{313}\u{300}\u{3bf}\u{314}\u{300}\u{3bf}\u{313}\u{301}\u{3bf}\u{314}\u{301}\u{39f}\u{313}\u{39f}\u{314}\u{39f}\u{313}\u{300}\u{39f}\u{314}\u{300}\u{39f}\u{313}\u{301}\u{39f}\u{314}\u{301}\u{3c5}\u{313}\u{3c5}\u{314}\u{3c5}\u{313}\u{300}\u{3c5}\u{314}\u{300}\u{3c5}\u{313}\u{301}\u{3c5}\u{314}\u{301}\u{3c5}\u{313}\u{342}\u{3c5}\u{314}\u{342}\u{3a5}\u{314}\u{3a5}\u{314}\u{300}\u{3a5}\u{314}\u{301}\u{3a5}\u{314}\u{342}\u{3c9}\u{313}\u{3c9}\u{314}\u{3c9}\u{313}\u{300}\u{3c9}\u{314}\u{300}\u{3c9}\u{313}\u{301}\u{3c9}\u{314}\u{301}\u{3c9}\u{313}\u{342}\u{3c9}\u{314}\u{342}\u{3a9}\u{313}\u{3a9}\u{314}\u{3a9}\u{313}\u{300}\u{3a9}\u{314}\u{300}\u{3a9}\u{313}\u{301}\u{3a9}\u{314}\u{301}\u{3a9}\u{313}\u{342}\u{3a9}\u{314}\u{342}\u{3b1}\u{300}\u{3b1}\u{301}\u{3b5}\u{300}\u{3b5}\u{301}\u{3b7}\u{300}\u{3b7}\u{301}\u{3b9}\u{300}\u{3b9}\u{301}\u{3bf}\u{300}\u{3bf}\u{301}\u{3c5}\u{300}\u{3c5}\u{301}\u{3c9}\u{300}\u{3c9}\u{301}\u{3b1}\u{313}\u{345}\u{3b1}\u{314}\u{345}\u{3b1}\u{313}\u{300}\u{345}\u{3b1}\u{314}\u{300}\u{345}\u{3b1}\u{313}\u{301}\u{345}\u{3b1}\u{314}\u{301}\u{345}\u{3b1}\u{313}\u{342}\u{345}\u{3b1}\u{314}\u{342}\u{345}\u{391}\u{313}\u{345}\u{391}\u{314}\u{345}\u{391}\u{313}\u{300}\u{345}\u{391}\u{314}\u{300}\u{345}\u{391}\u{313}\u{301}\u{345}\u{391}\u{314}\u{301}\u{345}\u{391}\u{313}\u{342}\u{345}\u{391}\u{314}\u{342}\u{345}\u{3b7}\u{313}\u{345}\u{3b7}\u{314}\u{345}\u{3b7}\u{313}\u{300}\u{345}\u{3b7}\u{314}\u{300}\u{345}\u{3b7}\u{313}\u{301}\u{345}\u{3b7}\u{314}\u{301}\u{345}\u{3b7}\u{313}\u{342}\u{345}\u{3b7}\u{314}\u{342}\u{345}\u{397}\u{313}\u{345}\u{397}\u{314}\u{345}\u{397}\u{313}\u{300}\u{345}\u{397}\u{314}\u{300}\u{345}\u{397}\u{313}\u{301}\u{345}\u{397}\u{314}\u{301}\u{345}\u{397}\u{313}\u{342}\u{345}\u{397}\u{314}\u{342}\u{345}\u{3c9}\u{313}\u{345}\u{3c9}\u{314}\u{345}\u{3c9}\u{313}\u{300}\u{345}\u{3c9}\u{314}\u{300}\u{345}\u{3c9}\u{313}\u{301}\u{345}\u{3c9}\u{314}\u{301}\u{345}\u{3c9}\u{313}\u{342}\u{345}\u{3c9}\u{314}\u{342}\u{345}\u{3a9}\u{313}\u{345}\u{3a9}\u{314}\u{345}\u{3a9}\u{313}\u{300}\u{345}\u{3a9}\u{314}\u{300}\u{345}\u{3a9}\u{313}\u{301}\u{345}\u{3a9}\u{314}\u{301}\u{345}\u{3a9}\u{313}\u{342}\u{345}\u{3a9}\u{314}\u{342}\u{345}\u{3b1}\u{306}\u{3b1}\u{304}\u{3b1}\u{300}\u{345}\u{3b1}\u{345}\u{3b1}\u{301}\u{345}\u{3b1}\u{342}\u{3b1}\u{342}\u{345}\u{391}\u{306}\u{391}\u{304}\u{391}\u{300}\u{391}\u{301}\u{391}\u{345} \u{313}\u{3b9} \u{313} \u{342} \u{308}\u{342}\u{3b7}\u{300}\u{345}\u{3b7}\u{345}\u{3b7}\u{301}\u{345}\u{3b7}\u{342}\u{3b7}\u{342}\u{345}\u{395}\u{300}\u{395}\u{301}\u{397}\u{300}\u{397}\u{301}\u{397}\u{345} \u{313}\u{300} \u{313}\u{301} \u{313}\u{342}\u{3b9}\u{306}\u{3b9}\u{304}\u{3b9}\u{308}\u{300}\u{3b9}\u{308}\u{301}\u{3b9}\u{342}\u{3b9}\u{308}\u{342}\u{399}\u{306}\u{399}\u{304}\u{399}\u{300}\u{399}\u{301} \u{314}\u{300} \u{314}\u{301} \u{314}\u{342}\u{3c5}\u{306}\u{3c5}\u{304}\u{3c5}\u{308}\u{300}\u{3c5}\u{308}\u{301}\u{3c1}\u{313}\u{3c1}\u{314}\u{3c5}\u{342}\u{3c5}\u{308}\u{342}\u{3a5}\u{306}\u{3a5}\u{304}\u{3a5}\u{300}\u{3a5}\u{301}\u{3a1}\u{314} \u{308}\u{300} \u{308}\u{301}`\u{3c9}\u{300}\u{345}\u{3c9}\u{345}\u{3c9}\u{301}\u{345}\u{3c9}\u{342}\u{3c9}\u{342}\u{345}\u{39f}\u{300}\u{39f}\u{301}\u{3a9}\u{300}\u{3a9}\u{301}\u{3a9}\u{345} \u{301} \u{314}";
+
+#[cfg(feature = "nfkd-cjk")]
+#[rustfmt::skip]
+pub(super) const CJK_DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0x2e9f, 0, 3),
+    (0x2ef3, 3, 3),
+    (0x2f00, 6, 3),
+    (0x2f01, 9, 3),
+    (0x2f02, 12, 3),
+    (0x2f03, 15, 3),
+    (0x2f04, 18, 3),
+    (0x2f05, 21, 3),
+    (0x2f06, 24, 3),
+    (0x2f07, 27, 3),
+    (0x2f08, 30, 3),
+    (0x2f09, 33, 3),
+    (0x2f0a, 36, 3),
+    (0x2f0b, 39, 3),
+    (0x2f0c, 42, 3),
+    (0x2f0d, 45, 3),
+    (0x2f0e, 48, 3),
+    (0x2f0f, 51, 3),
+    (0x2f10, 54, 3),
+    (0x2f11, 57, 3),
+    (0x2f12, 60, 3),
+    (0x2f13, 63, 3),
+    (0x2f14, 66, 3),
+    (0x2f15, 69, 3),
+    (0x2f16, 72, 3),
+    (0x2f17, 75, 3),
+    (0x2f18, 78, 3),
+    (0x2f19, 81, 3),
+    (0x2f1a, 84, 3),
+    (0x2f1b, 87, 3),
+    (0x2f1c, 90, 3),
+    (0x2f1d, 93, 3),
+    (0x2f1e, 96, 3),
+    (0x2f1f, 99, 3),
+    (0x2f20, 102, 3),
+    (0x2f21, 105, 3),
+    (0x2f22, 108, 3),
+    (0x2f23, 111, 3),
+    (0x2f24, 114, 3),
+    (0x2f25, 117, 3),
+    (0x2f26, 120, 3),
+    (0x2f27, 123, 3),
+    (0x2f28, 126, 3),
+    (0x2f29, 129, 3),
+    (0x2f2a, 132, 3),
+    (0x2f2b, 135, 3),
+    (0x2f2c, 138, 3),
+    (0x2f2d, 141, 3),
+    (0x2f2e, 144, 3),
+    (0x2f2f, 147, 3),
+    (0x2f30, 150, 3),
+    (0x2f31, 153, 3),
+    (0x2f32, 156, 3),
+    (0x2f33, 159, 3),
+    (0x2f34, 162, 3),
+    (0x2f35, 165, 3),
+    (0x2f36, 168, 3),
+    (0x2f37, 171, 3),
+    (0x2f38, 174, 3),
+    (0x2f39, 177, 3),
+    (0x2f3a, 180, 3),
+    (0x2f3b, 183, 3),
+    (0x2f3c, 186, 3),
+    (0x2f3d, 189, 3),
+    (0x2f3e, 192, 3),
+    (0x2f3f, 195, 3),
+    (0x2f40, 198, 3),
+    (0x2f41, 201, 3),
+    (0x2f42, 204, 3),
+    (0x2f43, 207, 3),
+    (0x2f44, 210, 3),
+    (0x2f45, 213, 3),
+    (0x2f46, 216, 3),
+    (0x2f47, 219, 3),
+    (0x2f48, 222, 3),
+    (0x2f49, 225, 3),
+    (0x2f4a, 228, 3),
+    (0x2f4b, 231, 3),
+    (0x2f4c, 234, 3),
+    (0x2f4d, 237, 3),
+    (0x2f4e, 240, 3),
+    (0x2f4f, 243, 3),
+    (0x2f50, 246, 3),
+    (0x2f51, 249, 3),
+    (0x2f52, 252, 3),
+    (0x2f53, 255, 3),
+    (0x2f54, 258, 3),
+    (0x2f55, 261, 3),
+    (0x2f56, 264, 3),
+    (0x2f57, 267, 3),
+    (0x2f58, 270, 3),
+    (0x2f59, 273, 3),
+    (0x2f5a, 276, 3),
+    (0x2f5b, 279, 3),
+    (0x2f5c, 282, 3),
+    (0x2f5d, 285, 3),
+    (0x2f5e, 288, 3),
+    (0x2f5f, 291, 3),
+    (0x2f60, 294, 3),
+    (0x2f61, 297, 3),
+    (0x2f62, 300, 3),
+    (0x2f63, 303, 3),
+    (0x2f64, 306, 3),
+    (0x2f65, 309, 3),
+    (0x2f66, 312, 3),
+    (0x2f67, 315, 3),
+    (0x2f68, 318, 3),
+    (0x2f69, 321, 3),
+    (0x2f6a, 324, 3),
+    (0x2f6b, 327, 3),
+    (0x2f6c, 330, 3),
+    (0x2f6d, 333, 3),
+    (0x2f6e, 336, 3),
+    (0x2f6f, 339, 3),
+    (0x2f70, 342, 3),
+    (0x2f71, 345, 3),
+    (0x2f72, 348, 3),
+    (0x2f73, 351, 3),
+    (0x2f74, 354, 3),
+    (0x2f75, 357, 3),
+    (0x2f76, 360, 3),
+    (0x2f77, 363, 3),
+    (0x2f78, 366, 3),
+    (0x2f79, 369, 3),
+    (0x2f7a, 372, 3),
+    (0x2f7b, 375, 3),
+    (0x2f7c, 378, 3),
+    (0x2f7d, 381, 3),
+    (0x2f7e, 384, 3),
+    (0x2f7f, 387, 3),
+    (0x2f80, 390, 3),
+    (0x2f81, 393, 3),
+    (0x2f82, 396, 3),
+    (0x2f83, 399, 3),
+    (0x2f84, 402, 3),
+    (0x2f85, 405, 3),
+    (0x2f86, 408, 3),
+    (0x2f87, 411, 3),
+    (0x2f88, 414, 3),
+    (0x2f89, 417, 3),
+    (0x2f8a, 420, 3),
+    (0x2f8b, 423, 3),
+    (0x2f8c, 426, 3),
+    (0x2f8d, 429, 3),
+    (0x2f8e, 432, 3),
+    (0x2f8f, 435, 3),
+    (0x2f90, 438, 3),
+    (0x2f91, 441, 3),
+    (0x2f92, 444, 3),
+    (0x2f93, 447, 3),
+    (0x2f94, 450, 3),
+    (0x2f95, 453, 3),
+    (0x2f96, 456, 3),
+    (0x2f97, 459, 3),
+    (0x2f98, 462, 3),
+    (0x2f99, 465, 3),
+    (0x2f9a, 468, 3),
+    (0x2f9b, 471, 3),
+    (0x2f9c, 474, 3),
+    (0x2f9d, 477, 3),
+    (0x2f9e, 480, 3),
+    (0x2f9f, 483, 3),
+    (0x2fa0, 486, 3),
+    (0x2fa1, 489, 3),
+    (0x2fa2, 492, 3),
+    (0x2fa3, 495, 3),
+    (0x2fa4, 498, 3),
+    (0x2fa5, 501, 3),
+    (0x2fa6, 504, 3),
+    (0x2fa7, 507, 3),
+    (0x2fa8, 510, 3),
+    (0x2fa9, 513, 3),
+    (0x2faa, 516, 3),
+    (0x2fab, 519, 3),
+    (0x2fac, 522, 3),
+    (0x2fad, 525, 3),
+    (0x2fae, 528, 3),
+    (0x2faf, 531, 3),
+    (0x2fb0, 534, 3),
+    (0x2fb1, 537, 3),
+    (0x2fb2, 540, 3),
+    (0x2fb3, 543, 3),
+    (0x2fb4, 546, 3),
+    (0x2fb5, 549, 3),
+    (0x2fb6, 552, 3),
+    (0x2fb7, 555, 3),
+    (0x2fb8, 558, 3),
+    (0x2fb9, 561, 3),
+    (0x2fba, 564, 3),
+    (0x2fbb, 567, 3),
+    (0x2fbc, 570, 3),
+    (0x2fbd, 573, 3),
+    (0x2fbe, 576, 3),
+    (0x2fbf, 579, 3),
+    (0x2fc0, 582, 3),
+    (0x2fc1, 585, 3),
+    (0x2fc2, 588, 3),
+    (0x2fc3, 591, 3),
+    (0x2fc4, 594, 3),
+    (0x2fc5, 597, 3),
+    (0x2fc6, 600, 3),
+    (0x2fc7, 603, 3),
+    (0x2fc8, 606, 3),
+    (0x2fc9, 609, 3),
+    (0x2fca, 612, 3),
+    (0x2fcb, 615, 3),
+    (0x2fcc, 618, 3),
+    (0x2fcd, 621, 3),
+    (0x2fce, 624, 3),
+    (0x2fcf, 627, 3),
+    (0x2fd0, 630, 3),
+    (0x2fd1, 633, 3),
+    (0x2fd2, 636, 3),
+    (0x2fd3, 639, 3),
+    (0x2fd4, 642, 3),
+    (0x2fd5, 645, 3),
+    (0x3000, 648, 1),
+    (0x3036, 649, 3),
+    (0x3038, 652, 3),
+    (0x3039, 655, 3),
+    (0x303a, 658, 3),
+    (0x304c, 661, 6),
+    (0x304e, 667, 6),
+    (0x3050, 673, 6),
+    (0x3052, 679, 6),
+    (0x3054, 685, 6),
+    (0x3056, 691, 6),
+    (0x3058, 697, 6),
+    (0x305a, 703, 6),
+    (0x305c, 709, 6),
+    (0x305e, 715, 6),
+    (0x3060, 721, 6),
+    (0x3062, 727, 6),
+    (0x3065, 733, 6),
+    (0x3067, 739, 6),
+    (0x3069, 745, 6),
+    (0x3070, 751, 6),
+    (0x3071, 757, 6),
+    (0x3073, 763, 6),
+    (0x3074, 769, 6),
+    (0x3076, 775, 6),
+    (0x3077, 781, 6),
+    (0x3079, 787, 6),
+    (0x307a, 793, 6),
+    (0x307c, 799, 6),
+    (0x307d, 805, 6),
+    (0x3094, 811, 6),
+    (0x309b, 817, 4),
+    (0x309c, 821, 4),
+    (0x309e, 825, 6),
+    (0x309f, 831, 6),
+    (0x30ac, 837, 6),
+    (0x30ae, 843, 6),
+    (0x30b0, 849, 6),
+    (0x30b2, 855, 6),
+    (0x30b4, 861, 6),
+    (0x30b6, 867, 6),
+    (0x30b8, 873, 6),
+    (0x30ba, 879, 6),
+    (0x30bc, 885, 6),
+    (0x30be, 891, 6),
+    (0x30c0, 897, 6),
+    (0x30c2, 903, 6),
+    (0x30c5, 909, 6),
+    (0x30c7, 915, 6),
+    (0x30c9, 921, 6),
+    (0x30d0, 927, 6),
+    (0x30d1, 933, 6),
+    (0x30d3, 939, 6),
+    (0x30d4, 945, 6),
+    (0x30d6, 951, 6),
+    (0x30d7, 957, 6),
+    (0x30d9, 963, 6),
+    (0x30da, 969, 6),
+    (0x30dc, 975, 6),
+    (0x30dd, 981, 6),
+    (0x30f4, 987, 6),
+    (0x30f7, 993, 6),
+    (0x30f8, 999, 6),
+    (0x30f9, 1005, 6),
+    (0x30fa, 1011, 6),
+    (0x30fe, 1017, 6),
+    (0x30ff, 1023, 6),
+    (0x3192, 1029, 3),
+    (0x3193, 1032, 3),
+    (0x3194, 1035, 3),
+    (0x3195, 1038, 3),
+    (0x3196, 1041, 3),
+    (0x3197, 1044, 3),
+    (0x3198, 1047, 3),
+    (0x3199, 1050, 3),
+    (0x319a, 1053, 3),
+    (0x319b, 1056, 3),
+    (0x319c, 1059, 3),
+    (0x319d, 1062, 3),
+    (0x319e, 1065, 3),
+    (0x319f, 1068, 3),
+    (0x3200, 1071, 5),
+    (0x3201, 1076, 5),
+    (0x3202, 1081, 5),
+    (0x3203, 1086, 5),
+    (0x3204, 1091, 5),
+    (0x3205, 1096, 5),
+    (0x3206, 1101, 5),
+    (0x3207, 1106, 5),
+    (0x3208, 1111, 5),
+    (0x3209, 1116, 5),
+    (0x320a, 1121, 5),
+    (0x320b, 1126, 5),
+    (0x320c, 1131, 5),
+    (0x320d, 1136, 5),
+    (0x320e, 1141, 8),
+    (0x320f, 1149, 8),
+    (0x3210, 1157, 8),
+    (0x3211, 1165, 8),
+    (0x3212, 1173, 8),
+    (0x3213, 1181, 8),
+    (0x3214, 1189, 8),
+    (0x3215, 1197, 8),
+    (0x3216, 1205, 8),
+    (0x3217, 1213, 8),
+    (0x3218, 1221, 8),
+    (0x3219, 1229, 8),
+    (0x321a, 1237, 8),
+    (0x321b, 1245, 8),
+    (0x321c, 1253, 8),
+    (0x321d, 1261, 17),
+    (0x321e, 1278, 14),
+    (0x3220, 1292, 5),
+    (0x3221, 1297, 5),
+    (0x3222, 1302, 5),
+    (0x3223, 1307, 5),
+    (0x3224, 1312, 5),
+    (0x3225, 1317, 5),
+    (0x3226, 1322, 5),
+    (0x3227, 1327, 5),
+    (0x3228, 1332, 5),
+    (0x3229, 1337, 5),
+    (0x322a, 1342, 5),
+    (0x322b, 1347, 5),
+    (0x322c, 1352, 5),
+    (0x322d, 1357, 5),
+    (0x322e, 1362, 5),
+    (0x322f, 1367, 5),
+    (0x3230, 1372, 5),
+    (0x3231, 1377, 5),
+    (0x3232, 1382, 5),
+    (0x3233, 1387, 5),
+    (0x3234, 1392, 5),
+    (0x3235, 1397, 5),
+    (0x3236, 1402, 5),
+    (0x3237, 1407, 5),
+    (0x3238, 1412, 5),
+    (0x3239, 1417, 5),
+    (0x323a, 1422, 5),
+    (0x323b, 1427, 5),
+    (0x323c, 1432, 5),
+    (0x323d, 1437, 5),
+    (0x323e, 1442, 5),
+    (0x323f, 1447, 5),
+    (0x3240, 1452, 5),
+    (0x3241, 1457, 5),
+    (0x3242, 1462, 5),
+    (0x3243, 1467, 5),
+    (0x3244, 1472, 3),
+    (0x3245, 1475, 3),
+    (0x3246, 1478, 3),
+    (0x3247, 1481, 3),
+    (0x3250, 1484, 3),
+    (0x3251, 1487, 2),
+    (0x3252, 1489, 2),
+    (0x3253, 1491, 2),
+    (0x3254, 1493, 2),
+    (0x3255, 1495, 2),
+    (0x3256, 1497, 2),
+    (0x3257, 1499, 2),
+    (0x3258, 1501, 2),
+    (0x3259, 1503, 2),
+    (0x325a, 1505, 2),
+    (0x325b, 1507, 2),
+    (0x325c, 1509, 2),
+    (0x325d, 1511, 2),
+    (0x325e, 1513, 2),
+    (0x325f, 1515, 2),
+    (0x3260, 1517, 3),
+    (0x3261, 1520, 3),
+    (0x3262, 1523, 3),
+    (0x3263, 1526, 3),
+    (0x3264, 1529, 3),
+    (0x3265, 1532, 3),
+    (0x3266, 1535, 3),
+    (0x3267, 1538, 3),
+    (0x3268, 1541, 3),
+    (0x3269, 1544, 3),
+    (0x326a, 1547, 3),
+    (0x326b, 1550, 3),
+    (0x326c, 1553, 3),
+    (0x326d, 1556, 3),
+    (0x326e, 1559, 6),
+    (0x326f, 1565, 6),
+    (0x3270, 1571, 6),
+    (0x3271, 1577, 6),
+    (0x3272, 1583, 6),
+    (0x3273, 1589, 6),
+    (0x3274, 1595, 6),
+    (0x3275, 1601, 6),
+    (0x3276, 1607, 6),
+    (0x3277, 1613, 6),
+    (0x3278, 1619, 6),
+    (0x3279, 1625, 6),
+    (0x327a, 1631, 6),
+    (0x327b, 1637, 6),
+    (0x327c, 1643, 15),
+    (0x327d, 1658, 12),
+    (0x327e, 1670, 6),
+    (0x3280, 1676, 3),
+    (0x3281, 1679, 3),
+    (0x3282, 1682, 3),
+    (0x3283, 1685, 3),
+    (0x3284, 1688, 3),
+    (0x3285, 1691, 3),
+    (0x3286, 1694, 3),
+    (0x3287, 1697, 3),
+    (0x3288, 1700, 3),
+    (0x3289, 1703, 3),
+    (0x328a, 1706, 3),
+    (0x328b, 1709, 3),
+    (0x328c, 1712, 3),
+    (0x328d, 1715, 3),
+    (0x328e, 1718, 3),
+    (0x328f, 1721, 3),
+    (0x3290, 1724, 3),
+    (0x3291, 1727, 3),
+    (0x3292, 1730, 3),
+    (0x3293, 1733, 3),
+    (0x3294, 1736, 3),
+    (0x3295, 1739, 3),
+    (0x3296, 1742, 3),
+    (0x3297, 1745, 3),
+    (0x3298, 1748, 3),
+    (0x3299, 1751, 3),
+    (0x329a, 1754, 3),
+    (0x329b, 1757, 3),
+    (0x329c, 1760, 3),
+    (0x329d, 1763, 3),
+    (0x329e, 1766, 3),
+    (0x329f, 1769, 3),
+    (0x32a0, 1772, 3),
+    (0x32a1, 1775, 3),
+    (0x32a2, 1778, 3),
+    (0x32a3, 1781, 3),
+    (0x32a4, 1784, 3),
+    (0x32a5, 1787, 3),
+    (0x32a6, 1790, 3),
+    (0x32a7, 1793, 3),
+    (0x32a8, 1796, 3),
+    (0x32a9, 1799, 3),
+    (0x32aa, 1802, 3),
+    (0x32ab, 1805, 3),
+    (0x32ac, 1808, 3),
+    (0x32ad, 1811, 3),
+    (0x32ae, 1814, 3),
+    (0x32af, 1817, 3),
+    (0x32b0, 1820, 3),
+    (0x32b1, 1823, 2),
+    (0x32b2, 1825, 2),
+    (0x32b3, 1827, 2),
+    (0x32b4, 1829, 2),
+    (0x32b5, 1831, 2),
+    (0x32b6, 1833, 2),
+    (0x32b7, 1835, 2),
+    (0x32b8, 1837, 2),
+    (0x32b9, 1839, 2),
+    (0x32ba, 1841, 2),
+    (0x32bb, 1843, 2),
+    (0x32bc, 1845, 2),
+    (0x32bd, 1847, 2),
+    (0x32be, 1849, 2),
+    (0x32bf, 1851, 2),
+    (0x32c0, 1853, 4),
+    (0x32c1, 1857, 4),
+    (0x32c2, 1861, 4),
+    (0x32c3, 1865, 4),
+    (0x32c4, 1869, 4),
+    (0x32c5, 1873, 4),
+    (0x32c6, 1877, 4),
+    (0x32c7, 1881, 4),
+    (0x32c8, 1885, 4),
+    (0x32c9, 1889, 5),
+    (0x32ca, 1894, 5),
+    (0x32cb, 1899, 5),
+    (0x32cc, 1904, 2),
+    (0x32cd, 1906, 3),
+    (0x32ce, 1909, 2),
+    (0x32cf, 1911, 3),
+    (0x32d0, 1914, 3),
+    (0x32d1, 1917, 3),
+    (0x32d2, 1920, 3),
+    (0x32d3, 1923, 3),
+    (0x32d4, 1926, 3),
+    (0x32d5, 1929, 3),
+    (0x32d6, 1932, 3),
+    (0x32d7, 1935, 3),
+    (0x32d8, 1938, 3),
+    (0x32d9, 1941, 3),
+    (0x32da, 1944, 3),
+    (0x32db, 1947, 3),
+    (0x32dc, 1950, 3),
+    (0x32dd, 1953, 3),
+    (0x32de, 1956, 3),
+    (0x32df, 1959, 3),
+    (0x32e0, 1962, 3),
+    (0x32e1, 1965, 3),
+    (0x32e2, 1968, 3),
+    (0x32e3, 1971, 3),
+    (0x32e4, 1974, 3),
+    (0x32e5, 1977, 3),
+    (0x32e6, 1980, 3),
+    (0x32e7, 1983, 3),
+    (0x32e8, 1986, 3),
+    (0x32e9, 1989, 3),
+    (0x32ea, 1992, 3),
+    (0x32eb, 1995, 3),
+    (0x32ec, 1998, 3),
+    (0x32ed, 2001, 3),
+    (0x32ee, 2004, 3),
+    (0x32ef, 2007, 3),
+    (0x32f0, 2010, 3),
+    (0x32f1, 2013, 3),
+    (0x32f2, 2016, 3),
+    (0x32f3, 2019, 3),
+    (0x32f4, 2022, 3),
+    (0x32f5, 2025, 3),
+    (0x32f6, 2028, 3),
+    (0x32f7, 2031, 3),
+    (0x32f8, 2034, 3),
+    (0x32f9, 2037, 3),
+    (0x32fa, 2040, 3),
+    (0x32fb, 2043, 3),
+    (0x32fc, 2046, 3),
+    (0x32fd, 2049, 3),
+    (0x32fe, 2052, 3),
+    (0x32ff, 2055, 6),
+    (0x3300, 2061, 15),
+    (0x3301, 2076, 12),
+    (0x3302, 2088, 15),
+    (0x3303, 2103, 9),
+    (0x3304, 2112, 15),
+    (0x3305, 2127, 9),
+    (0x3306, 2136, 9),
+    (0x3307, 2145, 18),
+    (0x3308, 2163, 12),
+    (0x3309, 2175, 9),
+    (0x330a, 2184, 9),
+    (0x330b, 2193, 9),
+    (0x330c, 2202, 12),
+    (0x330d, 2214, 12),
+    (0x330e, 2226, 12),
+    (0x330f, 2238, 12),
+    (0x3310, 2250, 12),
+    (0x3311, 2262, 12),
+    (0x3312, 2274, 12),
+    (0x3313, 2286, 18),
+    (0x3314, 2304, 6),
+    (0x3315, 2310, 18),
+    (0x3316, 2328, 18),
+    (0x3317, 2346, 15),
+    (0x3318, 2361, 12),
+    (0x3319, 2373, 18),
+    (0x331a, 2391, 18),
+    (0x331b, 2409, 12),
+    (0x331c, 2421, 9),
+    (0x331d, 2430, 9),
+    (0x331e, 2439, 12),
+    (0x331f, 2451, 12),
+    (0x3320, 2463, 15),
+    (0x3321, 2478, 15),
+    (0x3322, 2493, 9),
+    (0x3323, 2502, 9),
+    (0x3324, 2511, 12),
+    (0x3325, 2523, 9),
+    (0x3326, 2532, 9),
+    (0x3327, 2541, 6),
+    (0x3328, 2547, 6),
+    (0x3329, 2553, 9),
+    (0x332a, 2562, 9),
+    (0x332b, 2571, 18),
+    (0x332c, 2589, 12),
+    (0x332d, 2601, 15),
+    (0x332e, 2616, 18),
+    (0x332f, 2634, 12),
+    (0x3330, 2646, 9),
+    (0x3331, 2655, 9),
+    (0x3332, 2664, 18),
+    (0x3333, 2682, 12),
+    (0x3334, 2694, 18),
+    (0x3335, 2712, 9),
+    (0x3336, 2721, 15),
+    (0x3337, 2736, 9),
+    (0x3338, 2745, 12),
+    (0x3339, 2757, 9),
+    (0x333a, 2766, 12),
+    (0x333b, 2778, 15),
+    (0x333c, 2793, 12),
+    (0x333d, 2805, 15),
+    (0x333e, 2820, 12),
+    (0x333f, 2832, 6),
+    (0x3340, 2838, 15),
+    (0x3341, 2853, 9),
+    (0x3342, 2862, 9),
+    (0x3343, 2871, 12),
+    (0x3344, 2883, 9),
+    (0x3345, 2892, 9),
+    (0x3346, 2901, 9),
+    (0x3347, 2910, 15),
+    (0x3348, 2925, 12),
+    (0x3349, 2937, 6),
+    (0x334a, 2943, 18),
+    (0x334b, 2961, 9),
+    (0x334c, 2970, 15),
+    (0x334d, 2985, 12),
+    (0x334e, 2997, 12),
+    (0x334f, 3009, 9),
+    (0x3350, 3018, 9),
+    (0x3351, 3027, 12),
+    (0x3352, 3039, 6),
+    (0x3353, 3045, 12),
+    (0x3354, 3057, 15),
+    (0x3355, 3072, 6),
+    (0x3356, 3078, 18),
+    (0x3357, 3096, 9),
+    (0x3358, 3105, 4),
+    (0x3359, 3109, 4),
+    (0x335a, 3113, 4),
+    (0x335b, 3117, 4),
+    (0x335c, 3121, 4),
+    (0x335d, 3125, 4),
+    (0x335e, 3129, 4),
+    (0x335f, 3133, 4),
+    (0x3360, 3137, 4),
+    (0x3361, 3141, 4),
+    (0x3362, 3145, 5),
+    (0x3363, 3150, 5),
+    (0x3364, 3155, 5),
+    (0x3365, 3160, 5),
+    (0x3366, 3165, 5),
+    (0x3367, 3170, 5),
+    (0x3368, 3175, 5),
+    (0x3369, 3180, 5),
+    (0x336a, 3185, 5),
+    (0x336b, 3190, 5),
+    (0x336c, 3195, 5),
+    (0x336d, 3200, 5),
+    (0x336e, 3205, 5),
+    (0x336f, 3210, 5),
+    (0x3370, 3215, 5),
+    (0x3371, 3220, 3),
+    (0x3372, 3223, 2),
+    (0x3373, 3225, 2),
+    (0x3374, 3227, 3),
+    (0x3375, 3230, 2),
+    (0x3376, 3232, 2),
+    (0x3377, 3234, 2),
+    (0x3378, 3236, 3),
+    (0x3379, 3239, 3),
+    (0x337a, 3242, 2),
+    (0x337b, 3244, 6),
+    (0x337c, 3250, 6),
+    (0x337d, 3256, 6),
+    (0x337e, 3262, 6),
+    (0x337f, 3268, 12),
+    (0x3380, 3280, 2),
+    (0x3381, 3282, 2),
+    (0x3382, 3284, 3),
+    (0x3383, 3287, 2),
+    (0x3384, 3289, 2),
+    (0x3385, 3291, 2),
+    (0x3386, 3293, 2),
+    (0x3387, 3295, 2),
+    (0x3388, 3297, 3),
+    (0x3389, 3300, 4),
+    (0x338a, 3304, 2),
+    (0x338b, 3306, 2),
+    (0x338c, 3308, 3),
+    (0x338d, 3311, 3),
+    (0x338e, 3314, 2),
+    (0x338f, 3316, 2),
+    (0x3390, 3318, 2),
+    (0x3391, 3320, 3),
+    (0x3392, 3323, 3),
+    (0x3393, 3326, 3),
+    (0x3394, 3329, 3),
+    (0x3395, 3332, 3),
+    (0x3396, 3335, 2),
+    (0x3397, 3337, 2),
+    (0x3398, 3339, 2),
+    (0x3399, 3341, 2),
+    (0x339a, 3343, 2),
+    (0x339b, 3345, 3),
+    (0x339c, 3348, 2),
+    (0x339d, 3350, 2),
+    (0x339e, 3352, 2),
+    (0x339f, 3354, 3),
+    (0x33a0, 3357, 3),
+    (0x33a1, 3360, 2),
+    (0x33a2, 3362, 3),
+    (0x33a3, 3365, 3),
+    (0x33a4, 3368, 3),
+    (0x33a5, 3371, 2),
+    (0x33a6, 3373, 3),
+    (0x33a7, 3376, 5),
+    (0x33a8, 3381, 6),
+    (0x33a9, 3387, 2),
+    (0x33aa, 3389, 3),
+    (0x33ab, 3392, 3),
+    (0x33ac, 3395, 3),
+    (0x33ad, 3398, 3),
+    (0x33ae, 3401, 7),
+    (0x33af, 3408, 8),
+    (0x33b0, 3416, 2),
+    (0x33b1, 3418, 2),
+    (0x33b2, 3420, 3),
+    (0x33b3, 3423, 2),
+    (0x33b4, 3425, 2),
+    (0x33b5, 3427, 2),
+    (0x33b6, 3429, 3),
+    (0x33b7, 3432, 2),
+    (0x33b8, 3434, 2),
+    (0x33b9, 3436, 2),
+    (0x33ba, 3438, 2),
+    (0x33bb, 3440, 2),
+    (0x33bc, 3442, 3),
+    (0x33bd, 3445, 2),
+    (0x33be, 3447, 2),
+    (0x33bf, 3449, 2),
+    (0x33c0, 3451, 3),
+    (0x33c1, 3454, 3),
+    (0x33c2, 3457, 4),
+    (0x33c3, 3461, 2),
+    (0x33c4, 3463, 2),
+    (0x33c5, 3465, 2),
+    (0x33c6, 3467, 6),
+    (0x33c7, 3473, 3),
+    (0x33c8, 3476, 2),
+    (0x33c9, 3478, 2),
+    (0x33ca, 3480, 2),
+    (0x33cb, 3482, 2),
+    (0x33cc, 3484, 2),
+    (0x33cd, 3486, 2),
+    (0x33ce, 3488, 2),
+    (0x33cf, 3490, 2),
+    (0x33d0, 3492, 2),
+    (0x33d1, 3494, 2),
+    (0x33d2, 3496, 3),
+    (0x33d3, 3499, 2),
+    (0x33d4, 3501, 2),
+    (0x33d5, 3503, 3),
+    (0x33d6, 3506, 3),
+    (0x33d7, 3509, 2),
+    (0x33d8, 3511, 4),
+    (0x33d9, 3515, 3),
+    (0x33da, 3518, 2),
+    (0x33db, 3520, 2),
+    (0x33dc, 3522, 2),
+    (0x33dd, 3524, 2),
+    (0x33de, 3526, 5),
+    (0x33df, 3531, 5),
+    (0x33e0, 3536, 4),
+    (0x33e1, 3540, 4),
+    (0x33e2, 3544, 4),
+    (0x33e3, 3548, 4),
+    (0x33e4, 3552, 4),
+    (0x33e5, 3556, 4),
+    (0x33e6, 3560, 4),
+    (0x33e7, 3564, 4),
+    (0x33e8, 3568, 4),
+    (0x33e9, 3572, 5),
+    (0x33ea, 3577, 5),
+    (0x33eb, 3582, 5),
+    (0x33ec, 3587, 5),
+    (0x33ed, 3592, 5),
+    (0x33ee, 3597, 5),
+    (0x33ef, 3602, 5),
+    (0x33f0, 3607, 5),
+    (0x33f1, 3612, 5),
+    (0x33f2, 3617, 5),
+    (0x33f3, 3622, 5),
+    (0x33f4, 3627, 5),
+    (0x33f5, 3632, 5),
+    (0x33f6, 3637, 5),
+    (0x33f7, 3642, 5),
+    (0x33f8, 3647, 5),
+    (0x33f9, 3652, 5),
+    (0x33fa, 3657, 5),
+    (0x33fb, 3662, 5),
+    (0x33fc, 3667, 5),
+    (0x33fd, 3672, 5),
+    (0x33fe, 3677, 5),
+    (0x33ff, 3682, 3),
+    (0xf900, 3685, 3),
+    (0xf901, 3688, 3),
+    (0xf902, 3691, 3),
+    (0xf903, 3694, 3),
+    (0xf904, 3697, 3),
+    (0xf905, 3700, 3),
+    (0xf906, 3703, 3),
+    (0xf907, 3706, 3),
+    (0xf908, 3709, 3),
+    (0xf909, 3712, 3),
+    (0xf90a, 3715, 3),
+    (0xf90b, 3718, 3),
+    (0xf90c, 3721, 3),
+    (0xf90d, 3724, 3),
+    (0xf90e, 3727, 3),
+    (0xf90f, 3730, 3),
+    (0xf910, 3733, 3),
+    (0xf911, 3736, 3),
+    (0xf912, 3739, 3),
+    (0xf913, 3742, 3),
+    (0xf914, 3745, 3),
+    (0xf915, 3748, 3),
+    (0xf916, 3751, 3),
+    (0xf917, 3754, 3),
+    (0xf918, 3757, 3),
+    (0xf919, 3760, 3),
+    (0xf91a, 3763, 3),
+    (0xf91b, 3766, 3),
+    (0xf91c, 3769, 3),
+    (0xf91d, 3772, 3),
+    (0xf91e, 3775, 3),
+    (0xf91f, 3778, 3),
+    (0xf920, 3781, 3),
+    (0xf921, 3784, 3),
+    (0xf922, 3787, 3),
+    (0xf923, 3790, 3),
+    (0xf924, 3793, 3),
+    (0xf925, 3796, 3),
+    (0xf926, 3799, 3),
+    (0xf927, 3802, 3),
+    (0xf928, 3805, 3),
+    (0xf929, 3808, 3),
+    (0xf92a, 3811, 3),
+    (0xf92b, 3814, 3),
+    (0xf92c, 3817, 3),
+    (0xf92d, 3820, 3),
+    (0xf92e, 3823, 3),
+    (0xf92f, 3826, 3),
+    (0xf930, 3829, 3),
+    (0xf931, 3832, 3),
+    (0xf932, 3835, 3),
+    (0xf933, 3838, 3),
+    (0xf934, 3841, 3),
+    (0xf935, 3844, 3),
+    (0xf936, 3847, 3),
+    (0xf937, 3850, 3),
+    (0xf938, 3853, 3),
+    (0xf939, 3856, 3),
+    (0xf93a, 3859, 3),
+    (0xf93b, 3862, 3),
+    (0xf93c, 3865, 3),
+    (0xf93d, 3868, 3),
+    (0xf93e, 3871, 3),
+    (0xf93f, 3874, 3),
+    (0xf940, 3877, 3),
+    (0xf941, 3880, 3),
+    (0xf942, 3883, 3),
+    (0xf943, 3886, 3),
+    (0xf944, 3889, 3),
+    (0xf945, 3892, 3),
+    (0xf946, 3895, 3),
+    (0xf947, 3898, 3),
+    (0xf948, 3901, 3),
+    (0xf949, 3904, 3),
+    (0xf94a, 3907, 3),
+    (0xf94b, 3910, 3),
+    (0xf94c, 3913, 3),
+    (0xf94d, 3916, 3),
+    (0xf94e, 3919, 3),
+    (0xf94f, 3922, 3),
+    (0xf950, 3925, 3),
+    (0xf951, 3928, 3),
+    (0xf952, 3931, 3),
+    (0xf953, 3934, 3),
+    (0xf954, 3937, 3),
+    (0xf955, 3940, 3),
+    (0xf956, 3943, 3),
+    (0xf957, 3946, 3),
+    (0xf958, 3949, 3),
+    (0xf959, 3952, 3),
+    (0xf95a, 3955, 3),
+    (0xf95b, 3958, 3),
+    (0xf95c, 3961, 3),
+    (0xf95d, 3964, 3),
+    (0xf95e, 3967, 3),
+    (0xf95f, 3970, 3),
+    (0xf960, 3973, 3),
+    (0xf961, 3976, 3),
+    (0xf962, 3979, 3),
+    (0xf963, 3982, 3),
+    (0xf964, 3985, 3),
+    (0xf965, 3988, 3),
+    (0xf966, 3991, 3),
+    (0xf967, 3994, 3),
+    (0xf968, 3997, 3),
+    (0xf969, 4000, 3),
+    (0xf96a, 4003, 3),
+    (0xf96b, 4006, 3),
+    (0xf96c, 4009, 3),
+    (0xf96d, 4012, 3),
+    (0xf96e, 4015, 3),
+    (0xf96f, 4018, 3),
+    (0xf970, 4021, 3),
+    (0xf971, 4024, 3),
+    (0xf972, 4027, 3),
+    (0xf973, 4030, 3),
+    (0xf974, 4033, 3),
+    (0xf975, 4036, 3),
+    (0xf976, 4039, 3),
+    (0xf977, 4042, 3),
+    (0xf978, 4045, 3),
+    (0xf979, 4048, 3),
+    (0xf97a, 4051, 3),
+    (0xf97b, 4054, 3),
+    (0xf97c, 4057, 3),
+    (0xf97d, 4060, 3),
+    (0xf97e, 4063, 3),
+    (0xf97f, 4066, 3),
+    (0xf980, 4069, 3),
+    (0xf981, 4072, 3),
+    (0xf982, 4075, 3),
+    (0xf983, 4078, 3),
+    (0xf984, 4081, 3),
+    (0xf985, 4084, 3),
+    (0xf986, 4087, 3),
+    (0xf987, 4090, 3),
+    (0xf988, 4093, 3),
+    (0xf989, 4096, 3),
+    (0xf98a, 4099, 3),
+    (0xf98b, 4102, 3),
+    (0xf98c, 4105, 3),
+    (0xf98d, 4108, 3),
+    (0xf98e, 4111, 3),
+    (0xf98f, 4114, 3),
+    (0xf990, 4117, 3),
+    (0xf991, 4120, 3),
+    (0xf992, 4123, 3),
+    (0xf993, 4126, 3),
+    (0xf994, 4129, 3),
+    (0xf995, 4132, 3),
+    (0xf996, 4135, 3),
+    (0xf997, 4138, 3),
+    (0xf998, 4141, 3),
+    (0xf999, 4144, 3),
+    (0xf99a, 4147, 3),
+    (0xf99b, 4150, 3),
+    (0xf99c, 4153, 3),
+    (0xf99d, 4156, 3),
+    (0xf99e, 4159, 3),
+    (0xf99f, 4162, 3),
+    (0xf9a0, 4165, 3),
+    (0xf9a1, 4168, 3),
+    (0xf9a2, 4171, 3),
+    (0xf9a3, 4174, 3),
+    (0xf9a4, 4177, 3),
+    (0xf9a5, 4180, 3),
+    (0xf9a6, 4183, 3),
+    (0xf9a7, 4186, 3),
+    (0xf9a8, 4189, 3),
+    (0xf9a9, 4192, 3),
+    (0xf9aa, 4195, 3),
+    (0xf9ab, 4198, 3),
+    (0xf9ac, 4201, 3),
+    (0xf9ad, 4204, 3),
+    (0xf9ae, 4207, 3),
+    (0xf9af, 4210, 3),
+    (0xf9b0, 4213, 3),
+    (0xf9b1, 4216, 3),
+    (0xf9b2, 4219, 3),
+    (0xf9b3, 4222, 3),
+    (0xf9b4, 4225, 3),
+    (0xf9b5, 4228, 3),
+    (0xf9b6, 4231, 3),
+    (0xf9b7, 4234, 3),
+    (0xf9b8, 4237, 3),
+    (0xf9b9, 4240, 3),
+    (0xf9ba, 4243, 3),
+    (0xf9bb, 4246, 3),
+    (0xf9bc, 4249, 3),
+    (0xf9bd, 4252, 3),
+    (0xf9be, 4255, 3),
+    (0xf9bf, 4258, 3),
+    (0xf9c0, 4261, 3),
+    (0xf9c1, 4264, 3),
+    (0xf9c2, 4267, 3),
+    (0xf9c3, 4270, 3),
+    (0xf9c4, 4273, 3),
+    (0xf9c5, 4276, 3),
+    (0xf9c6, 4279, 3),
+    (0xf9c7, 4282, 3),
+    (0xf9c8, 4285, 3),
+    (0xf9c9, 4288, 3),
+    (0xf9ca, 4291, 3),
+    (0xf9cb, 4294, 3),
+    (0xf9cc, 4297, 3),
+    (0xf9cd, 4300, 3),
+    (0xf9ce, 4303, 3),
+    (0xf9cf, 4306, 3),
+    (0xf9d0, 4309, 3),
+    (0xf9d1, 4312, 3),
+    (0xf9d2, 4315, 3),
+    (0xf9d3, 4318, 3),
+    (0xf9d4, 4321, 3),
+    (0xf9d5, 4324, 3),
+    (0xf9d6, 4327, 3),
+    (0xf9d7, 4330, 3),
+    (0xf9d8, 4333, 3),
+    (0xf9d9, 4336, 3),
+    (0xf9da, 4339, 3),
+    (0xf9db, 4342, 3),
+    (0xf9dc, 4345, 3),
+    (0xf9dd, 4348, 3),
+    (0xf9de, 4351, 3),
+    (0xf9df, 4354, 3),
+    (0xf9e0, 4357, 3),
+    (0xf9e1, 4360, 3),
+    (0xf9e2, 4363, 3),
+    (0xf9e3, 4366, 3),
+    (0xf9e4, 4369, 3),
+    (0xf9e5, 4372, 3),
+    (0xf9e6, 4375, 3),
+    (0xf9e7, 4378, 3),
+    (0xf9e8, 4381, 3),
+    (0xf9e9, 4384, 3),
+    (0xf9ea, 4387, 3),
+    (0xf9eb, 4390, 3),
+    (0xf9ec, 4393, 3),
+    (0xf9ed, 4396, 3),
+    (0xf9ee, 4399, 3),
+    (0xf9ef, 4402, 3),
+    (0xf9f0, 4405, 3),
+    (0xf9f1, 4408, 3),
+    (0xf9f2, 4411, 3),
+    (0xf9f3, 4414, 3),
+    (0xf9f4, 4417, 3),
+    (0xf9f5, 4420, 3),
+    (0xf9f6, 4423, 3),
+    (0xf9f7, 4426, 3),
+    (0xf9f8, 4429, 3),
+    (0xf9f9, 4432, 3),
+    (0xf9fa, 4435, 3),
+    (0xf9fb, 4438, 3),
+    (0xf9fc, 4441, 3),
+    (0xf9fd, 4444, 3),
+    (0xf9fe, 4447, 3),
+    (0xf9ff, 4450, 3),
+    (0xfa00, 4453, 3),
+    (0xfa01, 4456, 3),
+    (0xfa02, 4459, 3),
+    (0xfa03, 4462, 3),
+    (0xfa04, 4465, 3),
+    (0xfa05, 4468, 3),
+    (0xfa06, 4471, 3),
+    (0xfa07, 4474, 3),
+    (0xfa08, 4477, 3),
+    (0xfa09, 4480, 3),
+    (0xfa0a, 4483, 3),
+    (0xfa0b, 4486, 3),
+    (0xfa0c, 4489, 3),
+    (0xfa0d, 4492, 3),
+    (0xfa10, 4495, 3),
+    (0xfa12, 4498, 3),
+    (0xfa15, 4501, 3),
+    (0xfa16, 4504, 3),
+    (0xfa17, 4507, 3),
+    (0xfa18, 4510, 3),
+    (0xfa19, 4513, 3),
+    (0xfa1a, 4516, 3),
+    (0xfa1b, 4519, 3),
+    (0xfa1c, 4522, 3),
+    (0xfa1d, 4525, 3),
+    (0xfa1e, 4528, 3),
+    (0xfa20, 4531, 3),
+    (0xfa22, 4534, 3),
+    (0xfa25, 4537, 3),
+    (0xfa26, 4540, 3),
+    (0xfa2a, 4543, 3),
+    (0xfa2b, 4546, 3),
+    (0xfa2c, 4549, 3),
+    (0xfa2d, 4552, 3),
+    (0xfa2e, 4555, 3),
+    (0xfa2f, 4558, 3),
+    (0xfa30, 4561, 3),
+    (0xfa31, 4564, 3),
+    (0xfa32, 4567, 3),
+    (0xfa33, 4570, 3),
+    (0xfa34, 4573, 3),
+    (0xfa35, 4576, 3),
+    (0xfa36, 4579, 3),
+    (0xfa37, 4582, 3),
+    (0xfa38, 4585, 3),
+    (0xfa39, 4588, 3),
+    (0xfa3a, 4591, 3),
+    (0xfa3b, 4594, 3),
+    (0xfa3c, 4597, 3),
+    (0xfa3d, 4600, 3),
+    (0xfa3e, 4603, 3),
+    (0xfa3f, 4606, 3),
+    (0xfa40, 4609, 3),
+    (0xfa41, 4612, 3),
+    (0xfa42, 4615, 3),
+    (0xfa43, 4618, 3),
+    (0xfa44, 4621, 3),
+    (0xfa45, 4624, 3),
+    (0xfa46, 4627, 3),
+    (0xfa47, 4630, 3),
+    (0xfa48, 4633, 3),
+    (0xfa49, 4636, 3),
+    (0xfa4a, 4639, 3),
+    (0xfa4b, 4642, 3),
+    (0xfa4c, 4645, 3),
+    (0xfa4d, 4648, 3),
+    (0xfa4e, 4651, 3),
+    (0xfa4f, 4654, 3),
+    (0xfa50, 4657, 3),
+    (0xfa51, 4660, 3),
+    (0xfa52, 4663, 3),
+    (0xfa53, 4666, 3),
+    (0xfa54, 4669, 3),
+    (0xfa55, 4672, 3),
+    (0xfa56, 4675, 3),
+    (0xfa57, 4678, 3),
+    (0xfa58, 4681, 3),
+    (0xfa59, 4684, 3),
+    (0xfa5a, 4687, 3),
+    (0xfa5b, 4690, 3),
+    (0xfa5c, 4693, 3),
+    (0xfa5d, 4696, 3),
+    (0xfa5e, 4699, 3),
+    (0xfa5f, 4702, 3),
+    (0xfa60, 4705, 3),
+    (0xfa61, 4708, 3),
+    (0xfa62, 4711, 3),
+    (0xfa63, 4714, 3),
+    (0xfa64, 4717, 3),
+    (0xfa65, 4720, 3),
+    (0xfa66, 4723, 3),
+    (0xfa67, 4726, 3),
+    (0xfa68, 4729, 3),
+    (0xfa69, 4732, 3),
+    (0xfa6a, 4735, 3),
+    (0xfa6b, 4738, 3),
+    (0xfa6c, 4741, 4),
+    (0xfa6d, 4745, 3),
+    (0xfa70, 4748, 3),
+    (0xfa71, 4751, 3),
+    (0xfa72, 4754, 3),
+    (0xfa73, 4757, 3),
+    (0xfa74, 4760, 3),
+    (0xfa75, 4763, 3),
+    (0xfa76, 4766, 3),
+    (0xfa77, 4769, 3),
+    (0xfa78, 4772, 3),
+    (0xfa79, 4775, 3),
+    (0xfa7a, 4778, 3),
+    (0xfa7b, 4781, 3),
+    (0xfa7c, 4784, 3),
+    (0xfa7d, 4787, 3),
+    (0xfa7e, 4790, 3),
+    (0xfa7f, 4793, 3),
+    (0xfa80, 4796, 3),
+    (0xfa81, 4799, 3),
+    (0xfa82, 4802, 3),
+    (0xfa83, 4805, 3),
+    (0xfa84, 4808, 3),
+    (0xfa85, 4811, 3),
+    (0xfa86, 4814, 3),
+    (0xfa87, 4817, 3),
+    (0xfa88, 4820, 3),
+    (0xfa89, 4823, 3),
+    (0xfa8a, 4826, 3),
+    (0xfa8b, 4829, 3),
+    (0xfa8c, 4832, 3),
+    (0xfa8d, 4835, 3),
+    (0xfa8e, 4838, 3),
+    (0xfa8f, 4841, 3),
+    (0xfa90, 4844, 3),
+    (0xfa91, 4847, 3),
+    (0xfa92, 4850, 3),
+    (0xfa93, 4853, 3),
+    (0xfa94, 4856, 3),
+    (0xfa95, 4859, 3),
+    (0xfa96, 4862, 3),
+    (0xfa97, 4865, 3),
+    (0xfa98, 4868, 3),
+    (0xfa99, 4871, 3),
+    (0xfa9a, 4874, 3),
+    (0xfa9b, 4877, 3),
+    (0xfa9c, 4880, 3),
+    (0xfa9d, 4883, 3),
+    (0xfa9e, 4886, 3),
+    (0xfa9f, 4889, 3),
+    (0xfaa0, 4892, 3),
+    (0xfaa1, 4895, 3),
+    (0xfaa2, 4898, 3),
+    (0xfaa3, 4901, 3),
+    (0xfaa4, 4904, 3),
+    (0xfaa5, 4907, 3),
+    (0xfaa6, 4910, 3),
+    (0xfaa7, 4913, 3),
+    (0xfaa8, 4916, 3),
+    (0xfaa9, 4919, 3),
+    (0xfaaa, 4922, 3),
+    (0xfaab, 4925, 3),
+    (0xfaac, 4928, 3),
+    (0xfaad, 4931, 3),
+    (0xfaae, 4934, 3),
+    (0xfaaf, 4937, 3),
+    (0xfab0, 4940, 3),
+    (0xfab1, 4943, 3),
+    (0xfab2, 4946, 3),
+    (0xfab3, 4949, 3),
+    (0xfab4, 4952, 3),
+    (0xfab5, 4955, 3),
+    (0xfab6, 4958, 3),
+    (0xfab7, 4961, 3),
+    (0xfab8, 4964, 3),
+    (0xfab9, 4967, 3),
+    (0xfaba, 4970, 3),
+    (0xfabb, 4973, 3),
+    (0xfabc, 4976, 3),
+    (0xfabd, 4979, 3),
+    (0xfabe, 4982, 3),
+    (0xfabf, 4985, 3),
+    (0xfac0, 4988, 3),
+    (0xfac1, 4991, 3),
+    (0xfac2, 4994, 3),
+    (0xfac3, 4997, 3),
+    (0xfac4, 5000, 3),
+    (0xfac5, 5003, 3),
+    (0xfac6, 5006, 3),
+    (0xfac7, 5009, 3),
+    (0xfac8, 5012, 3),
+    (0xfac9, 5015, 3),
+    (0xfaca, 5018, 3),
+    (0xfacb, 5021, 3),
+    (0xfacc, 5024, 3),
+    (0xfacd, 5027, 3),
+    (0xface, 5030, 3),
+    (0xfacf, 5033, 4),
+    (0xfad0, 5037, 4),
+    (0xfad1, 5041, 4),
+    (0xfad2, 5045, 3),
+    (0xfad3, 5048, 3),
+    (0xfad4, 5051, 3),
+    (0xfad5, 5054, 4),
+    (0xfad6, 5058, 4),
+    (0xfad7, 5062, 4),
+    (0xfad8, 5066, 3),
+    (0xfad9, 5069, 3),
+    (0xfe30, 5072, 2),
+    (0xfe31, 5074, 3),
+    (0xfe32, 5077, 3),
+    (0xfe33, 5080, 1),
+    (0xfe34, 5081, 1),
+    (0xfe35, 5082, 1),
+    (0xfe36, 5083, 1),
+    (0xfe37, 5084, 1),
+    (0xfe38, 5085, 1),
+    (0xfe39, 5086, 3),
+    (0xfe3a, 5089, 3),
+    (0xfe3b, 5092, 3),
+    (0xfe3c, 5095, 3),
+    (0xfe3d, 5098, 3),
+    (0xfe3e, 5101, 3),
+    (0xfe3f, 5104, 3),
+    (0xfe40, 5107, 3),
+    (0xfe41, 5110, 3),
+    (0xfe42, 5113, 3),
+    (0xfe43, 5116, 3),
+    (0xfe44, 5119, 3),
+    (0xfe47, 5122, 1),
+    (0xfe48, 5123, 1),
+    (0xfe49, 5124, 3),
+    (0xfe4a, 5127, 3),
+    (0xfe4b, 5130, 3),
+    (0xfe4c, 5133, 3),
+    (0xfe4d, 5136, 1),
+    (0xfe4e, 5137, 1),
+    (0xfe4f, 5138, 1),
+    (0xff01, 5139, 1),
+    (0xff02, 5140, 1),
+    (0xff03, 5141, 1),
+    (0xff04, 5142, 1),
+    (0xff05, 5143, 1),
+    (0xff06, 5144, 1),
+    (0xff07, 5145, 1),
+    (0xff08, 5146, 1),
+    (0xff09, 5147, 1),
+    (0xff0a, 5148, 1),
+    (0xff0b, 5149, 1),
+    (0xff0c, 5150, 1),
+    (0xff0d, 5151, 1),
+    (0xff0e, 5152, 1),
+    (0xff0f, 5153, 1),
+    (0xff10, 5154, 1),
+    (0xff11, 5155, 1),
+    (0xff12, 5156, 1),
+    (0xff13, 5157, 1),
+    (0xff14, 5158, 1),
+    (0xff15, 5159, 1),
+    (0xff16, 5160, 1),
+    (0xff17, 5161, 1),
+    (0xff18, 5162, 1),
+    (0xff19, 5163, 1),
+    (0xff1a, 5164, 1),
+    (0xff1b, 5165, 1),
+    (0xff1c, 5166, 1),
+    (0xff1d, 5167, 1),
+    (0xff1e, 5168, 1),
+    (0xff1f, 5169, 1),
+    (0xff20, 5170, 1),
+    (0xff21, 5171, 1),
+    (0xff22, 5172, 1),
+    (0xff23, 5173, 1),
+    (0xff24, 5174, 1),
+    (0xff25, 5175, 1),
+    (0xff26, 5176, 1),
+    (0xff27, 5177, 1),
+    (0xff28, 5178, 1),
+    (0xff29, 5179, 1),
+    (0xff2a, 5180, 1),
+    (0xff2b, 5181, 1),
+    (0xff2c, 5182, 1),
+    (0xff2d, 5183, 1),
+    (0xff2e, 5184, 1),
+    (0xff2f, 5185, 1),
+    (0xff30, 5186, 1),
+    (0xff31, 5187, 1),
+    (0xff32, 5188, 1),
+    (0xff33, 5189, 1),
+    (0xff34, 5190, 1),
+    (0xff35, 5191, 1),
+    (0xff36, 5192, 1),
+    (0xff37, 5193, 1),
+    (0xff38, 5194, 1),
+    (0xff39, 5195, 1),
+    (0xff3a, 5196, 1),
+    (0xff3b, 5197, 1),
+    (0xff3c, 5198, 1),
+    (0xff3d, 5199, 1),
+    (0xff3e, 5200, 1),
+    (0xff3f, 5201, 1),
+    (0xff40, 5202, 1),
+    (0xff41, 5203, 1),
+    (0xff42, 5204, 1),
+    (0xff43, 5205, 1),
+    (0xff44, 5206, 1),
+    (0xff45, 5207, 1),
+    (0xff46, 5208, 1),
+    (0xff47, 5209, 1),
+    (0xff48, 5210, 1),
+    (0xff49, 5211, 1),
+    (0xff4a, 5212, 1),
+    (0xff4b, 5213, 1),
+    (0xff4c, 5214, 1),
+    (0xff4d, 5215, 1),
+    (0xff4e, 5216, 1),
+    (0xff4f, 5217, 1),
+    (0xff50, 5218, 1),
+    (0xff51, 5219, 1),
+    (0xff52, 5220, 1),
+    (0xff53, 5221, 1),
+    (0xff54, 5222, 1),
+    (0xff55, 5223, 1),
+    (0xff56, 5224, 1),
+    (0xff57, 5225, 1),
+    (0xff58, 5226, 1),
+    (0xff59, 5227, 1),
+    (0xff5a, 5228, 1),
+    (0xff5b, 5229, 1),
+    (0xff5c, 5230, 1),
+    (0xff5d, 5231, 1),
+    (0xff5e, 5232, 1),
+    (0xff5f, 5233, 3),
+    (0xff60, 5236, 3),
+    (0xff61, 5239, 3),
+    (0xff62, 5242, 3),
+    (0xff63, 5245, 3),
+    (0xff64, 5248, 3),
+    (0xff65, 5251, 3),
+    (0xff66, 5254, 3),
+    (0xff67, 5257, 3),
+    (0xff68, 5260, 3),
+    (0xff69, 5263, 3),
+    (0xff6a, 5266, 3),
+    (0xff6b, 5269, 3),
+    (0xff6c, 5272, 3),
+    (0xff6d, 5275, 3),
+    (0xff6e, 5278, 3),
+    (0xff6f, 5281, 3),
+    (0xff70, 5284, 3),
+    (0xff71, 5287, 3),
+    (0xff72, 5290, 3),
+    (0xff73, 5293, 3),
+    (0xff74, 5296, 3),
+    (0xff75, 5299, 3),
+    (0xff76, 5302, 3),
+    (0xff77, 5305, 3),
+    (0xff78, 5308, 3),
+    (0xff79, 5311, 3),
+    (0xff7a, 5314, 3),
+    (0xff7b, 5317, 3),
+    (0xff7c, 5320, 3),
+    (0xff7d, 5323, 3),
+    (0xff7e, 5326, 3),
+    (0xff7f, 5329, 3),
+    (0xff80, 5332, 3),
+    (0xff81, 5335, 3),
+    (0xff82, 5338, 3),
+    (0xff83, 5341, 3),
+    (0xff84, 5344, 3),
+    (0xff85, 5347, 3),
+    (0xff86, 5350, 3),
+    (0xff87, 5353, 3),
+    (0xff88, 5356, 3),
+    (0xff89, 5359, 3),
+    (0xff8a, 5362, 3),
+    (0xff8b, 5365, 3),
+    (0xff8c, 5368, 3),
+    (0xff8d, 5371, 3),
+    (0xff8e, 5374, 3),
+    (0xff8f, 5377, 3),
+    (0xff90, 5380, 3),
+    (0xff91, 5383, 3),
+    (0xff92, 5386, 3),
+    (0xff93, 5389, 3),
+    (0xff94, 5392, 3),
+    (0xff95, 5395, 3),
+    (0xff96, 5398, 3),
+    (0xff97, 5401, 3),
+    (0xff98, 5404, 3),
+    (0xff99, 5407, 3),
+    (0xff9a, 5410, 3),
+    (0xff9b, 5413, 3),
+    (0xff9c, 5416, 3),
+    (0xff9d, 5419, 3),
+    (0xff9e, 5422, 3),
+    (0xff9f, 5425, 3),
+    (0xffe0, 5428, 2),
+    (0xffe1, 5430, 2),
+    (0xffe2, 5432, 2),
+    (0xffe3, 5434, 3),
+    (0xffe4, 5437, 2),
+    (0xffe5, 5439, 2),
+    (0xffe6, 5441, 3),
+    (0xffe8, 5444, 3),
+    (0xffe9, 5447, 3),
+    (0xffea, 5450, 3),
+    (0xffeb, 5453, 3),
+    (0xffec, 5456, 3),
+    (0xffed, 5459, 3),
+    (0xffee, 5462, 3),
+    (0x1f100, 5465, 2),
+    (0x1f101, 5467, 2),
+    (0x1f102, 5469, 2),
+    (0x1f103, 5471, 2),
+    (0x1f104, 5473, 2),
+    (0x1f105, 5475, 2),
+    (0x1f106, 5477, 2),
+    (0x1f107, 5479, 2),
+    (0x1f108, 5481, 2),
+    (0x1f109, 5483, 2),
+    (0x1f10a, 5485, 2),
+    (0x1f110, 5487, 3),
+    (0x1f111, 5490, 3),
+    (0x1f112, 5493, 3),
+    (0x1f113, 5496, 3),
+    (0x1f114, 5499, 3),
+    (0x1f115, 5502, 3),
+    (0x1f116, 5505, 3),
+    (0x1f117, 5508, 3),
+    (0x1f118, 5511, 3),
+    (0x1f119, 5514, 3),
+    (0x1f11a, 5517, 3),
+    (0x1f11b, 5520, 3),
+    (0x1f11c, 5523, 3),
+    (0x1f11d, 5526, 3),
+    (0x1f11e, 5529, 3),
+    (0x1f11f, 5532, 3),
+    (0x1f120, 5535, 3),
+    (0x1f121, 5538, 3),
+    (0x1f122, 5541, 3),
+    (0x1f123, 5544, 3),
+    (0x1f124, 5547, 3),
+    (0x1f125, 5550, 3),
+    (0x1f126, 5553, 3),
+    (0x1f127, 5556, 3),
+    (0x1f128, 5559, 3),
+    (0x1f129, 5562, 3),
+    (0x1f12a, 5565, 7),
+    (0x1f12b, 5572, 1),
+    (0x1f12c, 5573, 1),
+    (0x1f12d, 5574, 2),
+    (0x1f12e, 5576, 2),
+    (0x1f130, 5578, 1),
+    (0x1f131, 5579, 1),
+    (0x1f132, 5580, 1),
+    (0x1f133, 5581, 1),
+    (0x1f134, 5582, 1),
+    (0x1f135, 5583, 1),
+    (0x1f136, 5584, 1),
+    (0x1f137, 5585, 1),
+    (0x1f138, 5586, 1),
+    (0x1f139, 5587, 1),
+    (0x1f13a, 5588, 1),
+    (0x1f13b, 5589, 1),
+    (0x1f13c, 5590, 1),
+    (0x1f13d, 5591, 1),
+    (0x1f13e, 5592, 1),
+    (0x1f13f, 5593, 1),
+    (0x1f140, 5594, 1),
+    (0x1f141, 5595, 1),
+    (0x1f142, 5596, 1),
+    (0x1f143, 5597, 1),
+    (0x1f144, 5598, 1),
+    (0x1f145, 5599, 1),
+    (0x1f146, 5600, 1),
+    (0x1f147, 5601, 1),
+    (0x1f148, 5602, 1),
+    (0x1f149, 5603, 1),
+    (0x1f14a, 5604, 2),
+    (0x1f14b, 5606, 2),
+    (0x1f14c, 5608, 2),
+    (0x1f14d, 5610, 2),
+    (0x1f14e, 5612, 3),
+    (0x1f14f, 5615, 2),
+    (0x1f16a, 5617, 2),
+    (0x1f16b, 5619, 2),
+    (0x1f16c, 5621, 2),
+    (0x1f190, 5623, 2),
+    (0x1f200, 5625, 6),
+    (0x1f201, 5631, 6),
+    (0x1f202, 5637, 3),
+    (0x1f210, 5640, 3),
+    (0x1f211, 5643, 3),
+    (0x1f212, 5646, 3),
+    (0x1f213, 5649, 6),
+    (0x1f214, 5655, 3),
+    (0x1f215, 5658, 3),
+    (0x1f216, 5661, 3),
+    (0x1f217, 5664, 3),
+    (0x1f218, 5667, 3),
+    (0x1f219, 5670, 3),
+    (0x1f21a, 5673, 3),
+    (0x1f21b, 5676, 3),
+    (0x1f21c, 5679, 3),
+    (0x1f21d, 5682, 3),
+    (0x1f21e, 5685, 3),
+    (0x1f21f, 5688, 3),
+    (0x1f220, 5691, 3),
+    (0x1f221, 5694, 3),
+    (0x1f222, 5697, 3),
+    (0x1f223, 5700, 3),
+    (0x1f224, 5703, 3),
+    (0x1f225, 5706, 3),
+    (0x1f226, 5709, 3),
+    (0x1f227, 5712, 3),
+    (0x1f228, 5715, 3),
+    (0x1f229, 5718, 3),
+    (0x1f22a, 5721, 3),
+    (0x1f22b, 5724, 3),
+    (0x1f22c, 5727, 3),
+    (0x1f22d, 5730, 3),
+    (0x1f22e, 5733, 3),
+    (0x1f22f, 5736, 3),
+    (0x1f230, 5739, 3),
+    (0x1f231, 5742, 3),
+    (0x1f232, 5745, 3),
+    (0x1f233, 5748, 3),
+    (0x1f234, 5751, 3),
+    (0x1f235, 5754, 3),
+    (0x1f236, 5757, 3),
+    (0x1f237, 5760, 3),
+    (0x1f238, 5763, 3),
+    (0x1f239, 5766, 3),
+    (0x1f23a, 5769, 3),
+    (0x1f23b, 5772, 3),
+    (0x1f240, 5775, 9),
+    (0x1f241, 5784, 9),
+    (0x1f242, 5793, 9),
+    (0x1f243, 5802, 9),
+    (0x1f244, 5811, 9),
+    (0x1f245, 5820, 9),
+    (0x1f246, 5829, 9),
+    (0x1f247, 5838, 9),
+    (0x1f248, 5847, 9),
+    (0x1f250, 5856, 3),
+    (0x1f251, 5859, 3),
+    (0x2f800, 5862, 3),
+    (0x2f801, 5865, 3),
+    (0x2f802, 5868, 3),
+    (0x2f803, 5871, 4),
+    (0x2f804, 5875, 3),
+    (0x2f805, 5878, 3),
+    (0x2f806, 5881, 3),
+    (0x2f807, 5884, 3),
+    (0x2f808, 5887, 3),
+    (0x2f809, 5890, 3),
+    (0x2f80a, 5893, 3),
+    (0x2f80b, 5896, 3),
+    (0x2f80c, 5899, 3),
+    (0x2f80d, 5902, 4),
+    (0x2f80e, 5906, 3),
+    (0x2f80f, 5909, 3),
+    (0x2f810, 5912, 3),
+    (0x2f811, 5915, 3),
+    (0x2f812, 5918, 4),
+    (0x2f813, 5922, 3),
+    (0x2f814, 5925, 3),
+    (0x2f815, 5928, 3),
+    (0x2f816, 5931, 4),
+    (0x2f817, 5935, 3),
+    (0x2f818, 5938, 3),
+    (0x2f819, 5941, 3),
+    (0x2f81a, 5944, 3),
+    (0x2f81b, 5947, 3),
+    (0x2f81c, 5950, 4),
+    (0x2f81d, 5954, 3),
+    (0x2f81e, 5957, 3),
+    (0x2f81f, 5960, 3),
+    (0x2f820, 5963, 3),
+    (0x2f821, 5966, 3),
+    (0x2f822, 5969, 3),
+    (0x2f823, 5972, 3),
+    (0x2f824, 5975, 3),
+    (0x2f825, 5978, 3),
+    (0x2f826, 5981, 3),
+    (0x2f827, 5984, 3),
+    (0x2f828, 5987, 3),
+    (0x2f829, 5990, 3),
+    (0x2f82a, 5993, 3),
+    (0x2f82b, 5996, 3),
+    (0x2f82c, 5999, 3),
+    (0x2f82d, 6002, 3),
+    (0x2f82e, 6005, 3),
+    (0x2f82f, 6008, 3),
+    (0x2f830, 6011, 3),
+    (0x2f831, 6014, 3),
+    (0x2f832, 6017, 3),
+    (0x2f833, 6020, 3),
+    (0x2f834, 6023, 4),
+    (0x2f835, 6027, 3),
+    (0x2f836, 6030, 3),
+    (0x2f837, 6033, 3),
+    (0x2f838, 6036, 4),
+    (0x2f839, 6040, 3),
+    (0x2f83a, 6043, 3),
+    (0x2f83b, 6046, 3),
+    (0x2f83c, 6049, 3),
+    (0x2f83d, 6052, 3),
+    (0x2f83e, 6055, 3),
+    (0x2f83f, 6058, 3),
+    (0x2f840, 6061, 3),
+    (0x2f841, 6064, 3),
+    (0x2f842, 6067, 3),
+    (0x2f843, 6070, 3),
+    (0x2f844, 6073, 3),
+    (0x2f845, 6076, 3),
+    (0x2f846, 6079, 3),
+    (0x2f847, 6082, 3),
+    (0x2f848, 6085, 3),
+    (0x2f849, 6088, 3),
+    (0x2f84a, 6091, 3),
+    (0x2f84b, 6094, 3),
+    (0x2f84c, 6097, 3),
+    (0x2f84d, 6100, 3),
+    (0x2f84e, 6103, 3),
+    (0x2f84f, 6106, 3),
+    (0x2f850, 6109, 3),
+    (0x2f851, 6112, 3),
+    (0x2f852, 6115, 3),
+    (0x2f853, 6118, 3),
+    (0x2f854, 6121, 3),
+    (0x2f855, 6124, 3),
+    (0x2f856, 6127, 3),
+    (0x2f857, 6130, 3),
+    (0x2f858, 6133, 3),
+    (0x2f859, 6136, 4),
+    (0x2f85a, 6140, 3),
+    (0x2f85b, 6143, 3),
+    (0x2f85c, 6146, 3),
+    (0x2f85d, 6149, 3),
+    (0x2f85e, 6152, 3),
+    (0x2f85f, 6155, 3),
+    (0x2f860, 6158, 4),
+    (0x2f861, 6162, 4),
+    (0x2f862, 6166, 3),
+    (0x2f863, 6169, 3),
+    (0x2f864, 6172, 3),
+    (0x2f865, 6175, 3),
+    (0x2f866, 6178, 3),
+    (0x2f867, 6181, 3),
+    (0x2f868, 6184, 3),
+    (0x2f869, 6187, 3),
+    (0x2f86a, 6190, 3),
+    (0x2f86b, 6193, 3),
+    (0x2f86c, 6196, 4),
+    (0x2f86d, 6200, 3),
+    (0x2f86e, 6203, 3),
+    (0x2f86f, 6206, 3),
+    (0x2f870, 6209, 3),
+    (0x2f871, 6212, 4),
+    (0x2f872, 6216, 3),
+    (0x2f873, 6219, 3),
+    (0x2f874, 6222, 3),
+    (0x2f875, 6225, 3),
+    (0x2f876, 6228, 3),
+    (0x2f877, 6231, 3),
+    (0x2f878, 6234, 3),
+    (0x2f879, 6237, 3),
+    (0x2f87a, 6240, 3),
+    (0x2f87b, 6243, 4),
+    (0x2f87c, 6247, 3),
+    (0x2f87d, 6250, 4),
+    (0x2f87e, 6254, 3),
+    (0x2f87f, 6257, 3),
+    (0x2f880, 6260, 3),
+    (0x2f881, 6263, 3),
+    (0x2f882, 6266, 3),
+    (0x2f883, 6269, 3),
+    (0x2f884, 6272, 3),
+    (0x2f885, 6275, 3),
+    (0x2f886, 6278, 3),
+    (0x2f887, 6281, 3),
+    (0x2f888, 6284, 3),
+    (0x2f889, 6287, 4),
+    (0x2f88a, 6291, 3),
+    (0x2f88b, 6294, 3),
+    (0x2f88c, 6297, 3),
+    (0x2f88d, 6300, 3),
+    (0x2f88e, 6303, 3),
+    (0x2f88f, 6306, 4),
+    (0x2f890, 6310, 3),
+    (0x2f891, 6313, 4),
+    (0x2f892, 6317, 4),
+    (0x2f893, 6321, 3),
+    (0x2f894, 6324, 3),
+    (0x2f895, 6327, 3),
+    (0x2f896, 6330, 3),
+    (0x2f897, 6333, 4),
+    (0x2f898, 6337, 4),
+    (0x2f899, 6341, 3),
+    (0x2f89a, 6344, 3),
+    (0x2f89b, 6347, 3),
+    (0x2f89c, 6350, 3),
+    (0x2f89d, 6353, 3),
+    (0x2f89e, 6356, 3),
+    (0x2f89f, 6359, 3),
+    (0x2f8a0, 6362, 3),
+    (0x2f8a1, 6365, 3),
+    (0x2f8a2, 6368, 3),
+    (0x2f8a3, 6371, 3),
+    (0x2f8a4, 6374, 4),
+    (0x2f8a5, 6378, 3),
+    (0x2f8a6, 6381, 3),
+    (0x2f8a7, 6384, 3),
+    (0x2f8a8, 6387, 3),
+    (0x2f8a9, 6390, 3),
+    (0x2f8aa, 6393, 3),
+    (0x2f8ab, 6396, 3),
+    (0x2f8ac, 6399, 3),
+    (0x2f8ad, 6402, 3),
+    (0x2f8ae, 6405, 3),
+    (0x2f8af, 6408, 3),
+    (0x2f8b0, 6411, 3),
+    (0x2f8b1, 6414, 3),
+    (0x2f8b2, 6417, 3),
+    (0x2f8b3, 6420, 3),
+    (0x2f8b4, 6423, 3),
+    (0x2f8b5, 6426, 3),
+    (0x2f8b6, 6429, 3),
+    (0x2f8b7, 6432, 3),
+    (0x2f8b8, 6435, 4),
+    (0x2f8b9, 6439, 3),
+    (0x2f8ba, 6442, 3),
+    (0x2f8bb, 6445, 3),
+    (0x2f8bc, 6448, 3),
+    (0x2f8bd, 6451, 3),
+    (0x2f8be, 6454, 4),
+    (0x2f8bf, 6458, 3),
+    (0x2f8c0, 6461, 3),
+    (0x2f8c1, 6464, 3),
+    (0x2f8c2, 6467, 3),
+    (0x2f8c3, 6470, 3),
+    (0x2f8c4, 6473, 3),
+    (0x2f8c5, 6476, 3),
+    (0x2f8c6, 6479, 3),
+    (0x2f8c7, 6482, 3),
+    (0x2f8c8, 6485, 3),
+    (0x2f8c9, 6488, 3),
+    (0x2f8ca, 6491, 4),
+    (0x2f8cb, 6495, 3),
+    (0x2f8cc, 6498, 3),
+    (0x2f8cd, 6501, 3),
+    (0x2f8ce, 6504, 3),
+    (0x2f8cf, 6507, 3),
+    (0x2f8d0, 6510, 3),
+    (0x2f8d1, 6513, 3),
+    (0x2f8d2, 6516, 3),
+    (0x2f8d3, 6519, 3),
+    (0x2f8d4, 6522, 3),
+    (0x2f8d5, 6525, 3),
+    (0x2f8d6, 6528, 3),
+    (0x2f8d7, 6531, 3),
+    (0x2f8d8, 6534, 3),
+    (0x2f8d9, 6537, 3),
+    (0x2f8da, 6540, 3),
+    (0x2f8db, 6543, 3),
+    (0x2f8dc, 6546, 3),
+    (0x2f8dd, 6549, 4),
+    (0x2f8de, 6553, 3),
+    (0x2f8df, 6556, 3),
+    (0x2f8e0, 6559, 3),
+    (0x2f8e1, 6562, 3),
+    (0x2f8e2, 6565, 3),
+    (0x2f8e3, 6568, 4),
+    (0x2f8e4, 6572, 3),
+    (0x2f8e5, 6575, 3),
+    (0x2f8e6, 6578, 3),
+    (0x2f8e7, 6581, 3),
+    (0x2f8e8, 6584, 3),
+    (0x2f8e9, 6587, 3),
+    (0x2f8ea, 6590, 3),
+    (0x2f8eb, 6593, 3),
+    (0x2f8ec, 6596, 4),
+    (0x2f8ed, 6600, 3),
+    (0x2f8ee, 6603, 3),
+    (0x2f8ef, 6606, 3),
+    (0x2f8f0, 6609, 4),
+    (0x2f8f1, 6613, 3),
+    (0x2f8f2, 6616, 3),
+    (0x2f8f3, 6619, 3),
+    (0x2f8f4, 6622, 3),
+    (0x2f8f5, 6625, 3),
+    (0x2f8f6, 6628, 3),
+    (0x2f8f7, 6631, 4),
+    (0x2f8f8, 6635, 4),
+    (0x2f8f9, 6639, 4),
+    (0x2f8fa, 6643, 3),
+    (0x2f8fb, 6646, 4),
+    (0x2f8fc, 6650, 3),
+    (0x2f8fd, 6653, 3),
+    (0x2f8fe, 6656, 3),
+    (0x2f8ff, 6659, 3),
+    (0x2f900, 6662, 3),
+    (0x2f901, 6665, 3),
+    (0x2f902, 6668, 3),
+    (0x2f903, 6671, 3),
+    (0x2f904, 6674, 3),
+    (0x2f905, 6677, 3),
+    (0x2f906, 6680, 4),
+    (0x2f907, 6684, 3),
+    (0x2f908, 6687, 3),
+    (0x2f909, 6690, 3),
+    (0x2f90a, 6693, 3),
+    (0x2f90b, 6696, 3),
+    (0x2f90c, 6699, 3),
+    (0x2f90d, 6702, 4),
+    (0x2f90e, 6706, 3),
+    (0x2f90f, 6709, 3),
+    (0x2f910, 6712, 4),
+    (0x2f911, 6716, 4),
+    (0x2f912, 6720, 3),
+    (0x2f913, 6723, 3),
+    (0x2f914, 6726, 3),
+    (0x2f915, 6729, 3),
+    (0x2f916, 6732, 3),
+    (0x2f917, 6735, 3),
+    (0x2f918, 6738, 3),
+    (0x2f919, 6741, 3),
+    (0x2f91a, 6744, 3),
+    (0x2f91b, 6747, 4),
+    (0x2f91c, 6751, 3),
+    (0x2f91d, 6754, 4),
+    (0x2f91e, 6758, 3),
+    (0x2f91f, 6761, 4),
+    (0x2f920, 6765, 3),
+    (0x2f921, 6768, 3),
+    (0x2f922, 6771, 3),
+    (0x2f923, 6774, 4),
+    (0x2f924, 6778, 3),
+    (0x2f925, 6781, 3),
+    (0x2f926, 6784, 4),
+    (0x2f927, 6788, 4),
+    (0x2f928, 6792, 3),
+    (0x2f929, 6795, 3),
+    (0x2f92a, 6798, 3),
+    (0x2f92b, 6801, 3),
+    (0x2f92c, 6804, 3),
+    (0x2f92d, 6807, 3),
+    (0x2f92e, 6810, 3),
+    (0x2f92f, 6813, 3),
+    (0x2f930, 6816, 3),
+    (0x2f931, 6819, 3),
+    (0x2f932, 6822, 3),
+    (0x2f933, 6825, 3),
+    (0x2f934, 6828, 3),
+    (0x2f935, 6831, 4),
+    (0x2f936, 6835, 3),
+    (0x2f937, 6838, 4),
+    (0x2f938, 6842, 3),
+    (0x2f939, 6845, 4),
+    (0x2f93a, 6849, 3),
+    (0x2f93b, 6852, 4),
+    (0x2f93c, 6856, 4),
+    (0x2f93d, 6860, 4),
+    (0x2f93e, 6864, 3),
+    (0x2f93f, 6867, 3),
+    (0x2f940, 6870, 3),
+    (0x2f941, 6873, 4),
+    (0x2f942, 6877, 4),
+    (0x2f943, 6881, 4),
+    (0x2f944, 6885, 4),
+    (0x2f945, 6889, 3),
+    (0x2f946, 6892, 3),
+    (0x2f947, 6895, 3),
+    (0x2f948, 6898, 3),
+    (0x2f949, 6901, 3),
+    (0x2f94a, 6904, 3),
+    (0x2f94b, 6907, 3),
+    (0x2f94c, 6910, 3),
+    (0x2f94d, 6913, 4),
+    (0x2f94e, 6917, 3),
+    (0x2f94f, 6920, 3),
+    (0x2f950, 6923, 3),
+    (0x2f951, 6926, 3),
+    (0x2f952, 6929, 4),
+    (0x2f953, 6933, 3),
+    (0x2f954, 6936, 4),
+    (0x2f955, 6940, 4),
+    (0x2f956, 6944, 3),
+    (0x2f957, 6947, 3),
+    (0x2f958, 6950, 3),
+    (0x2f959, 6953, 3),
+    (0x2f95a, 6956, 3),
+    (0x2f95b, 6959, 3),
+    (0x2f95c, 6962, 4),
+    (0x2f95d, 6966, 4),
+    (0x2f95e, 6970, 4),
+    (0x2f95f, 6974, 3),
+    (0x2f960, 6977, 3),
+    (0x2f961, 6980, 4),
+    (0x2f962, 6984, 3),
+    (0x2f963, 6987, 3),
+    (0x2f964, 6990, 3),
+    (0x2f965, 6993, 4),
+    (0x2f966, 6997, 3),
+    (0x2f967, 7000, 3),
+    (0x2f968, 7003, 3),
+    (0x2f969, 7006, 3),
+    (0x2f96a, 7009, 3),
+    (0x2f96b, 7012, 4),
+    (0x2f96c, 7016, 3),
+    (0x2f96d, 7019, 3),
+    (0x2f96e, 7022, 3),
+    (0x2f96f, 7025, 3),
+    (0x2f970, 7028, 3),
+    (0x2f971, 7031, 3),
+    (0x2f972, 7034, 4),
+    (0x2f973, 7038, 4),
+    (0x2f974, 7042, 3),
+    (0x2f975, 7045, 4),
+    (0x2f976, 7049, 3),
+    (0x2f977, 7052, 4),
+    (0x2f978, 7056, 3),
+    (0x2f979, 7059, 3),
+    (0x2f97a, 7062, 3),
+    (0x2f97b, 7065, 4),
+    (0x2f97c, 7069, 4),
+    (0x2f97d, 7073, 3),
+    (0x2f97e, 7076, 4),
+    (0x2f97f, 7080, 3),
+    (0x2f980, 7083, 4),
+    (0x2f981, 7087, 3),
+    (0x2f982, 7090, 3),
+    (0x2f983, 7093, 3),
+    (0x2f984, 7096, 3),
+    (0x2f985, 7099, 3),
+    (0x2f986, 7102, 3),
+    (0x2f987, 7105, 4),
+    (0x2f988, 7109, 4),
+    (0x2f989, 7113, 4),
+    (0x2f98a, 7117, 4),
+    (0x2f98b, 7121, 3),
+    (0x2f98c, 7124, 3),
+    (0x2f98d, 7127, 3),
+    (0x2f98e, 7130, 3),
+    (0x2f98f, 7133, 3),
+    (0x2f990, 7136, 3),
+    (0x2f991, 7139, 3),
+    (0x2f992, 7142, 3),
+    (0x2f993, 7145, 3),
+    (0x2f994, 7148, 3),
+    (0x2f995, 7151, 3),
+    (0x2f996, 7154, 3),
+    (0x2f997, 7157, 4),
+    (0x2f998, 7161, 3),
+    (0x2f999, 7164, 3),
+    (0x2f99a, 7167, 3),
+    (0x2f99b, 7170, 3),
+    (0x2f99c, 7173, 3),
+    (0x2f99d, 7176, 3),
+    (0x2f99e, 7179, 3),
+    (0x2f99f, 7182, 3),
+    (0x2f9a0, 7185, 3),
+    (0x2f9a1, 7188, 3),
+    (0x2f9a2, 7191, 3),
+    (0x2f9a3, 7194, 3),
+    (0x2f9a4, 7197, 4),
+    (0x2f9a5, 7201, 4),
+    (0x2f9a6, 7205, 4),
+    (0x2f9a7, 7209, 3),
+    (0x2f9a8, 7212, 3),
+    (0x2f9a9, 7215, 3),
+    (0x2f9aa, 7218, 3),
+    (0x2f9ab, 7221, 4),
+    (0x2f9ac, 7225, 3),
+    (0x2f9ad, 7228, 4),
+    (0x2f9ae, 7232, 3),
+    (0x2f9af, 7235, 3),
+    (0x2f9b0, 7238, 4),
+    (0x2f9b1, 7242, 4),
+    (0x2f9b2, 7246, 3),
+    (0x2f9b3, 7249, 3),
+    (0x2f9b4, 7252, 3),
+    (0x2f9b5, 7255, 3),
+    (0x2f9b6, 7258, 3),
+    (0x2f9b7, 7261, 3),
+    (0x2f9b8, 7264, 3),
+    (0x2f9b9, 7267, 3),
+    (0x2f9ba, 7270, 3),
+    (0x2f9bb, 7273, 3),
+    (0x2f9bc, 7276, 3),
+    (0x2f9bd, 7279, 3),
+    (0x2f9be, 7282, 3),
+    (0x2f9bf, 7285, 3),
+    (0x2f9c0, 7288, 3),
+    (0x2f9c1, 7291, 3),
+    (0x2f9c2, 7294, 3),
+    (0x2f9c3, 7297, 3),
+    (0x2f9c4, 7300, 3),
+    (0x2f9c5, 7303, 4),
+    (0x2f9c6, 7307, 3),
+    (0x2f9c7, 7310, 3),
+    (0x2f9c8, 7313, 3),
+    (0x2f9c9, 7316, 3),
+    (0x2f9ca, 7319, 3),
+    (0x2f9cb, 7322, 4),
+    (0x2f9cc, 7326, 4),
+    (0x2f9cd, 7330, 3),
+    (0x2f9ce, 7333, 3),
+    (0x2f9cf, 7336, 3),
+    (0x2f9d0, 7339, 3),
+    (0x2f9d1, 7342, 3),
+    (0x2f9d2, 7345, 3),
+    (0x2f9d3, 7348, 4),
+    (0x2f9d4, 7352, 3),
+    (0x2f9d5, 7355, 3),
+    (0x2f9d6, 7358, 3),
+    (0x2f9d7, 7361, 3),
+    (0x2f9d8, 7364, 4),
+    (0x2f9d9, 7368, 4),
+    (0x2f9da, 7372, 3),
+    (0x2f9db, 7375, 3),
+    (0x2f9dc, 7378, 3),
+    (0x2f9dd, 7381, 4),
+    (0x2f9de, 7385, 3),
+    (0x2f9df, 7388, 3),
+    (0x2f9e0, 7391, 4),
+    (0x2f9e1, 7395, 4),
+    (0x2f9e2, 7399, 3),
+    (0x2f9e3, 7402, 3),
+    (0x2f9e4, 7405, 3),
+    (0x2f9e5, 7408, 4),
+    (0x2f9e6, 7412, 3),
+    (0x2f9e7, 7415, 3),
+    (0x2f9e8, 7418, 3),
+    (0x2f9e9, 7421, 3),
+    (0x2f9ea, 7424, 3),
+    (0x2f9eb, 7427, 3),
+    (0x2f9ec, 7430, 3),
+    (0x2f9ed, 7433, 4),
+    (0x2f9ee, 7437, 3),
+    (0x2f9ef, 7440, 3),
+    (0x2f9f0, 7443, 3),
+    (0x2f9f1, 7446, 4),
+    (0x2f9f2, 7450, 3),
+    (0x2f9f3, 7453, 3),
+    (0x2f9f4, 7456, 3),
+    (0x2f9f5, 7459, 3),
+    (0x2f9f6, 7462, 4),
+    (0x2f9f7, 7466, 4),
+    (0x2f9f8, 7470, 3),
+    (0x2f9f9, 7473, 3),
+    (0x2f9fa, 7476, 3),
+    (0x2f9fb, 7479, 4),
+    (0x2f9fc, 7483, 3),
+    (0x2f9fd, 7486, 4),
+    (0x2f9fe, 7490, 3),
+    (0x2f9ff, 7493, 3),
+    (0x2fa00, 7496, 3),
+    (0x2fa01, 7499, 4),
+    (0x2fa02, 7503, 3),
+    (0x2fa03, 7506, 3),
+    (0x2fa04, 7509, 3),
+    (0x2fa05, 7512, 3),
+    (0x2fa06, 7515, 3),
+    (0x2fa07, 7518, 3),
+    (0x2fa08, 7521, 3),
+    (0x2fa09, 7524, 4),
+    (0x2fa0a, 7528, 3),
+    (0x2fa0b, 7531, 3),
+    (0x2fa0c, 7534, 3),
+    (0x2fa0d, 7537, 3),
+    (0x2fa0e, 7540, 3),
+    (0x2fa0f, 7543, 3),
+    (0x2fa10, 7546, 4),
+    (0x2fa11, 7550, 3),
+    (0x2fa12, 7553, 4),
+    (0x2fa13, 7557, 4),
+    (0x2fa14, 7561, 4),
+    (0x2fa15, 7565, 3),
+    (0x2fa16, 7568, 3),
+    (0x2fa17, 7571, 3),
+    (0x2fa18, 7574, 3),
+    (0x2fa19, 7577, 3),
+    (0x2fa1a, 7580, 3),
+    (0x2fa1b, 7583, 3),
+    (0x2fa1c, 7586, 3),
+    (0x2fa1d, 7589, 4),
+];
+#[cfg(feature = "nfkd-cjk")]
+#[rustfmt::skip]
+pub(super) const CJK_DECOMPOSED: &str = "\u{6bcd}\u{9f9f}\u{4e00}\u{4e28}\u{4e36}\u{4e3f}\u{4e59}\u{4e85}\u{4e8c}\u{4ea0}\u{4eba}\u{513f}\u{5165}\u{516b}\u{5182}\u{5196}\u{51ab}\u{51e0}\u{51f5}\u{5200}\u{529b}\u{52f9}\u{5315}\u{531a}\u{5338}\u{5341}\u{535c}\u{5369}\u{5382}\u{53b6}\u{53c8}\u{53e3}\u{56d7}\u{571f}\u{58eb}\u{5902}\u{590a}\u{5915}\u{5927}\u{5973}\u{5b50}\u{5b80}\u{5bf8}\u{5c0f}\u{5c22}\u{5c38}\u{5c6e}\u{5c71}\u{5ddb}\u{5de5}\u{5df1}\u{5dfe}\u{5e72}\u{5e7a}\u{5e7f}\u{5ef4}\u{5efe}\u{5f0b}\u{5f13}\u{5f50}\u{5f61}\u{5f73}\u{5fc3}\u{6208}\u{6236}\u{624b}\u{652f}\u{6534}\u{6587}\u{6597}\u{65a4}\u{65b9}\u{65e0}\u{65e5}\u{66f0}\u{6708}\u{6728}\u{6b20}\u{6b62}\u{6b79}\u{6bb3}\u{6bcb}\u{6bd4}\u{6bdb}\u{6c0f}\u{6c14}\u{6c34}\u{706b}\u{722a}\u{7236}\u{723b}\u{723f}\u{7247}\u{7259}\u{725b}\u{72ac}\u{7384}\u{7389}\u{74dc}\u{74e6}\u{7518}\u{751f}\u{7528}\u{7530}\u{758b}\u{7592}\u{7676}\u{767d}\u{76ae}\u{76bf}\u{76ee}\u{77db}\u{77e2}\u{77f3}\u{793a}\u{79b8}\u{79be}\u{7a74}\u{7acb}\u{7af9}\u{7c73}\u{7cf8}\u{7f36}\u{7f51}\u{7f8a}\u{7fbd}\u{8001}\u{800c}\u{8012}\u{8033}\u{807f}\u{8089}\u{81e3}\u{81ea}\u{81f3}\u{81fc}\u{820c}\u{821b}\u{821f}\u{826e}\u{8272}\u{8278}\u{864d}\u{866b}\u{8840}\u{884c}\u{8863}\u{897e}\u{898b}\u{89d2}\u{8a00}\u{8c37}\u{8c46}\u{8c55}\u{8c78}\u{8c9d}\u{8d64}\u{8d70}\u{8db3}\u{8eab}\u{8eca}\u{8f9b}\u{8fb0}\u{8fb5}\u{9091}\u{9149}\u{91c6}\u{91cc}\u{91d1}\u{9577}\u{9580}\u{961c}\u{96b6}\u{96b9}\u{96e8}\u{9751}\u{975e}\u{9762}\u{9769}\u{97cb}\u{97ed}\u{97f3}\u{9801}\u{98a8}\u{98db}\u{98df}\u{9996}\u{9999}\u{99ac}\u{9aa8}\u{9ad8}\u{9adf}\u{9b25}\u{9b2f}\u{9b32}\u{9b3c}\u{9b5a}\u{9ce5}\u{9e75}\u{9e7f}\u{9ea5}\u{9ebb}\u{9ec3}\u{9ecd}\u{9ed1}\u{9ef9}\u{9efd}\u{9f0e}\u{9f13}\u{9f20}\u{9f3b}\u{9f4a}\u{9f52}\u{9f8d}\u{9f9c}\u{9fa0} \u{3012}\u{5341}\u{5344}\u{5345}\u{304b}\u{3099}\u{304d}\u{3099}\u{304f}\u{3099}\u{3051}\u{3099}\u{3053}\u{3099}\u{3055}\u{3099}\u{3057}\u{3099}\u{3059}\u{3099}\u{305b}\u{3099}\u{305d}\u{3099}\u{305f}\u{3099}\u{3061}\u{3099}\u{3064}\u{3099}\u{3066}\u{3099}\u{3068}\u{3099}\u{306f}\u{3099}\u{306f}\u{309a}\u{3072}\u{3099}\u{3072}\u{309a}\u{3075}\u{3099}\u{3075}\u{309a}\u{3078}\u{3099}\u{3078}\u{309a}\u{307b}\u{3099}\u{307b}\u{309a}\u{3046}\u{3099} \u{3099} \u{309a}\u{309d}\u{3099}\u{3088}\u{308a}\u{30ab}\u{3099}\u{30ad}\u{3099}\u{30af}\u{3099}\u{30b1}\u{3099}\u{30b3}\u{3099}\u{30b5}\u{3099}\u{30b7}\u{3099}\u{30b9}\u{3099}\u{30bb}\u{3099}\u{30bd}\u{3099}\u{30bf}\u{3099}\u{30c1}\u{3099}\u{30c4}\u{3099}\u{30c6}\u{3099}\u{30c8}\u{3099}\u{30cf}\u{3099}\u{30cf}\u{309a}\u{30d2}\u{3099}\u{30d2}\u{309a}\u{30d5}\u{3099}\u{30d5}\u{309a}\u{30d8}\u{3099}\u{30d8}\u{309a}\u{30db}\u{3099}\u{30db}\u{309a}\u{30a6}\u{3099}\u{30ef}\u{3099}\u{30f0}\u{3099}\u{30f1}\u{3099}\u{30f2}\u{3099}\u{30fd}\u{3099}\u{30b3}\u{30c8}\u{4e00}\u{4e8c}\u{4e09}\u{56db}\u{4e0a}\u{4e2d}\u{4e0b}\u{7532}\u{4e59}\u{4e19}\u{4e01}\u{5929}\u{5730}\u{4eba}(\u{1100})(\u{1102})(\u{1103})(\u{1105})(\u{1106})(\u{1107})(\u{1109})(\u{110b})(\u{110c})(\u{110e})(\u{110f})(\u{1110})(\u{1111})(\u{1112})(\u{1100}\u{1161})(\u{1102}\u{1161})(\u{1103}\u{1161})(\u{1105}\u{1161})(\u{1106}\u{1161})(\u{1107}\u{1161})(\u{1109}\u{1161})(\u{110b}\u{1161})(\u{110c}\u{1161})(\u{110e}\u{1161})(\u{110f}\u{1161})(\u{1110}\u{1161})(\u{1111}\u{1161})(\u{1112}\u{1161})(\u{110c}\u{116e})(\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab})(\u{110b}\u{1169}\u{1112}\u{116e})(\u{4e00})(\u{4e8c})(\u{4e09})(\u{56db})(\u{4e94})(\u{516d})(\u{4e03})(\u{516b})(\u{4e5d})(\u{5341})(\u{6708})(\u{706b})(\u{6c34})(\u{6728})(\u{91d1})(\u{571f})(\u{65e5})(\u{682a})(\u{6709})(\u{793e})(\u{540d})(\u{7279})(\u{8ca1})(\u{795d})(\u{52b4})(\u{4ee3})(\u{547c})(\u{5b66})(\u{76e3})(\u{4f01})(\u{8cc7})(\u{5354})(\u{796d})(\u{4f11})(\u{81ea})(\u{81f3})\u{554f}\u{5e7c}\u{6587}\u{7b8f}PTE212223242526272829303132333435\u{1100}\u{1102}\u{1103}\u{1105}\u{1106}\u{1107}\u{1109}\u{110b}\u{110c}\u{110e}\u{110f}\u{1110}\u{1111}\u{1112}\u{1100}\u{1161}\u{1102}\u{1161}\u{1103}\u{1161}\u{1105}\u{1161}\u{1106}\u{1161}\u{1107}\u{1161}\u{1109}\u{1161}\u{110b}\u{1161}\u{110c}\u{1161}\u{110e}\u{1161}\u{110f}\u{1161}\u{1110}\u{1161}\u{1111}\u{1161}\u{1112}\u{1161}\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}\u{110c}\u{116e}\u{110b}\u{1174}\u{110b}\u{116e}\u{4e00}\u{4e8c}\u{4e09}\u{56db}\u{4e94}\u{516d}\u{4e03}\u{516b}\u{4e5d}\u{5341}\u{6708}\u{706b}\u{6c34}\u{6728}\u{91d1}\u{571f}\u{65e5}\u{682a}\u{6709}\u{793e}\u{540d}\u{7279}\u{8ca1}\u{795d}\u{52b4}\u{79d8}\u{7537}\u{5973}\u{9069}\u{512a}\u{5370}\u{6ce8}\u{9805}\u{4f11}\u{5199}\u{6b63}\u{4e0a}\u{4e2d}\u{4e0b}\u{5de6}\u{53f3}\u{533b}\u{5b97}\u{5b66}\u{76e3}\u{4f01}\u{8cc7}\u{5354}\u{591c}3637383940414243444546474849501\u{6708}2\u{6708}3\u{6708}4\u{6708}5\u{6708}6\u{6708}7\u{6708}8\u{6708}9\u{6708}10\u{6708}11\u{6708}12\u{6708}HgergeVLTD\u{30a2}\u{30a4}\u{30a6}\u{30a8}\u{30aa}\u{30ab}\u{30ad}\u{30af}\u{30b1}\u{30b3}\u{30b5}\u{30b7}\u{30b9}\u{30bb}\u{30bd}\u{30bf}\u{30c1}\u{30c4}\u{30c6}\u{30c8}\u{30ca}\u{30cb}\u{30cc}\u{30cd}\u{30ce}\u{30cf}\u{30d2}\u{30d5}\u{30d8}\u{30db}\u{30de}\u{30df}\u{30e0}\u{30e1}\u{30e2}\u{30e4}\u{30e6}\u{30e8}\u{30e9}\u{30ea}\u{30eb}\u{30ec}\u{30ed}\u{30ef}\u{30f0}\u{30f1}\u{30f2}\u{4ee4}\u{548c}\u{30a2}\u{30cf}\u{309a}\u{30fc}\u{30c8}\u{30a2}\u{30eb}\u{30d5}\u{30a1}\u{30a2}\u{30f3}\u{30d8}\u{309a}\u{30a2}\u{30a2}\u{30fc}\u{30eb}\u{30a4}\u{30cb}\u{30f3}\u{30af}\u{3099}\u{30a4}\u{30f3}\u{30c1}\u{30a6}\u{30a9}\u{30f3}\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c8}\u{3099}\u{30a8}\u{30fc}\u{30ab}\u{30fc}\u{30aa}\u{30f3}\u{30b9}\u{30aa}\u{30fc}\u{30e0}\u{30ab}\u{30a4}\u{30ea}\u{30ab}\u{30e9}\u{30c3}\u{30c8}\u{30ab}\u{30ed}\u{30ea}\u{30fc}\u{30ab}\u{3099}\u{30ed}\u{30f3}\u{30ab}\u{3099}\u{30f3}\u{30de}\u{30ad}\u{3099}\u{30ab}\u{3099}\u{30ad}\u{3099}\u{30cb}\u{30fc}\u{30ad}\u{30e5}\u{30ea}\u{30fc}\u{30ad}\u{3099}\u{30eb}\u{30bf}\u{3099}\u{30fc}\u{30ad}\u{30ed}\u{30ad}\u{30ed}\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30c8}\u{30f3}\u{30af}\u{30eb}\u{30bb}\u{3099}\u{30a4}\u{30ed}\u{30af}\u{30ed}\u{30fc}\u{30cd}\u{30b1}\u{30fc}\u{30b9}\u{30b3}\u{30eb}\u{30ca}\u{30b3}\u{30fc}\u{30db}\u{309a}\u{30b5}\u{30a4}\u{30af}\u{30eb}\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}\u{30b7}\u{30ea}\u{30f3}\u{30af}\u{3099}\u{30bb}\u{30f3}\u{30c1}\u{30bb}\u{30f3}\u{30c8}\u{30bf}\u{3099}\u{30fc}\u{30b9}\u{30c6}\u{3099}\u{30b7}\u{30c8}\u{3099}\u{30eb}\u{30c8}\u{30f3}\u{30ca}\u{30ce}\u{30ce}\u{30c3}\u{30c8}\u{30cf}\u{30a4}\u{30c4}\u{30cf}\u{309a}\u{30fc}\u{30bb}\u{30f3}\u{30c8}\u{30cf}\u{309a}\u{30fc}\u{30c4}\u{30cf}\u{3099}\u{30fc}\u{30ec}\u{30eb}\u{30d2}\u{309a}\u{30a2}\u{30b9}\u{30c8}\u{30eb}\u{30d2}\u{309a}\u{30af}\u{30eb}\u{30d2}\u{309a}\u{30b3}\u{30d2}\u{3099}\u{30eb}\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c8}\u{3099}\u{30d5}\u{30a3}\u{30fc}\u{30c8}\u{30d5}\u{3099}\u{30c3}\u{30b7}\u{30a7}\u{30eb}\u{30d5}\u{30e9}\u{30f3}\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}\u{30d8}\u{309a}\u{30bd}\u{30d8}\u{309a}\u{30cb}\u{30d2}\u{30d8}\u{30eb}\u{30c4}\u{30d8}\u{309a}\u{30f3}\u{30b9}\u{30d8}\u{309a}\u{30fc}\u{30b7}\u{3099}\u{30d8}\u{3099}\u{30fc}\u{30bf}\u{30db}\u{309a}\u{30a4}\u{30f3}\u{30c8}\u{30db}\u{3099}\u{30eb}\u{30c8}\u{30db}\u{30f3}\u{30db}\u{309a}\u{30f3}\u{30c8}\u{3099}\u{30db}\u{30fc}\u{30eb}\u{30db}\u{30fc}\u{30f3}\u{30de}\u{30a4}\u{30af}\u{30ed}\u{30de}\u{30a4}\u{30eb}\u{30de}\u{30c3}\u{30cf}\u{30de}\u{30eb}\u{30af}\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}\u{30df}\u{30af}\u{30ed}\u{30f3}\u{30df}\u{30ea}\u{30df}\u{30ea}\u{30cf}\u{3099}\u{30fc}\u{30eb}\u{30e1}\u{30ab}\u{3099}\u{30e1}\u{30ab}\u{3099}\u{30c8}\u{30f3}\u{30e1}\u{30fc}\u{30c8}\u{30eb}\u{30e4}\u{30fc}\u{30c8}\u{3099}\u{30e4}\u{30fc}\u{30eb}\u{30e6}\u{30a2}\u{30f3}\u{30ea}\u{30c3}\u{30c8}\u{30eb}\u{30ea}\u{30e9}\u{30eb}\u{30d2}\u{309a}\u{30fc}\u{30eb}\u{30fc}\u{30d5}\u{3099}\u{30eb}\u{30ec}\u{30e0}\u{30ec}\u{30f3}\u{30c8}\u{30b1}\u{3099}\u{30f3}\u{30ef}\u{30c3}\u{30c8}0\u{70b9}1\u{70b9}2\u{70b9}3\u{70b9}4\u{70b9}5\u{70b9}6\u{70b9}7\u{70b9}8\u{70b9}9\u{70b9}10\u{70b9}11\u{70b9}12\u{70b9}13\u{70b9}14\u{70b9}15\u{70b9}16\u{70b9}17\u{70b9}18\u{70b9}19\u{70b9}20\u{70b9}21\u{70b9}22\u{70b9}23\u{70b9}24\u{70b9}hPadaAUbaroVpcdmdm2dm3IU\u{5e73}\u{6210}\u{662d}\u{548c}\u{5927}\u{6b63}\u{660e}\u{6cbb}\u{682a}\u{5f0f}\u{4f1a}\u{793e}pAnA\u{3bc}AmAkAKBMBGBcalkcalpFnF\u{3bc}F\u{3bc}gmgkgHzkHzMHzGHzTHz\u{3bc}lmldlklfmnm\u{3bc}mmmcmkmmm2cm2m2km2mm3cm3m3km3m\u{2215}sm\u{2215}s2PakPaMPaGParadrad\u{2215}srad\u{2215}s2psns\u{3bc}smspVnV\u{3bc}VmVkVMVpWnW\u{3bc}WmWkWMWk\u{3a9}M\u{3a9}a.m.BqcccdC\u{2215}kgCo.dBGyhaHPinKKKMktlmlnloglxmbmilmolPHp.m.PPMPRsrSvWbV\u{2215}mA\u{2215}m1\u{65e5}2\u{65e5}3\u{65e5}4\u{65e5}5\u{65e5}6\u{65e5}7\u{65e5}8\u{65e5}9\u{65e5}10\u{65e5}11\u{65e5}12\u{65e5}13\u{65e5}14\u{65e5}15\u{65e5}16\u{65e5}17\u{65e5}18\u{65e5}19\u{65e5}20\u{65e5}21\u{65e5}22\u{65e5}23\u{65e5}24\u{65e5}25\u{65e5}26\u{65e5}27\u{65e5}28\u{65e5}29\u{65e5}30\u{65e5}31\u{65e5}gal\u{8c48}\u{66f4}\u{8eca}\u{8cc8}\u{6ed1}\u{4e32}\u{53e5}\u{9f9c}\u{9f9c}\u{5951}\u{91d1}\u{5587}\u{5948}\u{61f6}\u{7669}\u{7f85}\u{863f}\u{87ba}\u{88f8}\u{908f}\u{6a02}\u{6d1b}\u{70d9}\u{73de}\u{843d}\u{916a}\u{99f1}\u{4e82}\u{5375}\u{6b04}\u{721b}\u{862d}\u{9e1e}\u{5d50}\u{6feb}\u{85cd}\u{8964}\u{62c9}\u{81d8}\u{881f}\u{5eca}\u{6717}\u{6d6a}\u{72fc}\u{90ce}\u{4f86}\u{51b7}\u{52de}\u{64c4}\u{6ad3}\u{7210}\u{76e7}\u{8001}\u{8606}\u{865c}\u{8def}\u{9732}\u{9b6f}\u{9dfa}\u{788c}\u{797f}\u{7da0}\u{83c9}\u{9304}\u{9e7f}\u{8ad6}\u{58df}\u{5f04}\u{7c60}\u{807e}\u{7262}\u{78ca}\u{8cc2}\u{96f7}\u{58d8}\u{5c62}\u{6a13}\u{6dda}\u{6f0f}\u{7d2f}\u{7e37}\u{964b}\u{52d2}\u{808b}\u{51dc}\u{51cc}\u{7a1c}\u{7dbe}\u{83f1}\u{9675}\u{8b80}\u{62cf}\u{6a02}\u{8afe}\u{4e39}\u{5be7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78fb}\u{4fbf}\u{5fa9}\u{4e0d}\u{6ccc}\u{6578}\u{7d22}\u{53c3}\u{585e}\u{7701}\u{8449}\u{8aaa}\u{6bba}\u{8fb0}\u{6c88}\u{62fe}\u{82e5}\u{63a0}\u{7565}\u{4eae}\u{5169}\u{51c9}\u{6881}\u{7ce7}\u{826f}\u{8ad2}\u{91cf}\u{52f5}\u{5442}\u{5973}\u{5eec}\u{65c5}\u{6ffe}\u{792a}\u{95ad}\u{9a6a}\u{9e97}\u{9ece}\u{529b}\u{66c6}\u{6b77}\u{8f62}\u{5e74}\u{6190}\u{6200}\u{649a}\u{6f23}\u{7149}\u{7489}\u{79ca}\u{7df4}\u{806f}\u{8f26}\u{84ee}\u{9023}\u{934a}\u{5217}\u{52a3}\u{54bd}\u{70c8}\u{88c2}\u{8aaa}\u{5ec9}\u{5ff5}\u{637b}\u{6bae}\u{7c3e}\u{7375}\u{4ee4}\u{56f9}\u{5be7}\u{5dba}\u{601c}\u{73b2}\u{7469}\u{7f9a}\u{8046}\u{9234}\u{96f6}\u{9748}\u{9818}\u{4f8b}\u{79ae}\u{91b4}\u{96b8}\u{60e1}\u{4e86}\u{50da}\u{5bee}\u{5c3f}\u{6599}\u{6a02}\u{71ce}\u{7642}\u{84fc}\u{907c}\u{9f8d}\u{6688}\u{962e}\u{5289}\u{677b}\u{67f3}\u{6d41}\u{6e9c}\u{7409}\u{7559}\u{786b}\u{7d10}\u{985e}\u{516d}\u{622e}\u{9678}\u{502b}\u{5d19}\u{6dea}\u{8f2a}\u{5f8b}\u{6144}\u{6817}\u{7387}\u{9686}\u{5229}\u{540f}\u{5c65}\u{6613}\u{674e}\u{68a8}\u{6ce5}\u{7406}\u{75e2}\u{7f79}\u{88cf}\u{88e1}\u{91cc}\u{96e2}\u{533f}\u{6eba}\u{541d}\u{71d0}\u{7498}\u{85fa}\u{96a3}\u{9c57}\u{9e9f}\u{6797}\u{6dcb}\u{81e8}\u{7acb}\u{7b20}\u{7c92}\u{72c0}\u{7099}\u{8b58}\u{4ec0}\u{8336}\u{523a}\u{5207}\u{5ea6}\u{62d3}\u{7cd6}\u{5b85}\u{6d1e}\u{66b4}\u{8f3b}\u{884c}\u{964d}\u{898b}\u{5ed3}\u{5140}\u{55c0}\u{585a}\u{6674}\u{51de}\u{732a}\u{76ca}\u{793c}\u{795e}\u{7965}\u{798f}\u{9756}\u{7cbe}\u{7fbd}\u{8612}\u{8af8}\u{9038}\u{90fd}\u{98ef}\u{98fc}\u{9928}\u{9db4}\u{90de}\u{96b7}\u{4fae}\u{50e7}\u{514d}\u{52c9}\u{52e4}\u{5351}\u{559d}\u{5606}\u{5668}\u{5840}\u{58a8}\u{5c64}\u{5c6e}\u{6094}\u{6168}\u{618e}\u{61f2}\u{654f}\u{65e2}\u{6691}\u{6885}\u{6d77}\u{6e1a}\u{6f22}\u{716e}\u{722b}\u{7422}\u{7891}\u{793e}\u{7949}\u{7948}\u{7950}\u{7956}\u{795d}\u{798d}\u{798e}\u{7a40}\u{7a81}\u{7bc0}\u{7df4}\u{7e09}\u{7e41}\u{7f72}\u{8005}\u{81ed}\u{8279}\u{8279}\u{8457}\u{8910}\u{8996}\u{8b01}\u{8b39}\u{8cd3}\u{8d08}\u{8fb6}\u{9038}\u{96e3}\u{97ff}\u{983b}\u{6075}\u{242ee}\u{8218}\u{4e26}\u{51b5}\u{5168}\u{4f80}\u{5145}\u{5180}\u{52c7}\u{52fa}\u{559d}\u{5555}\u{5599}\u{55e2}\u{585a}\u{58b3}\u{5944}\u{5954}\u{5a62}\u{5b28}\u{5ed2}\u{5ed9}\u{5f69}\u{5fad}\u{60d8}\u{614e}\u{6108}\u{618e}\u{6160}\u{61f2}\u{6234}\u{63c4}\u{641c}\u{6452}\u{6556}\u{6674}\u{6717}\u{671b}\u{6756}\u{6b79}\u{6bba}\u{6d41}\u{6edb}\u{6ecb}\u{6f22}\u{701e}\u{716e}\u{77a7}\u{7235}\u{72af}\u{732a}\u{7471}\u{7506}\u{753b}\u{761d}\u{761f}\u{76ca}\u{76db}\u{76f4}\u{774a}\u{7740}\u{78cc}\u{7ab1}\u{7bc0}\u{7c7b}\u{7d5b}\u{7df4}\u{7f3e}\u{8005}\u{8352}\u{83ef}\u{8779}\u{8941}\u{8986}\u{8996}\u{8abf}\u{8af8}\u{8acb}\u{8b01}\u{8afe}\u{8aed}\u{8b39}\u{8b8a}\u{8d08}\u{8f38}\u{9072}\u{9199}\u{9276}\u{967c}\u{96e3}\u{9756}\u{97db}\u{97ff}\u{980b}\u{983b}\u{9b12}\u{9f9c}\u{2284a}\u{22844}\u{233d5}\u{3b9d}\u{4018}\u{4039}\u{25249}\u{25cd0}\u{27ed3}\u{9f43}\u{9f8e}..\u{2014}\u{2013}__(){}\u{3014}\u{3015}\u{3010}\u{3011}\u{300a}\u{300b}\u{3008}\u{3009}\u{300c}\u{300d}\u{300e}\u{300f}[] \u{305} \u{305} \u{305} \u{305}___!\u{22}#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\u{5c}]^_`abcdefghijklmnopqrstuvwxyz{|}~\u{2985}\u{2986}\u{3002}\u{300c}\u{300d}\u{3001}\u{30fb}\u{30f2}\u{30a1}\u{30a3}\u{30a5}\u{30a7}\u{30a9}\u{30e3}\u{30e5}\u{30e7}\u{30c3}\u{30fc}\u{30a2}\u{30a4}\u{30a6}\u{30a8}\u{30aa}\u{30ab}\u{30ad}\u{30af}\u{30b1}\u{30b3}\u{30b5}\u{30b7}\u{30b9}\u{30bb}\u{30bd}\u{30bf}\u{30c1}\u{30c4}\u{30c6}\u{30c8}\u{30ca}\u{30cb}\u{30cc}\u{30cd}\u{30ce}\u{30cf}\u{30d2}\u{30d5}\u{30d8}\u{30db}\u{30de}\u{30df}\u{30e0}\u{30e1}\u{30e2}\u{30e4}\u{30e6}\u{30e8}\u{30e9}\u{30ea}\u{30eb}\u{30ec}\u{30ed}\u{30ef}\u{30f3}\u{3099}\u{309a}\u{a2}\u{a3}\u{ac} \u{304}\u{a6}\u{a5}\u{20a9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25a0}\u{25cb}0.0,1,2,3,4,5,6,7,8,9,(A)(B)(C)(D)(E)(F)(G)(H)(I)(J)(K)(L)(M)(N)(O)(P)(Q)(R)(S)(T)(U)(V)(W)(X)(Y)(Z)\u{3014}S\u{3015}CRCDWZABCDEFGHIJKLMNOPQRSTUVWXYZHVMVSDSSPPVWCMCMDMRDJ\u{307b}\u{304b}\u{30b3}\u{30b3}\u{30b5}\u{624b}\u{5b57}\u{53cc}\u{30c6}\u{3099}\u{4e8c}\u{591a}\u{89e3}\u{5929}\u{4ea4}\u{6620}\u{7121}\u{6599}\u{524d}\u{5f8c}\u{518d}\u{65b0}\u{521d}\u{7d42}\u{751f}\u{8ca9}\u{58f0}\u{5439}\u{6f14}\u{6295}\u{6355}\u{4e00}\u{4e09}\u{904a}\u{5de6}\u{4e2d}\u{53f3}\u{6307}\u{8d70}\u{6253}\u{7981}\u{7a7a}\u{5408}\u{6e80}\u{6709}\u{6708}\u{7533}\u{5272}\u{55b6}\u{914d}\u{3014}\u{672c}\u{3015}\u{3014}\u{4e09}\u{3015}\u{3014}\u{4e8c}\u{3015}\u{3014}\u{5b89}\u{3015}\u{3014}\u{70b9}\u{3015}\u{3014}\u{6253}\u{3015}\u{3014}\u{76d7}\u{3015}\u{3014}\u{52dd}\u{3015}\u{3014}\u{6557}\u{3015}\u{5f97}\u{53ef}\u{4e3d}\u{4e38}\u{4e41}\u{20122}\u{4f60}\u{4fae}\u{4fbb}\u{5002}\u{507a}\u{5099}\u{50e7}\u{50cf}\u{349e}\u{2063a}\u{514d}\u{5154}\u{5164}\u{5177}\u{2051c}\u{34b9}\u{5167}\u{518d}\u{2054b}\u{5197}\u{51a4}\u{4ecc}\u{51ac}\u{51b5}\u{291df}\u{51f5}\u{5203}\u{34df}\u{523b}\u{5246}\u{5272}\u{5277}\u{3515}\u{52c7}\u{52c9}\u{52e4}\u{52fa}\u{5305}\u{5306}\u{5317}\u{5349}\u{5351}\u{535a}\u{5373}\u{537d}\u{537f}\u{537f}\u{537f}\u{20a2c}\u{7070}\u{53ca}\u{53df}\u{20b63}\u{53eb}\u{53f1}\u{5406}\u{549e}\u{5438}\u{5448}\u{5468}\u{54a2}\u{54f6}\u{5510}\u{5553}\u{5563}\u{5584}\u{5584}\u{5599}\u{55ab}\u{55b3}\u{55c2}\u{5716}\u{5606}\u{5717}\u{5651}\u{5674}\u{5207}\u{58ee}\u{57ce}\u{57f4}\u{580d}\u{578b}\u{5832}\u{5831}\u{58ac}\u{214e4}\u{58f2}\u{58f7}\u{5906}\u{591a}\u{5922}\u{5962}\u{216a8}\u{216ea}\u{59ec}\u{5a1b}\u{5a27}\u{59d8}\u{5a66}\u{36ee}\u{36fc}\u{5b08}\u{5b3e}\u{5b3e}\u{219c8}\u{5bc3}\u{5bd8}\u{5be7}\u{5bf3}\u{21b18}\u{5bff}\u{5c06}\u{5f53}\u{5c22}\u{3781}\u{5c60}\u{5c6e}\u{5cc0}\u{5c8d}\u{21de4}\u{5d43}\u{21de6}\u{5d6e}\u{5d6b}\u{5d7c}\u{5de1}\u{5de2}\u{382f}\u{5dfd}\u{5e28}\u{5e3d}\u{5e69}\u{3862}\u{22183}\u{387c}\u{5eb0}\u{5eb3}\u{5eb6}\u{5eca}\u{2a392}\u{5efe}\u{22331}\u{22331}\u{8201}\u{5f22}\u{5f22}\u{38c7}\u{232b8}\u{261da}\u{5f62}\u{5f6b}\u{38e3}\u{5f9a}\u{5fcd}\u{5fd7}\u{5ff9}\u{6081}\u{393a}\u{391c}\u{6094}\u{226d4}\u{60c7}\u{6148}\u{614c}\u{614e}\u{614c}\u{617a}\u{618e}\u{61b2}\u{61a4}\u{61af}\u{61de}\u{61f2}\u{61f6}\u{6210}\u{621b}\u{625d}\u{62b1}\u{62d4}\u{6350}\u{22b0c}\u{633d}\u{62fc}\u{6368}\u{6383}\u{63e4}\u{22bf1}\u{6422}\u{63c5}\u{63a9}\u{3a2e}\u{6469}\u{647e}\u{649d}\u{6477}\u{3a6c}\u{654f}\u{656c}\u{2300a}\u{65e3}\u{66f8}\u{6649}\u{3b19}\u{6691}\u{3b08}\u{3ae4}\u{5192}\u{5195}\u{6700}\u{669c}\u{80ad}\u{43d9}\u{6717}\u{671b}\u{6721}\u{675e}\u{6753}\u{233c3}\u{3b49}\u{67fa}\u{6785}\u{6852}\u{6885}\u{2346d}\u{688e}\u{681f}\u{6914}\u{3b9d}\u{6942}\u{69a3}\u{69ea}\u{6aa8}\u{236a3}\u{6adb}\u{3c18}\u{6b21}\u{238a7}\u{6b54}\u{3c4e}\u{6b72}\u{6b9f}\u{6bba}\u{6bbb}\u{23a8d}\u{21d0b}\u{23afa}\u{6c4e}\u{23cbc}\u{6cbf}\u{6ccd}\u{6c67}\u{6d16}\u{6d3e}\u{6d77}\u{6d41}\u{6d69}\u{6d78}\u{6d85}\u{23d1e}\u{6d34}\u{6e2f}\u{6e6e}\u{3d33}\u{6ecb}\u{6ec7}\u{23ed1}\u{6df9}\u{6f6e}\u{23f5e}\u{23f8e}\u{6fc6}\u{7039}\u{701e}\u{701b}\u{3d96}\u{704a}\u{707d}\u{7077}\u{70ad}\u{20525}\u{7145}\u{24263}\u{719c}\u{243ab}\u{7228}\u{7235}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737a}\u{738b}\u{3eac}\u{73a5}\u{3eb8}\u{3eb8}\u{7447}\u{745c}\u{7471}\u{7485}\u{74ca}\u{3f1b}\u{7524}\u{24c36}\u{753e}\u{24c92}\u{7570}\u{2219f}\u{7610}\u{24fa1}\u{24fb8}\u{25044}\u{3ffc}\u{4008}\u{76f4}\u{250f3}\u{250f2}\u{25119}\u{25133}\u{771e}\u{771f}\u{771f}\u{774a}\u{4039}\u{778b}\u{4046}\u{4096}\u{2541d}\u{784e}\u{788c}\u{78cc}\u{40e3}\u{25626}\u{7956}\u{2569a}\u{256c5}\u{798f}\u{79eb}\u{412f}\u{7a40}\u{7a4a}\u{7a4f}\u{2597c}\u{25aa7}\u{25aa7}\u{7aee}\u{4202}\u{25bab}\u{7bc6}\u{7bc9}\u{4227}\u{25c80}\u{7cd2}\u{42a0}\u{7ce8}\u{7ce3}\u{7d00}\u{25f86}\u{7d63}\u{4301}\u{7dc7}\u{7e02}\u{7e45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262d9}\u{7f7a}\u{2633e}\u{7f95}\u{7ffa}\u{8005}\u{264da}\u{26523}\u{8060}\u{265a8}\u{8070}\u{2335f}\u{43d5}\u{80b2}\u{8103}\u{440b}\u{813e}\u{5ab5}\u{267a7}\u{267b5}\u{23393}\u{2339c}\u{8201}\u{8204}\u{8f9e}\u{446b}\u{8291}\u{828b}\u{829d}\u{52b3}\u{82b1}\u{82b3}\u{82bd}\u{82e6}\u{26b3c}\u{82e5}\u{831d}\u{8363}\u{83ad}\u{8323}\u{83bd}\u{83e7}\u{8457}\u{8353}\u{83ca}\u{83cc}\u{83dc}\u{26c36}\u{26d6b}\u{26cd5}\u{452b}\u{84f1}\u{84f3}\u{8516}\u{273ca}\u{8564}\u{26f2c}\u{455d}\u{4561}\u{26fb1}\u{270d2}\u{456b}\u{8650}\u{865c}\u{8667}\u{8669}\u{86a9}\u{8688}\u{870e}\u{86e2}\u{8779}\u{8728}\u{876b}\u{8786}\u{45d7}\u{87e1}\u{8801}\u{45f9}\u{8860}\u{8863}\u{27667}\u{88d7}\u{88de}\u{4635}\u{88fa}\u{34bb}\u{278ae}\u{27966}\u{46be}\u{46c7}\u{8aa0}\u{8aed}\u{8b8a}\u{8c55}\u{27ca8}\u{8cab}\u{8cc1}\u{8d1b}\u{8d77}\u{27f2f}\u{20804}\u{8dcb}\u{8dbc}\u{8df0}\u{208de}\u{8ed4}\u{8f38}\u{285d2}\u{285ed}\u{9094}\u{90f1}\u{9111}\u{2872e}\u{911b}\u{9238}\u{92d7}\u{92d8}\u{927c}\u{93f9}\u{9415}\u{28bfa}\u{958b}\u{4995}\u{95b7}\u{28d77}\u{49e6}\u{96c3}\u{5db2}\u{9723}\u{29145}\u{2921a}\u{4a6e}\u{4a76}\u{97e0}\u{2940a}\u{4ab2}\u{29496}\u{980b}\u{980b}\u{9829}\u{295b6}\u{98e2}\u{4b33}\u{9929}\u{99a7}\u{99c2}\u{99fe}\u{4bce}\u{29b30}\u{9b12}\u{9c40}\u{9cfd}\u{4cce}\u{4ced}\u{9d67}\u{2a0ce}\u{4cf8}\u{2a105}\u{2a20e}\u{2a291}\u{9ebb}\u{4d56}\u{9ef9}\u{9efe}\u{9f05}\u{9f0f}\u{9f16}\u{9f3b}\u{2a600}";
+
+#[cfg(feature = "nfkd-hangul")]
+#[rustfmt::skip]
+pub(super) const HANGUL_DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0x3131, 0, 3),
+    (0x3132, 3, 3),
+    (0x3133, 6, 3),
+    (0x3134, 9, 3),
+    (0x3135, 12, 3),
+    (0x3136, 15, 3),
+    (0x3137, 18, 3),
+    (0x3138, 21, 3),
+    (0x3139, 24, 3),
+    (0x313a, 27, 3),
+    (0x313b, 30, 3),
+    (0x313c, 33, 3),
+    (0x313d, 36, 3),
+    (0x313e, 39, 3),
+    (0x313f, 42, 3),
+    (0x3140, 45, 3),
+    (0x3141, 48, 3),
+    (0x3142, 51, 3),
+    (0x3143, 54, 3),
+    (0x3144, 57, 3),
+    (0x3145, 60, 3),
+    (0x3146, 63, 3),
+    (0x3147, 66, 3),
+    (0x3148, 69, 3),
+    (0x3149, 72, 3),
+    (0x314a, 75, 3),
+    (0x314b, 78, 3),
+    (0x314c, 81, 3),
+    (0x314d, 84, 3),
+    (0x314e, 87, 3),
+    (0x314f, 90, 3),
+    (0x3150, 93, 3),
+    (0x3151, 96, 3),
+    (0x3152, 99, 3),
+    (0x3153, 102, 3),
+    (0x3154, 105, 3),
+    (0x3155, 108, 3),
+    (0x3156, 111, 3),
+    (0x3157, 114, 3),
+    (0x3158, 117, 3),
+    (0x3159, 120, 3),
+    (0x315a, 123, 3),
+    (0x315b, 126, 3),
+    (0x315c, 129, 3),
+    (0x315d, 132, 3),
+    (0x315e, 135, 3),
+    (0x315f, 138, 3),
+    (0x3160, 141, 3),
+    (0x3161, 144, 3),
+    (0x3162, 147, 3),
+    (0x3163, 150, 3),
+    (0x3164, 153, 3),
+    (0x3165, 156, 3),
+    (0x3166, 159, 3),
+    (0x3167, 162, 3),
+    (0x3168, 165, 3),
+    (0x3169, 168, 3),
+    (0x316a, 171, 3),
+    (0x316b, 174, 3),
+    (0x316c, 177, 3),
+    (0x316d, 180, 3),
+    (0x316e, 183, 3),
+    (0x316f, 186, 3),
+    (0x3170, 189, 3),
+    (0x3171, 192, 3),
+    (0x3172, 195, 3),
+    (0x3173, 198, 3),
+    (0x3174, 201, 3),
+    (0x3175, 204, 3),
+    (0x3176, 207, 3),
+    (0x3177, 210, 3),
+    (0x3178, 213, 3),
+    (0x3179, 216, 3),
+    (0x317a, 219, 3),
+    (0x317b, 222, 3),
+    (0x317c, 225, 3),
+    (0x317d, 228, 3),
+    (0x317e, 231, 3),
+    (0x317f, 234, 3),
+    (0x3180, 237, 3),
+    (0x3181, 240, 3),
+    (0x3182, 243, 3),
+    (0x3183, 246, 3),
+    (0x3184, 249, 3),
+    (0x3185, 252, 3),
+    (0x3186, 255, 3),
+    (0x3187, 258, 3),
+    (0x3188, 261, 3),
+    (0x3189, 264, 3),
+    (0x318a, 267, 3),
+    (0x318b, 270, 3),
+    (0x318c, 273, 3),
+    (0x318d, 276, 3),
+    (0x318e, 279, 3),
+    (0xffa0, 282, 3),
+    (0xffa1, 285, 3),
+    (0xffa2, 288, 3),
+    (0xffa3, 291, 3),
+    (0xffa4, 294, 3),
+    (0xffa5, 297, 3),
+    (0xffa6, 300, 3),
+    (0xffa7, 303, 3),
+    (0xffa8, 306, 3),
+    (0xffa9, 309, 3),
+    (0xffaa, 312, 3),
+    (0xffab, 315, 3),
+    (0xffac, 318, 3),
+    (0xffad, 321, 3),
+    (0xffae, 324, 3),
+    (0xffaf, 327, 3),
+    (0xffb0, 330, 3),
+    (0xffb1, 333, 3),
+    (0xffb2, 336, 3),
+    (0xffb3, 339, 3),
+    (0xffb4, 342, 3),
+    (0xffb5, 345, 3),
+    (0xffb6, 348, 3),
+    (0xffb7, 351, 3),
+    (0xffb8, 354, 3),
+    (0xffb9, 357, 3),
+    (0xffba, 360, 3),
+    (0xffbb, 363, 3),
+    (0xffbc, 366, 3),
+    (0xffbd, 369, 3),
+    (0xffbe, 372, 3),
+    (0xffc2, 375, 3),
+    (0xffc3, 378, 3),
+    (0xffc4, 381, 3),
+    (0xffc5, 384, 3),
+    (0xffc6, 387, 3),
+    (0xffc7, 390, 3),
+    (0xffca, 393, 3),
+    (0xffcb, 396, 3),
+    (0xffcc, 399, 3),
+    (0xffcd, 402, 3),
+    (0xffce, 405, 3),
+    (0xffcf, 408, 3),
+    (0xffd2, 411, 3),
+    (0xffd3, 414, 3),
+    (0xffd4, 417, 3),
+    (0xffd5, 420, 3),
+    (0xffd6, 423, 3),
+    (0xffd7, 426, 3),
+    (0xffda, 429, 3),
+    (0xffdb, 432, 3),
+    (0xffdc, 435, 3),
+];
+#[cfg(feature = "nfkd-hangul")]
+#[rustfmt::skip]
+pub(super) const HANGUL_DECOMPOSED: &str = "\u{1100}\u{1101}\u{11aa}\u{1102}\u{11ac}\u{11ad}\u{1103}\u{1104}\u{1105}\u{11b0}\u{11b1}\u{11b2}\u{11b3}\u{11b4}\u{11b5}\u{111a}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110a}\u{110b}\u{110c}\u{110d}\u{110e}\u{110f}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116a}\u{116b}\u{116c}\u{116d}\u{116e}\u{116f}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1160}\u{1114}\u{1115}\u{11c7}\u{11c8}\u{11cc}\u{11ce}\u{11d3}\u{11d7}\u{11d9}\u{111c}\u{11dd}\u{11df}\u{111d}\u{111e}\u{1120}\u{1122}\u{1123}\u{1127}\u{1129}\u{112b}\u{112c}\u{112d}\u{112e}\u{112f}\u{1132}\u{1136}\u{1140}\u{1147}\u{114c}\u{11f1}\u{11f2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\u{1194}\u{119e}\u{11a1}\u{1160}\u{1100}\u{1101}\u{11aa}\u{1102}\u{11ac}\u{11ad}\u{1103}\u{1104}\u{1105}\u{11b0}\u{11b1}\u{11b2}\u{11b3}\u{11b4}\u{11b5}\u{111a}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110a}\u{110b}\u{110c}\u{110d}\u{110e}\u{110f}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116a}\u{116b}\u{116c}\u{116d}\u{116e}\u{116f}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}";
+
+#[cfg(feature = "nfkd-other")]
+#[rustfmt::skip]
+pub(super) const OTHER_DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0x587, 0, 4),
+    (0x622, 4, 4),
+    (0x623, 8, 4),
+    (0x624, 12, 4),
+    (0x625, 16, 4),
+    (0x626, 20, 4),
+    (0x675, 24, 4),
+    (0x676, 28, 4),
+    (0x677, 32, 4),
+    (0x678, 36, 4),
+    (0x6c0, 40, 4),
+    (0x6c2, 44, 4),
+    (0x6d3, 48, 4),
+    (0x929, 52, 6),
+    (0x931, 58, 6),
+    (0x934, 64, 6),
+    (0x958, 70, 6),
+    (0x959, 76, 6),
+    (0x95a, 82, 6),
+    (0x95b, 88, 6),
+    (0x95c, 94, 6),
+    (0x95d, 100, 6),
+    (0x95e, 106, 6),
+    (0x95f, 112, 6),
+    (0x9cb, 118, 6),
+    (0x9cc, 124, 6),
+    (0x9dc, 130, 6),
+    (0x9dd, 136, 6),
+    (0x9df, 142, 6),
+    (0xa33, 148, 6),
+    (0xa36, 154, 6),
+    (0xa59, 160, 6),
+    (0xa5a, 166, 6),
+    (0xa5b, 172, 6),
+    (0xa5e, 178, 6),
+    (0xb48, 184, 6),
+    (0xb4b, 190, 6),
+    (0xb4c, 196, 6),
+    (0xb5c, 202, 6),
+    (0xb5d, 208, 6),
+    (0xb94, 214, 6),
+    (0xbca, 220, 6),
+    (0xbcb, 226, 6),
+    (0xbcc, 232, 6),
+    (0xc48, 238, 6),
+    (0xcc0, 244, 6),
+    (0xcc7, 250, 6),
+    (0xcc8, 256, 6),
+    (0xcca, 262, 6),
+    (0xccb, 268, 9),
+    (0xd4a, 277, 6),
+    (0xd4b, 283, 6),
+    (0xd4c, 289, 6),
+    (0xdda, 295, 6),
+    (0xddc, 301, 6),
+    (0xddd, 307, 9),
+    (0xdde, 316, 6),
+    (0xe33, 322, 6),
+    (0xeb3, 328, 6),
+    (0xedc, 334, 6),
+    (0xedd, 340, 6),
+    (0xf0c, 346, 3),
+    (0xf43, 349, 6),
+    (0xf4d, 355, 6),
+    (0xf52, 361, 6),
+    (0xf57, 367, 6),
+    (0xf5c, 373, 6),
+    (0xf69, 379, 6),
+    (0xf73, 385, 6),
+    (0xf75, 391, 6),
+    (0xf76, 397, 6),
+    (0xf77, 403, 9),
+    (0xf78, 412, 6),
+    (0xf79, 418, 9),
+    (0xf81, 427, 6),
+    (0xf93, 433, 6),
+    (0xf9d, 439, 6),
+    (0xfa2, 445, 6),
+    (0xfa7, 451, 6),
+    (0xfac, 457, 6),
+    (0xfb9, 463, 6),
+    (0x1026, 469, 6),
+    (0x10fc, 475, 3),
+    (0x1b06, 478, 6),
+    (0x1b08, 484, 6),
+    (0x1b0a, 490, 6),
+    (0x1b0c, 496, 6),
+    (0x1b0e, 502, 6),
+    (0x1b12, 508, 6),
+    (0x1b3b, 514, 6),
+    (0x1b3d, 520, 6),
+    (0x1b40, 526, 6),
+    (0x1b41, 532, 6),
+    (0x1b43, 538, 6),
+    (0x2c7c, 544, 1),
+    (0x2c7d, 545, 1),
+    (0x2d6f, 546, 3),
+    (0xa69c, 549, 2),
+    (0xa69d, 551, 2),
+    (0xa770, 553, 3),
+    (0xa7f1, 556, 1),
+    (0xa7f2, 557, 1),
+    (0xa7f3, 558, 1),
+    (0xa7f4, 559, 1),
+    (0xa7f8, 560, 2),
+    (0xa7f9, 562, 2),
+    (0xab5c, 564, 3),
+    (0xab5d, 567, 3),
+    (0xab5e, 570, 2),
+    (0xab5f, 572, 3),
+    (0xab69, 575, 2),
+    (0xfb13, 577, 4),
+    (0xfb14, 581, 4),
+    (0xfb15, 585, 4),
+    (0xfb16, 589, 4),
+    (0xfb17, 593, 4),
+    (0xfb1d, 597, 4),
+    (0xfb1f, 601, 4),
+    (0xfb20, 605, 2),
+    (0xfb21, 607, 2),
+    (0xfb22, 609, 2),
+    (0xfb23, 611, 2),
+    (0xfb24, 613, 2),
+    (0xfb25, 615, 2),
+    (0xfb26, 617, 2),
+    (0xfb27, 619, 2),
+    (0xfb28, 621, 2),
+    (0xfb29, 623, 1),
+    (0xfb2a, 624, 4),
+    (0xfb2b, 628, 4),
+    (0xfb2c, 632, 6),
+    (0xfb2d, 638, 6),
+    (0xfb2e, 644, 4),
+    (0xfb2f, 648, 4),
+    (0xfb30, 652, 4),
+    (0xfb31, 656, 4),
+    (0xfb32, 660, 4),
+    (0xfb33, 664, 4),
+    (0xfb34, 668, 4),
+    (0xfb35, 672, 4),
+    (0xfb36, 676, 4),
+    (0xfb38, 680, 4),
+    (0xfb39, 684, 4),
+    (0xfb3a, 688, 4),
+    (0xfb3b, 692, 4),
+    (0xfb3c, 696, 4),
+    (0xfb3e, 700, 4),
+    (0xfb40, 704, 4),
+    (0xfb41, 708, 4),
+    (0xfb43, 712, 4),
+    (0xfb44, 716, 4),
+    (0xfb46, 720, 4),
+    (0xfb47, 724, 4),
+    (0xfb48, 728, 4),
+    (0xfb49, 732, 4),
+    (0xfb4a, 736, 4),
+    (0xfb4b, 740, 4),
+    (0xfb4c, 744, 4),
+    (0xfb4d, 748, 4),
+    (0xfb4e, 752, 4),
+    (0xfb4f, 756, 4),
+    (0xfb50, 760, 2),
+    (0xfb51, 762, 2),
+    (0xfb52, 764, 2),
+    (0xfb53, 766, 2),
+    (0xfb54, 768, 2),
+    (0xfb55, 770, 2),
+    (0xfb56, 772, 2),
+    (0xfb57, 774, 2),
+    (0xfb58, 776, 2),
+    (0xfb59, 778, 2),
+    (0xfb5a, 780, 2),
+    (0xfb5b, 782, 2),
+    (0xfb5c, 784, 2),
+    (0xfb5d, 786, 2),
+    (0xfb5e, 788, 2),
+    (0xfb5f, 790, 2),
+    (0xfb60, 792, 2),
+    (0xfb61, 794, 2),
+    (0xfb62, 796, 2),
+    (0xfb63, 798, 2),
+    (0xfb64, 800, 2),
+    (0xfb65, 802, 2),
+    (0xfb66, 804, 2),
+    (0xfb67, 806, 2),
+    (0xfb68, 808, 2),
+    (0xfb69, 810, 2),
+    (0xfb6a, 812, 2),
+    (0xfb6b, 814, 2),
+    (0xfb6c, 816, 2),
+    (0xfb6d, 818, 2),
+    (0xfb6e, 820, 2),
+    (0xfb6f, 822, 2),
+    (0xfb70, 824, 2),
+    (0xfb71, 826, 2),
+    (0xfb72, 828, 2),
+    (0xfb73, 830, 2),
+    (0xfb74, 832, 2),
+    (0xfb75, 834, 2),
+    (0xfb76, 836, 2),
+    (0xfb77, 838, 2),
+    (0xfb78, 840, 2),
+    (0xfb79, 842, 2),
+    (0xfb7a, 844, 2),
+    (0xfb7b, 846, 2),
+    (0xfb7c, 848, 2),
+    (0xfb7d, 850, 2),
+    (0xfb7e, 852, 2),
+    (0xfb7f, 854, 2),
+    (0xfb80, 856, 2),
+    (0xfb81, 858, 2),
+    (0xfb82, 860, 2),
+    (0xfb83, 862, 2),
+    (0xfb84, 864, 2),
+    (0xfb85, 866, 2),
+    (0xfb86, 868, 2),
+    (0xfb87, 870, 2),
+    (0xfb88, 872, 2),
+    (0xfb89, 874, 2),
+    (0xfb8a, 876, 2),
+    (0xfb8b, 878, 2),
+    (0xfb8c, 880, 2),
+    (0xfb8d, 882, 2),
+    (0xfb8e, 884, 2),
+    (0xfb8f, 886, 2),
+    (0xfb90, 888, 2),
+    (0xfb91, 890, 2),
+    (0xfb92, 892, 2),
+    (0xfb93, 894, 2),
+    (0xfb94, 896, 2),
+    (0xfb95, 898, 2),
+    (0xfb96, 900, 2),
+    (0xfb97, 902, 2),
+    (0xfb98, 904, 2),
+    (0xfb99, 906, 2),
+    (0xfb9a, 908, 2),
+    (0xfb9b, 910, 2),
+    (0xfb9c, 912, 2),
+    (0xfb9d, 914, 2),
+    (0xfb9e, 916, 2),
+    (0xfb9f, 918, 2),
+    (0xfba0, 920, 2),
+    (0xfba1, 922, 2),
+    (0xfba2, 924, 2),
+    (0xfba3, 926, 2),
+    (0xfba4, 928, 4),
+    (0xfba5, 932, 4),
+    (0xfba6, 936, 2),
+    (0xfba7, 938, 2),
+    (0xfba8, 940, 2),
+    (0xfba9, 942, 2),
+    (0xfbaa, 944, 2),
+    (0xfbab, 946, 2),
+    (0xfbac, 948, 2),
+    (0xfbad, 950, 2),
+    (0xfbae, 952, 2),
+    (0xfbaf, 954, 2),
+    (0xfbb0, 956, 4),
+    (0xfbb1, 960, 4),
+    (0xfbd3, 964, 2),
+    (0xfbd4, 966, 2),
+    (0xfbd5, 968, 2),
+    (0xfbd6, 970, 2),
+    (0xfbd7, 972, 2),
+    (0xfbd8, 974, 2),
+    (0xfbd9, 976, 2),
+    (0xfbda, 978, 2),
+    (0xfbdb, 980, 2),
+    (0xfbdc, 982, 2),
+    (0xfbdd, 984, 4),
+    (0xfbde, 988, 2),
+    (0xfbdf, 990, 2),
+    (0xfbe0, 992, 2),
+    (0xfbe1, 994, 2),
+    (0xfbe2, 996, 2),
+    (0xfbe3, 998, 2),
+    (0xfbe4, 1000, 2),
+    (0xfbe5, 1002, 2),
+    (0xfbe6, 1004, 2),
+    (0xfbe7, 1006, 2),
+    (0xfbe8, 1008, 2),
+    (0xfbe9, 1010, 2),
+    (0xfbea, 1012, 6),
+    (0xfbeb, 1018, 6),
+    (0xfbec, 1024, 6),
+    (0xfbed, 1030, 6),
+    (0xfbee, 1036, 6),
+    (0xfbef, 1042, 6),
+    (0xfbf0, 1048, 6),
+    (0xfbf1, 1054, 6),
+    (0xfbf2, 1060, 6),
+    (0xfbf3, 1066, 6),
+    (0xfbf4, 1072, 6),
+    (0xfbf5, 1078, 6),
+    (0xfbf6, 1084, 6),
+    (0xfbf7, 1090, 6),
+    (0xfbf8, 1096, 6),
+    (0xfbf9, 1102, 6),
+    (0xfbfa, 1108, 6),
+    (0xfbfb, 1114, 6),
+    (0xfbfc, 1120, 2),
+    (0xfbfd, 1122, 2),
+    (0xfbfe, 1124, 2),
+    (0xfbff, 1126, 2),
+    (0xfc00, 1128, 6),
+    (0xfc01, 1134, 6),
+    (0xfc02, 1140, 6),
+    (0xfc03, 1146, 6),
+    (0xfc04, 1152, 6),
+    (0xfc05, 1158, 4),
+    (0xfc06, 1162, 4),
+    (0xfc07, 1166, 4),
+    (0xfc08, 1170, 4),
+    (0xfc09, 1174, 4),
+    (0xfc0a, 1178, 4),
+    (0xfc0b, 1182, 4),
+    (0xfc0c, 1186, 4),
+    (0xfc0d, 1190, 4),
+    (0xfc0e, 1194, 4),
+    (0xfc0f, 1198, 4),
+    (0xfc10, 1202, 4),
+    (0xfc11, 1206, 4),
+    (0xfc12, 1210, 4),
+    (0xfc13, 1214, 4),
+    (0xfc14, 1218, 4),
+    (0xfc15, 1222, 4),
+    (0xfc16, 1226, 4),
+    (0xfc17, 1230, 4),
+    (0xfc18, 1234, 4),
+    (0xfc19, 1238, 4),
+    (0xfc1a, 1242, 4),
+    (0xfc1b, 1246, 4),
+    (0xfc1c, 1250, 4),
+    (0xfc1d, 1254, 4),
+    (0xfc1e, 1258, 4),
+    (0xfc1f, 1262, 4),
+    (0xfc20, 1266, 4),
+    (0xfc21, 1270, 4),
+    (0xfc22, 1274, 4),
+    (0xfc23, 1278, 4),
+    (0xfc24, 1282, 4),
+    (0xfc25, 1286, 4),
+    (0xfc26, 1290, 4),
+    (0xfc27, 1294, 4),
+    (0xfc28, 1298, 4),
+    (0xfc29, 1302, 4),
+    (0xfc2a, 1306, 4),
+    (0xfc2b, 1310, 4),
+    (0xfc2c, 1314, 4),
+    (0xfc2d, 1318, 4),
+    (0xfc2e, 1322, 4),
+    (0xfc2f, 1326, 4),
+    (0xfc30, 1330, 4),
+    (0xfc31, 1334, 4),
+    (0xfc32, 1338, 4),
+    (0xfc33, 1342, 4),
+    (0xfc34, 1346, 4),
+    (0xfc35, 1350, 4),
+    (0xfc36, 1354, 4),
+    (0xfc37, 1358, 4),
+    (0xfc38, 1362, 4),
+    (0xfc39, 1366, 4),
+    (0xfc3a, 1370, 4),
+    (0xfc3b, 1374, 4),
+    (0xfc3c, 1378, 4),
+    (0xfc3d, 1382, 4),
+    (0xfc3e, 1386, 4),
+    (0xfc3f, 1390, 4),
+    (0xfc40, 1394, 4),
+    (0xfc41, 1398, 4),
+    (0xfc42, 1402, 4),
+    (0xfc43, 1406, 4),
+    (0xfc44, 1410, 4),
+    (0xfc45, 1414, 4),
+    (0xfc46, 1418, 4),
+    (0xfc47, 1422, 4),
+    (0xfc48, 1426, 4),
+    (0xfc49, 1430, 4),
+    (0xfc4a, 1434, 4),
+    (0xfc4b, 1438, 4),
+    (0xfc4c, 1442, 4),
+    (0xfc4d, 1446, 4),
+    (0xfc4e, 1450, 4),
+    (0xfc4f, 1454, 4),
+    (0xfc50, 1458, 4),
+    (0xfc51, 1462, 4),
+    (0xfc52, 1466, 4),
+    (0xfc53, 1470, 4),
+    (0xfc54, 1474, 4),
+    (0xfc55, 1478, 4),
+    (0xfc56, 1482, 4),
+    (0xfc57, 1486, 4),
+    (0xfc58, 1490, 4),
+    (0xfc59, 1494, 4),
+    (0xfc5a, 1498, 4),
+    (0xfc5b, 1502, 4),
+    (0xfc5c, 1506, 4),
+    (0xfc5d, 1510, 4),
+    (0xfc5e, 1514, 5),
+    (0xfc5f, 1519, 5),
+    (0xfc60, 1524, 5),
+    (0xfc61, 1529, 5),
+    (0xfc62, 1534, 5),
+    (0xfc63, 1539, 5),
+    (0xfc64, 1544, 6),
+    (0xfc65, 1550, 6),
+    (0xfc66, 1556, 6),
+    (0xfc67, 1562, 6),
+    (0xfc68, 1568, 6),
+    (0xfc69, 1574, 6),
+    (0xfc6a, 1580, 4),
+    (0xfc6b, 1584, 4),
+    (0xfc6c, 1588, 4),
+    (0xfc6d, 1592, 4),
+    (0xfc6e, 1596, 4),
+    (0xfc6f, 1600, 4),
+    (0xfc70, 1604, 4),
+    (0xfc71, 1608, 4),
+    (0xfc72, 1612, 4),
+    (0xfc73, 1616, 4),
+    (0xfc74, 1620, 4),
+    (0xfc75, 1624, 4),
+    (0xfc76, 1628, 4),
+    (0xfc77, 1632, 4),
+    (0xfc78, 1636, 4),
+    (0xfc79, 1640, 4),
+    (0xfc7a, 1644, 4),
+    (0xfc7b, 1648, 4),
+    (0xfc7c, 1652, 4),
+    (0xfc7d, 1656, 4),
+    (0xfc7e, 1660, 4),
+    (0xfc7f, 1664, 4),
+    (0xfc80, 1668, 4),
+    (0xfc81, 1672, 4),
+    (0xfc82, 1676, 4),
+    (0xfc83, 1680, 4),
+    (0xfc84, 1684, 4),
+    (0xfc85, 1688, 4),
+    (0xfc86, 1692, 4),
+    (0xfc87, 1696, 4),
+    (0xfc88, 1700, 4),
+    (0xfc89, 1704, 4),
+    (0xfc8a, 1708, 4),
+    (0xfc8b, 1712, 4),
+    (0xfc8c, 1716, 4),
+    (0xfc8d, 1720, 4),
+    (0xfc8e, 1724, 4),
+    (0xfc8f, 1728, 4),
+    (0xfc90, 1732, 4),
+    (0xfc91, 1736, 4),
+    (0xfc92, 1740, 4),
+    (0xfc93, 1744, 4),
+    (0xfc94, 1748, 4),
+    (0xfc95, 1752, 4),
+    (0xfc96, 1756, 4),
+    (0xfc97, 1760, 6),
+    (0xfc98, 1766, 6),
+    (0xfc99, 1772, 6),
+    (0xfc9a, 1778, 6),
+    (0xfc9b, 1784, 6),
+    (0xfc9c, 1790, 4),
+    (0xfc9d, 1794, 4),
+    (0xfc9e, 1798, 4),
+    (0xfc9f, 1802, 4),
+    (0xfca0, 1806, 4),
+    (0xfca1, 1810, 4),
+    (0xfca2, 1814, 4),
+    (0xfca3, 1818, 4),
+    (0xfca4, 1822, 4),
+    (0xfca5, 1826, 4),
+    (0xfca6, 1830, 4),
+    (0xfca7, 1834, 4),
+    (0xfca8, 1838, 4),
+    (0xfca9, 1842, 4),
+    (0xfcaa, 1846, 4),
+    (0xfcab, 1850, 4),
+    (0xfcac, 1854, 4),
+    (0xfcad, 1858, 4),
+    (0xfcae, 1862, 4),
+    (0xfcaf, 1866, 4),
+    (0xfcb0, 1870, 4),
+    (0xfcb1, 1874, 4),
+    (0xfcb2, 1878, 4),
+    (0xfcb3, 1882, 4),
+    (0xfcb4, 1886, 4),
+    (0xfcb5, 1890, 4),
+    (0xfcb6, 1894, 4),
+    (0xfcb7, 1898, 4),
+    (0xfcb8, 1902, 4),
+    (0xfcb9, 1906, 4),
+    (0xfcba, 1910, 4),
+    (0xfcbb, 1914, 4),
+    (0xfcbc, 1918, 4),
+    (0xfcbd, 1922, 4),
+    (0xfcbe, 1926, 4),
+    (0xfcbf, 1930, 4),
+    (0xfcc0, 1934, 4),
+    (0xfcc1, 1938, 4),
+    (0xfcc2, 1942, 4),
+    (0xfcc3, 1946, 4),
+    (0xfcc4, 1950, 4),
+    (0xfcc5, 1954, 4),
+    (0xfcc6, 1958, 4),
+    (0xfcc7, 1962, 4),
+    (0xfcc8, 1966, 4),
+    (0xfcc9, 1970, 4),
+    (0xfcca, 1974, 4),
+    (0xfccb, 1978, 4),
+    (0xfccc, 1982, 4),
+    (0xfccd, 1986, 4),
+    (0xfcce, 1990, 4),
+    (0xfccf, 1994, 4),
+    (0xfcd0, 1998, 4),
+    (0xfcd1, 2002, 4),
+    (0xfcd2, 2006, 4),
+    (0xfcd3, 2010, 4),
+    (0xfcd4, 2014, 4),
+    (0xfcd5, 2018, 4),
+    (0xfcd6, 2022, 4),
+    (0xfcd7, 2026, 4),
+    (0xfcd8, 2030, 4),
+    (0xfcd9, 2034, 4),
+    (0xfcda, 2038, 4),
+    (0xfcdb, 2042, 4),
+    (0xfcdc, 2046, 4),
+    (0xfcdd, 2050, 4),
+    (0xfcde, 2054, 4),
+    (0xfcdf, 2058, 6),
+    (0xfce0, 2064, 6),
+    (0xfce1, 2070, 4),
+    (0xfce2, 2074, 4),
+    (0xfce3, 2078, 4),
+    (0xfce4, 2082, 4),
+    (0xfce5, 2086, 4),
+    (0xfce6, 2090, 4),
+    (0xfce7, 2094, 4),
+    (0xfce8, 2098, 4),
+    (0xfce9, 2102, 4),
+    (0xfcea, 2106, 4),
+    (0xfceb, 2110, 4),
+    (0xfcec, 2114, 4),
+    (0xfced, 2118, 4),
+    (0xfcee, 2122, 4),
+    (0xfcef, 2126, 4),
+    (0xfcf0, 2130, 4),
+    (0xfcf1, 2134, 4),
+    (0xfcf2, 2138, 6),
+    (0xfcf3, 2144, 6),
+    (0xfcf4, 2150, 6),
+    (0xfcf5, 2156, 4),
+    (0xfcf6, 2160, 4),
+    (0xfcf7, 2164, 4),
+    (0xfcf8, 2168, 4),
+    (0xfcf9, 2172, 4),
+    (0xfcfa, 2176, 4),
+    (0xfcfb, 2180, 4),
+    (0xfcfc, 2184, 4),
+    (0xfcfd, 2188, 4),
+    (0xfcfe, 2192, 4),
+    (0xfcff, 2196, 4),
+    (0xfd00, 2200, 4),
+    (0xfd01, 2204, 4),
+    (0xfd02, 2208, 4),
+    (0xfd03, 2212, 4),
+    (0xfd04, 2216, 4),
+    (0xfd05, 2220, 4),
+    (0xfd06, 2224, 4),
+    (0xfd07, 2228, 4),
+    (0xfd08, 2232, 4),
+    (0xfd09, 2236, 4),
+    (0xfd0a, 2240, 4),
+    (0xfd0b, 2244, 4),
+    (0xfd0c, 2248, 4),
+    (0xfd0d, 2252, 4),
+    (0xfd0e, 2256, 4),
+    (0xfd0f, 2260, 4),
+    (0xfd10, 2264, 4),
+    (0xfd11, 2268, 4),
+    (0xfd12, 2272, 4),
+    (0xfd13, 2276, 4),
+    (0xfd14, 2280, 4),
+    (0xfd15, 2284, 4),
+    (0xfd16, 2288, 4),
+    (0xfd17, 2292, 4),
+    (0xfd18, 2296, 4),
+    (0xfd19, 2300, 4),
+    (0xfd1a, 2304, 4),
+    (0xfd1b, 2308, 4),
+    (0xfd1c, 2312, 4),
+    (0xfd1d, 2316, 4),
+    (0xfd1e, 2320, 4),
+    (0xfd1f, 2324, 4),
+    (0xfd20, 2328, 4),
+    (0xfd21, 2332, 4),
+    (0xfd22, 2336, 4),
+    (0xfd23, 2340, 4),
+    (0xfd24, 2344, 4),
+    (0xfd25, 2348, 4),
+    (0xfd26, 2352, 4),
+    (0xfd27, 2356, 4),
+    (0xfd28, 2360, 4),
+    (0xfd29, 2364, 4),
+    (0xfd2a, 2368, 4),
+    (0xfd2b, 2372, 4),
+    (0xfd2c, 2376, 4),
+    (0xfd2d, 2380, 4),
+    (0xfd2e, 2384, 4),
+    (0xfd2f, 2388, 4),
+    (0xfd30, 2392, 4),
+    (0xfd31, 2396, 4),
+    (0xfd32, 2400, 4),
+    (0xfd33, 2404, 4),
+    (0xfd34, 2408, 4),
+    (0xfd35, 2412, 4),
+    (0xfd36, 2416, 4),
+    (0xfd37, 2420, 4),
+    (0xfd38, 2424, 4),
+    (0xfd39, 2428, 4),
+    (0xfd3a, 2432, 4),
+    (0xfd3b, 2436, 4),
+    (0xfd3c, 2440, 4),
+    (0xfd3d, 2444, 4),
+    (0xfd50, 2448, 6),
+    (0xfd51, 2454, 6),
+    (0xfd52, 2460, 6),
+    (0xfd53, 2466, 6),
+    (0xfd54, 2472, 6),
+    (0xfd55, 2478, 6),
+    (0xfd56, 2484, 6),
+    (0xfd57, 2490, 6),
+    (0xfd58, 2496, 6),
+    (0xfd59, 2502, 6),
+    (0xfd5a, 2508, 6),
+    (0xfd5b, 2514, 6),
+    (0xfd5c, 2520, 6),
+    (0xfd5d, 2526, 6),
+    (0xfd5e, 2532, 6),
+    (0xfd5f, 2538, 6),
+    (0xfd60, 2544, 6),
+    (0xfd61, 2550, 6),
+    (0xfd62, 2556, 6),
+    (0xfd63, 2562, 6),
+    (0xfd64, 2568, 6),
+    (0xfd65, 2574, 6),
+    (0xfd66, 2580, 6),
+    (0xfd67, 2586, 6),
+    (0xfd68, 2592, 6),
+    (0xfd69, 2598, 6),
+    (0xfd6a, 2604, 6),
+    (0xfd6b, 2610, 6),
+    (0xfd6c, 2616, 6),
+    (0xfd6d, 2622, 6),
+    (0xfd6e, 2628, 6),
+    (0xfd6f, 2634, 6),
+    (0xfd70, 2640, 6),
+    (0xfd71, 2646, 6),
+    (0xfd72, 2652, 6),
+    (0xfd73, 2658, 6),
+    (0xfd74, 2664, 6),
+    (0xfd75, 2670, 6),
+    (0xfd76, 2676, 6),
+    (0xfd77, 2682, 6),
+    (0xfd78, 2688, 6),
+    (0xfd79, 2694, 6),
+    (0xfd7a, 2700, 6),
+    (0xfd7b, 2706, 6),
+    (0xfd7c, 2712, 6),
+    (0xfd7d, 2718, 6),
+    (0xfd7e, 2724, 6),
+    (0xfd7f, 2730, 6),
+    (0xfd80, 2736, 6),
+    (0xfd81, 2742, 6),
+    (0xfd82, 2748, 6),
+    (0xfd83, 2754, 6),
+    (0xfd84, 2760, 6),
+    (0xfd85, 2766, 6),
+    (0xfd86, 2772, 6),
+    (0xfd87, 2778, 6),
+    (0xfd88, 2784, 6),
+    (0xfd89, 2790, 6),
+    (0xfd8a, 2796, 6),
+    (0xfd8b, 2802, 6),
+    (0xfd8c, 2808, 6),
+    (0xfd8d, 2814, 6),
+    (0xfd8e, 2820, 6),
+    (0xfd8f, 2826, 6),
+    (0xfd92, 2832, 6),
+    (0xfd93, 2838, 6),
+    (0xfd94, 2844, 6),
+    (0xfd95, 2850, 6),
+    (0xfd96, 2856, 6),
+    (0xfd97, 2862, 6),
+    (0xfd98, 2868, 6),
+    (0xfd99, 2874, 6),
+    (0xfd9a, 2880, 6),
+    (0xfd9b, 2886, 6),
+    (0xfd9c, 2892, 6),
+    (0xfd9d, 2898, 6),
+    (0xfd9e, 2904, 6),
+    (0xfd9f, 2910, 6),
+    (0xfda0, 2916, 6),
+    (0xfda1, 2922, 6),
+    (0xfda2, 2928, 6),
+    (0xfda3, 2934, 6),
+    (0xfda4, 2940, 6),
+    (0xfda5, 2946, 6),
+    (0xfda6, 2952, 6),
+    (0xfda7, 2958, 6),
+    (0xfda8, 2964, 6),
+    (0xfda9, 2970, 6),
+    (0xfdaa, 2976, 6),
+    (0xfdab, 2982, 6),
+    (0xfdac, 2988, 6),
+    (0xfdad, 2994, 6),
+    (0xfdae, 3000, 6),
+    (0xfdaf, 3006, 6),
+    (0xfdb0, 3012, 6),
+    (0xfdb1, 3018, 6),
+    (0xfdb2, 3024, 6),
+    (0xfdb3, 3030, 6),
+    (0xfdb4, 3036, 6),
+    (0xfdb5, 3042, 6),
+    (0xfdb6, 3048, 6),
+    (0xfdb7, 3054, 6),
+    (0xfdb8, 3060, 6),
+    (0xfdb9, 3066, 6),
+    (0xfdba, 3072, 6),
+    (0xfdbb, 3078, 6),
+    (0xfdbc, 3084, 6),
+    (0xfdbd, 3090, 6),
+    (0xfdbe, 3096, 6),
+    (0xfdbf, 3102, 6),
+    (0xfdc0, 3108, 6),
+    (0xfdc1, 3114, 6),
+    (0xfdc2, 3120, 6),
+    (0xfdc3, 3126, 6),
+    (0xfdc4, 3132, 6),
+    (0xfdc5, 3138, 6),
+    (0xfdc6, 3144, 6),
+    (0xfdc7, 3150, 6),
+    (0xfdf0, 3156, 6),
+    (0xfdf1, 3162, 6),
+    (0xfdf2, 3168, 8),
+    (0xfdf3, 3176, 8),
+    (0xfdf4, 3184, 8),
+    (0xfdf5, 3192, 8),
+    (0xfdf6, 3200, 8),
+    (0xfdf7, 3208, 8),
+    (0xfdf8, 3216, 8),
+    (0xfdf9, 3224, 6),
+    (0xfdfa, 3230, 33),
+    (0xfdfb, 3263, 15),
+    (0xfdfc, 3278, 8),
+    (0xfe10, 3286, 1),
+    (0xfe11, 3287, 3),
+    (0xfe12, 3290, 3),
+    (0xfe13, 3293, 1),
+    (0xfe14, 3294, 1),
+    (0xfe15, 3295, 1),
+    (0xfe16, 3296, 1),
+    (0xfe17, 3297, 3),
+    (0xfe18, 3300, 3),
+    (0xfe19, 3303, 3),
+    (0xfe50, 3306, 1),
+    (0xfe51, 3307, 3),
+    (0xfe52, 3310, 1),
+    (0xfe54, 3311, 1),
+    (0xfe55, 3312, 1),
+    (0xfe56, 3313, 1),
+    (0xfe57, 3314, 1),
+    (0xfe58, 3315, 3),
+    (0xfe59, 3318, 1),
+    (0xfe5a, 3319, 1),
+    (0xfe5b, 3320, 1),
+    (0xfe5c, 3321, 1),
+    (0xfe5d, 3322, 3),
+    (0xfe5e, 3325, 3),
+    (0xfe5f, 3328, 1),
+    (0xfe60, 3329, 1),
+    (0xfe61, 3330, 1),
+    (0xfe62, 3331, 1),
+    (0xfe63, 3332, 1),
+    (0xfe64, 3333, 1),
+    (0xfe65, 3334, 1),
+    (0xfe66, 3335, 1),
+    (0xfe68, 3336, 1),
+    (0xfe69, 3337, 1),
+    (0xfe6a, 3338, 1),
+    (0xfe6b, 3339, 1),
+    (0xfe70, 3340, 3),
+    (0xfe71, 3343, 4),
+    (0xfe72, 3347, 3),
+    (0xfe74, 3350, 3),
+    (0xfe76, 3353, 3),
+    (0xfe77, 3356, 4),
+    (0xfe78, 3360, 3),
+    (0xfe79, 3363, 4),
+    (0xfe7a, 3367, 3),
+    (0xfe7b, 3370, 4),
+    (0xfe7c, 3374, 3),
+    (0xfe7d, 3377, 4),
+    (0xfe7e, 3381, 3),
+    (0xfe7f, 3384, 4),
+    (0xfe80, 3388, 2),
+    (0xfe81, 3390, 4),
+    (0xfe82, 3394, 4),
+    (0xfe83, 3398, 4),
+    (0xfe84, 3402, 4),
+    (0xfe85, 3406, 4),
+    (0xfe86, 3410, 4),
+    (0xfe87, 3414, 4),
+    (0xfe88, 3418, 4),
+    (0xfe89, 3422, 4),
+    (0xfe8a, 3426, 4),
+    (0xfe8b, 3430, 4),
+    (0xfe8c, 3434, 4),
+    (0xfe8d, 3438, 2),
+    (0xfe8e, 3440, 2),
+    (0xfe8f, 3442, 2),
+    (0xfe90, 3444, 2),
+    (0xfe91, 3446, 2),
+    (0xfe92, 3448, 2),
+    (0xfe93, 3450, 2),
+    (0xfe94, 3452, 2),
+    (0xfe95, 3454, 2),
+    (0xfe96, 3456, 2),
+    (0xfe97, 3458, 2),
+    (0xfe98, 3460, 2),
+    (0xfe99, 3462, 2),
+    (0xfe9a, 3464, 2),
+    (0xfe9b, 3466, 2),
+    (0xfe9c, 3468, 2),
+    (0xfe9d, 3470, 2),
+    (0xfe9e, 3472, 2),
+    (0xfe9f, 3474, 2),
+    (0xfea0, 3476, 2),
+    (0xfea1, 3478, 2),
+    (0xfea2, 3480, 2),
+    (0xfea3, 3482, 2),
+    (0xfea4, 3484, 2),
+    (0xfea5, 3486, 2),
+    (0xfea6, 3488, 2),
+    (0xfea7, 3490, 2),
+    (0xfea8, 3492, 2),
+    (0xfea9, 3494, 2),
+    (0xfeaa, 3496, 2),
+    (0xfeab, 3498, 2),
+    (0xfeac, 3500, 2),
+    (0xfead, 3502, 2),
+    (0xfeae, 3504, 2),
+    (0xfeaf, 3506, 2),
+    (0xfeb0, 3508, 2),
+    (0xfeb1, 3510, 2),
+    (0xfeb2, 3512, 2),
+    (0xfeb3, 3514, 2),
+    (0xfeb4, 3516, 2),
+    (0xfeb5, 3518, 2),
+    (0xfeb6, 3520, 2),
+    (0xfeb7, 3522, 2),
+    (0xfeb8, 3524, 2),
+    (0xfeb9, 3526, 2),
+    (0xfeba, 3528, 2),
+    (0xfebb, 3530, 2),
+    (0xfebc, 3532, 2),
+    (0xfebd, 3534, 2),
+    (0xfebe, 3536, 2),
+    (0xfebf, 3538, 2),
+    (0xfec0, 3540, 2),
+    (0xfec1, 3542, 2),
+    (0xfec2, 3544, 2),
+    (0xfec3, 3546, 2),
+    (0xfec4, 3548, 2),
+    (0xfec5, 3550, 2),
+    (0xfec6, 3552, 2),
+    (0xfec7, 3554, 2),
+    (0xfec8, 3556, 2),
+    (0xfec9, 3558, 2),
+    (0xfeca, 3560, 2),
+    (0xfecb, 3562, 2),
+    (0xfecc, 3564, 2),
+    (0xfecd, 3566, 2),
+    (0xfece, 3568, 2),
+    (0xfecf, 3570, 2),
+    (0xfed0, 3572, 2),
+    (0xfed1, 3574, 2),
+    (0xfed2, 3576, 2),
+    (0xfed3, 3578, 2),
+    (0xfed4, 3580, 2),
+    (0xfed5, 3582, 2),
+    (0xfed6, 3584, 2),
+    (0xfed7, 3586, 2),
+    (0xfed8, 3588, 2),
+    (0xfed9, 3590, 2),
+    (0xfeda, 3592, 2),
+    (0xfedb, 3594, 2),
+    (0xfedc, 3596, 2),
+    (0xfedd, 3598, 2),
+    (0xfede, 3600, 2),
+    (0xfedf, 3602, 2),
+    (0xfee0, 3604, 2),
+    (0xfee1, 3606, 2),
+    (0xfee2, 3608, 2),
+    (0xfee3, 3610, 2),
+    (0xfee4, 3612, 2),
+    (0xfee5, 3614, 2),
+    (0xfee6, 3616, 2),
+    (0xfee7, 3618, 2),
+    (0xfee8, 3620, 2),
+    (0xfee9, 3622, 2),
+    (0xfeea, 3624, 2),
+    (0xfeeb, 3626, 2),
+    (0xfeec, 3628, 2),
+    (0xfeed, 3630, 2),
+    (0xfeee, 3632, 2),
+    (0xfeef, 3634, 2),
+    (0xfef0, 3636, 2),
+    (0xfef1, 3638, 2),
+    (0xfef2, 3640, 2),
+    (0xfef3, 3642, 2),
+    (0xfef4, 3644, 2),
+    (0xfef5, 3646, 6),
+    (0xfef6, 3652, 6),
+    (0xfef7, 3658, 6),
+    (0xfef8, 3664, 6),
+    (0xfef9, 3670, 6),
+    (0xfefa, 3676, 6),
+    (0xfefb, 3682, 4),
+    (0xfefc, 3686, 4),
+    (0x105c9, 3690, 6),
+    (0x105e4, 3696, 6),
+    (0x10781, 3702, 2),
+    (0x10782, 3704, 2),
+    (0x10783, 3706, 2),
+    (0x10784, 3708, 2),
+    (0x10785, 3710, 2),
+    (0x10787, 3712, 2),
+    (0x10788, 3714, 3),
+    (0x10789, 3717, 2),
+    (0x1078a, 3719, 2),
+    (0x1078b, 3721, 2),
+    (0x1078c, 3723, 2),
+    (0x1078d, 3725, 3),
+    (0x1078e, 3728, 2),
+    (0x1078f, 3730, 2),
+    (0x10790, 3732, 2),
+    (0x10791, 3734, 2),
+    (0x10792, 3736, 2),
+    (0x10793, 3738, 2),
+    (0x10794, 3740, 2),
+    (0x10795, 3742, 2),
+    (0x10796, 3744, 2),
+    (0x10797, 3746, 2),
+    (0x10798, 3748, 2),
+    (0x10799, 3750, 2),
+    (0x1079a, 3752, 2),
+    (0x1079b, 3754, 2),
+    (0x1079c, 3756, 4),
+    (0x1079d, 3760, 3),
+    (0x1079e, 3763, 2),
+    (0x1079f, 3765, 4),
+    (0x107a0, 3769, 2),
+    (0x107a1, 3771, 4),
+    (0x107a2, 3775, 2),
+    (0x107a3, 3777, 2),
+    (0x107a4, 3779, 2),
+    (0x107a5, 3781, 1),
+    (0x107a6, 3782, 2),
+    (0x107a7, 3784, 4),
+    (0x107a8, 3788, 2),
+    (0x107a9, 3790, 2),
+    (0x107aa, 3792, 2),
+    (0x107ab, 3794, 2),
+    (0x107ac, 3796, 2),
+    (0x107ad, 3798, 3),
+    (0x107ae, 3801, 2),
+    (0x107af, 3803, 2),
+    (0x107b0, 3805, 3),
+    (0x107b2, 3808, 2),
+    (0x107b3, 3810, 2),
+    (0x107b4, 3812, 2),
+    (0x107b5, 3814, 2),
+    (0x107b6, 3816, 2),
+    (0x107b7, 3818, 2),
+    (0x107b8, 3820, 2),
+    (0x107b9, 3822, 4),
+    (0x107ba, 3826, 4),
+    (0x1109a, 3830, 8),
+    (0x1109c, 3838, 8),
+    (0x110ab, 3846, 8),
+    (0x1112e, 3854, 8),
+    (0x1112f, 3862, 8),
+    (0x1134b, 3870, 8),
+    (0x1134c, 3878, 8),
+    (0x11383, 3886, 8),
+    (0x11385, 3894, 8),
+    (0x1138e, 3902, 8),
+    (0x11391, 3910, 8),
+    (0x113c5, 3918, 8),
+    (0x113c7, 3926, 8),
+    (0x113c8, 3934, 8),
+    (0x114bb, 3942, 8),
+    (0x114bc, 3950, 8),
+    (0x114be, 3958, 8),
+    (0x115ba, 3966, 8),
+    (0x115bb, 3974, 8),
+    (0x11938, 3982, 8),
+    (0x16121, 3990, 8),
+    (0x16122, 3998, 8),
+    (0x16123, 4006, 8),
+    (0x16124, 4014, 8),
+    (0x16125, 4022, 8),
+    (0x16126, 4030, 12),
+    (0x16127, 4042, 12),
+    (0x16128, 4054, 12),
+    (0x16d68, 4066, 8),
+    (0x16d69, 4074, 8),
+    (0x16d6a, 4082, 12),
+    (0x1ccd6, 4094, 1),
+    (0x1ccd7, 4095, 1),
+    (0x1ccd8, 4096, 1),
+    (0x1ccd9, 4097, 1),
+    (0x1ccda, 4098, 1),
+    (0x1ccdb, 4099, 1),
+    (0x1ccdc, 4100, 1),
+    (0x1ccdd, 4101, 1),
+    (0x1ccde, 4102, 1),
+    (0x1ccdf, 4103, 1),
+    (0x1cce0, 4104, 1),
+    (0x1cce1, 4105, 1),
+    (0x1cce2, 4106, 1),
+    (0x1cce3, 4107, 1),
+    (0x1cce4, 4108, 1),
+    (0x1cce5, 4109, 1),
+    (0x1cce6, 4110, 1),
+    (0x1cce7, 4111, 1),
+    (0x1cce8, 4112, 1),
+    (0x1cce9, 4113, 1),
+    (0x1ccea, 4114, 1),
+    (0x1cceb, 4115, 1),
+    (0x1ccec, 4116, 1),
+    (0x1cced, 4117, 1),
+    (0x1ccee, 4118, 1),
+    (0x1ccef, 4119, 1),
+    (0x1ccf0, 4120, 1),
+    (0x1ccf1, 4121, 1),
+    (0x1ccf2, 4122, 1),
+    (0x1ccf3, 4123, 1),
+    (0x1ccf4, 4124, 1),
+    (0x1ccf5, 4125, 1),
+    (0x1ccf6, 4126, 1),
+    (0x1ccf7, 4127, 1),
+    (0x1ccf8, 4128, 1),
+    (0x1ccf9, 4129, 1),
+    (0x1d15e, 4130, 8),
+    (0x1d15f, 4138, 8),
+    (0x1d160, 4146, 12),
+    (0x1d161, 4158, 12),
+    (0x1d162, 4170, 12),
+    (0x1d163, 4182, 12),
+    (0x1d164, 4194, 12),
+    (0x1d1bb, 4206, 8),
+    (0x1d1bc, 4214, 8),
+    (0x1d1bd, 4222, 12),
+    (0x1d1be, 4234, 12),
+    (0x1d1bf, 4246, 12),
+    (0x1d1c0, 4258, 12),
+    (0x1d400, 4270, 1),
+    (0x1d401, 4271, 1),
+    (0x1d402, 4272, 1),
+    (0x1d403, 4273, 1),
+    (0x1d404, 4274, 1),
+    (0x1d405, 4275, 1),
+    (0x1d406, 4276, 1),
+    (0x1d407, 4277, 1),
+    (0x1d408, 4278, 1),
+    (0x1d409, 4279, 1),
+    (0x1d40a, 4280, 1),
+    (0x1d40b, 4281, 1),
+    (0x1d40c, 4282, 1),
+    (0x1d40d, 4283, 1),
+    (0x1d40e, 4284, 1),
+    (0x1d40f, 4285, 1),
+    (0x1d410, 4286, 1),
+    (0x1d411, 4287, 1),
+    (0x1d412, 4288, 1),
+    (0x1d413, 4289, 1),
+    (0x1d414, 4290, 1),
+    (0x1d415, 4291, 1),
+    (0x1d416, 4292, 1),
+    (0x1d417, 4293, 1),
+    (0x1d418, 4294, 1),
+    (0x1d419, 4295, 1),
+    (0x1d41a, 4296, 1),
+    (0x1d41b, 4297, 1),
+    (0x1d41c, 4298, 1),
+    (0x1d41d, 4299, 1),
+    (0x1d41e, 4300, 1),
+    (0x1d41f, 4301, 1),
+    (0x1d420, 4302, 1),
+    (0x1d421, 4303, 1),
+    (0x1d422, 4304, 1),
+    (0x1d423, 4305, 1),
+    (0x1d424, 4306, 1),
+    (0x1d425, 4307, 1),
+    (0x1d426, 4308, 1),
+    (0x1d427, 4309, 1),
+    (0x1d428, 4310, 1),
+    (0x1d429, 4311, 1),
+    (0x1d42a, 4312, 1),
+    (0x1d42b, 4313, 1),
+    (0x1d42c, 4314, 1),
+    (0x1d42d, 4315, 1),
+    (0x1d42e, 4316, 1),
+    (0x1d42f, 4317, 1),
+    (0x1d430, 4318, 1),
+    (0x1d431, 4319, 1),
+    (0x1d432, 4320, 1),
+    (0x1d433, 4321, 1),
+    (0x1d434, 4322, 1),
+    (0x1d435, 4323, 1),
+    (0x1d436, 4324, 1),
+    (0x1d437, 4325, 1),
+    (0x1d438, 4326, 1),
+    (0x1d439, 4327, 1),
+    (0x1d43a, 4328, 1),
+    (0x1d43b, 4329, 1),
+    (0x1d43c, 4330, 1),
+    (0x1d43d, 4331, 1),
+    (0x1d43e, 4332, 1),
+    (0x1d43f, 4333, 1),
+    (0x1d440, 4334, 1),
+    (0x1d441, 4335, 1),
+    (0x1d442, 4336, 1),
+    (0x1d443, 4337, 1),
+    (0x1d444, 4338, 1),
+    (0x1d445, 4339, 1),
+    (0x1d446, 4340, 1),
+    (0x1d447, 4341, 1),
+    (0x1d448, 4342, 1),
+    (0x1d449, 4343, 1),
+    (0x1d44a, 4344, 1),
+    (0x1d44b, 4345, 1),
+    (0x1d44c, 4346, 1),
+    (0x1d44d, 4347, 1),
+    (0x1d44e, 4348, 1),
+    (0x1d44f, 4349, 1),
+    (0x1d450, 4350, 1),
+    (0x1d451, 4351, 1),
+    (0x1d452, 4352, 1),
+    (0x1d453, 4353, 1),
+    (0x1d454, 4354, 1),
+    (0x1d456, 4355, 1),
+    (0x1d457, 4356, 1),
+    (0x1d458, 4357, 1),
+    (0x1d459, 4358, 1),
+    (0x1d45a, 4359, 1),
+    (0x1d45b, 4360, 1),
+    (0x1d45c, 4361, 1),
+    (0x1d45d, 4362, 1),
+    (0x1d45e, 4363, 1),
+    (0x1d45f, 4364, 1),
+    (0x1d460, 4365, 1),
+    (0x1d461, 4366, 1),
+    (0x1d462, 4367, 1),
+    (0x1d463, 4368, 1),
+    (0x1d464, 4369, 1),
+    (0x1d465, 4370, 1),
+    (0x1d466, 4371, 1),
+    (0x1d467, 4372, 1),
+    (0x1d468, 4373, 1),
+    (0x1d469, 4374, 1),
+    (0x1d46a, 4375, 1),
+    (0x1d46b, 4376, 1),
+    (0x1d46c, 4377, 1),
+    (0x1d46d, 4378, 1),
+    (0x1d46e, 4379, 1),
+    (0x1d46f, 4380, 1),
+    (0x1d470, 4381, 1),
+    (0x1d471, 4382, 1),
+    (0x1d472, 4383, 1),
+    (0x1d473, 4384, 1),
+    (0x1d474, 4385, 1),
+    (0x1d475, 4386, 1),
+    (0x1d476, 4387, 1),
+    (0x1d477, 4388, 1),
+    (0x1d478, 4389, 1),
+    (0x1d479, 4390, 1),
+    (0x1d47a, 4391, 1),
+    (0x1d47b, 4392, 1),
+    (0x1d47c, 4393, 1),
+    (0x1d47d, 4394, 1),
+    (0x1d47e, 4395, 1),
+    (0x1d47f, 4396, 1),
+    (0x1d480, 4397, 1),
+    (0x1d481, 4398, 1),
+    (0x1d482, 4399, 1),
+    (0x1d483, 4400, 1),
+    (0x1d484, 4401, 1),
+    (0x1d485, 4402, 1),
+    (0x1d486, 4403, 1),
+    (0x1d487, 4404, 1),
+    (0x1d488, 4405, 1),
+    (0x1d489, 4406, 1),
+    (0x1d48a, 4407, 1),
+    (0x1d48b, 4408, 1),
+    (0x1d48c, 4409, 1),
+    (0x1d48d, 4410, 1),
+    (0x1d48e, 4411, 1),
+    (0x1d48f, 4412, 1),
+    (0x1d490, 4413, 1),
+    (0x1d491, 4414, 1),
+    (0x1d492, 4415, 1),
+    (0x1d493, 4416, 1),
+    (0x1d494, 4417, 1),
+    (0x1d495, 4418, 1),
+    (0x1d496, 4419, 1),
+    (0x1d497, 4420, 1),
+    (0x1d498, 4421, 1),
+    (0x1d499, 4422, 1),
+    (0x1d49a, 4423, 1),
+    (0x1d49b, 4424, 1),
+    (0x1d49c, 4425, 1),
+    (0x1d49e, 4426, 1),
+    (0x1d49f, 4427, 1),
+    (0x1d4a2, 4428, 1),
+    (0x1d4a5, 4429, 1),
+    (0x1d4a6, 4430, 1),
+    (0x1d4a9, 4431, 1),
+    (0x1d4aa, 4432, 1),
+    (0x1d4ab, 4433, 1),
+    (0x1d4ac, 4434, 1),
+    (0x1d4ae, 4435, 1),
+    (0x1d4af, 4436, 1),
+    (0x1d4b0, 4437, 1),
+    (0x1d4b1, 4438, 1),
+    (0x1d4b2, 4439, 1),
+    (0x1d4b3, 4440, 1),
+    (0x1d4b4, 4441, 1),
+    (0x1d4b5, 4442, 1),
+    (0x1d4b6, 4443, 1),
+    (0x1d4b7, 4444, 1),
+    (0x1d4b8, 4445, 1),
+    (0x1d4b9, 4446, 1),
+    (0x1d4bb, 4447, 1),
+    (0x1d4bd, 4448, 1),
+    (0x1d4be, 4449, 1),
+    (0x1d4bf, 4450, 1),
+    (0x1d4c0, 4451, 1),
+    (0x1d4c1, 4452, 1),
+    (0x1d4c2, 4453, 1),
+    (0x1d4c3, 4454, 1),
+    (0x1d4c5, 4455, 1),
+    (0x1d4c6, 4456, 1),
+    (0x1d4c7, 4457, 1),
+    (0x1d4c8, 4458, 1),
+    (0x1d4c9, 4459, 1),
+    (0x1d4ca, 4460, 1),
+    (0x1d4cb, 4461, 1),
+    (0x1d4cc, 4462, 1),
+    (0x1d4cd, 4463, 1),
+    (0x1d4ce, 4464, 1),
+    (0x1d4cf, 4465, 1),
+    (0x1d4d0, 4466, 1),
+    (0x1d4d1, 4467, 1),
+    (0x1d4d2, 4468, 1),
+    (0x1d4d3, 4469, 1),
+    (0x1d4d4, 4470, 1),
+    (0x1d4d5, 4471, 1),
+    (0x1d4d6, 4472, 1),
+    (0x1d4d7, 4473, 1),
+    (0x1d4d8, 4474, 1),
+    (0x1d4d9, 4475, 1),
+    (0x1d4da, 4476, 1),
+    (0x1d4db, 4477, 1),
+    (0x1d4dc, 4478, 1),
+    (0x1d4dd, 4479, 1),
+    (0x1d4de, 4480, 1),
+    (0x1d4df, 4481, 1),
+    (0x1d4e0, 4482, 1),
+    (0x1d4e1, 4483, 1),
+    (0x1d4e2, 4484, 1),
+    (0x1d4e3, 4485, 1),
+    (0x1d4e4, 4486, 1),
+    (0x1d4e5, 4487, 1),
+    (0x1d4e6, 4488, 1),
+    (0x1d4e7, 4489, 1),
+    (0x1d4e8, 4490, 1),
+    (0x1d4e9, 4491, 1),
+    (0x1d4ea, 4492, 1),
+    (0x1d4eb, 4493, 1),
+    (0x1d4ec, 4494, 1),
+    (0x1d4ed, 4495, 1),
+    (0x1d4ee, 4496, 1),
+    (0x1d4ef, 4497, 1),
+    (0x1d4f0, 4498, 1),
+    (0x1d4f1, 4499, 1),
+    (0x1d4f2, 4500, 1),
+    (0x1d4f3, 4501, 1),
+    (0x1d4f4, 4502, 1),
+    (0x1d4f5, 4503, 1),
+    (0x1d4f6, 4504, 1),
+    (0x1d4f7, 4505, 1),
+    (0x1d4f8, 4506, 1),
+    (0x1d4f9, 4507, 1),
+    (0x1d4fa, 4508, 1),
+    (0x1d4fb, 4509, 1),
+    (0x1d4fc, 4510, 1),
+    (0x1d4fd, 4511, 1),
+    (0x1d4fe, 4512, 1),
+    (0x1d4ff, 4513, 1),
+    (0x1d500, 4514, 1),
+    (0x1d501, 4515, 1),
+    (0x1d502, 4516, 1),
+    (0x1d503, 4517, 1),
+    (0x1d504, 4518, 1),
+    (0x1d505, 4519, 1),
+    (0x1d507, 4520, 1),
+    (0x1d508, 4521, 1),
+    (0x1d509, 4522, 1),
+    (0x1d50a, 4523, 1),
+    (0x1d50d, 4524, 1),
+    (0x1d50e, 4525, 1),
+    (0x1d50f, 4526, 1),
+    (0x1d510, 4527, 1),
+    (0x1d511, 4528, 1),
+    (0x1d512, 4529, 1),
+    (0x1d513, 4530, 1),
+    (0x1d514, 4531, 1),
+    (0x1d516, 4532, 1),
+    (0x1d517, 4533, 1),
+    (0x1d518, 4534, 1),
+    (0x1d519, 4535, 1),
+    (0x1d51a, 4536, 1),
+    (0x1d51b, 4537, 1),
+    (0x1d51c, 4538, 1),
+    (0x1d51e, 4539, 1),
+    (0x1d51f, 4540, 1),
+    (0x1d520, 4541, 1),
+    (0x1d521, 4542, 1),
+    (0x1d522, 4543, 1),
+    (0x1d523, 4544, 1),
+    (0x1d524, 4545, 1),
+    (0x1d525, 4546, 1),
+    (0x1d526, 4547, 1),
+    (0x1d527, 4548, 1),
+    (0x1d528, 4549, 1),
+    (0x1d529, 4550, 1),
+    (0x1d52a, 4551, 1),
+    (0x1d52b, 4552, 1),
+    (0x1d52c, 4553, 1),
+    (0x1d52d, 4554, 1),
+    (0x1d52e, 4555, 1),
+    (0x1d52f, 4556, 1),
+    (0x1d530, 4557, 1),
+    (0x1d531, 4558, 1),
+    (0x1d532, 4559, 1),
+    (0x1d533, 4560, 1),
+    (0x1d534, 4561, 1),
+    (0x1d535, 4562, 1),
+    (0x1d536, 4563, 1),
+    (0x1d537, 4564, 1),
+    (0x1d538, 4565, 1),
+    (0x1d539, 4566, 1),
+    (0x1d53b, 4567, 1),
+    (0x1d53c, 4568, 1),
+    (0x1d53d, 4569, 1),
+    (0x1d53e, 4570, 1),
+    (0x1d540, 4571, 1),
+    (0x1d541, 4572, 1),
+    (0x1d542, 4573, 1),
+    (0x1d543, 4574, 1),
+    (0x1d544, 4575, 1),
+    (0x1d546, 4576, 1),
+    (0x1d54a, 4577, 1),
+    (0x1d54b, 4578, 1),
+    (0x1d54c, 4579, 1),
+    (0x1d54d, 4580, 1),
+    (0x1d54e, 4581, 1),
+    (0x1d54f, 4582, 1),
+    (0x1d550, 4583, 1),
+    (0x1d552, 4584, 1),
+    (0x1d553, 4585, 1),
+    (0x1d554, 4586, 1),
+    (0x1d555, 4587, 1),
+    (0x1d556, 4588, 1),
+    (0x1d557, 4589, 1),
+    (0x1d558, 4590, 1),
+    (0x1d559, 4591, 1),
+    (0x1d55a, 4592, 1),
+    (0x1d55b, 4593, 1),
+    (0x1d55c, 4594, 1),
+    (0x1d55d, 4595, 1),
+    (0x1d55e, 4596, 1),
+    (0x1d55f, 4597, 1),
+    (0x1d560, 4598, 1),
+    (0x1d561, 4599, 1),
+    (0x1d562, 4600, 1),
+    (0x1d563, 4601, 1),
+    (0x1d564, 4602, 1),
+    (0x1d565, 4603, 1),
+    (0x1d566, 4604, 1),
+    (0x1d567, 4605, 1),
+    (0x1d568, 4606, 1),
+    (0x1d569, 4607, 1),
+    (0x1d56a, 4608, 1),
+    (0x1d56b, 4609, 1),
+    (0x1d56c, 4610, 1),
+    (0x1d56d, 4611, 1),
+    (0x1d56e, 4612, 1),
+    (0x1d56f, 4613, 1),
+    (0x1d570, 4614, 1),
+    (0x1d571, 4615, 1),
+    (0x1d572, 4616, 1),
+    (0x1d573, 4617, 1),
+    (0x1d574, 4618, 1),
+    (0x1d575, 4619, 1),
+    (0x1d576, 4620, 1),
+    (0x1d577, 4621, 1),
+    (0x1d578, 4622, 1),
+    (0x1d579, 4623, 1),
+    (0x1d57a, 4624, 1),
+    (0x1d57b, 4625, 1),
+    (0x1d57c, 4626, 1),
+    (0x1d57d, 4627, 1),
+    (0x1d57e, 4628, 1),
+    (0x1d57f, 4629, 1),
+    (0x1d580, 4630, 1),
+    (0x1d581, 4631, 1),
+    (0x1d582, 4632, 1),
+    (0x1d583, 4633, 1),
+    (0x1d584, 4634, 1),
+    (0x1d585, 4635, 1),
+    (0x1d586, 4636, 1),
+    (0x1d587, 4637, 1),
+    (0x1d588, 4638, 1),
+    (0x1d589, 4639, 1),
+    (0x1d58a, 4640, 1),
+    (0x1d58b, 4641, 1),
+    (0x1d58c, 4642, 1),
+    (0x1d58d, 4643, 1),
+    (0x1d58e, 4644, 1),
+    (0x1d58f, 4645, 1),
+    (0x1d590, 4646, 1),
+    (0x1d591, 4647, 1),
+    (0x1d592, 4648, 1),
+    (0x1d593, 4649, 1),
+    (0x1d594, 4650, 1),
+    (0x1d595, 4651, 1),
+    (0x1d596, 4652, 1),
+    (0x1d597, 4653, 1),
+    (0x1d598, 4654, 1),
+    (0x1d599, 4655, 1),
+    (0x1d59a, 4656, 1),
+    (0x1d59b, 4657, 1),
+    (0x1d59c, 4658, 1),
+    (0x1d59d, 4659, 1),
+    (0x1d59e, 4660, 1),
+    (0x1d59f, 4661, 1),
+    (0x1d5a0, 4662, 1),
+    (0x1d5a1, 4663, 1),
+    (0x1d5a2, 4664, 1),
+    (0x1d5a3, 4665, 1),
+    (0x1d5a4, 4666, 1),
+    (0x1d5a5, 4667, 1),
+    (0x1d5a6, 4668, 1),
+    (0x1d5a7, 4669, 1),
+    (0x1d5a8, 4670, 1),
+    (0x1d5a9, 4671, 1),
+    (0x1d5aa, 4672, 1),
+    (0x1d5ab, 4673, 1),
+    (0x1d5ac, 4674, 1),
+    (0x1d5ad, 4675, 1),
+    (0x1d5ae, 4676, 1),
+    (0x1d5af, 4677, 1),
+    (0x1d5b0, 4678, 1),
+    (0x1d5b1, 4679, 1),
+    (0x1d5b2, 4680, 1),
+    (0x1d5b3, 4681, 1),
+    (0x1d5b4, 4682, 1),
+    (0x1d5b5, 4683, 1),
+    (0x1d5b6, 4684, 1),
+    (0x1d5b7, 4685, 1),
+    (0x1d5b8, 4686, 1),
+    (0x1d5b9, 4687, 1),
+    (0x1d5ba, 4688, 1),
+    (0x1d5bb, 4689, 1),
+    (0x1d5bc, 4690, 1),
+    (0x1d5bd, 4691, 1),
+    (0x1d5be, 4692, 1),
+    (0x1d5bf, 4693, 1),
+    (0x1d5c0, 4694, 1),
+    (0x1d5c1, 4695, 1),
+    (0x1d5c2, 4696, 1),
+    (0x1d5c3, 4697, 1),
+    (0x1d5c4, 4698, 1),
+    (0x1d5c5, 4699, 1),
+    (0x1d5c6, 4700, 1),
+    (0x1d5c7, 4701, 1),
+    (0x1d5c8, 4702, 1),
+    (0x1d5c9, 4703, 1),
+    (0x1d5ca, 4704, 1),
+    (0x1d5cb, 4705, 1),
+    (0x1d5cc, 4706, 1),
+    (0x1d5cd, 4707, 1),
+    (0x1d5ce, 4708, 1),
+    (0x1d5cf, 4709, 1),
+    (0x1d5d0, 4710, 1),
+    (0x1d5d1, 4711, 1),
+    (0x1d5d2, 4712, 1),
+    (0x1d5d3, 4713, 1),
+    (0x1d5d4, 4714, 1),
+    (0x1d5d5, 4715, 1),
+    (0x1d5d6, 4716, 1),
+    (0x1d5d7, 4717, 1),
+    (0x1d5d8, 4718, 1),
+    (0x1d5d9, 4719, 1),
+    (0x1d5da, 4720, 1),
+    (0x1d5db, 4721, 1),
+    (0x1d5dc, 4722, 1),
+    (0x1d5dd, 4723, 1),
+    (0x1d5de, 4724, 1),
+    (0x1d5df, 4725, 1),
+    (0x1d5e0, 4726, 1),
+    (0x1d5e1, 4727, 1),
+    (0x1d5e2, 4728, 1),
+    (0x1d5e3, 4729, 1),
+    (0x1d5e4, 4730, 1),
+    (0x1d5e5, 4731, 1),
+    (0x1d5e6, 4732, 1),
+    (0x1d5e7, 4733, 1),
+    (0x1d5e8, 4734, 1),
+    (0x1d5e9, 4735, 1),
+    (0x1d5ea, 4736, 1),
+    (0x1d5eb, 4737, 1),
+    (0x1d5ec, 4738, 1),
+    (0x1d5ed, 4739, 1),
+    (0x1d5ee, 4740, 1),
+    (0x1d5ef, 4741, 1),
+    (0x1d5f0, 4742, 1),
+    (0x1d5f1, 4743, 1),
+    (0x1d5f2, 4744, 1),
+    (0x1d5f3, 4745, 1),
+    (0x1d5f4, 4746, 1),
+    (0x1d5f5, 4747, 1),
+    (0x1d5f6, 4748, 1),
+    (0x1d5f7, 4749, 1),
+    (0x1d5f8, 4750, 1),
+    (0x1d5f9, 4751, 1),
+    (0x1d5fa, 4752, 1),
+    (0x1d5fb, 4753, 1),
+    (0x1d5fc, 4754, 1),
+    (0x1d5fd, 4755, 1),
+    (0x1d5fe, 4756, 1),
+    (0x1d5ff, 4757, 1),
+    (0x1d600, 4758, 1),
+    (0x1d601, 4759, 1),
+    (0x1d602, 4760, 1),
+    (0x1d603, 4761, 1),
+    (0x1d604, 4762, 1),
+    (0x1d605, 4763, 1),
+    (0x1d606, 4764, 1),
+    (0x1d607, 4765, 1),
+    (0x1d608, 4766, 1),
+    (0x1d609, 4767, 1),
+    (0x1d60a, 4768, 1),
+    (0x1d60b, 4769, 1),
+    (0x1d60c, 4770, 1),
+    (0x1d60d, 4771, 1),
+    (0x1d60e, 4772, 1),
+    (0x1d60f, 4773, 1),
+    (0x1d610, 4774, 1),
+    (0x1d611, 4775, 1),
+    (0x1d612, 4776, 1),
+    (0x1d613, 4777, 1),
+    (0x1d614, 4778, 1),
+    (0x1d615, 4779, 1),
+    (0x1d616, 4780, 1),
+    (0x1d617, 4781, 1),
+    (0x1d618, 4782, 1),
+    (0x1d619, 4783, 1),
+    (0x1d61a, 4784, 1),
+    (0x1d61b, 4785, 1),
+    (0x1d61c, 4786, 1),
+    (0x1d61d, 4787, 1),
+    (0x1d61e, 4788, 1),
+    (0x1d61f, 4789, 1),
+    (0x1d620, 4790, 1),
+    (0x1d621, 4791, 1),
+    (0x1d622, 4792, 1),
+    (0x1d623, 4793, 1),
+    (0x1d624, 4794, 1),
+    (0x1d625, 4795, 1),
+    (0x1d626, 4796, 1),
+    (0x1d627, 4797, 1),
+    (0x1d628, 4798, 1),
+    (0x1d629, 4799, 1),
+    (0x1d62a, 4800, 1),
+    (0x1d62b, 4801, 1),
+    (0x1d62c, 4802, 1),
+    (0x1d62d, 4803, 1),
+    (0x1d62e, 4804, 1),
+    (0x1d62f, 4805, 1),
+    (0x1d630, 4806, 1),
+    (0x1d631, 4807, 1),
+    (0x1d632, 4808, 1),
+    (0x1d633, 4809, 1),
+    (0x1d634, 4810, 1),
+    (0x1d635, 4811, 1),
+    (0x1d636, 4812, 1),
+    (0x1d637, 4813, 1),
+    (0x1d638, 4814, 1),
+    (0x1d639, 4815, 1),
+    (0x1d63a, 4816, 1),
+    (0x1d63b, 4817, 1),
+    (0x1d63c, 4818, 1),
+    (0x1d63d, 4819, 1),
+    (0x1d63e, 4820, 1),
+    (0x1d63f, 4821, 1),
+    (0x1d640, 4822, 1),
+    (0x1d641, 4823, 1),
+    (0x1d642, 4824, 1),
+    (0x1d643, 4825, 1),
+    (0x1d644, 4826, 1),
+    (0x1d645, 4827, 1),
+    (0x1d646, 4828, 1),
+    (0x1d647, 4829, 1),
+    (0x1d648, 4830, 1),
+    (0x1d649, 4831, 1),
+    (0x1d64a, 4832, 1),
+    (0x1d64b, 4833, 1),
+    (0x1d64c, 4834, 1),
+    (0x1d64d, 4835, 1),
+    (0x1d64e, 4836, 1),
+    (0x1d64f, 4837, 1),
+    (0x1d650, 4838, 1),
+    (0x1d651, 4839, 1),
+    (0x1d652, 4840, 1),
+    (0x1d653, 4841, 1),
+    (0x1d654, 4842, 1),
+    (0x1d655, 4843, 1),
+    (0x1d656, 4844, 1),
+    (0x1d657, 4845, 1),
+    (0x1d658, 4846, 1),
+    (0x1d659, 4847, 1),
+    (0x1d65a, 4848, 1),
+    (0x1d65b, 4849, 1),
+    (0x1d65c, 4850, 1),
+    (0x1d65d, 4851, 1),
+    (0x1d65e, 4852, 1),
+    (0x1d65f, 4853, 1),
+    (0x1d660, 4854, 1),
+    (0x1d661, 4855, 1),
+    (0x1d662, 4856, 1),
+    (0x1d663, 4857, 1),
+    (0x1d664, 4858, 1),
+    (0x1d665, 4859, 1),
+    (0x1d666, 4860, 1),
+    (0x1d667, 4861, 1),
+    (0x1d668, 4862, 1),
+    (0x1d669, 4863, 1),
+    (0x1d66a, 4864, 1),
+    (0x1d66b, 4865, 1),
+    (0x1d66c, 4866, 1),
+    (0x1d66d, 4867, 1),
+    (0x1d66e, 4868, 1),
+    (0x1d66f, 4869, 1),
+    (0x1d670, 4870, 1),
+    (0x1d671, 4871, 1),
+    (0x1d672, 4872, 1),
+    (0x1d673, 4873, 1),
+    (0x1d674, 4874, 1),
+    (0x1d675, 4875, 1),
+    (0x1d676, 4876, 1),
+    (0x1d677, 4877, 1),
+    (0x1d678, 4878, 1),
+    (0x1d679, 4879, 1),
+    (0x1d67a, 4880, 1),
+    (0x1d67b, 4881, 1),
+    (0x1d67c, 4882, 1),
+    (0x1d67d, 4883, 1),
+    (0x1d67e, 4884, 1),
+    (0x1d67f, 4885, 1),
+    (0x1d680, 4886, 1),
+    (0x1d681, 4887, 1),
+    (0x1d682, 4888, 1),
+    (0x1d683, 4889, 1),
+    (0x1d684, 4890, 1),
+    (0x1d685, 4891, 1),
+    (0x1d686, 4892, 1),
+    (0x1d687, 4893, 1),
+    (0x1d688, 4894, 1),
+    (0x1d689, 4895, 1),
+    (0x1d68a, 4896, 1),
+    (0x1d68b, 4897, 1),
+    (0x1d68c, 4898, 1),
+    (0x1d68d, 4899, 1),
+    (0x1d68e, 4900, 1),
+    (0x1d68f, 4901, 1),
+    (0x1d690, 4902, 1),
+    (0x1d691, 4903, 1),
+    (0x1d692, 4904, 1),
+    (0x1d693, 4905, 1),
+    (0x1d694, 4906, 1),
+    (0x1d695, 4907, 1),
+    (0x1d696, 4908, 1),
+    (0x1d697, 4909, 1),
+    (0x1d698, 4910, 1),
+    (0x1d699, 4911, 1),
+    (0x1d69a, 4912, 1),
+    (0x1d69b, 4913, 1),
+    (0x1d69c, 4914, 1),
+    (0x1d69d, 4915, 1),
+    (0x1d69e, 4916, 1),
+    (0x1d69f, 4917, 1),
+    (0x1d6a0, 4918, 1),
+    (0x1d6a1, 4919, 1),
+    (0x1d6a2, 4920, 1),
+    (0x1d6a3, 4921, 1),
+    (0x1d6a4, 4922, 2),
+    (0x1d6a5, 4924, 2),
+    (0x1d6a8, 4926, 2),
+    (0x1d6a9, 4928, 2),
+    (0x1d6aa, 4930, 2),
+    (0x1d6ab, 4932, 2),
+    (0x1d6ac, 4934, 2),
+    (0x1d6ad, 4936, 2),
+    (0x1d6ae, 4938, 2),
+    (0x1d6af, 4940, 2),
+    (0x1d6b0, 4942, 2),
+    (0x1d6b1, 4944, 2),
+    (0x1d6b2, 4946, 2),
+    (0x1d6b3, 4948, 2),
+    (0x1d6b4, 4950, 2),
+    (0x1d6b5, 4952, 2),
+    (0x1d6b6, 4954, 2),
+    (0x1d6b7, 4956, 2),
+    (0x1d6b8, 4958, 2),
+    (0x1d6b9, 4960, 2),
+    (0x1d6ba, 4962, 2),
+    (0x1d6bb, 4964, 2),
+    (0x1d6bc, 4966, 2),
+    (0x1d6bd, 4968, 2),
+    (0x1d6be, 4970, 2),
+    (0x1d6bf, 4972, 2),
+    (0x1d6c0, 4974, 2),
+    (0x1d6c1, 4976, 3),
+    (0x1d6c2, 4979, 2),
+    (0x1d6c3, 4981, 2),
+    (0x1d6c4, 4983, 2),
+    (0x1d6c5, 4985, 2),
+    (0x1d6c6, 4987, 2),
+    (0x1d6c7, 4989, 2),
+    (0x1d6c8, 4991, 2),
+    (0x1d6c9, 4993, 2),
+    (0x1d6ca, 4995, 2),
+    (0x1d6cb, 4997, 2),
+    (0x1d6cc, 4999, 2),
+    (0x1d6cd, 5001, 2),
+    (0x1d6ce, 5003, 2),
+    (0x1d6cf, 5005, 2),
+    (0x1d6d0, 5007, 2),
+    (0x1d6d1, 5009, 2),
+    (0x1d6d2, 5011, 2),
+    (0x1d6d3, 5013, 2),
+    (0x1d6d4, 5015, 2),
+    (0x1d6d5, 5017, 2),
+    (0x1d6d6, 5019, 2),
+    (0x1d6d7, 5021, 2),
+    (0x1d6d8, 5023, 2),
+    (0x1d6d9, 5025, 2),
+    (0x1d6da, 5027, 2),
+    (0x1d6db, 5029, 3),
+    (0x1d6dc, 5032, 2),
+    (0x1d6dd, 5034, 2),
+    (0x1d6de, 5036, 2),
+    (0x1d6df, 5038, 2),
+    (0x1d6e0, 5040, 2),
+    (0x1d6e1, 5042, 2),
+    (0x1d6e2, 5044, 2),
+    (0x1d6e3, 5046, 2),
+    (0x1d6e4, 5048, 2),
+    (0x1d6e5, 5050, 2),
+    (0x1d6e6, 5052, 2),
+    (0x1d6e7, 5054, 2),
+    (0x1d6e8, 5056, 2),
+    (0x1d6e9, 5058, 2),
+    (0x1d6ea, 5060, 2),
+    (0x1d6eb, 5062, 2),
+    (0x1d6ec, 5064, 2),
+    (0x1d6ed, 5066, 2),
+    (0x1d6ee, 5068, 2),
+    (0x1d6ef, 5070, 2),
+    (0x1d6f0, 5072, 2),
+    (0x1d6f1, 5074, 2),
+    (0x1d6f2, 5076, 2),
+    (0x1d6f3, 5078, 2),
+    (0x1d6f4, 5080, 2),
+    (0x1d6f5, 5082, 2),
+    (0x1d6f6, 5084, 2),
+    (0x1d6f7, 5086, 2),
+    (0x1d6f8, 5088, 2),
+    (0x1d6f9, 5090, 2),
+    (0x1d6fa, 5092, 2),
+    (0x1d6fb, 5094, 3),
+    (0x1d6fc, 5097, 2),
+    (0x1d6fd, 5099, 2),
+    (0x1d6fe, 5101, 2),
+    (0x1d6ff, 5103, 2),
+    (0x1d700, 5105, 2),
+    (0x1d701, 5107, 2),
+    (0x1d702, 5109, 2),
+    (0x1d703, 5111, 2),
+    (0x1d704, 5113, 2),
+    (0x1d705, 5115, 2),
+    (0x1d706, 5117, 2),
+    (0x1d707, 5119, 2),
+    (0x1d708, 5121, 2),
+    (0x1d709, 5123, 2),
+    (0x1d70a, 5125, 2),
+    (0x1d70b, 5127, 2),
+    (0x1d70c, 5129, 2),
+    (0x1d70d, 5131, 2),
+    (0x1d70e, 5133, 2),
+    (0x1d70f, 5135, 2),
+    (0x1d710, 5137, 2),
+    (0x1d711, 5139, 2),
+    (0x1d712, 5141, 2),
+    (0x1d713, 5143, 2),
+    (0x1d714, 5145, 2),
+    (0x1d715, 5147, 3),
+    (0x1d716, 5150, 2),
+    (0x1d717, 5152, 2),
+    (0x1d718, 5154, 2),
+    (0x1d719, 5156, 2),
+    (0x1d71a, 5158, 2),
+    (0x1d71b, 5160, 2),
+    (0x1d71c, 5162, 2),
+    (0x1d71d, 5164, 2),
+    (0x1d71e, 5166, 2),
+    (0x1d71f, 5168, 2),
+    (0x1d720, 5170, 2),
+    (0x1d721, 5172, 2),
+    (0x1d722, 5174, 2),
+    (0x1d723, 5176, 2),
+    (0x1d724, 5178, 2),
+    (0x1d725, 5180, 2),
+    (0x1d726, 5182, 2),
+    (0x1d727, 5184, 2),
+    (0x1d728, 5186, 2),
+    (0x1d729, 5188, 2),
+    (0x1d72a, 5190, 2),
+    (0x1d72b, 5192, 2),
+    (0x1d72c, 5194, 2),
+    (0x1d72d, 5196, 2),
+    (0x1d72e, 5198, 2),
+    (0x1d72f, 5200, 2),
+    (0x1d730, 5202, 2),
+    (0x1d731, 5204, 2),
+    (0x1d732, 5206, 2),
+    (0x1d733, 5208, 2),
+    (0x1d734, 5210, 2),
+    (0x1d735, 5212, 3),
+    (0x1d736, 5215, 2),
+    (0x1d737, 5217, 2),
+    (0x1d738, 5219, 2),
+    (0x1d739, 5221, 2),
+    (0x1d73a, 5223, 2),
+    (0x1d73b, 5225, 2),
+    (0x1d73c, 5227, 2),
+    (0x1d73d, 5229, 2),
+    (0x1d73e, 5231, 2),
+    (0x1d73f, 5233, 2),
+    (0x1d740, 5235, 2),
+    (0x1d741, 5237, 2),
+    (0x1d742, 5239, 2),
+    (0x1d743, 5241, 2),
+    (0x1d744, 5243, 2),
+    (0x1d745, 5245, 2),
+    (0x1d746, 5247, 2),
+    (0x1d747, 5249, 2),
+    (0x1d748, 5251, 2),
+    (0x1d749, 5253, 2),
+    (0x1d74a, 5255, 2),
+    (0x1d74b, 5257, 2),
+    (0x1d74c, 5259, 2),
+    (0x1d74d, 5261, 2),
+    (0x1d74e, 5263, 2),
+    (0x1d74f, 5265, 3),
+    (0x1d750, 5268, 2),
+    (0x1d751, 5270, 2),
+    (0x1d752, 5272, 2),
+    (0x1d753, 5274, 2),
+    (0x1d754, 5276, 2),
+    (0x1d755, 5278, 2),
+    (0x1d756, 5280, 2),
+    (0x1d757, 5282, 2),
+    (0x1d758, 5284, 2),
+    (0x1d759, 5286, 2),
+    (0x1d75a, 5288, 2),
+    (0x1d75b, 5290, 2),
+    (0x1d75c, 5292, 2),
+    (0x1d75d, 5294, 2),
+    (0x1d75e, 5296, 2),
+    (0x1d75f, 5298, 2),
+    (0x1d760, 5300, 2),
+    (0x1d761, 5302, 2),
+    (0x1d762, 5304, 2),
+    (0x1d763, 5306, 2),
+    (0x1d764, 5308, 2),
+    (0x1d765, 5310, 2),
+    (0x1d766, 5312, 2),
+    (0x1d767, 5314, 2),
+    (0x1d768, 5316, 2),
+    (0x1d769, 5318, 2),
+    (0x1d76a, 5320, 2),
+    (0x1d76b, 5322, 2),
+    (0x1d76c, 5324, 2),
+    (0x1d76d, 5326, 2),
+    (0x1d76e, 5328, 2),
+    (0x1d76f, 5330, 3),
+    (0x1d770, 5333, 2),
+    (0x1d771, 5335, 2),
+    (0x1d772, 5337, 2),
+    (0x1d773, 5339, 2),
+    (0x1d774, 5341, 2),
+    (0x1d775, 5343, 2),
+    (0x1d776, 5345, 2),
+    (0x1d777, 5347, 2),
+    (0x1d778, 5349, 2),
+    (0x1d779, 5351, 2),
+    (0x1d77a, 5353, 2),
+    (0x1d77b, 5355, 2),
+    (0x1d77c, 5357, 2),
+    (0x1d77d, 5359, 2),
+    (0x1d77e, 5361, 2),
+    (0x1d77f, 5363, 2),
+    (0x1d780, 5365, 2),
+    (0x1d781, 5367, 2),
+    (0x1d782, 5369, 2),
+    (0x1d783, 5371, 2),
+    (0x1d784, 5373, 2),
+    (0x1d785, 5375, 2),
+    (0x1d786, 5377, 2),
+    (0x1d787, 5379, 2),
+    (0x1d788, 5381, 2),
+    (0x1d789, 5383, 3),
+    (0x1d78a, 5386, 2),
+    (0x1d78b, 5388, 2),
+    (0x1d78c, 5390, 2),
+    (0x1d78d, 5392, 2),
+    (0x1d78e, 5394, 2),
+    (0x1d78f, 5396, 2),
+    (0x1d790, 5398, 2),
+    (0x1d791, 5400, 2),
+    (0x1d792, 5402, 2),
+    (0x1d793, 5404, 2),
+    (0x1d794, 5406, 2),
+    (0x1d795, 5408, 2),
+    (0x1d796, 5410, 2),
+    (0x1d797, 5412, 2),
+    (0x1d798, 5414, 2),
+    (0x1d799, 5416, 2),
+    (0x1d79a, 5418, 2),
+    (0x1d79b, 5420, 2),
+    (0x1d79c, 5422, 2),
+    (0x1d79d, 5424, 2),
+    (0x1d79e, 5426, 2),
+    (0x1d79f, 5428, 2),
+    (0x1d7a0, 5430, 2),
+    (0x1d7a1, 5432, 2),
+    (0x1d7a2, 5434, 2),
+    (0x1d7a3, 5436, 2),
+    (0x1d7a4, 5438, 2),
+    (0x1d7a5, 5440, 2),
+    (0x1d7a6, 5442, 2),
+    (0x1d7a7, 5444, 2),
+    (0x1d7a8, 5446, 2),
+    (0x1d7a9, 5448, 3),
+    (0x1d7aa, 5451, 2),
+    (0x1d7ab, 5453, 2),
+    (0x1d7ac, 5455, 2),
+    (0x1d7ad, 5457, 2),
+    (0x1d7ae, 5459, 2),
+    (0x1d7af, 5461, 2),
+    (0x1d7b0, 5463, 2),
+    (0x1d7b1, 5465, 2),
+    (0x1d7b2, 5467, 2),
+    (0x1d7b3, 5469, 2),
+    (0x1d7b4, 5471, 2),
+    (0x1d7b5, 5473, 2),
+    (0x1d7b6, 5475, 2),
+    (0x1d7b7, 5477, 2),
+    (0x1d7b8, 5479, 2),
+    (0x1d7b9, 5481, 2),
+    (0x1d7ba, 5483, 2),
+    (0x1d7bb, 5485, 2),
+    (0x1d7bc, 5487, 2),
+    (0x1d7bd, 5489, 2),
+    (0x1d7be, 5491, 2),
+    (0x1d7bf, 5493, 2),
+    (0x1d7c0, 5495, 2),
+    (0x1d7c1, 5497, 2),
+    (0x1d7c2, 5499, 2),
+    (0x1d7c3, 5501, 3),
+    (0x1d7c4, 5504, 2),
+    (0x1d7c5, 5506, 2),
+    (0x1d7c6, 5508, 2),
+    (0x1d7c7, 5510, 2),
+    (0x1d7c8, 5512, 2),
+    (0x1d7c9, 5514, 2),
+    (0x1d7ca, 5516, 2),
+    (0x1d7cb, 5518, 2),
+    (0x1d7ce, 5520, 1),
+    (0x1d7cf, 5521, 1),
+    (0x1d7d0, 5522, 1),
+    (0x1d7d1, 5523, 1),
+    (0x1d7d2, 5524, 1),
+    (0x1d7d3, 5525, 1),
+    (0x1d7d4, 5526, 1),
+    (0x1d7d5, 5527, 1),
+    (0x1d7d6, 5528, 1),
+    (0x1d7d7, 5529, 1),
+    (0x1d7d8, 5530, 1),
+    (0x1d7d9, 5531, 1),
+    (0x1d7da, 5532, 1),
+    (0x1d7db, 5533, 1),
+    (0x1d7dc, 5534, 1),
+    (0x1d7dd, 5535, 1),
+    (0x1d7de, 5536, 1),
+    (0x1d7df, 5537, 1),
+    (0x1d7e0, 5538, 1),
+    (0x1d7e1, 5539, 1),
+    (0x1d7e2, 5540, 1),
+    (0x1d7e3, 5541, 1),
+    (0x1d7e4, 5542, 1),
+    (0x1d7e5, 5543, 1),
+    (0x1d7e6, 5544, 1),
+    (0x1d7e7, 5545, 1),
+    (0x1d7e8, 5546, 1),
+    (0x1d7e9, 5547, 1),
+    (0x1d7ea, 5548, 1),
+    (0x1d7eb, 5549, 1),
+    (0x1d7ec, 5550, 1),
+    (0x1d7ed, 5551, 1),
+    (0x1d7ee, 5552, 1),
+    (0x1d7ef, 5553, 1),
+    (0x1d7f0, 5554, 1),
+    (0x1d7f1, 5555, 1),
+    (0x1d7f2, 5556, 1),
+    (0x1d7f3, 5557, 1),
+    (0x1d7f4, 5558, 1),
+    (0x1d7f5, 5559, 1),
+    (0x1d7f6, 5560, 1),
+    (0x1d7f7, 5561, 1),
+    (0x1d7f8, 5562, 1),
+    (0x1d7f9, 5563, 1),
+    (0x1d7fa, 5564, 1),
+    (0x1d7fb, 5565, 1),
+    (0x1d7fc, 5566, 1),
+    (0x1d7fd, 5567, 1),
+    (0x1d7fe, 5568, 1),
+    (0x1d7ff, 5569, 1),
+    (0x1e030, 5570, 2),
+    (0x1e031, 5572, 2),
+    (0x1e032, 5574, 2),
+    (0x1e033, 5576, 2),
+    (0x1e034, 5578, 2),
+    (0x1e035, 5580, 2),
+    (0x1e036, 5582, 2),
+    (0x1e037, 5584, 2),
+    (0x1e038, 5586, 2),
+    (0x1e039, 5588, 2),
+    (0x1e03a, 5590, 2),
+    (0x1e03b, 5592, 2),
+    (0x1e03c, 5594, 2),
+    (0x1e03d, 5596, 2),
+    (0x1e03e, 5598, 2),
+    (0x1e03f, 5600, 2),
+    (0x1e040, 5602, 2),
+    (0x1e041, 5604, 2),
+    (0x1e042, 5606, 2),
+    (0x1e043, 5608, 2),
+    (0x1e044, 5610, 2),
+    (0x1e045, 5612, 2),
+    (0x1e046, 5614, 2),
+    (0x1e047, 5616, 2),
+    (0x1e048, 5618, 2),
+    (0x1e049, 5620, 2),
+    (0x1e04a, 5622, 3),
+    (0x1e04b, 5625, 2),
+    (0x1e04c, 5627, 2),
+    (0x1e04d, 5629, 2),
+    (0x1e04e, 5631, 2),
+    (0x1e04f, 5633, 2),
+    (0x1e050, 5635, 2),
+    (0x1e051, 5637, 2),
+    (0x1e052, 5639, 2),
+    (0x1e053, 5641, 2),
+    (0x1e054, 5643, 2),
+    (0x1e055, 5645, 2),
+    (0x1e056, 5647, 2),
+    (0x1e057, 5649, 2),
+    (0x1e058, 5651, 2),
+    (0x1e059, 5653, 2),
+    (0x1e05a, 5655, 2),
+    (0x1e05b, 5657, 2),
+    (0x1e05c, 5659, 2),
+    (0x1e05d, 5661, 2),
+    (0x1e05e, 5663, 2),
+    (0x1e05f, 5665, 2),
+    (0x1e060, 5667, 2),
+    (0x1e061, 5669, 2),
+    (0x1e062, 5671, 2),
+    (0x1e063, 5673, 2),
+    (0x1e064, 5675, 2),
+    (0x1e065, 5677, 2),
+    (0x1e066, 5679, 2),
+    (0x1e067, 5681, 2),
+    (0x1e068, 5683, 2),
+    (0x1e069, 5685, 2),
+    (0x1e06a, 5687, 2),
+    (0x1e06b, 5689, 2),
+    (0x1e06c, 5691, 3),
+    (0x1e06d, 5694, 2),
+    (0x1ee00, 5696, 2),
+    (0x1ee01, 5698, 2),
+    (0x1ee02, 5700, 2),
+    (0x1ee03, 5702, 2),
+    (0x1ee05, 5704, 2),
+    (0x1ee06, 5706, 2),
+    (0x1ee07, 5708, 2),
+    (0x1ee08, 5710, 2),
+    (0x1ee09, 5712, 2),
+    (0x1ee0a, 5714, 2),
+    (0x1ee0b, 5716, 2),
+    (0x1ee0c, 5718, 2),
+    (0x1ee0d, 5720, 2),
+    (0x1ee0e, 5722, 2),
+    (0x1ee0f, 5724, 2),
+    (0x1ee10, 5726, 2),
+    (0x1ee11, 5728, 2),
+    (0x1ee12, 5730, 2),
+    (0x1ee13, 5732, 2),
+    (0x1ee14, 5734, 2),
+    (0x1ee15, 5736, 2),
+    (0x1ee16, 5738, 2),
+    (0x1ee17, 5740, 2),
+    (0x1ee18, 5742, 2),
+    (0x1ee19, 5744, 2),
+    (0x1ee1a, 5746, 2),
+    (0x1ee1b, 5748, 2),
+    (0x1ee1c, 5750, 2),
+    (0x1ee1d, 5752, 2),
+    (0x1ee1e, 5754, 2),
+    (0x1ee1f, 5756, 2),
+    (0x1ee21, 5758, 2),
+    (0x1ee22, 5760, 2),
+    (0x1ee24, 5762, 2),
+    (0x1ee27, 5764, 2),
+    (0x1ee29, 5766, 2),
+    (0x1ee2a, 5768, 2),
+    (0x1ee2b, 5770, 2),
+    (0x1ee2c, 5772, 2),
+    (0x1ee2d, 5774, 2),
+    (0x1ee2e, 5776, 2),
+    (0x1ee2f, 5778, 2),
+    (0x1ee30, 5780, 2),
+    (0x1ee31, 5782, 2),
+    (0x1ee32, 5784, 2),
+    (0x1ee34, 5786, 2),
+    (0x1ee35, 5788, 2),
+    (0x1ee36, 5790, 2),
+    (0x1ee37, 5792, 2),
+    (0x1ee39, 5794, 2),
+    (0x1ee3b, 5796, 2),
+    (0x1ee42, 5798, 2),
+    (0x1ee47, 5800, 2),
+    (0x1ee49, 5802, 2),
+    (0x1ee4b, 5804, 2),
+    (0x1ee4d, 5806, 2),
+    (0x1ee4e, 5808, 2),
+    (0x1ee4f, 5810, 2),
+    (0x1ee51, 5812, 2),
+    (0x1ee52, 5814, 2),
+    (0x1ee54, 5816, 2),
+    (0x1ee57, 5818, 2),
+    (0x1ee59, 5820, 2),
+    (0x1ee5b, 5822, 2),
+    (0x1ee5d, 5824, 2),
+    (0x1ee5f, 5826, 2),
+    (0x1ee61, 5828, 2),
+    (0x1ee62, 5830, 2),
+    (0x1ee64, 5832, 2),
+    (0x1ee67, 5834, 2),
+    (0x1ee68, 5836, 2),
+    (0x1ee69, 5838, 2),
+    (0x1ee6a, 5840, 2),
+    (0x1ee6c, 5842, 2),
+    (0x1ee6d, 5844, 2),
+    (0x1ee6e, 5846, 2),
+    (0x1ee6f, 5848, 2),
+    (0x1ee70, 5850, 2),
+    (0x1ee71, 5852, 2),
+    (0x1ee72, 5854, 2),
+    (0x1ee74, 5856, 2),
+    (0x1ee75, 5858, 2),
+    (0x1ee76, 5860, 2),
+    (0x1ee77, 5862, 2),
+    (0x1ee79, 5864, 2),
+    (0x1ee7a, 5866, 2),
+    (0x1ee7b, 5868, 2),
+    (0x1ee7c, 5870, 2),
+    (0x1ee7e, 5872, 2),
+    (0x1ee80, 5874, 2),
+    (0x1ee81, 5876, 2),
+    (0x1ee82, 5878, 2),
+    (0x1ee83, 5880, 2),
+    (0x1ee84, 5882, 2),
+    (0x1ee85, 5884, 2),
+    (0x1ee86, 5886, 2),
+    (0x1ee87, 5888, 2),
+    (0x1ee88, 5890, 2),
+    (0x1ee89, 5892, 2),
+    (0x1ee8b, 5894, 2),
+    (0x1ee8c, 5896, 2),
+    (0x1ee8d, 5898, 2),
+    (0x1ee8e, 5900, 2),
+    (0x1ee8f, 5902, 2),
+    (0x1ee90, 5904, 2),
+    (0x1ee91, 5906, 2),
+    (0x1ee92, 5908, 2),
+    (0x1ee93, 5910, 2),
+    (0x1ee94, 5912, 2),
+    (0x1ee95, 5914, 2),
+    (0x1ee96, 5916, 2),
+    (0x1ee97, 5918, 2),
+    (0x1ee98, 5920, 2),
+    (0x1ee99, 5922, 2),
+    (0x1ee9a, 5924, 2),
+    (0x1ee9b, 5926, 2),
+    (0x1eea1, 5928, 2),
+    (0x1eea2, 5930, 2),
+    (0x1eea3, 5932, 2),
+    (0x1eea5, 5934, 2),
+    (0x1eea6, 5936, 2),
+    (0x1eea7, 5938, 2),
+    (0x1eea8, 5940, 2),
+    (0x1eea9, 5942, 2),
+    (0x1eeab, 5944, 2),
+    (0x1eeac, 5946, 2),
+    (0x1eead, 5948, 2),
+    (0x1eeae, 5950, 2),
+    (0x1eeaf, 5952, 2),
+    (0x1eeb0, 5954, 2),
+    (0x1eeb1, 5956, 2),
+    (0x1eeb2, 5958, 2),
+    (0x1eeb3, 5960, 2),
+    (0x1eeb4, 5962, 2),
+    (0x1eeb5, 5964, 2),
+    (0x1eeb6, 5966, 2),
+    (0x1eeb7, 5968, 2),
+    (0x1eeb8, 5970, 2),
+    (0x1eeb9, 5972, 2),
+    (0x1eeba, 5974, 2),
+    (0x1eebb, 5976, 2),
+    (0x1fbf0, 5978, 1),
+    (0x1fbf1, 5979, 1),
+    (0x1fbf2, 5980, 1),
+    (0x1fbf3, 5981, 1),
+    (0x1fbf4, 5982, 1),
+    (0x1fbf5, 5983, 1),
+    (0x1fbf6, 5984, 1),
+    (0x1fbf7, 5985, 1),
+    (0x1fbf8, 5986, 1),
+    (0x1fbf9, 5987, 1),
+];
+#[cfg(feature = "nfkd-other")]
+#[rustfmt::skip]
+pub(super) const OTHER_DECOMPOSED: &str = "\u{565}\u{582}\u{627}\u{653}\u{627}\u{654}\u{648}\u{654}\u{627}\u{655}\u{64a}\u{654}\u{627}\u{674}\u{648}\u{674}\u{6c7}\u{674}\u{64a}\u{674}\u{6d5}\u{654}\u{6c1}\u{654}\u{6d2}\u{654}\u{928}\u{93c}\u{930}\u{93c}\u{933}\u{93c}\u{915}\u{93c}\u{916}\u{93c}\u{917}\u{93c}\u{91c}\u{93c}\u{921}\u{93c}\u{922}\u{93c}\u{92b}\u{93c}\u{92f}\u{93c}\u{9c7}\u{9be}\u{9c7}\u{9d7}\u{9a1}\u{9bc}\u{9a2}\u{9bc}\u{9af}\u{9bc}\u{a32}\u{a3c}\u{a38}\u{a3c}\u{a16}\u{a3c}\u{a17}\u{a3c}\u{a1c}\u{a3c}\u{a2b}\u{a3c}\u{b47}\u{b56}\u{b47}\u{b3e}\u{b47}\u{b57}\u{b21}\u{b3c}\u{b22}\u{b3c}\u{b92}\u{bd7}\u{bc6}\u{bbe}\u{bc7}\u{bbe}\u{bc6}\u{bd7}\u{c46}\u{c56}\u{cbf}\u{cd5}\u{cc6}\u{cd5}\u{cc6}\u{cd6}\u{cc6}\u{cc2}\u{cc6}\u{cc2}\u{cd5}\u{d46}\u{d3e}\u{d47}\u{d3e}\u{d46}\u{d57}\u{dd9}\u{dca}\u{dd9}\u{dcf}\u{dd9}\u{dcf}\u{dca}\u{dd9}\u{ddf}\u{e4d}\u{e32}\u{ecd}\u{eb2}\u{eab}\u{e99}\u{eab}\u{ea1}\u{f0b}\u{f42}\u{fb7}\u{f4c}\u{fb7}\u{f51}\u{fb7}\u{f56}\u{fb7}\u{f5b}\u{fb7}\u{f40}\u{fb5}\u{f71}\u{f72}\u{f71}\u{f74}\u{fb2}\u{f80}\u{fb2}\u{f71}\u{f80}\u{fb3}\u{f80}\u{fb3}\u{f71}\u{f80}\u{f71}\u{f80}\u{f92}\u{fb7}\u{f9c}\u{fb7}\u{fa1}\u{fb7}\u{fa6}\u{fb7}\u{fab}\u{fb7}\u{f90}\u{fb5}\u{1025}\u{102e}\u{10dc}\u{1b05}\u{1b35}\u{1b07}\u{1b35}\u{1b09}\u{1b35}\u{1b0b}\u{1b35}\u{1b0d}\u{1b35}\u{1b11}\u{1b35}\u{1b3a}\u{1b35}\u{1b3c}\u{1b35}\u{1b3e}\u{1b35}\u{1b3f}\u{1b35}\u{1b42}\u{1b35}jV\u{2d61}\u{44a}\u{44c}\u{a76f}SCFQ\u{126}\u{153}\u{a727}\u{ab37}\u{26b}\u{ab52}\u{28d}\u{574}\u{576}\u{574}\u{565}\u{574}\u{56b}\u{57e}\u{576}\u{574}\u{56d}\u{5d9}\u{5b4}\u{5f2}\u{5b7}\u{5e2}\u{5d0}\u{5d3}\u{5d4}\u{5db}\u{5dc}\u{5dd}\u{5e8}\u{5ea}+\u{5e9}\u{5c1}\u{5e9}\u{5c2}\u{5e9}\u{5bc}\u{5c1}\u{5e9}\u{5bc}\u{5c2}\u{5d0}\u{5b7}\u{5d0}\u{5b8}\u{5d0}\u{5bc}\u{5d1}\u{5bc}\u{5d2}\u{5bc}\u{5d3}\u{5bc}\u{5d4}\u{5bc}\u{5d5}\u{5bc}\u{5d6}\u{5bc}\u{5d8}\u{5bc}\u{5d9}\u{5bc}\u{5da}\u{5bc}\u{5db}\u{5bc}\u{5dc}\u{5bc}\u{5de}\u{5bc}\u{5e0}\u{5bc}\u{5e1}\u{5bc}\u{5e3}\u{5bc}\u{5e4}\u{5bc}\u{5e6}\u{5bc}\u{5e7}\u{5bc}\u{5e8}\u{5bc}\u{5e9}\u{5bc}\u{5ea}\u{5bc}\u{5d5}\u{5b9}\u{5d1}\u{5bf}\u{5db}\u{5bf}\u{5e4}\u{5bf}\u{5d0}\u{5dc}\u{671}\u{671}\u{67b}\u{67b}\u{67b}\u{67b}\u{67e}\u{67e}\u{67e}\u{67e}\u{680}\u{680}\u{680}\u{680}\u{67a}\u{67a}\u{67a}\u{67a}\u{67f}\u{67f}\u{67f}\u{67f}\u{679}\u{679}\u{679}\u{679}\u{6a4}\u{6a4}\u{6a4}\u{6a4}\u{6a6}\u{6a6}\u{6a6}\u{6a6}\u{684}\u{684}\u{684}\u{684}\u{683}\u{683}\u{683}\u{683}\u{686}\u{686}\u{686}\u{686}\u{687}\u{687}\u{687}\u{687}\u{68d}\u{68d}\u{68c}\u{68c}\u{68e}\u{68e}\u{688}\u{688}\u{698}\u{698}\u{691}\u{691}\u{6a9}\u{6a9}\u{6a9}\u{6a9}\u{6af}\u{6af}\u{6af}\u{6af}\u{6b3}\u{6b3}\u{6b3}\u{6b3}\u{6b1}\u{6b1}\u{6b1}\u{6b1}\u{6ba}\u{6ba}\u{6bb}\u{6bb}\u{6bb}\u{6bb}\u{6d5}\u{654}\u{6d5}\u{654}\u{6c1}\u{6c1}\u{6c1}\u{6c1}\u{6be}\u{6be}\u{6be}\u{6be}\u{6d2}\u{6d2}\u{6d2}\u{654}\u{6d2}\u{654}\u{6ad}\u{6ad}\u{6ad}\u{6ad}\u{6c7}\u{6c7}\u{6c6}\u{6c6}\u{6c8}\u{6c8}\u{6c7}\u{674}\u{6cb}\u{6cb}\u{6c5}\u{6c5}\u{6c9}\u{6c9}\u{6d0}\u{6d0}\u{6d0}\u{6d0}\u{649}\u{649}\u{64a}\u{654}\u{627}\u{64a}\u{654}\u{627}\u{64a}\u{654}\u{6d5}\u{64a}\u{654}\u{6d5}\u{64a}\u{654}\u{648}\u{64a}\u{654}\u{648}\u{64a}\u{654}\u{6c7}\u{64a}\u{654}\u{6c7}\u{64a}\u{654}\u{6c6}\u{64a}\u{654}\u{6c6}\u{64a}\u{654}\u{6c8}\u{64a}\u{654}\u{6c8}\u{64a}\u{654}\u{6d0}\u{64a}\u{654}\u{6d0}\u{64a}\u{654}\u{6d0}\u{64a}\u{654}\u{649}\u{64a}\u{654}\u{649}\u{64a}\u{654}\u{649}\u{6cc}\u{6cc}\u{6cc}\u{6cc}\u{64a}\u{654}\u{62c}\u{64a}\u{654}\u{62d}\u{64a}\u{654}\u{645}\u{64a}\u{654}\u{649}\u{64a}\u{654}\u{64a}\u{628}\u{62c}\u{628}\u{62d}\u{628}\u{62e}\u{628}\u{645}\u{628}\u{649}\u{628}\u{64a}\u{62a}\u{62c}\u{62a}\u{62d}\u{62a}\u{62e}\u{62a}\u{645}\u{62a}\u{649}\u{62a}\u{64a}\u{62b}\u{62c}\u{62b}\u{645}\u{62b}\u{649}\u{62b}\u{64a}\u{62c}\u{62d}\u{62c}\u{645}\u{62d}\u{62c}\u{62d}\u{645}\u{62e}\u{62c}\u{62e}\u{62d}\u{62e}\u{645}\u{633}\u{62c}\u{633}\u{62d}\u{633}\u{62e}\u{633}\u{645}\u{635}\u{62d}\u{635}\u{645}\u{636}\u{62c}\u{636}\u{62d}\u{636}\u{62e}\u{636}\u{645}\u{637}\u{62d}\u{637}\u{645}\u{638}\u{645}\u{639}\u{62c}\u{639}\u{645}\u{63a}\u{62c}\u{63a}\u{645}\u{641}\u{62c}\u{641}\u{62d}\u{641}\u{62e}\u{641}\u{645}\u{641}\u{649}\u{641}\u{64a}\u{642}\u{62d}\u{642}\u{645}\u{642}\u{649}\u{642}\u{64a}\u{643}\u{627}\u{643}\u{62c}\u{643}\u{62d}\u{643}\u{62e}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\u{64a}\u{644}\u{62c}\u{644}\u{62d}\u{644}\u{62e}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64a}\u{645}\u{62c}\u{645}\u{62d}\u{645}\u{62e}\u{645}\u{645}\u{645}\u{649}\u{645}\u{64a}\u{646}\u{62c}\u{646}\u{62d}\u{646}\u{62e}\u{646}\u{645}\u{646}\u{649}\u{646}\u{64a}\u{647}\u{62c}\u{647}\u{645}\u{647}\u{649}\u{647}\u{64a}\u{64a}\u{62c}\u{64a}\u{62d}\u{64a}\u{62e}\u{64a}\u{645}\u{64a}\u{649}\u{64a}\u{64a}\u{630}\u{670}\u{631}\u{670}\u{649}\u{670} \u{64c}\u{651} \u{64d}\u{651} \u{64e}\u{651} \u{64f}\u{651} \u{650}\u{651} \u{651}\u{670}\u{64a}\u{654}\u{631}\u{64a}\u{654}\u{632}\u{64a}\u{654}\u{645}\u{64a}\u{654}\u{646}\u{64a}\u{654}\u{649}\u{64a}\u{654}\u{64a}\u{628}\u{631}\u{628}\u{632}\u{628}\u{645}\u{628}\u{646}\u{628}\u{649}\u{628}\u{64a}\u{62a}\u{631}\u{62a}\u{632}\u{62a}\u{645}\u{62a}\u{646}\u{62a}\u{649}\u{62a}\u{64a}\u{62b}\u{631}\u{62b}\u{632}\u{62b}\u{645}\u{62b}\u{646}\u{62b}\u{649}\u{62b}\u{64a}\u{641}\u{649}\u{641}\u{64a}\u{642}\u{649}\u{642}\u{64a}\u{643}\u{627}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\u{64a}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64a}\u{645}\u{627}\u{645}\u{645}\u{646}\u{631}\u{646}\u{632}\u{646}\u{645}\u{646}\u{646}\u{646}\u{649}\u{646}\u{64a}\u{649}\u{670}\u{64a}\u{631}\u{64a}\u{632}\u{64a}\u{645}\u{64a}\u{646}\u{64a}\u{649}\u{64a}\u{64a}\u{64a}\u{654}\u{62c}\u{64a}\u{654}\u{62d}\u{64a}\u{654}\u{62e}\u{64a}\u{654}\u{645}\u{64a}\u{654}\u{647}\u{628}\u{62c}\u{628}\u{62d}\u{628}\u{62e}\u{628}\u{645}\u{628}\u{647}\u{62a}\u{62c}\u{62a}\u{62d}\u{62a}\u{62e}\u{62a}\u{645}\u{62a}\u{647}\u{62b}\u{645}\u{62c}\u{62d}\u{62c}\u{645}\u{62d}\u{62c}\u{62d}\u{645}\u{62e}\u{62c}\u{62e}\u{645}\u{633}\u{62c}\u{633}\u{62d}\u{633}\u{62e}\u{633}\u{645}\u{635}\u{62d}\u{635}\u{62e}\u{635}\u{645}\u{636}\u{62c}\u{636}\u{62d}\u{636}\u{62e}\u{636}\u{645}\u{637}\u{62d}\u{638}\u{645}\u{639}\u{62c}\u{639}\u{645}\u{63a}\u{62c}\u{63a}\u{645}\u{641}\u{62c}\u{641}\u{62d}\u{641}\u{62e}\u{641}\u{645}\u{642}\u{62d}\u{642}\u{645}\u{643}\u{62c}\u{643}\u{62d}\u{643}\u{62e}\u{643}\u{644}\u{643}\u{645}\u{644}\u{62c}\u{644}\u{62d}\u{644}\u{62e}\u{644}\u{645}\u{644}\u{647}\u{645}\u{62c}\u{645}\u{62d}\u{645}\u{62e}\u{645}\u{645}\u{646}\u{62c}\u{646}\u{62d}\u{646}\u{62e}\u{646}\u{645}\u{646}\u{647}\u{647}\u{62c}\u{647}\u{645}\u{647}\u{670}\u{64a}\u{62c}\u{64a}\u{62d}\u{64a}\u{62e}\u{64a}\u{645}\u{64a}\u{647}\u{64a}\u{654}\u{645}\u{64a}\u{654}\u{647}\u{628}\u{645}\u{628}\u{647}\u{62a}\u{645}\u{62a}\u{647}\u{62b}\u{645}\u{62b}\u{647}\u{633}\u{645}\u{633}\u{647}\u{634}\u{645}\u{634}\u{647}\u{643}\u{644}\u{643}\u{645}\u{644}\u{645}\u{646}\u{645}\u{646}\u{647}\u{64a}\u{645}\u{64a}\u{647}\u{640}\u{64e}\u{651}\u{640}\u{64f}\u{651}\u{640}\u{650}\u{651}\u{637}\u{649}\u{637}\u{64a}\u{639}\u{649}\u{639}\u{64a}\u{63a}\u{649}\u{63a}\u{64a}\u{633}\u{649}\u{633}\u{64a}\u{634}\u{649}\u{634}\u{64a}\u{62d}\u{649}\u{62d}\u{64a}\u{62c}\u{649}\u{62c}\u{64a}\u{62e}\u{649}\u{62e}\u{64a}\u{635}\u{649}\u{635}\u{64a}\u{636}\u{649}\u{636}\u{64a}\u{634}\u{62c}\u{634}\u{62d}\u{634}\u{62e}\u{634}\u{645}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{637}\u{649}\u{637}\u{64a}\u{639}\u{649}\u{639}\u{64a}\u{63a}\u{649}\u{63a}\u{64a}\u{633}\u{649}\u{633}\u{64a}\u{634}\u{649}\u{634}\u{64a}\u{62d}\u{649}\u{62d}\u{64a}\u{62c}\u{649}\u{62c}\u{64a}\u{62e}\u{649}\u{62e}\u{64a}\u{635}\u{649}\u{635}\u{64a}\u{636}\u{649}\u{636}\u{64a}\u{634}\u{62c}\u{634}\u{62d}\u{634}\u{62e}\u{634}\u{645}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{634}\u{62c}\u{634}\u{62d}\u{634}\u{62e}\u{634}\u{645}\u{633}\u{647}\u{634}\u{647}\u{637}\u{645}\u{633}\u{62c}\u{633}\u{62d}\u{633}\u{62e}\u{634}\u{62c}\u{634}\u{62d}\u{634}\u{62e}\u{637}\u{645}\u{638}\u{645}\u{627}\u{64b}\u{627}\u{64b}\u{62a}\u{62c}\u{645}\u{62a}\u{62d}\u{62c}\u{62a}\u{62d}\u{62c}\u{62a}\u{62d}\u{645}\u{62a}\u{62e}\u{645}\u{62a}\u{645}\u{62c}\u{62a}\u{645}\u{62d}\u{62a}\u{645}\u{62e}\u{62c}\u{645}\u{62d}\u{62c}\u{645}\u{62d}\u{62d}\u{645}\u{64a}\u{62d}\u{645}\u{649}\u{633}\u{62d}\u{62c}\u{633}\u{62c}\u{62d}\u{633}\u{62c}\u{649}\u{633}\u{645}\u{62d}\u{633}\u{645}\u{62d}\u{633}\u{645}\u{62c}\u{633}\u{645}\u{645}\u{633}\u{645}\u{645}\u{635}\u{62d}\u{62d}\u{635}\u{62d}\u{62d}\u{635}\u{645}\u{645}\u{634}\u{62d}\u{645}\u{634}\u{62d}\u{645}\u{634}\u{62c}\u{64a}\u{634}\u{645}\u{62e}\u{634}\u{645}\u{62e}\u{634}\u{645}\u{645}\u{634}\u{645}\u{645}\u{636}\u{62d}\u{649}\u{636}\u{62e}\u{645}\u{636}\u{62e}\u{645}\u{637}\u{645}\u{62d}\u{637}\u{645}\u{62d}\u{637}\u{645}\u{645}\u{637}\u{645}\u{64a}\u{639}\u{62c}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{649}\u{63a}\u{645}\u{645}\u{63a}\u{645}\u{64a}\u{63a}\u{645}\u{649}\u{641}\u{62e}\u{645}\u{641}\u{62e}\u{645}\u{642}\u{645}\u{62d}\u{642}\u{645}\u{645}\u{644}\u{62d}\u{645}\u{644}\u{62d}\u{64a}\u{644}\u{62d}\u{649}\u{644}\u{62c}\u{62c}\u{644}\u{62c}\u{62c}\u{644}\u{62e}\u{645}\u{644}\u{62e}\u{645}\u{644}\u{645}\u{62d}\u{644}\u{645}\u{62d}\u{645}\u{62d}\u{62c}\u{645}\u{62d}\u{645}\u{645}\u{62d}\u{64a}\u{645}\u{62c}\u{62d}\u{645}\u{62c}\u{645}\u{645}\u{62e}\u{62c}\u{645}\u{62e}\u{645}\u{645}\u{62c}\u{62e}\u{647}\u{645}\u{62c}\u{647}\u{645}\u{645}\u{646}\u{62d}\u{645}\u{646}\u{62d}\u{649}\u{646}\u{62c}\u{645}\u{646}\u{62c}\u{645}\u{646}\u{62c}\u{649}\u{646}\u{645}\u{64a}\u{646}\u{645}\u{649}\u{64a}\u{645}\u{645}\u{64a}\u{645}\u{645}\u{628}\u{62e}\u{64a}\u{62a}\u{62c}\u{64a}\u{62a}\u{62c}\u{649}\u{62a}\u{62e}\u{64a}\u{62a}\u{62e}\u{649}\u{62a}\u{645}\u{64a}\u{62a}\u{645}\u{649}\u{62c}\u{645}\u{64a}\u{62c}\u{62d}\u{649}\u{62c}\u{645}\u{649}\u{633}\u{62e}\u{649}\u{635}\u{62d}\u{64a}\u{634}\u{62d}\u{64a}\u{636}\u{62d}\u{64a}\u{644}\u{62c}\u{64a}\u{644}\u{645}\u{64a}\u{64a}\u{62d}\u{64a}\u{64a}\u{62c}\u{64a}\u{64a}\u{645}\u{64a}\u{645}\u{645}\u{64a}\u{642}\u{645}\u{64a}\u{646}\u{62d}\u{64a}\u{642}\u{645}\u{62d}\u{644}\u{62d}\u{645}\u{639}\u{645}\u{64a}\u{643}\u{645}\u{64a}\u{646}\u{62c}\u{62d}\u{645}\u{62e}\u{64a}\u{644}\u{62c}\u{645}\u{643}\u{645}\u{645}\u{644}\u{62c}\u{645}\u{646}\u{62c}\u{62d}\u{62c}\u{62d}\u{64a}\u{62d}\u{62c}\u{64a}\u{645}\u{62c}\u{64a}\u{641}\u{645}\u{64a}\u{628}\u{62d}\u{64a}\u{643}\u{645}\u{645}\u{639}\u{62c}\u{645}\u{635}\u{645}\u{645}\u{633}\u{62e}\u{64a}\u{646}\u{62c}\u{64a}\u{635}\u{644}\u{6d2}\u{642}\u{644}\u{6d2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\u{645}\u{62d}\u{645}\u{62f}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\u{639}\u{644}\u{64a}\u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}\u{62c}\u{644} \u{62c}\u{644}\u{627}\u{644}\u{647}\u{631}\u{6cc}\u{627}\u{644},\u{3001}\u{3002}:;!?\u{3016}\u{3017}...,\u{3001}.;:?!\u{2014}(){}\u{3014}\u{3015}#&*+-<>=\u{5c}$%@ \u{64b}\u{640}\u{64b} \u{64c} \u{64d} \u{64e}\u{640}\u{64e} \u{64f}\u{640}\u{64f} \u{650}\u{640}\u{650} \u{651}\u{640}\u{651} \u{652}\u{640}\u{652}\u{621}\u{627}\u{653}\u{627}\u{653}\u{627}\u{654}\u{627}\u{654}\u{648}\u{654}\u{648}\u{654}\u{627}\u{655}\u{627}\u{655}\u{64a}\u{654}\u{64a}\u{654}\u{64a}\u{654}\u{64a}\u{654}\u{627}\u{627}\u{628}\u{628}\u{628}\u{628}\u{629}\u{629}\u{62a}\u{62a}\u{62a}\u{62a}\u{62b}\u{62b}\u{62b}\u{62b}\u{62c}\u{62c}\u{62c}\u{62c}\u{62d}\u{62d}\u{62d}\u{62d}\u{62e}\u{62e}\u{62e}\u{62e}\u{62f}\u{62f}\u{630}\u{630}\u{631}\u{631}\u{632}\u{632}\u{633}\u{633}\u{633}\u{633}\u{634}\u{634}\u{634}\u{634}\u{635}\u{635}\u{635}\u{635}\u{636}\u{636}\u{636}\u{636}\u{637}\u{637}\u{637}\u{637}\u{638}\u{638}\u{638}\u{638}\u{639}\u{639}\u{639}\u{639}\u{63a}\u{63a}\u{63a}\u{63a}\u{641}\u{641}\u{641}\u{641}\u{642}\u{642}\u{642}\u{642}\u{643}\u{643}\u{643}\u{643}\u{644}\u{644}\u{644}\u{644}\u{645}\u{645}\u{645}\u{645}\u{646}\u{646}\u{646}\u{646}\u{647}\u{647}\u{647}\u{647}\u{648}\u{648}\u{649}\u{649}\u{64a}\u{64a}\u{64a}\u{64a}\u{644}\u{627}\u{653}\u{644}\u{627}\u{653}\u{644}\u{627}\u{654}\u{644}\u{627}\u{654}\u{644}\u{627}\u{655}\u{644}\u{627}\u{655}\u{644}\u{627}\u{644}\u{627}\u{105d2}\u{307}\u{105da}\u{307}\u{2d0}\u{2d1}\u{e6}\u{299}\u{253}\u{2a3}\u{ab66}\u{2a5}\u{2a4}\u{256}\u{257}\u{1d91}\u{258}\u{25e}\u{2a9}\u{264}\u{262}\u{260}\u{29b}\u{127}\u{29c}\u{267}\u{284}\u{2aa}\u{2ab}\u{26c}\u{1df04}\u{a78e}\u{26e}\u{1df05}\u{28e}\u{1df06}\u{f8}\u{276}\u{277}q\u{27a}\u{1df08}\u{27d}\u{27e}\u{280}\u{2a8}\u{2a6}\u{ab67}\u{2a7}\u{288}\u{2c71}\u{28f}\u{2a1}\u{2a2}\u{298}\u{1c0}\u{1c1}\u{1c2}\u{1df0a}\u{1df1e}\u{11099}\u{110ba}\u{1109b}\u{110ba}\u{110a5}\u{110ba}\u{11131}\u{11127}\u{11132}\u{11127}\u{11347}\u{1133e}\u{11347}\u{11357}\u{11382}\u{113c9}\u{11384}\u{113bb}\u{1138b}\u{113c2}\u{11390}\u{113c9}\u{113c2}\u{113c2}\u{113c2}\u{113b8}\u{113c2}\u{113c9}\u{114b9}\u{114ba}\u{114b9}\u{114b0}\u{114b9}\u{114bd}\u{115b8}\u{115af}\u{115b9}\u{115af}\u{11935}\u{11930}\u{1611e}\u{1611e}\u{1611e}\u{16129}\u{1611e}\u{1611f}\u{16129}\u{1611f}\u{1611e}\u{16120}\u{1611e}\u{1611e}\u{1611f}\u{1611e}\u{16129}\u{1611f}\u{1611e}\u{1611e}\u{16120}\u{16d67}\u{16d67}\u{16d63}\u{16d67}\u{16d63}\u{16d67}\u{16d67}ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789\u{1d157}\u{1d165}\u{1d158}\u{1d165}\u{1d158}\u{1d165}\u{1d16e}\u{1d158}\u{1d165}\u{1d16f}\u{1d158}\u{1d165}\u{1d170}\u{1d158}\u{1d165}\u{1d171}\u{1d158}\u{1d165}\u{1d172}\u{1d1b9}\u{1d165}\u{1d1ba}\u{1d165}\u{1d1b9}\u{1d165}\u{1d16e}\u{1d1ba}\u{1d165}\u{1d16e}\u{1d1b9}\u{1d165}\u{1d16f}\u{1d1ba}\u{1d165}\u{1d16f}ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefgijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzACDGJKNOPQSTUVWXYZabcdfhijklmnpqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABDEFGJKLMNOPQSTUVWXYabcdefghijklmnopqrstuvwxyzABDEFGIJKLMOSTUVWXYabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz\u{131}\u{237}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39a}\u{39b}\u{39c}\u{39d}\u{39e}\u{39f}\u{3a0}\u{3a1}\u{398}\u{3a3}\u{3a4}\u{3a5}\u{3a6}\u{3a7}\u{3a8}\u{3a9}\u{2207}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3b9}\u{3ba}\u{3bb}\u{3bc}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c2}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{2202}\u{3b5}\u{3b8}\u{3ba}\u{3c6}\u{3c1}\u{3c0}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39a}\u{39b}\u{39c}\u{39d}\u{39e}\u{39f}\u{3a0}\u{3a1}\u{398}\u{3a3}\u{3a4}\u{3a5}\u{3a6}\u{3a7}\u{3a8}\u{3a9}\u{2207}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3b9}\u{3ba}\u{3bb}\u{3bc}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c2}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{2202}\u{3b5}\u{3b8}\u{3ba}\u{3c6}\u{3c1}\u{3c0}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39a}\u{39b}\u{39c}\u{39d}\u{39e}\u{39f}\u{3a0}\u{3a1}\u{398}\u{3a3}\u{3a4}\u{3a5}\u{3a6}\u{3a7}\u{3a8}\u{3a9}\u{2207}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3b9}\u{3ba}\u{3bb}\u{3bc}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c2}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{2202}\u{3b5}\u{3b8}\u{3ba}\u{3c6}\u{3c1}\u{3c0}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39a}\u{39b}\u{39c}\u{39d}\u{39e}\u{39f}\u{3a0}\u{3a1}\u{398}\u{3a3}\u{3a4}\u{3a5}\u{3a6}\u{3a7}\u{3a8}\u{3a9}\u{2207}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3b9}\u{3ba}\u{3bb}\u{3bc}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c2}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{2202}\u{3b5}\u{3b8}\u{3ba}\u{3c6}\u{3c1}\u{3c0}\u{391}\u{392}\u{393}\u{394}\u{395}\u{396}\u{397}\u{398}\u{399}\u{39a}\u{39b}\u{39c}\u{39d}\u{39e}\u{39f}\u{3a0}\u{3a1}\u{398}\u{3a3}\u{3a4}\u{3a5}\u{3a6}\u{3a7}\u{3a8}\u{3a9}\u{2207}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3b9}\u{3ba}\u{3bb}\u{3bc}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c2}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{2202}\u{3b5}\u{3b8}\u{3ba}\u{3c6}\u{3c1}\u{3c0}\u{3dc}\u{3dd}01234567890123456789012345678901234567890123456789\u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{43a}\u{43b}\u{43c}\u{43e}\u{43f}\u{440}\u{441}\u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{44b}\u{44d}\u{44e}\u{a689}\u{4d9}\u{456}\u{458}\u{4e9}\u{4af}\u{4cf}\u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{43a}\u{43b}\u{43e}\u{43f}\u{441}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{44a}\u{44b}\u{491}\u{456}\u{455}\u{45f}\u{4ab}\u{a651}\u{4b1}\u{627}\u{628}\u{62c}\u{62f}\u{648}\u{632}\u{62d}\u{637}\u{64a}\u{643}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62a}\u{62b}\u{62e}\u{630}\u{636}\u{638}\u{63a}\u{66e}\u{6ba}\u{6a1}\u{66f}\u{628}\u{62c}\u{647}\u{62d}\u{64a}\u{643}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62a}\u{62b}\u{62e}\u{636}\u{63a}\u{62c}\u{62d}\u{64a}\u{644}\u{646}\u{633}\u{639}\u{635}\u{642}\u{634}\u{62e}\u{636}\u{63a}\u{6ba}\u{66f}\u{628}\u{62c}\u{647}\u{62d}\u{637}\u{64a}\u{643}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{634}\u{62a}\u{62b}\u{62e}\u{636}\u{638}\u{63a}\u{66e}\u{6a1}\u{627}\u{628}\u{62c}\u{62f}\u{647}\u{648}\u{632}\u{62d}\u{637}\u{64a}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62a}\u{62b}\u{62e}\u{630}\u{636}\u{638}\u{63a}\u{628}\u{62c}\u{62f}\u{648}\u{632}\u{62d}\u{637}\u{64a}\u{644}\u{645}\u{646}\u{633}\u{639}\u{641}\u{635}\u{642}\u{631}\u{634}\u{62a}\u{62b}\u{62e}\u{630}\u{636}\u{638}\u{63a}0123456789";
@@ -24,7 +24,7 @@ pub fn make_root_key() -> XPrvKey {
         bip39::Mnemonics::from_string(&bip39::dictionary::ENGLISH, SLIP14_MNEMONICS).unwrap();
     let entropy = bip39::Entropy::from_mnemonics(&mnemonics).unwrap();
 
-    XPrvKey::from_entropy(&entropy, b"")
+    XPrvKey::from_entropy(&entropy, b"").unwrap()
 }
 
 pub fn make_address_keys() -> (XPrvKey, XPubKey) {
@@ -37,5 +37,5 @@ pub fn make_keys_for(path: &DerivationPath) -> (XPrvKey, XPubKey) {
         bip39::Mnemonics::from_string(&bip39::dictionary::ENGLISH, SLIP14_MNEMONICS).unwrap();
     let entropy = bip39::Entropy::from_mnemonics(&mnemonics).unwrap();
 
-    api::derive_key_pair(&entropy, b"", path)
+    api::derive_key_pair(&entropy, b"", path).unwrap()
 }
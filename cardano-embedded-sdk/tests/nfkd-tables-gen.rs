@@ -0,0 +1,150 @@
+//! Generator of `src/util/nfkd_tables.rs` from the `unicode-normalization`
+//! crate. The test fails if the checked-in tables are not the generated
+//! ones, and writes the generated tables in the target directory:
+//!
+//! ```text
+//! cargo test --test nfkd-tables-gen
+//! cp target/tmp/nfkd_tables.rs src/util/nfkd_tables.rs
+//! ```
+use cardano_embedded_sdk::util::nfkd::{script_of, Script};
+use std::fmt::Write;
+use unicode_normalization::char::{canonical_combining_class, decompose_compatible};
+
+const SCRIPTS: [(Script, &str, &str); 5] = [
+    (Script::Latin, "LATIN", "nfkd-latin"),
+    (
+        Script::GreekCyrillic,
+        "GREEK_CYRILLIC",
+        "nfkd-greek-cyrillic",
+    ),
+    (Script::Cjk, "CJK", "nfkd-cjk"),
+    (Script::Hangul, "HANGUL", "nfkd-hangul"),
+    (Script::Other, "OTHER", "nfkd-other"),
+];
+
+fn chars() -> impl Iterator<Item = char> {
+    (0..0x110000u32).filter_map(char::from_u32)
+}
+
+fn decomposition(c: char) -> Option<String> {
+    let mut d = String::new();
+    decompose_compatible(c, |x| d.push(x));
+    if d == c.to_string() {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            ' '..='~' if c != '"' && c != '\\' => c.to_string(),
+            _ => format!("\\u{{{:x}}}", c as u32),
+        })
+        .collect()
+}
+
+fn combining_classes(out: &mut String) {
+    let mut ranges: Vec<(u32, u32, u8)> = Vec::new();
+    for c in chars() {
+        let class = canonical_combining_class(c);
+        if class == 0 {
+            continue;
+        }
+        let cp = c as u32;
+        match ranges.last_mut() {
+            Some((start, end, k)) if *end + 1 == cp && *k == class && cp - *start < 256 => {
+                *end = cp
+            }
+            _ => ranges.push((cp, cp, class)),
+        }
+    }
+    writeln!(
+        out,
+        "/// (first code point, number of code points - 1, class)"
+    )
+    .unwrap();
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        out,
+        "pub(super) const COMBINING_CLASS: &[(u32, u8, u8)] = &["
+    )
+    .unwrap();
+    for (start, end, class) in ranges {
+        writeln!(out, "    (0x{start:x}, {}, {class}),", end - start).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn decompositions(out: &mut String, script: Script, name: &str, feature: &str) {
+    let mut entries = Vec::new();
+    let mut decomposed = String::new();
+    for c in chars().filter(|c| script_of(*c) == script) {
+        // Hangul syllables are decomposed algorithmically
+        if ('\u{ac00}'..='\u{d7a3}').contains(&c) {
+            continue;
+        }
+        if let Some(d) = decomposition(c) {
+            entries.push((c as u32, decomposed.len(), d.len()));
+            decomposed.push_str(&d);
+        }
+    }
+    assert!(decomposed.len() <= u16::MAX as usize);
+
+    writeln!(out).unwrap();
+    writeln!(out, "#[cfg(feature = \"{feature}\")]").unwrap();
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        out,
+        "pub(super) const {name}_DECOMPOSITION: &[(u32, u16, u8)] = &["
+    )
+    .unwrap();
+    for (cp, offset, len) in entries {
+        writeln!(out, "    (0x{cp:x}, {offset}, {len}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out, "#[cfg(feature = \"{feature}\")]").unwrap();
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        out,
+        "pub(super) const {name}_DECOMPOSED: &str = \"{}\";",
+        escape(&decomposed)
+    )
+    .unwrap();
+}
+
+#[test]
+fn generate_nfkd_tables() {
+    // every decomposition must belong to a feature gated script range
+    for c in chars() {
+        if decomposition(c).is_some() {
+            assert_ne!(script_of(c), Script::Common, "U+{:04X}", c as u32);
+        }
+    }
+
+    let (major, minor, patch) = unicode_normalization::UNICODE_VERSION;
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// @generated by tests/nfkd-tables-gen.rs, do not edit."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "pub(super) const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {patch});"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    combining_classes(&mut out);
+    for (script, name, feature) in SCRIPTS {
+        decompositions(&mut out, script, name, feature);
+    }
+    let path = concat!(env!("CARGO_TARGET_TMPDIR"), "/nfkd_tables.rs");
+    std::fs::write(path, &out).unwrap();
+    assert!(
+        out == include_str!("../src/util/nfkd_tables.rs"),
+        "src/util/nfkd_tables.rs is out of date, regenerated in {path}"
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "bip39-english")]
+
 use cardano_embedded_sdk::api as embedano;
 use cardano_embedded_sdk::bip::bip39;
 use cardano_embedded_sdk::types::XPrvKey;
@@ -31,14 +33,15 @@ fn test_slip14_keys() {
         .unwrap();
 
         let entropy = bip39::Entropy::from_mnemonics(&mnemonics).unwrap();
-        let root_key = XPrvKey::from_entropy(&entropy, b"");
+        let root_key = XPrvKey::from_entropy(&entropy, b"").unwrap();
 
         assert_eq!(root_key_reference_hex, root_key.to_hex());
 
         //test account 0 derived private key
         let prv_key_reference_hex = read_file_trimmed(data_path.join("addr_0_xprv_hex"));
         let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
-        let (acc_0_prv_key, acc_0_pub_key) = embedano::derive_key_pair(&entropy, b"", &path);
+        let (acc_0_prv_key, acc_0_pub_key) =
+            embedano::derive_key_pair(&entropy, b"", &path).unwrap();
 
         assert_eq!(prv_key_reference_hex, acc_0_prv_key.to_hex());
 
@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use cardano_embedded_sdk::text_envelope::{EnvelopeType, Era, TextEnvelope};
+use cardano_embedded_sdk::types::TxId;
+
+#[test]
+fn test_tx_id_of_slip14_transaction() {
+    // Known transaction id according to slip14-data/README.md
+    let tx_id = "bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb";
+
+    let signed = TextEnvelope::from_json(include_str!(
+        "../../slip14-data/txs/tx-bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb.signed"
+    ))
+    .unwrap();
+    assert_eq!(signed.envelope_type(), EnvelopeType::Tx(Era::Babbage));
+    assert_eq!(
+        TxId::from_tx_cbor(signed.to_tx().unwrap().0)
+            .unwrap()
+            .to_hex(),
+        tx_id
+    );
+
+    // body envelope of older cardano-cli: `[body, [], [], [], true, null]`
+    let raw = TextEnvelope::from_json(include_str!(
+        "../../slip14-data/txs/tx-bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb.raw"
+    ))
+    .unwrap();
+    assert_eq!(raw.envelope_type(), EnvelopeType::TxBody(Era::Babbage));
+    assert_eq!(
+        TxId::from_tx_cbor(raw.to_tx().unwrap().0).unwrap().to_hex(),
+        tx_id
+    );
+}
@@ -0,0 +1,83 @@
+//! Size regression test of the BIP39 dictionaries.
+//!
+//! Reports the size of each enabled dictionary feature, both in the default
+//! representation (2048 `&str` plus the words) and packed, and fails if a
+//! packed dictionary grows beyond its budget or is not under half the
+//! default one.
+//!
+//! The sizes are measured on the host: `size_of_val` of the statics plus the
+//! bytes of the words they point to. They are not the `.rodata` sections of
+//! a firmware, where a `&str` takes 8 bytes instead of 16, but the packed
+//! dictionaries have no pointers and take the same size on every target.
+//!
+//! Run with `cargo test --test wordlist-size-test -- --nocapture` to see
+//! the report.
+#![cfg(feature = "alloc")]
+use cardano_embedded_sdk::bip::bip39::dictionary::DefaultDictionary;
+use cardano_embedded_sdk::bip::packed_dictionary::{self, PackedDictionary};
+use std::mem::size_of_val;
+
+fn default_cost(dic: &DefaultDictionary) -> usize {
+    let words: usize = dic.words.iter().map(|w| w.len()).sum();
+    size_of_val(dic) + words + dic.name.len()
+}
+
+fn packed_cost<const N: usize>(packed: &PackedDictionary<N>) -> usize {
+    // the words are in the static, the name is shared with the default
+    // dictionary
+    size_of_val(packed)
+}
+
+fn report<const N: usize>(
+    feature: &str,
+    dic: &DefaultDictionary,
+    packed: &PackedDictionary<N>,
+    budget: usize,
+) {
+    let default = default_cost(dic);
+    let packed = packed_cost(packed);
+    println!("{feature:<28} default {default:>6} bytes   packed {packed:>6} bytes");
+    assert!(
+        packed <= budget,
+        "{feature}: packed dictionary is {packed} bytes, over its budget of {budget} bytes"
+    );
+    assert!(
+        packed < default / 2,
+        "{feature}: packed dictionary is {packed} bytes, default is {default} bytes"
+    );
+}
+
+macro_rules! size_test {
+    ($feature:literal, $test:ident, $name:ident, $budget:expr) => {
+        #[cfg(feature = $feature)]
+        #[test]
+        fn $test() {
+            use cardano_embedded_sdk::bip::bip39::dictionary;
+            report(
+                $feature,
+                &dictionary::$name,
+                &packed_dictionary::$name,
+                $budget,
+            );
+        }
+    };
+}
+
+size_test!("bip39-english", english, ENGLISH, 9 * 1024);
+size_test!("bip39-french", french, FRENCH, 12 * 1024);
+size_test!("bip39-japanese", japanese, JAPANESE, 15 * 1024);
+size_test!("bip39-korean", korean, KOREAN, 20 * 1024);
+size_test!(
+    "bip39-chinese-simplified",
+    chinese_simplified,
+    CHINESE_SIMPLIFIED,
+    8 * 1024
+);
+size_test!(
+    "bip39-chinese-traditional",
+    chinese_traditional,
+    CHINESE_TRADITIONAL,
+    8 * 1024
+);
+size_test!("bip39-italian", italian, ITALIAN, 12 * 1024);
+size_test!("bip39-spanish", spanish, SPANISH, 10 * 1024);
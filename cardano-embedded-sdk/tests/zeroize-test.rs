@@ -0,0 +1,163 @@
+//! Check that the secret keys are zeroed before their memory is released.
+//!
+//! The global allocator of this test scans every freed block for a given
+//! secret (the "needle"), the secret values are boxed so their memory goes
+//! through the allocator when they are dropped.
+#![cfg(feature = "alloc")]
+use cardano_embedded_sdk::api as embedano;
+use cardano_embedded_sdk::bip::bip39::Entropy;
+use cardano_embedded_sdk::chain_crypto::{Ed25519, SecretKey};
+use cardano_embedded_sdk::crypto::{Bip32PrivateKey, PrivateKey};
+use derivation_path::DerivationPath;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+const NEEDLE_SIZE: usize = 32;
+
+struct ScanningAllocator;
+
+static NEEDLE: [AtomicU8; NEEDLE_SIZE] = [const { AtomicU8::new(0) }; NEEDLE_SIZE];
+static ARMED: AtomicBool = AtomicBool::new(false);
+static FOUND: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for ScanningAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ARMED.load(Ordering::SeqCst) && layout.size() >= NEEDLE_SIZE {
+            let block = std::slice::from_raw_parts(ptr, layout.size());
+            let found = block.windows(NEEDLE_SIZE).any(|window| {
+                window
+                    .iter()
+                    .zip(NEEDLE.iter())
+                    .all(|(b, n)| *b == n.load(Ordering::SeqCst))
+            });
+            if found {
+                FOUND.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: ScanningAllocator = ScanningAllocator;
+
+// the needle is global, run one check at a time
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// run `f` and return the number of freed blocks that still contained the
+/// first bytes of `secret`.
+fn freed_copies_of(secret: &[u8], f: impl FnOnce()) -> usize {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    for (n, b) in NEEDLE.iter().zip(secret) {
+        n.store(*b, Ordering::SeqCst);
+    }
+    FOUND.store(0, Ordering::SeqCst);
+    ARMED.store(true, Ordering::SeqCst);
+    f();
+    ARMED.store(false, Ordering::SeqCst);
+    FOUND.load(Ordering::SeqCst)
+}
+
+fn entropy() -> Entropy {
+    Entropy::from_slice(&[0x5a; 16]).unwrap()
+}
+
+fn path() -> DerivationPath {
+    "m/1852'/1815'/0'/0/0".parse().unwrap()
+}
+
+/// first bytes of the given secret, copied on the stack.
+fn needle(mut secret: Vec<u8>) -> [u8; NEEDLE_SIZE] {
+    let mut needle = [0; NEEDLE_SIZE];
+    needle.copy_from_slice(&secret[..NEEDLE_SIZE]);
+    secret.iter_mut().for_each(|b| *b = 0);
+    needle
+}
+
+fn xprv_needle() -> [u8; NEEDLE_SIZE] {
+    let key = embedano::derive_key(&entropy(), b"", &path()).unwrap();
+    needle(hex::decode(key.to_hex()).unwrap())
+}
+
+#[test]
+fn test_sanity_check_scanner() {
+    let secret = [0xa5; NEEDLE_SIZE];
+    let found = freed_copies_of(&secret, || drop(Box::new(secret)));
+    assert_eq!(found, 1);
+}
+
+#[test]
+fn test_xprv_key_zeroed() {
+    let secret = xprv_needle();
+    let found = freed_copies_of(&secret, || {
+        let key = Box::new(embedano::derive_key(&entropy(), b"", &path()).unwrap());
+        let _ = key.to_public();
+        let _ = key.sign(b"some data");
+        let _ = key.to_hex();
+    });
+    assert_eq!(found, 0);
+}
+
+#[test]
+fn test_derived_keys_zeroed() {
+    let secret = xprv_needle();
+    let found = freed_copies_of(&secret, || {
+        let root =
+            embedano::derive_key(&entropy(), b"", &"m/1852'/1815'/0'/0".parse().unwrap()).unwrap();
+        let keys: Vec<_> = (0..4).map(|i| root.derive(i)).collect();
+        drop(keys);
+    });
+    assert_eq!(found, 0);
+}
+
+#[test]
+fn test_bip32_private_key_zeroed() {
+    let xprv = embedano::derive_key(&entropy(), b"", &path())
+        .unwrap()
+        .to_hex();
+    let secret = xprv_needle();
+    let found = freed_copies_of(&secret, || {
+        let key = Box::new(Bip32PrivateKey::from_hex(&xprv).unwrap());
+        let _ = key.to_hex();
+        let mut xprv_128 = key.to_128_xprv();
+        let _ = Bip32PrivateKey::from_128_xprv(&xprv_128).unwrap();
+        xprv_128.iter_mut().for_each(|b| *b = 0);
+        let raw = Box::new(key.to_raw_key());
+        let _ = raw.sign(b"some data");
+        let _ = raw.to_hex();
+    });
+    assert_eq!(found, 0);
+}
+
+#[test]
+fn test_private_key_zeroed() {
+    let mut extended = [0x42; 64];
+    extended[0] &= 0b1111_1000;
+    extended[31] &= 0b0011_1111;
+    extended[31] |= 0b0100_0000;
+    let found = freed_copies_of(&extended[..NEEDLE_SIZE], || {
+        let key = Box::new(PrivateKey::from_extended_bytes(&extended).unwrap());
+        let _ = key.to_public();
+        let _ = key.sign(b"some data");
+        let _ = PrivateKey::from_hex(&key.to_hex()).unwrap();
+    });
+    assert_eq!(found, 0);
+}
+
+// `PrivateKey` is an enum, the unused bytes of a normal (32 bytes) key may
+// hold stale copies of the stack, check the normal key type on its own.
+#[test]
+fn test_normal_secret_key_zeroed() {
+    let secret = [0x42; NEEDLE_SIZE];
+    let found = freed_copies_of(&secret, || {
+        let key = Box::new(SecretKey::<Ed25519>::from_binary(&secret).unwrap());
+        let _ = key.to_public();
+        let _ = key.sign_slice::<[u8]>(b"some data");
+    });
+    assert_eq!(found, 0);
+}
@@ -17,7 +17,6 @@ use crate::device::Device;
 mod device;
 mod node_client;
 mod tx_build;
-mod tx_envelope;
 
 #[derive(Parser, Debug)]
 #[command(about, long_about = None)]
@@ -149,13 +148,20 @@ fn submit_device_data(
     // Transaction will have output for script address
     // with inlined datum which holds temperature data from the device
     println!("Making unsigned Tx");
-    let unsigned_tx = tx_build::make_unsigned_tx(
+    let unsigned_tx = match tx_build::make_unsigned_tx(
         &device_wallet_address,
         &script_address,
         temp_data,
         &inputs,
         ins_total_value, //for balancing
-    );
+        derivation_path,
+    ) {
+        Ok(tx) => tx,
+        Err(msg) => {
+            println!("Transaction build failed: {}", msg);
+            return;
+        }
+    };
 
     let tx_id = node_client.get_tx_id(&unsigned_tx);
 
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, fmt::Display, process::Command};
 
-use cardano_embedded_sdk::types::TxId;
+use cardano_embedded_sdk::{
+    text_envelope::{EnvelopeType, Era, TextEnvelope},
+    types::TxId,
+};
 use cardano_serialization_lib::{
     address::Address, crypto::TransactionHash, Transaction, TransactionInput, TransactionInputs,
 };
@@ -12,8 +15,6 @@ pub struct NodeClientError {
     message: String,
 }
 
-use crate::tx_envelope::{self, write_as_envelope};
-
 pub trait NodeClient {
     fn query_raw_inputs(&self, address: &Address) -> Result<String, NodeClientError>;
     fn query_inputs(&self, address: &Address) -> Result<(TransactionInputs, u64), NodeClientError>;
@@ -92,7 +93,7 @@ impl NodeClient for CliNodeClient {
 
     fn submit_tx(&self, tx: &Transaction) -> Result<String, NodeClientError> {
         let tx_file = "./to_submit.tx";
-        tx_envelope::write_as_envelope(tx_file, tx);
+        write_as_envelope(tx_file, tx);
         let args: Vec<&str> = vec![
             "transaction",
             "submit",
@@ -111,16 +112,7 @@ impl NodeClient for CliNodeClient {
     }
 
     fn get_tx_id(&self, tx: &Transaction) -> TxId {
-        let tmp_tx_path = "./empty_wit_for_id.tx";
-        write_as_envelope(tmp_tx_path, tx);
-
-        let result = Command::new("cardano-cli")
-            .args(["transaction", "txid", "--tx-file", tmp_tx_path])
-            .output()
-            .expect("Should get Tx id with cardano-cli");
-        let result = String::from_utf8_lossy(&result.stdout);
-        let result = result.strip_suffix("\n").unwrap();
-        TxId::from_hex(result).expect("Should parse Tx id from hex")
+        TxId::from_tx_cbor(&tx.to_bytes()).expect("Should compute Tx id from the transaction")
     }
 }
 
@@ -145,3 +137,10 @@ fn translate_network(net: Network) -> &'static str {
         Network::Preprod => "--testnet-magic=1",
     }
 }
+
+fn write_as_envelope(path: &str, tx: &Transaction) {
+    TextEnvelope::new(EnvelopeType::Tx(Era::Babbage), tx.to_bytes())
+        .with_description("")
+        .write_to(path)
+        .expect("Unable to write the transaction envelope");
+}
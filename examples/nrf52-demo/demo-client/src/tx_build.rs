@@ -1,3 +1,4 @@
+use cardano_embedded_sdk::tx::{balance::ProtocolParams, estimate::FeeEstimator};
 use cardano_embedded_sdk::types::XPubKey;
 use cardano_serialization_lib::{
     address::Address,
@@ -8,11 +9,12 @@ use cardano_serialization_lib::{
     TransactionWitnessSet,
 };
 
+use derivation_path::DerivationPath;
+
 use crate::device::DeviceData;
 
-// some constants for balancing
-const FEE: u64 = 200000;
-const MIN_ADA: u64 = 2_000_000;
+// mainnet and preprod parameters for balancing
+const PARAMS: ProtocolParams = ProtocolParams::CONWAY_MAINNET;
 
 pub fn make_unsigned_tx(
     from_address: &Address,
@@ -20,9 +22,8 @@ pub fn make_unsigned_tx(
     device_data: DeviceData,
     inputs: &TransactionInputs,
     ins_value: u64,
-) -> Transaction {
-    let mut receiver = TransactionOutput::new(to_address, &lovelace(MIN_ADA));
-
+    signer_path: &DerivationPath,
+) -> Result<Transaction, String> {
     let mut to_send_data = PlutusList::new();
     // adding temperature data
     let sensor_data = format!("\"{}\"", device_data.sensor_readings);
@@ -36,18 +37,55 @@ pub fn make_unsigned_tx(
     let signed_data = PlutusData::new_bytes(device_data.signed_readings);
     to_send_data.add(&signed_data);
 
-    receiver.set_plutus_data(&PlutusData::new_list(&to_send_data));
+    let datum = PlutusData::new_list(&to_send_data);
 
-    let change = TransactionOutput::new(&from_address, &lovelace(ins_value - MIN_ADA - FEE));
+    // the receiver holds the minimum lovelace of its bytes, datum included
+    let receiver = |coin| {
+        let mut receiver = TransactionOutput::new(to_address, &lovelace(coin));
+        receiver.set_plutus_data(&datum);
+        receiver
+    };
+    let min_ada = PARAMS
+        .raw_min_coin(|coin| receiver(coin).to_bytes())
+        .expect("Should decode output built by the lib");
+    let receiver = receiver(min_ada);
 
-    let mut outputs = TransactionOutputs::new();
-    outputs.add(&receiver);
-    outputs.add(&change);
+    // the fee is in the body it pays for, rebuild until it covers its own size
+    let estimator = FeeEstimator::new(PARAMS).with_key_paths(&[signer_path.clone()]);
+    let mut fee = 0;
+    loop {
+        let change = ins_value
+            .checked_sub(min_ada)
+            .and_then(|rest| rest.checked_sub(fee))
+            .ok_or_else(|| {
+                format!("inputs of {ins_value} lovelace cannot pay {min_ada} and a fee of {fee}")
+            })?;
+        let tx_body = make_body(inputs, &receiver, from_address, change, fee);
+        let min_fee = estimator.estimate(&tx_body.to_bytes()).min_fee();
+        if fee >= min_fee {
+            return Ok(Transaction::new(
+                &tx_body,
+                &TransactionWitnessSet::new(),
+                None,
+            ));
+        }
+        fee = min_fee;
+    }
+}
 
-    let tx_fee: Coin = coin(FEE);
+fn make_body(
+    inputs: &TransactionInputs,
+    receiver: &TransactionOutput,
+    change_address: &Address,
+    change: u64,
+    fee: u64,
+) -> TransactionBody {
+    let mut outputs = TransactionOutputs::new();
+    outputs.add(receiver);
+    outputs.add(&TransactionOutput::new(change_address, &lovelace(change)));
 
-    let tx_body = TransactionBody::new_tx_body(&inputs, &outputs, &tx_fee);
-    Transaction::new(&tx_body, &TransactionWitnessSet::new(), None)
+    let tx_fee: Coin = coin(fee);
+    TransactionBody::new_tx_body(inputs, &outputs, &tx_fee)
 }
 
 pub fn make_signed_tx(
@@ -9,6 +9,7 @@ use panic_halt as _;
 
 use alloc_cortex_m::CortexMHeap;
 
+use cortex_m::peripheral::DWT;
 use cortex_m_rt::entry;
 use nrf52840_hal::clocks::Clocks;
 use nrf52840_hal::usbd::{UsbPeripheral, Usbd};
@@ -29,6 +30,9 @@ static ALLOCATOR: CortexMHeap = CortexMHeap::empty();
 
 const HEAP_SIZE: usize = 1 * 1024; // in bytes
 
+// lock the keys after 5 minutes (of 64 MHz cycles) without request
+const SESSION_TIMEOUT: u64 = 5 * 60 * 64_000_000;
+
 #[entry]
 fn main() -> ! {
     unsafe { ALLOCATOR.init(cortex_m_rt::heap_start() as usize, HEAP_SIZE) }
@@ -37,6 +41,10 @@ fn main() -> ! {
     // Initialization taken from GH hal UDB example:
     // https://github.com/nrf-rs/nrf-hal/tree/939c0175dbbebdb9457a492a9912b755cc56737c/examples/usb
     let periph = nrf52840_hal::pac::Peripherals::take().unwrap();
+    let mut core = cortex_m::Peripherals::take().unwrap();
+    core.DCB.enable_trace();
+    core.DWT.enable_cycle_counter();
+    let mut clock = Clock::new(DWT::cycle_count);
     let clocks = Clocks::new(periph.CLOCK);
     let clocks = clocks.enable_ext_hfosc();
 
@@ -52,12 +60,15 @@ fn main() -> ! {
         .build();
 
     let mut entropy: Option<Entropy> = None;
+    let mut session = Session::new(SESSION_TIMEOUT);
     let mut temp_sensor = Temp::new(periph.TEMP);
 
     let mut state = State::Read(Data::Head(vec![]));
 
     // Main loop that polls USB and process requests from the host
     loop {
+        // keep the clock up to date and the session timeout running
+        session.is_unlocked(clock.now());
         if !usb_dev.poll(&mut [&mut serial]) || !serial.dtr() {
             continue;
         }
@@ -144,6 +155,7 @@ fn main() -> ! {
                         .map(|v| Entropy::from_mnemonics(&v))
                         .flatten()
                         .map(|v| entropy = Some(v));
+                    session.lock();
                     let out = if let Err(e) = result {
                         Out::Error(format!("Decode mnemonics failed: {e}"))
                     } else {
@@ -153,36 +165,53 @@ fn main() -> ! {
                     state = State::Write(Data::Head(minicbor::to_vec(&out).unwrap()));
                 }
                 State::Exec(In::Sign(tx_id, password, path)) => {
+                    let now = clock.now();
                     let out = if let Some(entropy) = &entropy {
                         hprintln!("Firmware: signing transaction id");
-                        sign(&tx_id, entropy, &password, &path)
+                        match session.unlock(entropy, &password, now) {
+                            Ok(()) => sign(&tx_id, &mut session, &path, now),
+                            Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                        }
                     } else {
                         Out::Error(format!("Sign failed: no entropy"))
                     };
                     state = State::Write(Data::Head(minicbor::to_vec(&out).unwrap()));
                 }
                 State::Exec(In::Verify(tx_id, signature, password, path)) => {
+                    let now = clock.now();
                     let out = if let Some(entropy) = &entropy {
-                        verify(&tx_id, signature, entropy, &password, &path)
+                        match session.unlock(entropy, &password, now) {
+                            Ok(()) => verify(&tx_id, signature, &mut session, &path, now),
+                            Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                        }
                     } else {
                         Out::Error(format!("Verify failed: no entropy"))
                     };
                     state = State::Write(Data::Head(minicbor::to_vec(&out).unwrap()));
                 }
                 State::Exec(In::Temp(password, time, path)) => {
-                    use cardano_embedded_sdk::api::sign_data;
                     use derivation_path::DerivationPath;
 
                     let temperature: i32 = temp_sensor.measure().to_num();
                     hprintln!("Firmware: temperature: {}", temperature);
                     hprintln!("Firmware: time: {}", time);
+                    let now = clock.now();
                     let out = match (&entropy, path.parse::<DerivationPath>()) {
                         (Some(entropy), Ok(path)) => {
                             let data: Vec<u8> = chain_data_bytes(temperature, time);
                             hprintln!("Firmware: temperature: signing");
-                            let signature = sign_data(&data, entropy, &password, &path);
-                            hprintln!("Firmware: temperature: sending");
-                            Out::Temp(temperature, signature.to_bytes())
+                            match session.unlock(entropy, &password, now) {
+                                Ok(()) => match session.sign_data(&data, &path, now) {
+                                    Ok(signature) => {
+                                        hprintln!("Firmware: temperature: sending");
+                                        Out::Temp(temperature, signature.to_bytes())
+                                    }
+                                    Err(e) => {
+                                        Out::Error(format!("Getting temperature failed: {e}"))
+                                    }
+                                },
+                                Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                            }
                         }
                         (None, _) => Out::Error(format!("Getting temperature failed: no entropy")),
                         (_, Err(e)) => Out::Error(format!("Decode path failed: {e}")),
@@ -190,9 +219,13 @@ fn main() -> ! {
                     state = State::Write(Data::Head(minicbor::to_vec(&out).unwrap()));
                 }
                 State::Exec(In::PubKey(password, path)) => {
+                    let now = clock.now();
                     let out = if let Some(entropy) = &entropy {
                         hprintln!("Firmware: Sending public key");
-                        get_pub_key(entropy, &password, &path)
+                        match session.unlock(entropy, &password, now) {
+                            Ok(()) => get_pub_key(&mut session, &path, now),
+                            Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                        }
                     } else {
                         Out::Error(format!("Public key derivation failed: no entropy"))
                     };
@@ -3,7 +3,9 @@ use std::{thread, time::Duration};
 use cardano_embedded_sdk::tx_stream::{TxEntry, TxStream};
 use cardano_embedded_sdk::types::XPubKey;
 use cardano_serialization_lib::utils::BigNum;
-use cardano_serialization_lib::{Transaction, TransactionInputs};
+use cardano_serialization_lib::{
+    Transaction, TransactionInput, TransactionInputs, TransactionOutputs,
+};
 use derivation_path::DerivationPath;
 use serialport::SerialPort;
 
@@ -131,17 +133,54 @@ impl Device {
         }
     }
 
+    /// `tx_size` is the size of the signed transaction, for the fee check of
+    /// the device, and `amounts` the lovelace of the spent inputs, for its
+    /// balance check
     pub fn stream_tx(
         &mut self,
         tx: &Transaction,
+        tx_size: usize,
+        amounts: &[(TransactionInput, u64)],
         password: &String,
         derivation_path: &DerivationPath,
     ) -> Result<Vec<u8>, String> {
+        self.stream_context(tx_size)?;
+        self.stream_amounts(amounts)?;
         self.stream_inputs(tx.body().inputs())?;
+        self.stream_outputs(tx.body().outputs())?;
         self.stream_fee(tx.body().fee())?;
         self.finalize_stream(password, derivation_path)
     }
 
+    fn stream_context(&mut self, tx_size: usize) -> Result<String, String> {
+        // the transaction has no certificates, no pool or stake deposit is needed
+        let context_request = In::Stream(TxStream::Context(tx_size as u64, vec![], vec![]));
+        send(&mut self.port, context_request);
+        match receive(&mut self.port) {
+            Ok(Some(Out::StreamResponse(msg))) => {
+                println!("Device: streaming context: {}", msg);
+                Ok("".into())
+            }
+            other => Err(format!("Error streaming context: {:?}", other)),
+        }
+    }
+
+    fn stream_amounts(&mut self, amounts: &[(TransactionInput, u64)]) -> Result<String, String> {
+        for (inp, amount) in amounts {
+            let hash = inp.transaction_id().to_bytes();
+            let amount_request = In::Stream(TxStream::InputAmount(hash, inp.index(), *amount));
+            send(&mut self.port, amount_request);
+
+            match receive(&mut self.port) {
+                Ok(Some(Out::StreamResponse(msg))) => {
+                    println!("Device: streaming input amount: {}", msg);
+                }
+                other => return Err(format!("Error streaming input amounts: {:?}", other)),
+            }
+        }
+        Ok("".into())
+    }
+
     fn stream_inputs(&mut self, ins: TransactionInputs) -> Result<String, String> {
         for n in 0..ins.len() {
             let inp = ins.get(n);
@@ -161,6 +200,25 @@ impl Device {
         Ok("".into())
     }
 
+    fn stream_outputs(&mut self, outs: TransactionOutputs) -> Result<String, String> {
+        for n in 0..outs.len() {
+            let out = outs.get(n);
+            let address = out.address().to_bytes();
+            let coin = cardano_serialization_lib::utils::from_bignum(&out.amount().coin());
+
+            let out_stream_req = In::Stream(TxStream::Entry(TxEntry::Output(address, coin)));
+            send(&mut self.port, out_stream_req);
+
+            match receive(&mut self.port) {
+                Ok(Some(Out::StreamResponse(msg))) => {
+                    println!("Device: streaming TxOut: {}", msg);
+                }
+                other => return Err(format!("Error streaming outputs: {:?}", other)),
+            }
+        }
+        Ok("".into())
+    }
+
     fn stream_fee(&mut self, fee: BigNum) -> Result<String, String> {
         let fee_request = In::Stream(TxStream::Entry(TxEntry::Fee(
             cardano_serialization_lib::utils::from_bignum(&fee),
@@ -2,6 +2,7 @@ use cardano_embedded_sdk::crypto::Ed25519Signature;
 use cardano_serialization_lib::{
     address::{Address, EnterpriseAddress, StakeCredential},
     crypto::Ed25519KeyHash,
+    TransactionInputs,
 };
 
 use clap::{command, Parser};
@@ -13,7 +14,6 @@ use crate::device::Device;
 mod device;
 mod node_client;
 mod tx_build;
-mod tx_envelope;
 
 #[derive(Parser, Debug)]
 #[command(about, long_about = None)]
@@ -110,24 +110,38 @@ fn build_and_stream_tx(
     )
     .to_address();
 
-    // Get UTXOs from device address for balancing
-    let (inputs, ins_total_value) = node_client
-        .query_inputs(&device_wallet_address)
+    // Get UTXOs from device address for balancing, with their amounts for
+    // the balance check of the device
+    let amounts = node_client
+        .query_input_amounts(&device_wallet_address)
         .expect("Should return inputs from user address. Is node running and available?");
+    let mut inputs = TransactionInputs::new();
+    amounts.iter().for_each(|(input, _)| inputs.add(input));
+    let ins_total_value = amounts.iter().map(|(_, amount)| amount).sum();
 
     // Build balanced unsigned transaction
     // Transaction will have output for script address
     // with inlined datum which holds temperature data from the device
-    let unsigned_tx = tx_build::make_unsigned_tx(
+    let unsigned_tx = match tx_build::make_unsigned_tx(
         &device_wallet_address,
         &script_address,
         temp_data,
         &inputs,
         ins_total_value, //for balancing
-    );
+        derivation_path,
+    ) {
+        Ok(tx) => tx,
+        Err(msg) => {
+            println!("Transaction build failed: {}", msg);
+            return;
+        }
+    };
+
+    let tx_size = tx_build::signed_size(&unsigned_tx, derivation_path);
 
     println!("Streaming unsigned transaction to the device - 1");
-    let stream_result = device.stream_tx(&unsigned_tx, password, derivation_path);
+    let stream_result =
+        device.stream_tx(&unsigned_tx, tx_size, &amounts, password, derivation_path);
     match stream_result {
         Ok(signature) => {
             println!("Transaction was streamed to device successfully!");
@@ -138,7 +152,8 @@ fn build_and_stream_tx(
     }
 
     println!("Streaming unsigned transaction to the device - 2");
-    let stream_result = device.stream_tx(&unsigned_tx, password, derivation_path);
+    let stream_result =
+        device.stream_tx(&unsigned_tx, tx_size, &amounts, password, derivation_path);
     match stream_result {
         Ok(signature) => {
             println!("Transaction was streamed to device successfully!");
@@ -149,7 +164,8 @@ fn build_and_stream_tx(
     }
 
     println!("Streaming unsigned transaction to the device - 3");
-    let stream_result = device.stream_tx(&unsigned_tx, password, derivation_path);
+    let stream_result =
+        device.stream_tx(&unsigned_tx, tx_size, &amounts, password, derivation_path);
     match stream_result {
         Ok(signature) => {
             println!("Transaction was streamed to device successfully!");
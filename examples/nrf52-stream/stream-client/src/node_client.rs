@@ -1,6 +1,9 @@
 use std::{collections::HashMap, fmt::Display, process::Command};
 
-use cardano_embedded_sdk::types::TxId;
+use cardano_embedded_sdk::{
+    text_envelope::{EnvelopeType, Era, TextEnvelope},
+    types::TxId,
+};
 use cardano_serialization_lib::{
     address::Address, crypto::TransactionHash, Transaction, TransactionInput, TransactionInputs,
 };
@@ -12,11 +15,13 @@ pub struct NodeClientError {
     message: String,
 }
 
-use crate::tx_envelope::{self, write_as_envelope};
-
 pub trait NodeClient {
     fn query_raw_inputs(&self, address: &Address) -> Result<String, NodeClientError>;
     fn query_inputs(&self, address: &Address) -> Result<(TransactionInputs, u64), NodeClientError>;
+    fn query_input_amounts(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<(TransactionInput, u64)>, NodeClientError>;
 
     fn submit_tx(&self, tx: &Transaction) -> Result<String, NodeClientError>;
 
@@ -71,28 +76,43 @@ impl NodeClient for CliNodeClient {
     }
 
     fn query_inputs(&self, address: &Address) -> Result<(TransactionInputs, u64), NodeClientError> {
+        let amounts = self.query_input_amounts(address)?;
+
+        let mut tx_inputs = TransactionInputs::new();
+        for (input, _) in &amounts {
+            tx_inputs.add(input)
+        }
+        let total_inputs_value = amounts.iter().map(|(_, amount)| amount).sum();
+        Ok((tx_inputs, total_inputs_value))
+    }
+
+    fn query_input_amounts(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<(TransactionInput, u64)>, NodeClientError> {
         // run cardano-cli to get utxos
 
         let inputs = self.query_raw_inputs(address)?;
         let inputs: HashMap<String, Value> = serde_json::from_str(&inputs).map_err(to_err)?;
 
-        let total_inputs_value = get_total_value(&inputs);
-
-        let mut tx_inputs = TransactionInputs::new();
-        for key in inputs.keys() {
+        let mut amounts = Vec::new();
+        for (key, value) in &inputs {
             let res = key.split_once("#").unwrap(); //todo: error handling
             let res = TransactionInput::new(
                 &TransactionHash::from_hex(res.0).unwrap(), //todo: error handling
                 res.1.parse::<u32>().unwrap(),              //todo: error handling
             );
-            tx_inputs.add(&res)
+            let lovelace = value["value"]["lovelace"]
+                .as_u64()
+                .expect("Should be number");
+            amounts.push((res, lovelace))
         }
-        Ok((tx_inputs, total_inputs_value))
+        Ok(amounts)
     }
 
     fn submit_tx(&self, tx: &Transaction) -> Result<String, NodeClientError> {
         let tx_file = "./to_submit.tx";
-        tx_envelope::write_as_envelope(tx_file, tx);
+        write_as_envelope(tx_file, tx);
         let args: Vec<&str> = vec![
             "transaction",
             "submit",
@@ -111,16 +131,7 @@ impl NodeClient for CliNodeClient {
     }
 
     fn get_tx_id(&self, tx: &Transaction) -> TxId {
-        let tmp_tx_path = "./empty_wit_for_id.tx";
-        write_as_envelope(tmp_tx_path, tx);
-
-        let result = Command::new("cardano-cli")
-            .args(["transaction", "txid", "--tx-file", tmp_tx_path])
-            .output()
-            .expect("Should get Tx id with cardano-cli");
-        let result = String::from_utf8_lossy(&result.stdout);
-        let result = result.strip_suffix("\n").unwrap();
-        TxId::from_hex(result).expect("Should parse Tx id from hex")
+        TxId::from_tx_cbor(&tx.to_bytes()).expect("Should compute Tx id from the transaction")
     }
 }
 
@@ -130,18 +141,16 @@ fn to_err<T: Display>(e: T) -> NodeClientError {
     }
 }
 
-fn get_total_value(inputs: &HashMap<String, Value>) -> u64 {
-    let mut total_lovelace = 0;
-    for v in inputs.values() {
-        let input_lovelace = v["value"]["lovelace"].as_u64().expect("Should be number");
-        total_lovelace = total_lovelace + input_lovelace;
-    }
-    total_lovelace
-}
-
 fn translate_network(net: Network) -> &'static str {
     match net {
         Network::Mainnet => "--mainnet",
         Network::Preprod => "--testnet-magic=1",
     }
 }
+
+fn write_as_envelope(path: &str, tx: &Transaction) {
+    TextEnvelope::new(EnvelopeType::Tx(Era::Babbage), tx.to_bytes())
+        .with_description("")
+        .write_to(path)
+        .expect("Unable to write the transaction envelope");
+}
@@ -1,15 +1,29 @@
 #![no_std]
 
 extern crate alloc;
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::prelude::rust_2021::derive;
 use cortex_m_semihosting::hprintln;
 use mock_hasher::MockHahser;
 
 use cardano_embedded_sdk::bip::bip39::Entropy;
+use cardano_embedded_sdk::chain_crypto::PoolKeyHash;
 use cardano_embedded_sdk::crypto::Ed25519Signature;
 use cardano_embedded_sdk::types::{TxId, TxIdParseError};
-use cardano_embedded_sdk::{api as embedano, tx_stream, types::TransactionInput};
+use cardano_embedded_sdk::{
+    api::session::{self, UnlockSession},
+    tx::{
+        balance::{check_fee, BalanceCheck, BalanceContext, BalanceError, ProtocolParams},
+        summary::format_ada,
+        Address, Credential, DisplayItem, Severity, Value,
+    },
+    tx_stream,
+    types::TransactionInput,
+};
 use derivation_path::DerivationPath;
 
 use minicbor::{Decode, Encode};
@@ -78,25 +92,128 @@ pub enum Out {
     StreamResponse(#[n(0)] String),
 }
 
+/// Unlock session of the device, keeping the 2 last used account keys.
+pub type Session = UnlockSession<2>;
+
+/// Session clock of the device, counting CPU cycles. It has to be read more
+/// often than the cycle counter wraps (about a minute at 64 MHz).
+pub type Clock = session::Clock<fn() -> u32>;
+
+// parameters of the network for the fee and deposits
+const PARAMS: ProtocolParams = ProtocolParams::CONWAY_MAINNET;
+
+/// Balance and fee of the streamed transaction, with the context and the
+/// amounts of its inputs sent by the host before the entries
+pub struct StreamBalance {
+    check: BalanceCheck,
+    amounts: Vec<(TransactionInput, Value)>,
+    tx_size: usize,
+    fee: Option<u64>,
+}
+
+impl StreamBalance {
+    pub fn new() -> Self {
+        StreamBalance {
+            check: BalanceCheck::new(BalanceContext::new(Some(&PARAMS))),
+            amounts: Vec::new(),
+            tx_size: 0,
+            fee: None,
+        }
+    }
+
+    /// set the context given by the host, the entries added before are dropped
+    fn set_context(
+        &mut self,
+        tx_size: u64,
+        pools: &[Vec<u8>],
+        deposits: &[(Vec<u8>, u64)],
+    ) -> Result<(), String> {
+        let pools = pools
+            .iter()
+            .map(|pool| PoolKeyHash::try_from_slice(pool))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to parse registered pool: {e}"))?;
+        let deposits = deposits
+            .iter()
+            .map(|(key_hash, deposit)| {
+                let key_hash = key_hash[..].try_into().map_err(|_| {
+                    format!("Stake key hash of {} bytes, expected 28", key_hash.len())
+                })?;
+                Ok((Credential::KeyHash(key_hash), *deposit))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let ctx = BalanceContext::new(Some(&PARAMS))
+            .with_registered_pools(&pools)
+            .with_stake_deposits(&deposits);
+        self.check = BalanceCheck::new(ctx);
+        self.tx_size = tx_size as usize;
+        self.fee = None;
+        Ok(())
+    }
+
+    /// add a confirmed entry
+    fn add_entry(&mut self, entry: &tx_stream::TxEntry) -> Result<(), BalanceError> {
+        match entry {
+            tx_stream::TxEntry::TxInput(hash, index) => match parse_input(hash, index) {
+                Ok(input) => self.check.input(&input),
+                Err(_) => Ok(()),
+            },
+            tx_stream::TxEntry::Fee(fee) => {
+                self.fee = Some(*fee);
+                self.check.fee(*fee)
+            }
+            tx_stream::TxEntry::Output(_, coin) => self.check.output(&Value::new(*coin)),
+        }
+    }
+
+    /// check the fee and the balance of the streamed transaction and start a
+    /// new one, the warnings are shown before the confirmation of its id
+    fn finish(&mut self) -> Vec<DisplayItem> {
+        let StreamBalance {
+            check,
+            amounts,
+            tx_size,
+            fee,
+        } = core::mem::replace(self, StreamBalance::new());
+        let mut warnings = Vec::new();
+        // without a context only the constant part of the fee is checked
+        if let Err(e) = check_fee(fee.unwrap_or(0), tx_size, Some(&PARAMS), None) {
+            warnings.push(e.to_display_item());
+        }
+        if let Err(e) = check.finish(&amounts) {
+            warnings.push(DisplayItem::new(
+                "Balance",
+                e.to_string(),
+                Severity::Warning,
+            ));
+        }
+        warnings
+    }
+
+    fn reset(&mut self) {
+        *self = StreamBalance::new();
+    }
+}
+
 /// Helper function to perform signing on the device
-pub fn sign(tx_id: &[u8], entropy: &Entropy, password: &[u8], path: &str) -> Out {
+pub fn sign(tx_id: &[u8], session: &mut Session, path: &str, now: u64) -> Out {
     match (TxId::from_bytes(tx_id), path.parse::<DerivationPath>()) {
-        (Ok(tx_id), Ok(path)) => {
-            let signature = embedano::sign_tx_id(&tx_id, entropy, password, &path);
-            Out::Sign(signature.to_bytes())
-        }
+        (Ok(tx_id), Ok(path)) => match session.sign_tx_id(&tx_id, &path, now) {
+            Ok(signature) => Out::Sign(signature.to_bytes()),
+            Err(e) => Out::Error(format!("Sign failed: {e}")),
+        },
         (Err(e), _) => Out::Error(format!("Decode tx_id failed: {e:?}")),
         (_, Err(e)) => Out::Error(format!("Decode path failed: {e}")),
     }
 }
 
 /// Helper function to obtain public key on the device
-pub fn get_pub_key(entropy: &Entropy, password: &[u8], path: &str) -> Out {
+pub fn get_pub_key(session: &mut Session, path: &str, now: u64) -> Out {
     match path.parse::<DerivationPath>() {
-        Ok(path) => {
-            let (_, pub_key) = embedano::derive_key_pair(entropy, password, &path);
-            Out::PubKey(pub_key.to_hex())
-        }
+        Ok(path) => match session.public_key(&path, now) {
+            Ok(pub_key) => Out::PubKey(pub_key.to_hex()),
+            Err(e) => Out::Error(format!("Public key derivation failed: {e}")),
+        },
         Err(e) => Out::Error(format!("Decode path failed: {e}")),
     }
 }
@@ -105,19 +222,19 @@ pub fn get_pub_key(entropy: &Entropy, password: &[u8], path: &str) -> Out {
 pub fn verify(
     tx_id: &[u8],
     signature: Vec<u8>,
-    entropy: &Entropy,
-    password: &[u8],
+    session: &mut Session,
     path: &str,
+    now: u64,
 ) -> Out {
     match (
         TxId::from_bytes(tx_id),
         Ed25519Signature::from_bytes(signature),
         path.parse::<DerivationPath>(),
     ) {
-        (Ok(tx_id), Ok(signature), Ok(path)) => {
-            let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
-            Out::Verify(pub_key.verify(tx_id.to_bytes(), &signature))
-        }
+        (Ok(tx_id), Ok(signature), Ok(path)) => match session.public_key(&path, now) {
+            Ok(pub_key) => Out::Verify(pub_key.verify(tx_id.to_bytes(), &signature)),
+            Err(e) => Out::Error(format!("Verify failed: {e}")),
+        },
         (Err(e), _, _) => Out::Error(format!("Decode tx_id failed: {e:?}")),
         (_, Err(e), _) => Out::Error(format!("Decode signature failed: {e}")),
         (_, _, Err(e)) => Out::Error(format!("Decode path failed: {e}")),
@@ -127,31 +244,65 @@ pub fn verify(
 pub fn process_stream_item(
     stream: tx_stream::TxStream,
     hasher: &mut MockHahser,
+    balance: &mut StreamBalance,
     entropy: &Entropy,
+    session: &mut Session,
+    clock: &mut Clock,
     confirm_button: &Pin<Input<PullUp>>,
     reject_button: &Pin<Input<PullUp>>,
 ) -> Out {
     match stream {
         tx_stream::TxStream::Entry(tx_entry) => {
             let out = process_tx_entry(&tx_entry, &confirm_button, &reject_button);
-            if let Out::StreamResponse(_) = out {
-                // If entry was confirmed, add it to rolling hash
-                hasher.add_entry(&tx_entry)
-            } else {
-                // If entry was rejected, reset rolling hash
-                hasher.reset()
+            // If entry was confirmed, add it to rolling hash and balance
+            let out = match out {
+                Out::StreamResponse(_) => match balance.add_entry(&tx_entry) {
+                    Ok(()) => {
+                        hasher.add_entry(&tx_entry);
+                        out
+                    }
+                    Err(e) => Out::Error(format!("Balance check failed: {e}")),
+                },
+                _ => out,
+            };
+            if let Out::Error(_) = out {
+                // If entry was rejected, reset rolling hash and balance
+                hasher.reset();
+                balance.reset();
             }
             out
         }
+        tx_stream::TxStream::InputAmount(hash, index, coin) => match parse_input(&hash, &index) {
+            Ok(input) => {
+                balance.amounts.push((input, Value::new(coin)));
+                Out::StreamResponse("Input amount received".into())
+            }
+            Err(err) => Out::Error(format!("Failed to parse input of the amount: {:?}", err)),
+        },
+        tx_stream::TxStream::Context(tx_size, pools, deposits) => {
+            match balance.set_context(tx_size, &pools, &deposits) {
+                Ok(()) => Out::StreamResponse("Context received".into()),
+                Err(e) => Out::Error(e),
+            }
+        }
         tx_stream::TxStream::Done(password, path) => {
             let tx_id = hasher.final_tx_id();
             let tx_id_hex = hex::encode(tx_id.clone());
-            prompt_confirmation(format!("Confirm and sign transaction id:\n{}", tx_id_hex));
+            let mut message = format!("Confirm and sign transaction id:\n{}", tx_id_hex);
+            // a wrong fee or an unbalanced transaction is shown before the confirmation
+            for warning in balance.finish().iter().rev() {
+                message = format!("{}\n\n{}", warning, message);
+            }
+            prompt_confirmation(message);
             match wait_confirmation("TxId".into(), confirm_button, reject_button) {
                 err @ Out::Error(_) => err,
                 Out::StreamResponse(s) if s == "TxId confirmed" => {
                     hprintln!("Firmware: signing transaction id {}", tx_id_hex);
-                    sign(&tx_id, entropy, &password, &path)
+                    let now = clock.now();
+                    match session.unlock(entropy, &password, now) {
+                        Ok(()) => sign(&tx_id, session, &path, now),
+                        Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                    }
                 }
                 other => Out::Error(format!(
                     "Unexpected Output for TxId confirmation: {:?}",
@@ -172,18 +323,23 @@ fn process_tx_entry(
     match entry {
         tx_stream::TxEntry::TxInput(hash, index) => match parse_input(hash, index) {
             Ok(tx_in) => {
-                prompt_confirmation(format!(
-                    "Confirm Tx input {}",
-                    tx_in.transaction_id.to_hex()
-                ));
+                prompt_confirmation(DisplayItem::input(&tx_in).to_string());
                 wait_confirmation("TxIn".into(), confirm_button, reject_button)
             }
             Err(err) => Out::Error(format!("Failed to parse streamed input: {:?}", err)),
         },
         tx_stream::TxEntry::Fee(fee) => {
-            prompt_confirmation(format!("Confirm fee {}", fee));
+            prompt_confirmation(DisplayItem::fee(*fee).to_string());
             wait_confirmation("Fee".into(), confirm_button, reject_button)
         }
+        tx_stream::TxEntry::Output(address, coin) => match Address::from_bytes(address.clone()) {
+            Ok(address) => {
+                let text = format!("{} to {}", format_ada(*coin), address);
+                prompt_confirmation(DisplayItem::new("Output", text, Severity::Info).to_string());
+                wait_confirmation("TxOut".into(), confirm_button, reject_button)
+            }
+            Err(err) => Out::Error(format!("Failed to parse streamed output: {}", err)),
+        },
     }
 }
 
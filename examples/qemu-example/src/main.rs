@@ -43,10 +43,10 @@ fn main() -> ! {
     // Try to parse transaction id and sign it
     let tx_id =
         TxId::from_hex("bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb").unwrap();
-    let signature = embedano::sign_tx_id(&tx_id, &entropy, password, &path);
+    let signature = embedano::sign_tx_id(&tx_id, &entropy, password, &path).unwrap();
 
     // Derive key pair using same path ant try to verify signature from `sign_tx_id`
-    let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
+    let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path).unwrap();
     assert!(pub_key.verify(tx_id.to_bytes(), &signature));
 
     // Check if public key can be derived from given entropy by signing nonce
@@ -68,7 +68,7 @@ fn main() -> ! {
     // E.g.:
 
     // Create root private key from entropy
-    let root_key = XPrvKey::from_entropy(&entropy, password);
+    let root_key = XPrvKey::from_entropy(&entropy, password).unwrap();
     hprintln!("Root key: {}", root_key.to_hex());
 
     // Derive private key for same path that was used in `derive_key_pair` above
@@ -4,8 +4,7 @@ extern crate alloc;
 use alloc::{format, string::String, vec::Vec};
 use core::prelude::rust_2021::derive;
 
-use cardano_embedded_sdk::api as embedano;
-use cardano_embedded_sdk::bip::bip39::Entropy;
+use cardano_embedded_sdk::api::session::{self, UnlockSession};
 use cardano_embedded_sdk::crypto::Ed25519Signature;
 use cardano_embedded_sdk::types::TxId;
 use derivation_path::DerivationPath;
@@ -58,12 +57,19 @@ pub enum Out {
     Acc(#[n(0)] i16, #[n(1)] i16, #[n(2)] i16, #[n(3)] Vec<u8>),
 }
 
-pub fn sign(tx_id: &[u8], entropy: &Entropy, password: &[u8], path: &str) -> Out {
+/// Unlock session of the device, keeping the 2 last used account keys.
+pub type Session = UnlockSession<2>;
+
+/// Session clock of the device, counting CPU cycles. It has to be read more
+/// often than the cycle counter wraps (about a minute and a half at 48 MHz).
+pub type Clock = session::Clock<fn() -> u32>;
+
+pub fn sign(tx_id: &[u8], session: &mut Session, path: &str, now: u64) -> Out {
     match (TxId::from_bytes(tx_id), path.parse::<DerivationPath>()) {
-        (Ok(tx_id), Ok(path)) => {
-            let signature = embedano::sign_tx_id(&tx_id, entropy, password, &path);
-            Out::Sign(signature.to_bytes())
-        }
+        (Ok(tx_id), Ok(path)) => match session.sign_tx_id(&tx_id, &path, now) {
+            Ok(signature) => Out::Sign(signature.to_bytes()),
+            Err(e) => Out::Error(format!("Sign failed: {e}")),
+        },
         (Err(e), _) => Out::Error(format!("Decode tx_id failed: {e:?}")),
         (_, Err(e)) => Out::Error(format!("Decode path failed: {e}")),
     }
@@ -72,19 +78,19 @@ pub fn sign(tx_id: &[u8], entropy: &Entropy, password: &[u8], path: &str) -> Out
 pub fn verify(
     tx_id: &[u8],
     signature: Vec<u8>,
-    entropy: &Entropy,
-    password: &[u8],
+    session: &mut Session,
     path: &str,
+    now: u64,
 ) -> Out {
     match (
         TxId::from_bytes(tx_id),
         Ed25519Signature::from_bytes(signature),
         path.parse::<DerivationPath>(),
     ) {
-        (Ok(tx_id), Ok(signature), Ok(path)) => {
-            let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
-            Out::Verify(pub_key.verify(tx_id.to_bytes(), &signature))
-        }
+        (Ok(tx_id), Ok(signature), Ok(path)) => match session.public_key(&path, now) {
+            Ok(pub_key) => Out::Verify(pub_key.verify(tx_id.to_bytes(), &signature)),
+            Err(e) => Out::Error(format!("Verify failed: {e}")),
+        },
         (Err(e), _, _) => Out::Error(format!("Decode tx_id failed: {e:?}")),
         (_, Err(e), _) => Out::Error(format!("Decode signature failed: {e}")),
         (_, _, Err(e)) => Out::Error(format!("Decode path failed: {e}")),
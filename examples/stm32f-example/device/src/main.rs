@@ -10,6 +10,7 @@ use panic_halt as _; // you can put a breakpoint on `rust_begin_unwind` to catch
 
 use alloc_cortex_m::CortexMHeap;
 use cortex_m::asm::delay;
+use cortex_m::peripheral::DWT;
 use cortex_m_rt::entry;
 
 use stm32f3xx_hal as hal;
@@ -37,11 +38,18 @@ static ALLOCATOR: CortexMHeap = CortexMHeap::empty();
 
 const HEAP_SIZE: usize = 1 * 1024; // in bytes
 
+// lock the keys after 5 minutes (of 48 MHz cycles) without request
+const SESSION_TIMEOUT: u64 = 5 * 60 * 48_000_000;
+
 #[entry]
 fn main() -> ! {
     unsafe { ALLOCATOR.init(cortex_m_rt::heap_start() as usize, HEAP_SIZE) }
 
     let dp = pac::Peripherals::take().unwrap();
+    let mut core = cortex_m::Peripherals::take().unwrap();
+    core.DCB.enable_trace();
+    core.DWT.enable_cycle_counter();
+    let mut clock = Clock::new(DWT::cycle_count);
     let mut flash = dp.FLASH.constrain();
     let mut rcc = dp.RCC.constrain();
 
@@ -151,10 +159,13 @@ fn main() -> ! {
         .build();
 
     let mut entropy: Option<Entropy> = None;
+    let mut session = Session::new(SESSION_TIMEOUT);
 
     let mut state = State::Read(Data::Head(vec![]));
 
     loop {
+        // keep the clock up to date and the session timeout running
+        session.is_unlocked(clock.now());
         if !usb_dev.poll(&mut [&mut serial]) || !serial.dtr() {
             continue;
         }
@@ -270,6 +281,7 @@ fn main() -> ! {
                         .map(|v| Entropy::from_mnemonics(&v))
                         .flatten()
                         .map(|v| entropy = Some(v));
+                    session.lock();
                     let out = if let Err(e) = result {
                         Out::Error(format!("Decode mnemonics failed: {e}"))
                     } else {
@@ -282,8 +294,12 @@ fn main() -> ! {
                 State::Exec(In::Sign(tx_id, password, path)) => {
                     led_se.set_high().ok();
 
+                    let now = clock.now();
                     let out = if let Some(entropy) = &entropy {
-                        sign(&tx_id, entropy, &password, &path)
+                        match session.unlock(entropy, &password, now) {
+                            Ok(()) => sign(&tx_id, &mut session, &path, now),
+                            Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                        }
                     } else {
                         Out::Error(format!("Sign failed: no entropy"))
                     };
@@ -295,8 +311,12 @@ fn main() -> ! {
                     led_n.set_high().ok();
                     led_s.set_high().ok();
 
+                    let now = clock.now();
                     let out = if let Some(entropy) = &entropy {
-                        verify(&tx_id, signature, entropy, &password, &path)
+                        match session.unlock(entropy, &password, now) {
+                            Ok(()) => verify(&tx_id, signature, &mut session, &path, now),
+                            Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                        }
                     } else {
                         Out::Error(format!("Verify failed: no entropy"))
                     };
@@ -306,7 +326,6 @@ fn main() -> ! {
                     led_s.set_low().ok();
                 }
                 State::Exec(In::Acc(password, path)) => {
-                    use cardano_embedded_sdk::api::sign_data;
                     use derivation_path::DerivationPath;
 
                     let out = match (&entropy, path.parse::<DerivationPath>(), lsm303dlhc.accel()) {
@@ -315,8 +334,14 @@ fn main() -> ! {
                                 .into_iter()
                                 .flatten()
                                 .collect::<Vec<u8>>();
-                            let signature = sign_data(&data, entropy, &password, &path);
-                            Out::Acc(x, y, z, signature.to_bytes())
+                            let now = clock.now();
+                            match session.unlock(entropy, &password, now) {
+                                Ok(()) => match session.sign_data(&data, &path, now) {
+                                    Ok(signature) => Out::Acc(x, y, z, signature.to_bytes()),
+                                    Err(e) => Out::Error(format!("Accel failed: {e}")),
+                                },
+                                Err(e) => Out::Error(format!("Unlock failed: {e}")),
+                            }
                         }
                         (None, _, _) => Out::Error(format!("Accel failed: no entropy")),
                         (_, Err(e), _) => Out::Error(format!("Decode path failed: {e}")),
@@ -53,7 +53,7 @@ fn main() {
         Entropy::from_mnemonics(&Mnemonics::from_string(&dictionary::ENGLISH, mnemonics).unwrap())
             .unwrap();
     let path: DerivationPath = "m/1852'/1815'/0'/0/0".parse().unwrap();
-    let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path);
+    let (_prv_key, pub_key) = embedano::derive_key_pair(&entropy, password, &path).unwrap();
 
     let tx_id =
         TxId::from_hex("bb1eb401cd03b0cd8caa08997df0a2ab226772c4d3a08adfb5a60ba34de12dfb").unwrap();
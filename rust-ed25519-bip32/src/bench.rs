@@ -5,13 +5,6 @@ fn new_sk() -> XPrv {
     XPrv::normalize_bytes_force3rd(b)
 }
 
-#[bench]
-fn derivate_hard_v1(b: &mut test::Bencher) {
-    let sk = new_sk();
-    b.iter(|| {
-        let _ = sk.derive(DerivationScheme::V1, 0x80000000);
-    })
-}
 #[bench]
 fn derivate_hard_v2(b: &mut test::Bencher) {
     let sk = new_sk();
@@ -21,32 +14,38 @@ fn derivate_hard_v2(b: &mut test::Bencher) {
 }
 
 #[bench]
-fn derivate_soft_v1_xprv(b: &mut test::Bencher) {
+fn derivate_soft_v2_xprv(b: &mut test::Bencher) {
     let sk = new_sk();
     b.iter(|| {
-        let _ = sk.derive(DerivationScheme::V1, 0);
+        let _ = sk.derive(DerivationScheme::V2, 0);
     })
 }
 #[bench]
-fn derivate_soft_v2_xprv(b: &mut test::Bencher) {
+fn derivate_soft_v2_xpub(b: &mut test::Bencher) {
     let sk = new_sk();
+    let pk = sk.public();
     b.iter(|| {
-        let _ = sk.derive(DerivationScheme::V2, 0);
+        let _ = pk.derive(DerivationScheme::V2, 0);
     })
 }
+
+const RANGE: core::ops::Range<DerivationIndex> = 0..100;
+
 #[bench]
-fn derivate_soft_v1_xpub(b: &mut test::Bencher) {
-    let sk = new_sk();
-    let pk = sk.public();
+fn derivate_soft_v2_xpub_loop(b: &mut test::Bencher) {
+    let pk = new_sk().public();
     b.iter(|| {
-        let _ = pk.derive(DerivationScheme::V1, 0);
+        for index in RANGE {
+            let _ = pk.derive(DerivationScheme::V2, index);
+        }
     })
 }
 #[bench]
-fn derivate_soft_v2_xpub(b: &mut test::Bencher) {
-    let sk = new_sk();
-    let pk = sk.public();
+fn derivate_soft_v2_xpub_range(b: &mut test::Bencher) {
+    let pk = new_sk().public();
     b.iter(|| {
-        let _ = pk.derive(DerivationScheme::V2, 0);
+        for child in pk.derive_range(DerivationScheme::V2, RANGE).unwrap() {
+            let _ = child;
+        }
     })
 }
@@ -1,17 +1,14 @@
 mod common;
 pub mod v2;
 
-use core::{
-    convert::{TryFrom, TryInto},
-    error::Error,
-    fmt,
-};
-use cryptoxide::curve25519::{Ge, Scalar};
+use core::{convert::TryFrom, error::Error, fmt, ops::Range};
+use cryptoxide::curve25519::{Ge, GeCached, Scalar};
 use cryptoxide::hmac::Hmac;
 use cryptoxide::mac::Mac;
 use cryptoxide::sha2::Sha512;
 
 use super::key::{mk_public_key, mk_xprv, mk_xpub, XPrv, XPub, XPRV_SIZE, XPUB_SIZE};
+use super::securemem;
 pub use common::{DerivationIndex, DerivationScheme, DerivationType};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -49,8 +46,8 @@ pub fn private(xprv: &XPrv, index: DerivationIndex, scheme: DerivationScheme) ->
      **/
 
     let ekey = xprv.extended_secret_key_bytes();
-    let kl: &[u8; 32] = &ekey[0..32].try_into().unwrap();
-    let kr: &[u8; 32] = &ekey[32..64].try_into().unwrap();
+    let kl = <&[u8; 32]>::try_from(&ekey[0..32]).unwrap();
+    let kr = <&[u8; 32]>::try_from(&ekey[32..64]).unwrap();
     let chaincode = &xprv.as_ref()[64..96];
 
     let mut zmac = Hmac::new(Sha512::new(), &chaincode);
@@ -78,13 +75,13 @@ pub fn private(xprv: &XPrv, index: DerivationIndex, scheme: DerivationScheme) ->
 
     let mut zout = [0u8; 64];
     zmac.raw_result(&mut zout);
-    let zl: &[u8; 32] = &zout[0..32].try_into().unwrap();
-    let zr: &[u8; 32] = &zout[32..64].try_into().unwrap();
+    let zl = <&[u8; 32]>::try_from(&zout[0..32]).unwrap();
+    let zr = <&[u8; 32]>::try_from(&zout[32..64]).unwrap();
 
     // left = kl + 8 * trunc28(zl)
-    let left = add_28_mul8(kl, zl, scheme);
+    let mut left = add_28_mul8(kl, zl, scheme);
     // right = zr + kr
-    let right = add_256bits(kr, zr, scheme);
+    let mut right = add_256bits(kr, zr, scheme);
 
     // note: we don't perform the check for curve order divisibility because it will not happen:
     // 1. all keys are in the range K=2^254 .. 2^255 (actually the even smaller range 2^254+2^253)
@@ -97,11 +94,17 @@ pub fn private(xprv: &XPrv, index: DerivationIndex, scheme: DerivationScheme) ->
 
     let mut out = [0u8; XPRV_SIZE];
     mk_xprv(&mut out, &left, &right, cc);
+    let child = XPrv::from_bytes(out);
 
     imac.reset();
     zmac.reset();
+    securemem::zero(&mut zout);
+    securemem::zero(&mut iout);
+    securemem::zero(&mut left);
+    securemem::zero(&mut right);
+    securemem::zero(&mut out);
 
-    XPrv::from_bytes(out)
+    child
 }
 
 fn point_of_trunc28_mul8(sk: &[u8; 32], scheme: DerivationScheme) -> [u8; 32] {
@@ -176,6 +179,83 @@ pub fn public(
     Ok(XPub::from_bytes(out))
 }
 
+/// Soft children of an `XPub` over a range of indexes, see `XPub::derive_range`.
+///
+/// The HMACs keyed with the chain code and fed with the parent public key,
+/// and the decoded parent point, are shared by all the indexes of the range.
+pub struct PublicRange {
+    zmac: Hmac<Sha512>,
+    imac: Hmac<Sha512>,
+    parent: GeCached,
+    indexes: Range<DerivationIndex>,
+    scheme: DerivationScheme,
+}
+
+pub fn public_range(
+    xpub: &XPub,
+    indexes: Range<DerivationIndex>,
+    scheme: DerivationScheme,
+) -> Result<PublicRange, DerivationError> {
+    if !indexes.is_empty() && indexes.end > 0x8000_0000 {
+        return Err(DerivationError::ExpectedSoftDerivation);
+    }
+    let pk = <&[u8; 32]>::try_from(&xpub.as_ref()[0..32]).unwrap();
+    let chaincode = &xpub.as_ref()[32..64];
+    let parent = Ge::from_bytes(pk).ok_or(DerivationError::InvalidAddition)?;
+
+    let mut zmac = Hmac::new(Sha512::new(), &chaincode);
+    let mut imac = Hmac::new(Sha512::new(), &chaincode);
+    zmac.input(&[0x2]);
+    zmac.input(pk);
+    imac.input(&[0x3]);
+    imac.input(pk);
+
+    Ok(PublicRange {
+        zmac,
+        imac,
+        parent: parent.to_cached(),
+        indexes,
+        scheme,
+    })
+}
+
+impl Iterator for PublicRange {
+    type Item = Result<XPub, DerivationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indexes.next()?;
+        let seri = serialize_index(index, self.scheme);
+
+        let mut zmac = self.zmac.clone();
+        zmac.input(&seri);
+        let mut zout = [0u8; 64];
+        zmac.raw_result(&mut zout);
+        let zl = <&[u8; 32]>::try_from(&zout[0..32]).unwrap();
+
+        // left = kl + 8 * trunc28(zl), the parent point is already decoded
+        let point = match Ge::from_bytes(&point_of_trunc28_mul8(zl, self.scheme)) {
+            Some(point) => point,
+            None => return Some(Err(DerivationError::InvalidAddition)),
+        };
+        let mut left = (&point + &self.parent).to_full().to_bytes();
+        left[31] ^= 0x80;
+
+        let mut imac = self.imac.clone();
+        imac.input(&seri);
+        let mut iout = [0u8; 64];
+        imac.raw_result(&mut iout);
+        let cc = &iout[32..];
+
+        let mut out = [0u8; XPUB_SIZE];
+        mk_xpub(&mut out, &left, cc);
+        Some(Ok(XPub::from_bytes(out)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
 impl fmt::Display for DerivationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
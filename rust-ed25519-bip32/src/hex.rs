@@ -1,13 +1,16 @@
-use alloc::{string::String, vec::Vec};
+use core::fmt;
 
 const ALPHABET: &'static [u8] = b"0123456789abcdef";
 
-pub fn encode(input: &[u8]) -> String {
-    let mut v = Vec::with_capacity(input.len() * 2);
+/// write the hexadecimal representation of `input` without allocating.
+pub fn write(f: &mut fmt::Formatter, input: &[u8]) -> fmt::Result {
     for &byte in input.iter() {
-        v.push(ALPHABET[(byte >> 4) as usize]);
-        v.push(ALPHABET[(byte & 0xf) as usize]);
+        write!(
+            f,
+            "{}{}",
+            ALPHABET[(byte >> 4) as usize] as char,
+            ALPHABET[(byte & 0xf) as usize] as char
+        )?;
     }
-
-    unsafe { String::from_utf8_unchecked(v) }
+    Ok(())
 }
@@ -8,8 +8,9 @@ use cryptoxide::hashing::sha2::Sha512;
 use core::convert::{TryFrom, TryInto};
 use core::error::Error;
 use core::hash::{Hash, Hasher};
+use core::ops::Range;
 
-use super::derivation::{self, DerivationError, DerivationIndex, DerivationScheme};
+use super::derivation::{self, DerivationError, DerivationIndex, DerivationScheme, PublicRange};
 use super::hex;
 use super::securemem;
 use super::signature::Signature;
@@ -87,7 +88,9 @@ impl XPrv {
         let mut extended_out = [0u8; XPRV_SIZE];
         extended_out[0..64].copy_from_slice(&Sha512::new().update(bytes).finalize());
         extended_out[64..96].clone_from_slice(chain_code);
-        Self::normalize_bytes_force3rd(extended_out)
+        let xprv = Self::normalize_bytes_force3rd(extended_out);
+        securemem::zero(&mut extended_out);
+        xprv
     }
 
     /// Takes a non-extended Ed25519 secret key and hash through SHA512 it in the same way the standard
@@ -104,6 +107,7 @@ impl XPrv {
         extended_out[0..64].copy_from_slice(&Sha512::new().update(bytes).finalize());
         extended_out[64..96].clone_from_slice(chain_code);
         let xprv = Self::normalize_bytes_ed25519(extended_out);
+        securemem::zero(&mut extended_out);
         if xprv.is_3rd_highest_bit_clear() {
             Ok(xprv)
         } else {
@@ -121,7 +125,9 @@ impl XPrv {
         let mut buf = [0u8; XPRV_SIZE];
         buf[0..64].copy_from_slice(sk);
         buf[64..96].copy_from_slice(chain_code);
-        Self::from_bytes(buf)
+        let xprv = Self::from_bytes(buf);
+        securemem::zero(&mut buf);
+        xprv
     }
 
     // Create a XPrv from the given bytes.
@@ -162,19 +168,9 @@ impl XPrv {
 
         let mut buf = [0u8; XPRV_SIZE];
         buf[..].clone_from_slice(bytes);
-        XPrv::from_bytes_verified(buf)
-    }
-
-    /// Create a `XPrv` from the given slice. This slice must be of size `XPRV_SIZE`
-    /// otherwise it will return `Err`.
-    ///
-    fn from_slice(bytes: &[u8]) -> Result<Self, PrivateKeyError> {
-        if bytes.len() != XPRV_SIZE {
-            return Err(PrivateKeyError::LengthInvalid(bytes.len()));
-        }
-        let mut buf = [0u8; XPRV_SIZE];
-        buf[..].clone_from_slice(bytes);
-        Ok(XPrv::from_bytes(buf))
+        let xprv = XPrv::from_bytes_verified(buf);
+        securemem::zero(&mut buf);
+        xprv
     }
 
     /// Get the associated `XPub`
@@ -237,19 +233,14 @@ impl PartialEq for XPrv {
     }
 }
 impl Eq for XPrv {}
-impl Clone for XPrv {
-    fn clone(&self) -> Self {
-        Self::from_slice(self.as_ref()).expect("it is already a safely constructed XPrv")
-    }
-}
 impl fmt::Debug for XPrv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl fmt::Display for XPrv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl AsRef<[u8]> for XPrv {
@@ -318,6 +309,17 @@ impl XPub {
         derivation::public(self, index, scheme)
     }
 
+    /// derive the soft children of the given range of indexes, sharing
+    /// the setup of the derivation between the indexes. Faster than calling
+    /// `derive` for each index when scanning addresses.
+    pub fn derive_range(
+        &self,
+        scheme: DerivationScheme,
+        indexes: Range<DerivationIndex>,
+    ) -> Result<PublicRange, DerivationError> {
+        derivation::public_range(self, indexes, scheme)
+    }
+
     pub fn get_without_chaincode(&self, out: &mut [u8; 32]) {
         out.clone_from_slice(&self.0[0..32])
     }
@@ -357,12 +359,12 @@ impl Hash for XPub {
 }
 impl fmt::Display for XPub {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl fmt::Debug for XPub {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl AsRef<[u8]> for XPub {
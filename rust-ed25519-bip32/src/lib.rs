@@ -23,8 +23,6 @@ extern crate std;
 #[cfg(feature = "with-bench")]
 extern crate test;
 
-extern crate alloc;
-
 mod derivation;
 mod hex;
 mod key;
@@ -38,6 +36,6 @@ mod tests;
 #[cfg(feature = "with-bench")]
 mod bench;
 
-pub use derivation::{DerivationError, DerivationIndex, DerivationScheme};
+pub use derivation::{DerivationError, DerivationIndex, DerivationScheme, PublicRange};
 pub use key::{PrivateKeyError, PublicKeyError, XPrv, XPub, XPRV_SIZE, XPUB_SIZE};
 pub use signature::{Signature, SignatureError, SIGNATURE_SIZE};
@@ -54,12 +54,12 @@ impl<T> PartialEq for Signature<T> {
 impl<T> Eq for Signature<T> {}
 impl<T> fmt::Display for Signature<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl<T> fmt::Debug for Signature<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.as_ref()))
+        hex::write(f, self.as_ref())
     }
 }
 impl<T> AsRef<[u8]> for Signature<T> {
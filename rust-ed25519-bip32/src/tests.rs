@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use std::vec::Vec;
 
 use super::*;
 
@@ -85,3 +85,25 @@ fn marshall_xprv() {
     assert_eq!(xprv.public(), xprv2.public());
     assert_eq!(cc, xprv.public().chain_code());
 }
+
+#[test]
+fn xpub_derive_range() {
+    let xpub = XPrv::from_bytes_verified(D1).unwrap().public();
+    let children = xpub
+        .derive_range(DerivationScheme::V2, 10..60)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(children.len(), 50);
+    for (index, child) in (10..60).zip(children) {
+        assert_eq!(child, xpub.derive(DerivationScheme::V2, index).unwrap());
+    }
+
+    assert_eq!(
+        xpub.derive_range(DerivationScheme::V2, 0x7fff_fffe..0x8000_0001)
+            .err(),
+        Some(DerivationError::ExpectedSoftDerivation)
+    );
+    let last = xpub.derive_range(DerivationScheme::V2, 0x7fff_ffff..0x8000_0000);
+    assert_eq!(last.unwrap().count(), 1);
+}